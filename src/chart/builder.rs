@@ -1,9 +1,12 @@
 use super::context::ChartContext;
 
-use crate::coord::{AsRangedCoord, RangedCoord, Shift};
+use crate::coord::{AsRangedCoord, Cartesian3d, PolarCoord, RangedCoord, Shift};
+
 use crate::drawing::backend::DrawingBackend;
 use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
+use crate::error::ErrorContext;
 use crate::style::TextStyle;
+use std::ops::Range;
 
 /// The enum used to specify the position of label area.
 /// This is used when we configure the label area size with the API `set_label_area_size`
@@ -121,6 +124,40 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
         self
     }
 
+    /// Apply the margin and the caption to the root area, the rest of the area is left for the
+    /// label areas and the plotting area
+    fn layout_chart_area(
+        &self,
+    ) -> Result<DrawingArea<DB, Shift>, DrawingAreaErrorKind<DB::ErrorType>> {
+        let mut drawing_area = DrawingArea::clone(self.root_area);
+
+        if *self.margin.iter().max().unwrap_or(&0) > 0 {
+            drawing_area = drawing_area.margin(
+                self.margin[0] as i32,
+                self.margin[1] as i32,
+                self.margin[2] as i32,
+                self.margin[3] as i32,
+            );
+        }
+
+        if let Some((ref title, ref style)) = self.title {
+            drawing_area = drawing_area
+                .titled(title, style.clone())
+                .map_err(|e| e.context(ErrorContext::Layout))?;
+        }
+
+        Ok(drawing_area)
+    }
+
+    /// Get the largest label area size, which is left around the chart without the label areas
+    /// on each side, e.g. the polar chart, `default` is used if no label area size is set
+    fn max_label_area_size(&self, default: u32) -> u32 {
+        match *self.label_area_size.iter().max().unwrap_or(&0) {
+            0 => default,
+            size => size,
+        }
+    }
+
     /// Build the chart with a 2D Cartesian coordinate system. The function will returns a chart
     /// context, where data series can be rendered on.
     /// - `x_spec`: The specification of X axis
@@ -137,20 +174,7 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
     > {
         let mut label_areas = [None, None, None, None];
 
-        let mut drawing_area = DrawingArea::clone(self.root_area);
-
-        if *self.margin.iter().max().unwrap_or(&0) > 0 {
-            drawing_area = drawing_area.margin(
-                self.margin[0] as i32,
-                self.margin[1] as i32,
-                self.margin[2] as i32,
-                self.margin[3] as i32,
-            );
-        }
-
-        if let Some((ref title, ref style)) = self.title {
-            drawing_area = drawing_area.titled(title, style.clone())?;
-        }
+        let mut drawing_area = self.layout_chart_area()?;
 
         let (w, h) = drawing_area.dim_in_pixel();
 
@@ -202,6 +226,78 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
                 pixel_range,
             )),
             series_anno: vec![],
+            point_index: None,
+        })
+    }
+
+    /// Build the chart with a polar coordinate system, the guest coordinate is a tuple of
+    /// `(angle, radius)`. See `PolarCoord` for how the angle is mapped.
+    /// The largest label area size is left around the circle for the angle labels, if no label
+    /// area size is set, 20 pixels are left.
+    /// - `angle`: The angle range, which is mapped onto a full turn, e.g. `0.0..360.0`
+    /// - `radius`: The radius range
+    /// - Returns: A chart context
+    pub fn build_polar(
+        &mut self,
+        angle: Range<f64>,
+        radius: Range<f64>,
+    ) -> Result<ChartContext<'a, DB, PolarCoord>, DrawingAreaErrorKind<DB::ErrorType>> {
+        let drawing_area = self.layout_chart_area()?;
+        let label_size = self.max_label_area_size(20);
+
+        let (w, h) = drawing_area.dim_in_pixel();
+        let (x0, y0) = drawing_area.get_base_pixel();
+        let center = (x0 + w as i32 / 2, y0 + h as i32 / 2);
+        let pixel_radius = (w.min(h) / 2).saturating_sub(label_size);
+
+        Ok(ChartContext {
+            x_label_area: [None, None],
+            y_label_area: [None, None],
+            drawing_area: drawing_area.apply_coord_spec(PolarCoord::new(
+                angle,
+                radius,
+                center,
+                pixel_radius,
+            )),
+            series_anno: vec![],
+            point_index: None,
+        })
+    }
+
+    /// Build the chart with a 3D cartesian coordinate system, the guest coordinate is a tuple
+    /// of `(x, y, z)`. See `Cartesian3d` for how the axes are projected, and use
+    /// `ChartContext::with_projection` to change the projection.
+    /// The largest label area size is left around the plotting area for the axis labels, if no
+    /// label area size is set, 30 pixels are left.
+    /// - `x_spec`: The specification of X axis
+    /// - `y_spec`: The specification of Y axis, which is the vertical one
+    /// - `z_spec`: The specification of Z axis
+    /// - Returns: A chart context
+    #[allow(clippy::type_complexity)]
+    pub fn build_cartesian_3d<X: AsRangedCoord, Y: AsRangedCoord, Z: AsRangedCoord>(
+        &mut self,
+        x_spec: X,
+        y_spec: Y,
+        z_spec: Z,
+    ) -> Result<
+        ChartContext<'a, DB, Cartesian3d<X::CoordDescType, Y::CoordDescType, Z::CoordDescType>>,
+        DrawingAreaErrorKind<DB::ErrorType>,
+    > {
+        let drawing_area = self.layout_chart_area()?;
+        let label_size = self.max_label_area_size(30);
+
+        let (w, h) = drawing_area.dim_in_pixel();
+        let (x0, y0) = drawing_area.get_base_pixel();
+        let center = (x0 + w as i32 / 2, y0 + h as i32 / 2);
+        let size = w.min(h).saturating_sub(label_size * 2);
+
+        Ok(ChartContext {
+            x_label_area: [None, None],
+            y_label_area: [None, None],
+            drawing_area: drawing_area
+                .apply_coord_spec(Cartesian3d::new(x_spec, y_spec, z_spec, center, size)),
+            series_anno: vec![],
+            point_index: None,
         })
     }
 }
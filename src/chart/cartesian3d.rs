@@ -0,0 +1,360 @@
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::fmt::Debug;
+
+use super::context::{ChartContext, SeriesAnno};
+
+use crate::coord::{normalize_value, Cartesian3d, Projection3d, Ranged};
+use crate::drawing::backend::{BackendCoord, DrawingBackend};
+use crate::drawing::DrawingAreaErrorKind;
+use crate::element::{Drawable, Path, PointCollection};
+use crate::error::ErrorContext;
+use crate::style::{Color, FontDesc, RGBColor, ShapeStyle, TextStyle};
+
+impl<'a, DB, X, Y, Z> ChartContext<'a, DB, Cartesian3d<X, Y, Z>>
+where
+    DB: DrawingBackend,
+    X: Ranged,
+    Y: Ranged,
+    Z: Ranged,
+    X::ValueType: Debug,
+    Y::ValueType: Debug,
+    Z::ValueType: Debug,
+{
+    /// Initialize an axes configuration object, the axes are drawn by calling the function
+    /// `Axes3dStyle::draw`
+    pub fn configure_axes<'b>(&'b mut self) -> Axes3dStyle<'a, 'b, X, Y, Z, DB> {
+        Axes3dStyle {
+            n_labels: [5, 5, 5],
+            line_style: None,
+            axis_style: None,
+            label_style: None,
+            format_x: None,
+            format_y: None,
+            format_z: None,
+            target: self,
+        }
+    }
+}
+
+impl<'a, DB, X, Y, Z> ChartContext<'a, DB, Cartesian3d<X, Y, Z>>
+where
+    DB: DrawingBackend,
+    X: Ranged,
+    Y: Ranged,
+    Z: Ranged,
+{
+    /// Change the projection of the 3D coordinate, which is applied to everything drawn after
+    /// the change
+    /// - `func`: The function that takes the current projection and returns the new one
+    pub fn with_projection<F: FnOnce(Projection3d) -> Projection3d>(
+        &mut self,
+        func: F,
+    ) -> &mut Self {
+        let coord = self.drawing_area.as_coord_spec_mut();
+        let projection = func(coord.projection());
+        coord.set_projection(projection);
+        self
+    }
+
+    /// Draw a data series on the 3D chart, the coordinate of the elements is a tuple of
+    /// `(x, y, z)`. The elements are drawn from the farthest to the nearest, by the average
+    /// depth of their points, so the near elements cover the far ones.
+    pub fn draw_series<E, R, S>(
+        &mut self,
+        series: S,
+    ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        for<'b> &'b E: PointCollection<'b, (X::ValueType, Y::ValueType, Z::ValueType)>,
+        E: Drawable<DB>,
+        R: Borrow<E>,
+        S: IntoIterator<Item = R>,
+    {
+        let idx = self.series_anno.len();
+
+        let coord = self.drawing_area.as_coord_spec();
+        let mut elements: Vec<_> = series
+            .into_iter()
+            .map(|element| {
+                let (sum, count) = element
+                    .borrow()
+                    .point_iter()
+                    .into_iter()
+                    .fold((0.0, 0), |(sum, count), p| {
+                        (sum + coord.depth(p.borrow()), count + 1)
+                    });
+                (sum / f64::from(count.max(1)), element)
+            })
+            .collect();
+        elements.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(Ordering::Equal));
+
+        for (_, element) in elements {
+            self.drawing_area
+                .draw(element.borrow())
+                .map_err(|e| e.context(ErrorContext::Series(idx)))?;
+        }
+        Ok(self.alloc_series_anno())
+    }
+}
+
+/// Get the normalized positions and the labels of the key points on the axis
+#[allow(clippy::type_complexity)]
+fn axis_points<R: Ranged>(
+    axis: &R,
+    max_points: usize,
+    fmt: Option<&dyn Fn(&R::ValueType) -> String>,
+) -> Vec<(f64, String)>
+where
+    R::ValueType: Debug,
+{
+    axis.key_points(max_points)
+        .into_iter()
+        .map(|v| {
+            let label = match fmt {
+                Some(fmt) => fmt(&v),
+                None => axis.format_label(&v).unwrap_or_else(|| format!("{:?}", v)),
+            };
+            (normalize_value(axis, &v), label)
+        })
+        .collect()
+}
+
+/// The style used to draw the axes of a 3D chart, which is made of the three panels of the
+/// cube that are the farthest from the viewer, the grid lines on the panels and the labels
+/// along the edges of the cube
+#[allow(clippy::type_complexity)]
+pub struct Axes3dStyle<'a, 'b, X: Ranged, Y: Ranged, Z: Ranged, DB: DrawingBackend> {
+    n_labels: [usize; 3],
+    line_style: Option<ShapeStyle>,
+    axis_style: Option<ShapeStyle>,
+    label_style: Option<TextStyle<'b>>,
+    format_x: Option<&'b dyn Fn(&X::ValueType) -> String>,
+    format_y: Option<&'b dyn Fn(&Y::ValueType) -> String>,
+    format_z: Option<&'b dyn Fn(&Z::ValueType) -> String>,
+    target: &'b mut ChartContext<'a, DB, Cartesian3d<X, Y, Z>>,
+}
+
+impl<'a, 'b, X, Y, Z, DB> Axes3dStyle<'a, 'b, X, Y, Z, DB>
+where
+    X: Ranged,
+    Y: Ranged,
+    Z: Ranged,
+    X::ValueType: Debug,
+    Y::ValueType: Debug,
+    Z::ValueType: Debug,
+    DB: DrawingBackend,
+{
+    /// Set the maximum number of the labels and the grid lines on the X axis
+    pub fn x_labels(&mut self, value: usize) -> &mut Self {
+        self.n_labels[0] = value;
+        self
+    }
+
+    /// Set the maximum number of the labels and the grid lines on the Y axis
+    pub fn y_labels(&mut self, value: usize) -> &mut Self {
+        self.n_labels[1] = value;
+        self
+    }
+
+    /// Set the maximum number of the labels and the grid lines on the Z axis
+    pub fn z_labels(&mut self, value: usize) -> &mut Self {
+        self.n_labels[2] = value;
+        self
+    }
+
+    /// Set the style of the grid lines
+    /// - `style`: The style of the grid lines
+    pub fn line_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.line_style = Some(style.into());
+        self
+    }
+
+    /// Set the style of the edges of the panels
+    /// - `style`: The style of the edges
+    pub fn axis_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.axis_style = Some(style.into());
+        self
+    }
+
+    /// Set the style of the labels
+    /// - `style`: The text style for the labels
+    pub fn label_style<T: Into<TextStyle<'b>>>(&mut self, style: T) -> &mut Self {
+        self.label_style = Some(style.into());
+        self
+    }
+
+    /// Set the formatter function for the X labels
+    /// - `fmt`: The formatter function
+    pub fn x_label_formatter(&mut self, fmt: &'b dyn Fn(&X::ValueType) -> String) -> &mut Self {
+        self.format_x = Some(fmt);
+        self
+    }
+
+    /// Set the formatter function for the Y labels
+    /// - `fmt`: The formatter function
+    pub fn y_label_formatter(&mut self, fmt: &'b dyn Fn(&Y::ValueType) -> String) -> &mut Self {
+        self.format_y = Some(fmt);
+        self
+    }
+
+    /// Set the formatter function for the Z labels
+    /// - `fmt`: The formatter function
+    pub fn z_label_formatter(&mut self, fmt: &'b dyn Fn(&Z::ValueType) -> String) -> &mut Self {
+        self.format_z = Some(fmt);
+        self
+    }
+
+    /// Draw the configured axes on the target chart
+    pub fn draw(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let default_mesh_color = RGBColor(0, 0, 0).mix(0.2);
+        let default_axis_color = RGBColor(0, 0, 0);
+        let default_label_font = FontDesc::new("Arial", 12.0);
+
+        let line_style = self
+            .line_style
+            .clone()
+            .unwrap_or_else(|| (&default_mesh_color).into());
+        let axis_style = self
+            .axis_style
+            .clone()
+            .unwrap_or_else(|| (&default_axis_color).into());
+        let label_style = self
+            .label_style
+            .clone()
+            .unwrap_or_else(|| default_label_font.into());
+
+        let area = &self.target.drawing_area;
+        let coord = area.as_coord_spec();
+        let (bx, by) = area.get_base_pixel();
+        let pixel_area = area.strip_coord_spec();
+
+        let to_pixel = |p: [f64; 3]| -> BackendCoord {
+            let (x, y, _) = coord.project_normalized(p);
+            (x.round() as i32 - bx, y.round() as i32 - by)
+        };
+
+        let x_points = axis_points(coord.x_spec(), self.n_labels[0], self.format_x);
+        let y_points = axis_points(coord.y_spec(), self.n_labels[1], self.format_y);
+        let z_points = axis_points(coord.z_spec(), self.n_labels[2], self.format_z);
+
+        let [fx, fy, fz] = coord.back_faces();
+        let mesh = |e: DrawingAreaErrorKind<DB::ErrorType>| e.context(ErrorContext::Mesh);
+        let line = |from: [f64; 3], to: [f64; 3], style: &ShapeStyle| {
+            pixel_area
+                .draw(&Path::new(
+                    vec![to_pixel(from), to_pixel(to)],
+                    style.clone(),
+                ))
+                .map_err(mesh)
+        };
+
+        // The grid lines on the back panels
+        for (x, _) in x_points.iter() {
+            line([*x, fy, -0.5], [*x, fy, 0.5], &line_style)?;
+            line([*x, -0.5, fz], [*x, 0.5, fz], &line_style)?;
+        }
+        for (y, _) in y_points.iter() {
+            line([fx, *y, -0.5], [fx, *y, 0.5], &line_style)?;
+            line([-0.5, *y, fz], [0.5, *y, fz], &line_style)?;
+        }
+        for (z, _) in z_points.iter() {
+            line([fx, -0.5, *z], [fx, 0.5, *z], &line_style)?;
+            line([-0.5, fy, *z], [0.5, fy, *z], &line_style)?;
+        }
+
+        // The edges of the back panels
+        let panels = [
+            [
+                [fx, -0.5, -0.5],
+                [fx, 0.5, -0.5],
+                [fx, 0.5, 0.5],
+                [fx, -0.5, 0.5],
+            ],
+            [
+                [-0.5, fy, -0.5],
+                [0.5, fy, -0.5],
+                [0.5, fy, 0.5],
+                [-0.5, fy, 0.5],
+            ],
+            [
+                [-0.5, -0.5, fz],
+                [0.5, -0.5, fz],
+                [0.5, 0.5, fz],
+                [-0.5, 0.5, fz],
+            ],
+        ];
+        for panel in panels.iter() {
+            let mut path: Vec<_> = panel.iter().map(|p| to_pixel(*p)).collect();
+            path.push(path[0]);
+            pixel_area
+                .draw(&Path::new(path, axis_style.clone()))
+                .map_err(mesh)?;
+        }
+
+        // The labels are placed on the front edges of the floor and a vertical edge, on the
+        // side facing away from the cube
+        let draw_label = |text: &str, anchor: [f64; 3], outward: [f64; 3]| {
+            let (w, h) = label_style.font.box_size(text).unwrap_or((0, 0));
+            let (px, py) = to_pixel(anchor);
+            let (dx, dy, _) = coord.project_vector(outward);
+            let len = (dx * dx + dy * dy).sqrt();
+            let (ux, uy) = if len > 1e-9 {
+                (dx / len, dy / len)
+            } else {
+                (0.0, 1.0)
+            };
+            let x = f64::from(px) + ux * 5.0 + f64::from(w) / 2.0 * (ux - 1.0);
+            let y = f64::from(py) + uy * 5.0 + f64::from(h) / 2.0 * (uy - 1.0);
+            pixel_area
+                .draw_text(text, &label_style, (x.round() as i32, y.round() as i32))
+                .map_err(mesh)
+        };
+
+        for (x, label) in x_points.iter() {
+            draw_label(label, [*x, fy, -fz], [0.0, fy, -fz])?;
+        }
+        for (y, label) in y_points.iter() {
+            draw_label(label, [-fx, *y, fz], [-fx, 0.0, fz])?;
+        }
+        for (z, label) in z_points.iter() {
+            draw_label(label, [-fx, fy, *z], [-fx, fy, 0.0])?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_cartesian3d_chart() {
+        let drawing_area = create_mocked_drawing_area(300, 300, |m| {
+            m.drop_check(|b| {
+                // 3 key points on each axis make 2 grid lines each, 3 panels and the series
+                assert_eq!(b.num_draw_path_call, 3 * 2 * 3 + 3 + 1);
+                assert_eq!(b.num_draw_text_call, 3 * 3);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_3d(0.0..1.0, 0.0..1.0, 0.0..1.0)
+            .expect("Create chart");
+        chart.with_projection(|mut p| {
+            p.yaw = 0.3;
+            p
+        });
+
+        chart.configure_axes().draw().expect("Drawing axes");
+
+        chart
+            .draw_series(LineSeries::new(
+                (0..=10).map(|i| (i as f64 / 10.0, i as f64 / 10.0, 0.5)),
+                &RED,
+            ))
+            .expect("Drawing series");
+
+        assert_eq!(chart.plotting_area().as_coord_spec().projection().yaw, 0.3);
+    }
+}
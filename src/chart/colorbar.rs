@@ -0,0 +1,196 @@
+use std::ops::Range;
+
+use super::ChartBuilder;
+use crate::coord::Shift;
+use crate::drawing::backend::DrawingBackend;
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
+use crate::series::HeatmapSeries;
+use crate::style::{ColorMap, TextStyle};
+
+/// The colorbar, which shows the gradient of a colormap along an axis of the values, so the
+/// colors of a heatmap or a color-mapped series can be read back as the values.
+///
+/// The colorbar fills the drawing area it's drawn on, which is usually split from the root
+/// area next to the chart.
+///
+/// ```rust,no_run
+/// use plotters::prelude::*;
+///
+/// let root = BitMapBackend::new("colorbar.png", (640, 480)).into_drawing_area();
+/// let (left, right) = root.split_horizentally(560);
+/// let mut chart = ChartBuilder::on(&left)
+///     .build_ranged(0f64..10f64, 0f64..10f64)
+///     .unwrap();
+/// let values: Vec<Vec<f64>> = (0..10)
+///     .map(|y| (0..10).map(|x| (x * y) as f64).collect())
+///     .collect();
+/// chart
+///     .draw_series(HeatmapSeries::new(
+///         (0.0, 0.0)..(10.0, 10.0),
+///         &values,
+///         0.0..81.0,
+///         ViridisColorMap,
+///     ))
+///     .unwrap();
+/// ColorBar::new(ViridisColorMap, 0.0..81.0)
+///     .desc("Value")
+///     .draw(&right.margin(10, 10, 0, 0))
+///     .unwrap();
+/// ```
+pub struct ColorBar<'a, M: ColorMap> {
+    colormap: M,
+    value_range: Range<f64>,
+    horizontal: bool,
+    steps: usize,
+    label_area_size: u32,
+    labels: usize,
+    label_style: Option<TextStyle<'a>>,
+    desc: Option<String>,
+}
+
+impl<'a, M: ColorMap> ColorBar<'a, M> {
+    /// Create a new vertical colorbar, the labels are on the right of the bar
+    /// - `colormap`: The colormap to show
+    /// - `value_range`: The range of the values which is mapped to the colormap
+    pub fn new(colormap: M, value_range: Range<f64>) -> Self {
+        Self {
+            colormap,
+            value_range,
+            horizontal: false,
+            steps: 256,
+            label_area_size: 40,
+            labels: 5,
+            label_style: None,
+            desc: None,
+        }
+    }
+
+    /// Make the colorbar horizontal, the labels are below the bar
+    pub fn horizontal(mut self) -> Self {
+        self.horizontal = true;
+        self
+    }
+
+    /// Set the number of the color steps the gradient is drawn with, which is 256 by default
+    pub fn steps(mut self, steps: usize) -> Self {
+        self.steps = steps.max(1);
+        self
+    }
+
+    /// Set the size of the area for the tick labels and the description
+    /// - `size`: The size in pixels
+    pub fn label_area_size(mut self, size: u32) -> Self {
+        self.label_area_size = size;
+        self
+    }
+
+    /// Set the maximum number of the tick labels
+    pub fn labels(mut self, labels: usize) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    /// Set the style of the tick labels
+    pub fn label_style<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        self.label_style = Some(style.into());
+        self
+    }
+
+    /// Set the description of the values, which is drawn along the axis
+    pub fn desc<S: Into<String>>(mut self, desc: S) -> Self {
+        self.desc = Some(desc.into());
+        self
+    }
+
+    /// Draw the colorbar
+    /// - `area`: The drawing area the colorbar fills
+    pub fn draw<DB: DrawingBackend>(
+        &self,
+        area: &DrawingArea<DB, Shift>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let Range { start, end } = self.value_range;
+        let step = (end - start) / self.steps as f64;
+        let values: Vec<_> = (0..self.steps)
+            .map(|idx| start + step * (idx as f64 + 0.5))
+            .collect();
+        let colormap = |t: f64| self.colormap.get(t);
+
+        let mut builder = ChartBuilder::on(area);
+        if self.horizontal {
+            let mut chart = builder
+                .x_label_area_size(self.label_area_size)
+                .build_ranged(start..end, 0f64..1f64)?;
+            chart.draw_series(HeatmapSeries::new(
+                (start, 0.0)..(end, 1.0),
+                &[&values],
+                start..end,
+                colormap,
+            ))?;
+
+            let mut mesh = chart.configure_mesh();
+            mesh.disable_x_mesh()
+                .disable_y_mesh()
+                .disable_y_axis()
+                .x_labels(self.labels);
+            if let Some(style) = &self.label_style {
+                mesh.label_style(style.clone());
+            }
+            if let Some(desc) = &self.desc {
+                mesh.x_desc(desc.clone());
+            }
+            mesh.draw()
+        } else {
+            let mut chart = builder
+                .right_y_label_area_size(self.label_area_size)
+                .build_ranged(0f64..1f64, start..end)?;
+            let rows: Vec<_> = values.iter().map(|v| [*v]).collect();
+            chart.draw_series(HeatmapSeries::new(
+                (0.0, start)..(1.0, end),
+                &rows,
+                start..end,
+                colormap,
+            ))?;
+
+            let mut mesh = chart.configure_mesh();
+            mesh.disable_x_mesh()
+                .disable_y_mesh()
+                .disable_x_axis()
+                .y_labels(self.labels);
+            if let Some(style) = &self.label_style {
+                mesh.label_style(style.clone());
+            }
+            if let Some(desc) = &self.desc {
+                mesh.y_desc(desc.clone());
+            }
+            mesh.draw()
+        }
+    }
+}
+
+#[cfg(all(test, feature = "svg"))]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_colorbar() {
+        let mut buffer = vec![];
+        {
+            let root = SVGBackend::with_buffer(&mut buffer, (60, 200)).into_drawing_area();
+            ColorBar::new(GrayscaleColorMap, 0.0..1.0)
+                .steps(10)
+                .labels(3)
+                .draw(&root)
+                .expect("Drawing colorbar");
+        }
+
+        let content = String::from_utf8(buffer).unwrap();
+        assert_eq!(content.matches("<rect").count(), 10);
+        let labels: Vec<_> = content
+            .split("<text")
+            .skip(1)
+            .map(|text| text[text.find('>').unwrap() + 1..text.find('<').unwrap()].trim())
+            .collect();
+        assert!(labels.contains(&"0.5"));
+        assert!(labels.contains(&"1"));
+    }
+}
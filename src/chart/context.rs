@@ -1,18 +1,23 @@
 use std::borrow::Borrow;
 use std::fmt::Debug;
 use std::marker::PhantomData;
-use std::ops::Range;
+use std::ops::{Add, Range};
 
+use super::data_cursor::{DataCursor, SeriesPointIndex};
 use super::dual_coord::DualCoordChartContext;
+use super::hit_region::HitRegions;
 use super::mesh::MeshStyle;
 use super::series::SeriesLabelStyle;
 
 use crate::coord::{
-    AsRangedCoord, CoordTranslate, MeshLine, Ranged, RangedCoord, ReverseCoordTranslate, Shift,
+    AsRangedCoord, CoordTranslate, DescreteRanged, MeshLine, Ranged, RangedCoord,
+    ReverseCoordTranslate, Shift,
 };
 use crate::drawing::backend::{BackendCoord, DrawingBackend};
-use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
-use crate::element::{Drawable, DynElement, IntoDynElement, Path, PointCollection};
+use crate::drawing::{BoundsBackend, DrawingArea, DrawingAreaErrorKind};
+use crate::element::{Drawable, DynElement, IntoDynElement, Path, PointCollection, Rectangle};
+use crate::error::ErrorContext;
+use crate::series::{BarColumn, GroupedBarSeries, StackedBarSeries};
 use crate::style::{FontTransform, ShapeStyle, TextStyle};
 
 /// The annotations (such as the label of the series, the legend element, etc)
@@ -70,6 +75,7 @@ pub struct ChartContext<'a, DB: DrawingBackend, CT: CoordTranslate> {
     pub(super) y_label_area: [Option<DrawingArea<DB, Shift>>; 2],
     pub(super) drawing_area: DrawingArea<DB, CT>,
     pub(super) series_anno: Vec<SeriesAnno<'a, DB>>,
+    pub(super) point_index: Option<SeriesPointIndex<CT::From>>,
 }
 
 impl<
@@ -97,8 +103,14 @@ impl<
             line_style_1: None,
             line_style_2: None,
             label_style: None,
-            format_x: &|x| format!("{:?}", x),
-            format_y: &|y| format!("{:?}", y),
+            format_x: None,
+            format_y: None,
+            x_key_points: None,
+            y_key_points: None,
+            x_minor_divisions: 10,
+            y_minor_divisions: 10,
+            draw_x_minor_ticks: false,
+            draw_y_minor_ticks: false,
             target: Some(self),
             _pahtom_data: PhantomData,
             x_desc: None,
@@ -120,12 +132,89 @@ impl<'a, DB: DrawingBackend + 'a, CT: CoordTranslate> ChartContext<'a, DB, CT> {
     }
 }
 
+impl<'a, DB: DrawingBackend, CT: CoordTranslate> ChartContext<'a, DB, CT> {
+    pub(super) fn alloc_series_anno(&mut self) -> &mut SeriesAnno<'a, DB> {
+        let idx = self.series_anno.len();
+        self.series_anno.push(SeriesAnno::new());
+        &mut self.series_anno[idx]
+    }
+
+    /// Index the data points of the series drawn after this call with their pixel positions,
+    /// so that the data point under the mouse can be found by `ChartContext::nearest_point`,
+    /// e.g. to show a hover tooltip in an interactive frontend. The index is disabled by
+    /// default, since it keeps a copy of every drawn data point.
+    pub fn index_points(&mut self)
+    where
+        CT::From: Clone,
+    {
+        if self.point_index.is_none() {
+            self.point_index = Some(SeriesPointIndex {
+                cursor: DataCursor::new(),
+                copy: Clone::clone,
+            });
+        }
+    }
+
+    /// Remove all the indexed data points, which should be called before the series are
+    /// drawn again, e.g. when the chart is redrawn for a new frame. The index is cleared
+    /// automatically when the range of the chart is changed, since the pixel positions are
+    /// outdated then.
+    pub fn clear_point_index(&mut self) {
+        if let Some(index) = self.point_index.as_mut() {
+            index.cursor.clear();
+        }
+    }
+
+    /// Find the indexed data point nearest to the pixel, see `ChartContext::index_points`.
+    /// Returns the index of the series, which is the order the series was drawn in on the chart
+    /// as in `ErrorContext::Series`, and the data value, or `None` if there's no data point
+    /// within the radius.
+    /// - `pixel`: The pixel of the backend, e.g. the mouse position
+    /// - `radius`: The max distance between the pixel and the data point in pixels
+    pub fn nearest_point(&self, pixel: BackendCoord, radius: f64) -> Option<(usize, &CT::From)> {
+        let ((series, value), dist) = self.point_index.as_ref()?.cursor.nearest_point(pixel)?;
+        if dist <= radius {
+            Some((*series, value))
+        } else {
+            None
+        }
+    }
+}
+
 impl<'a, DB: DrawingBackend, CT: ReverseCoordTranslate> ChartContext<'a, DB, CT> {
     /// Convert the chart context into an closure that can be used for coordinate translation
     pub fn into_coord_trans(self) -> impl Fn(BackendCoord) -> Option<CT::From> {
         let coord_spec = self.drawing_area.into_coord_spec();
         move |coord| coord_spec.reverse_translate(coord)
     }
+
+    /// Get the data value at the pixel of the backend, e.g. to show a tooltip for the mouse
+    /// position or inspect the clicked point of an interactive chart. Returns `None` if the
+    /// pixel isn't in the plotting area.
+    /// - `pixel`: The pixel of the backend
+    pub fn coord_from_pixel(&self, pixel: BackendCoord) -> Option<CT::From> {
+        self.drawing_area.as_coord_spec().reverse_translate(pixel)
+    }
+}
+
+/// The size of the minor ticks in pixels, the major ticks are 5 pixels
+const MINOR_TICK_SIZE: i32 = 3;
+
+/// Get the end points of the tick at `pos` on an axis, which is placed on the edge of a label
+/// area with the size `(tw, th)`
+fn tick_line(
+    orientation: (i16, i16),
+    pos: i32,
+    (tw, th): (u32, u32),
+    tick_size: i32,
+) -> Vec<BackendCoord> {
+    match orientation {
+        (dx, dy) if dx > 0 && dy == 0 => vec![(0, pos), (tick_size, pos)],
+        (dx, dy) if dx < 0 && dy == 0 => vec![(tw as i32 - tick_size, pos), (tw as i32, pos)],
+        (dx, dy) if dx == 0 && dy > 0 => vec![(pos, 0), (pos, tick_size)],
+        (dx, dy) if dx == 0 && dy < 0 => vec![(pos, th as i32 - tick_size), (pos, th as i32)],
+        _ => panic!("Bug: Invlid orientation specification"),
+    }
 }
 
 impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCoord<X, Y>> {
@@ -139,12 +228,75 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
         self.drawing_area.get_y_range()
     }
 
+    /// Change the visible range of the X axis, e.g. to zoom or pan an interactive chart. The
+    /// layout of the chart is kept, thus the frontend only needs to clear the drawing area,
+    /// then draw the mesh and the series again, rather than building a new chart for each
+    /// frame.
+    ///
+    /// ```rust,no_run
+    /// use plotters::prelude::*;
+    ///
+    /// let root = BitMapBackend::gif("zoom.gif", (640, 480), 100)
+    ///     .unwrap()
+    ///     .into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&root)
+    ///     .x_label_area_size(30)
+    ///     .y_label_area_size(30)
+    ///     .build_ranged(0.0..100.0, -1.0..1.0)
+    ///     .unwrap();
+    /// for frame in 0..10 {
+    ///     let width = 100.0 - frame as f64 * 9.0;
+    ///     chart.set_x_range(50.0 - width / 2.0..50.0 + width / 2.0);
+    ///     root.fill(&WHITE).unwrap();
+    ///     chart.configure_mesh().draw().unwrap();
+    ///     chart
+    ///         .draw_series(LineSeries::new(
+    ///             (0..=1000).map(|x| (x as f64 / 10.0, (x as f64 / 50.0).sin())),
+    ///             &RED,
+    ///         ))
+    ///         .unwrap();
+    ///     root.present().unwrap();
+    /// }
+    /// ```
+    /// - `range`: The new range, or the new axis
+    pub fn set_x_range<R: Into<X>>(&mut self, range: R) {
+        self.drawing_area
+            .as_coord_spec_mut()
+            .set_x_spec(range.into());
+        self.clear_point_index();
+    }
+
+    /// Change the visible range of the Y axis, see `ChartContext::set_x_range`
+    /// - `range`: The new range, or the new axis
+    pub fn set_y_range<R: Into<Y>>(&mut self, range: R) {
+        self.drawing_area
+            .as_coord_spec_mut()
+            .set_y_spec(range.into());
+        self.clear_point_index();
+    }
+
     /// Maps the coordinate to the backend coordinate. This is typically used
     /// with an interactive chart.
     pub fn backend_coord(&self, coord: &(X::ValueType, Y::ValueType)) -> BackendCoord {
         self.drawing_area.map_coordinate(coord)
     }
 
+    /// Add the data points of the element to the point index, if it's enabled
+    fn index_element_points<E>(&mut self, series_idx: usize, element: &E)
+    where
+        for<'b> &'b E: PointCollection<'b, (X::ValueType, Y::ValueType)>,
+    {
+        if let Some(index) = self.point_index.as_mut() {
+            for point in element.point_iter() {
+                let point = point.borrow();
+                index.cursor.push(
+                    (series_idx, (index.copy)(point)),
+                    self.drawing_area.map_coordinate(point),
+                );
+            }
+        }
+    }
+
     pub(super) fn draw_series_impl<E, R, S>(
         &mut self,
         series: S,
@@ -155,18 +307,16 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
         R: Borrow<E>,
         S: IntoIterator<Item = R>,
     {
+        let idx = self.series_anno.len();
         for element in series {
-            self.drawing_area.draw(element.borrow())?;
+            self.index_element_points(idx, element.borrow());
+            self.drawing_area
+                .draw(element.borrow())
+                .map_err(|e| e.context(ErrorContext::Series(idx)))?;
         }
         Ok(())
     }
 
-    pub(super) fn alloc_series_anno(&mut self) -> &mut SeriesAnno<'a, DB> {
-        let idx = self.series_anno.len();
-        self.series_anno.push(SeriesAnno::new());
-        &mut self.series_anno[idx]
-    }
-
     /// Draw a data series. A data series in Plotters is abstracted as an iterator of elements
     pub fn draw_series<E, R, S>(
         &mut self,
@@ -182,6 +332,92 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
         Ok(self.alloc_series_anno())
     }
 
+    /// Draw a data series with an accessible description, which is emitted by the backends
+    /// that support accessibility, e.g. as the `aria-label` of the group of the series in SVG.
+    /// - `series`: The data series to draw
+    /// - `description`: The description of the series
+    pub fn draw_series_with_description<E, R, S>(
+        &mut self,
+        series: S,
+        description: &str,
+    ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        for<'b> &'b E: PointCollection<'b, (X::ValueType, Y::ValueType)>,
+        E: Drawable<DB>,
+        R: Borrow<E>,
+        S: IntoIterator<Item = R>,
+    {
+        self.drawing_area.begin_group(description)?;
+        let result = self.draw_series_impl(series);
+        self.drawing_area.end_group()?;
+        result?;
+        Ok(self.alloc_series_anno())
+    }
+
+    /// Draw a data series and record the region of each element in the rendered image, which
+    /// can be exported as an HTML image map or JSON, see `HitRegions`.
+    /// - `series`: The data series to draw
+    /// - `regions`: The hit regions to append to
+    /// - `label`: The function that creates the label of the element with the given index
+    pub fn draw_series_with_regions<E, R, S, L>(
+        &mut self,
+        series: S,
+        regions: &mut HitRegions,
+        mut label: L,
+    ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        for<'b> &'b E: PointCollection<'b, (X::ValueType, Y::ValueType)>,
+        E: Drawable<DB> + Drawable<BoundsBackend>,
+        R: Borrow<E>,
+        S: IntoIterator<Item = R>,
+        L: FnMut(usize) -> String,
+    {
+        let series_idx = self.series_anno.len();
+        for (idx, element) in series.into_iter().enumerate() {
+            let element = element.borrow();
+            if let Some(bounds) = self.drawing_area.element_bounds(element)? {
+                regions.push(label(idx), bounds);
+            }
+            self.index_element_points(series_idx, element);
+            self.drawing_area
+                .draw(element)
+                .map_err(|e| e.context(ErrorContext::Series(series_idx)))?;
+        }
+        Ok(self.alloc_series_anno())
+    }
+
+    /// Draw a data series and index the data points with their pixel positions, which allows
+    /// finding the data point nearest to a pixel with `DataCursor::nearest_point`.
+    /// - `series`: The data series to draw
+    /// - `cursor`: The index to add the data points to
+    pub fn draw_series_with_cursor<E, R, S>(
+        &mut self,
+        series: S,
+        cursor: &mut DataCursor<(X::ValueType, Y::ValueType)>,
+    ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        for<'b> &'b E: PointCollection<'b, (X::ValueType, Y::ValueType)>,
+        E: Drawable<DB>,
+        R: Borrow<E>,
+        S: IntoIterator<Item = R>,
+        X::ValueType: Clone,
+        Y::ValueType: Clone,
+    {
+        let series_idx = self.series_anno.len();
+        for element in series {
+            let element = element.borrow();
+            for point in element.point_iter() {
+                let point = point.borrow();
+                cursor.push(point.clone(), self.drawing_area.map_coordinate(point));
+            }
+            self.index_element_points(series_idx, element);
+            self.drawing_area
+                .draw(element)
+                .map_err(|e| e.context(ErrorContext::Series(series_idx)))?;
+        }
+        Ok(self.alloc_series_anno())
+    }
+
     /// The actual function that draws the mesh lines.
     /// It also returns the label that suppose to be there.
     #[allow(clippy::type_complexity)]
@@ -189,26 +425,28 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
         &mut self,
         (r, c): (usize, usize),
         (x_mesh, y_mesh): (bool, bool),
+        key_points: (Option<&[X::ValueType]>, Option<&[Y::ValueType]>),
         mesh_line_style: &ShapeStyle,
         mut fmt_label: FmtLabel,
     ) -> Result<(Vec<(i32, String)>, Vec<(i32, String)>), DrawingAreaErrorKind<DB::ErrorType>>
     where
-        FmtLabel: FnMut(&MeshLine<X, Y>) -> Option<String>,
+        FmtLabel: FnMut(&RangedCoord<X, Y>, &MeshLine<X, Y>) -> Option<String>,
     {
         let mut x_labels = vec![];
         let mut y_labels = vec![];
-        self.drawing_area.draw_mesh(
+        let coord = self.drawing_area.as_coord_spec();
+        self.drawing_area.draw_mesh_with_key_points(
             |b, l| {
                 let draw;
                 match l {
                     MeshLine::XMesh((x, _), _, _) => {
-                        if let Some(label_text) = fmt_label(&l) {
+                        if let Some(label_text) = fmt_label(coord, &l) {
                             x_labels.push((x, label_text));
                         }
                         draw = x_mesh;
                     }
                     MeshLine::YMesh((_, y), _, _) => {
-                        if let Some(label_text) = fmt_label(&l) {
+                        if let Some(label_text) = fmt_label(coord, &l) {
                             y_labels.push((y, label_text));
                         }
                         draw = y_mesh;
@@ -222,6 +460,7 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
             },
             r,
             c,
+            key_points,
         )?;
         Ok((x_labels, y_labels))
     }
@@ -246,6 +485,43 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
             return Ok(());
         };
 
+        // The description takes a strip at the outer edge of the label area, and the tick labels
+        // are laid out in the rest of the area, so they never overlap
+        let mut desc_area = None;
+        let reserved;
+        let area = if let Some((text, style)) = axis_desc {
+            let style = if orientation.0 == 0 {
+                style.clone()
+            } else if orientation.0 == -1 {
+                style.transform(FontTransform::Rotate270)
+            } else {
+                style.transform(FontTransform::Rotate90)
+            };
+            let (w, h) = self
+                .drawing_area
+                .estimate_text_size(text, &style.font)
+                .unwrap_or((0, 0));
+            let (tw, th) = area.dim_in_pixel();
+            let (w, h) = (w.min(tw) as i32, h.min(th) as i32);
+            let (inner, outer) = match orientation {
+                (0, dy) if dy > 0 => area.split_vertically(th as i32 - h),
+                (0, _) => {
+                    let (outer, inner) = area.split_vertically(h);
+                    (inner, outer)
+                }
+                (dx, _) if dx < 0 => {
+                    let (outer, inner) = area.split_horizentally(w);
+                    (inner, outer)
+                }
+                _ => area.split_horizentally(tw as i32 - w),
+            };
+            desc_area = Some((outer, text, style));
+            reserved = inner;
+            &reserved
+        } else {
+            area
+        };
+
         let (x0, y0) = self.drawing_area.get_base_pixel();
 
         /* TODO: make this configure adjustable */
@@ -337,55 +613,129 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
                 area.draw_text(&t, label_style, (text_x, text_y))?;
 
                 if let Some(style) = axis_style {
-                    let (kx0, ky0, kx1, ky1) = match orientation {
-                        (dx, dy) if dx > 0 && dy == 0 => (0, *p - y0, tick_size, *p - y0),
-                        (dx, dy) if dx < 0 && dy == 0 => {
-                            (tw as i32 - tick_size, *p - y0, tw as i32, *p - y0)
-                        }
-                        (dx, dy) if dx == 0 && dy > 0 => (*p - x0, 0, *p - x0, tick_size),
-                        (dx, dy) if dx == 0 && dy < 0 => {
-                            (*p - x0, th as i32 - tick_size, *p - x0, th as i32)
-                        }
-                        _ => panic!("Bug: Invlid orientation specification"),
-                    };
-                    let line = Path::new(vec![(kx0, ky0), (kx1, ky1)], style.clone());
+                    let line = Path::new(
+                        tick_line(orientation, rp, (tw, th), tick_size),
+                        style.clone(),
+                    );
                     area.draw(&line)?;
                 }
             }
         }
 
-        if let Some((text, style)) = axis_desc {
-            let actual_style = if orientation.0 == 0 {
-                style.clone()
-            } else if orientation.0 == -1 {
-                style.transform(FontTransform::Rotate270)
-            } else {
-                style.transform(FontTransform::Rotate90)
-            };
-
+        if let Some((desc_area, text, style)) = desc_area {
+            let (tw, th) = desc_area.dim_in_pixel();
             let (w, h) = self
                 .drawing_area
-                .estimate_text_size(text, &actual_style.font)
+                .estimate_text_size(text, &style.font)
                 .unwrap_or((0, 0));
 
-            let (x0, y0) = match orientation {
-                (dx, dy) if dx > 0 && dy == 0 => (tw - w, (th - h) / 2),
-                (dx, dy) if dx < 0 && dy == 0 => (0, (th - h) / 2),
-                (dx, dy) if dx == 0 && dy > 0 => ((tw - w) / 2, th - h),
-                (dx, dy) if dx == 0 && dy < 0 => ((tw - w) / 2, 0),
-                _ => panic!("Bug: Invlid orientation specification"),
+            let (x0, y0) = if orientation.0 == 0 {
+                ((tw as i32 - w as i32) / 2, 0)
+            } else {
+                (0, (th as i32 - h as i32) / 2)
             };
 
-            area.draw_text(&text, &actual_style, (x0 as i32, y0 as i32))?;
+            desc_area.draw_text(text, &style, (x0, y0))?;
         }
 
         Ok(())
     }
 
-    #[allow(clippy::too_many_arguments)]
+    /// Draw the ticks without labels on the axis, e.g. the minor ticks
+    fn draw_ticks(
+        &self,
+        area: Option<&DrawingArea<DB, Shift>>,
+        style: &ShapeStyle,
+        positions: &[i32],
+        orientation: (i16, i16),
+        tick_size: i32,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let area = if let Some(target) = area {
+            target
+        } else {
+            return Ok(());
+        };
+
+        let (x0, y0) = self.drawing_area.get_base_pixel();
+        let dim = area.dim_in_pixel();
+
+        let (axis_range, base) = if orientation.0 == 0 {
+            (self.drawing_area.get_x_axis_pixel_range(), x0)
+        } else {
+            (self.drawing_area.get_y_axis_pixel_range(), y0)
+        };
+        let (low, high) = (
+            axis_range.start.min(axis_range.end) - base,
+            axis_range.start.max(axis_range.end) - base,
+        );
+
+        for p in positions {
+            let rp = *p - base;
+            if rp < low || high < rp {
+                continue;
+            }
+            let line = Path::new(tick_line(orientation, rp, dim, tick_size), style.clone());
+            area.draw(&line)?;
+        }
+
+        Ok(())
+    }
+
+    /// Draw the minor mesh lines and the minor ticks on the axes
+    #[allow(clippy::type_complexity)]
+    pub(super) fn draw_minor_mesh(
+        &mut self,
+        (x_points, y_points): (&[X::ValueType], &[Y::ValueType]),
+        (x_mesh, y_mesh): (bool, bool),
+        (x_ticks, y_ticks): (bool, bool),
+        mesh_line_style: &ShapeStyle,
+        tick_style: &ShapeStyle,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let (x_positions, y_positions) = self
+            .draw_mesh_lines(
+                (0, 0),
+                (x_mesh, y_mesh),
+                (Some(x_points), Some(y_points)),
+                mesh_line_style,
+                |_, _| Some(String::new()),
+            )
+            .map_err(|e| e.context(ErrorContext::Mesh))?;
+
+        let x_positions: Vec<_> = x_positions.into_iter().map(|(p, _)| p).collect();
+        let y_positions: Vec<_> = y_positions.into_iter().map(|(p, _)| p).collect();
+
+        for idx in 0..2 {
+            if x_ticks {
+                self.draw_ticks(
+                    self.x_label_area[idx].as_ref(),
+                    tick_style,
+                    &x_positions[..],
+                    (0, -1 + idx as i16 * 2),
+                    MINOR_TICK_SIZE,
+                )
+                .map_err(|e| e.context(ErrorContext::Axis('x')))?;
+            }
+
+            if y_ticks {
+                self.draw_ticks(
+                    self.y_label_area[idx].as_ref(),
+                    tick_style,
+                    &y_positions[..],
+                    (-1 + idx as i16 * 2, 0),
+                    MINOR_TICK_SIZE,
+                )
+                .map_err(|e| e.context(ErrorContext::Axis('y')))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments, clippy::type_complexity)]
     pub(super) fn draw_mesh<FmtLabel>(
         &mut self,
         (r, c): (usize, usize),
+        key_points: (Option<&[X::ValueType]>, Option<&[Y::ValueType]>),
         mesh_line_style: &ShapeStyle,
         label_style: &TextStyle,
         fmt_label: FmtLabel,
@@ -401,10 +751,17 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
         y_desc: Option<String>,
     ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
     where
-        FmtLabel: FnMut(&MeshLine<X, Y>) -> Option<String>,
+        FmtLabel: FnMut(&RangedCoord<X, Y>, &MeshLine<X, Y>) -> Option<String>,
     {
-        let (x_labels, y_labels) =
-            self.draw_mesh_lines((r, c), (x_mesh, y_mesh), mesh_line_style, fmt_label)?;
+        let (x_labels, y_labels) = self
+            .draw_mesh_lines(
+                (r, c),
+                (x_mesh, y_mesh),
+                key_points,
+                mesh_line_style,
+                fmt_label,
+            )
+            .map_err(|e| e.context(ErrorContext::Mesh))?;
 
         for idx in 0..2 {
             self.draw_axis_and_labels(
@@ -416,7 +773,8 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
                 (0, -1 + idx as i16 * 2),
                 x_desc.as_ref().map(|desc| (&desc[..], axis_desc_style)),
                 5,
-            )?;
+            )
+            .map_err(|e| e.context(ErrorContext::Axis('x')))?;
 
             self.draw_axis_and_labels(
                 self.y_label_area[idx].as_ref(),
@@ -427,7 +785,8 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
                 (-1 + idx as i16 * 2, 0),
                 y_desc.as_ref().map(|desc| (&desc[..], axis_desc_style)),
                 5,
-            )?;
+            )
+            .map_err(|e| e.context(ErrorContext::Axis('y')))?;
         }
 
         Ok(())
@@ -451,3 +810,111 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
         DualCoordChartContext::new(self, RangedCoord::new(x_coord, y_coord, pixel_range))
     }
 }
+
+impl<'a, DB: DrawingBackend + 'a, X: DescreteRanged, Y: Ranged>
+    ChartContext<'a, DB, RangedCoord<X, Y>>
+where
+    X::ValueType: Eq + Clone + 'static,
+    Y::ValueType: Clone + Default + Add<Output = Y::ValueType> + 'static,
+{
+    /// Draw a stacked bar series. Each layer of the series is drawn as a separate series, and
+    /// the layers with a label are added to the series labels with a legend of their style.
+    /// - `series`: The stacked bar series to draw
+    pub fn draw_stacked_series(
+        &mut self,
+        series: StackedBarSeries<X, Y::ValueType>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        self.draw_bar_columns(series.into_layers())
+    }
+}
+
+impl<'a, DB: DrawingBackend + 'a, X: DescreteRanged, Y: Ranged>
+    ChartContext<'a, DB, RangedCoord<X, Y>>
+where
+    X::ValueType: Eq + Clone + 'static,
+    Y::ValueType: Clone + Default + 'static,
+{
+    /// Draw a grouped bar series. Each group of the series is drawn as a separate series, and
+    /// the groups with a label are added to the series labels with a legend of their style.
+    /// - `series`: The grouped bar series to draw
+    pub fn draw_grouped_series(
+        &mut self,
+        series: GroupedBarSeries<X, Y::ValueType>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        self.draw_bar_columns(series.into_groups())
+    }
+}
+
+impl<'a, DB: DrawingBackend + 'a, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCoord<X, Y>> {
+    /// Draw each column of a bar series as a separate series, and add the columns with a label
+    /// to the series labels with a legend of their style
+    fn draw_bar_columns<E>(
+        &mut self,
+        columns: Vec<BarColumn<E>>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        for<'b> &'b E: PointCollection<'b, (X::ValueType, Y::ValueType)>,
+        E: Drawable<DB>,
+    {
+        for column in columns {
+            let anno = self.draw_series(column.bars)?;
+            if let Some(label) = column.label {
+                let style = column.style;
+                anno.label(label).legend(move |(x, y)| {
+                    Rectangle::new([(x, y - 5), (x + 10, y + 5)], style.clone())
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::coord::MeshLine;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_set_range() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |_| {});
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0.0..10.0, 0..100)
+            .unwrap();
+        assert_eq!(chart.backend_coord(&(5.0, 50)), (50, 50));
+
+        let mesh_x = |chart: &ChartContext<_, RangedCoord<RangedCoordf64, RangedCoordi32>>| {
+            let mut points = vec![];
+            let _ = chart
+                .plotting_area()
+                .as_coord_spec()
+                .draw_mesh::<(), _>(3, 3, |line| {
+                    if let MeshLine::XMesh(_, _, x) = line {
+                        points.push(*x);
+                    }
+                    Ok(())
+                });
+            points
+        };
+        assert_eq!(mesh_x(&chart), vec![0.0, 5.0, 10.0]);
+
+        chart.set_x_range(5.0..15.0);
+        chart.set_y_range(50..150);
+        assert_eq!(chart.x_range(), 5.0..15.0);
+        assert_eq!(chart.backend_coord(&(5.0, 50)), (0, 100));
+        assert_eq!(chart.backend_coord(&(10.0, 100)), (50, 50));
+        // The key points of the old range are not used anymore
+        assert_eq!(mesh_x(&chart), vec![5.0, 10.0, 15.0]);
+    }
+
+    #[test]
+    fn test_coord_from_pixel() {
+        let drawing_area = create_mocked_drawing_area(120, 120, |_| {});
+        let chart = ChartBuilder::on(&drawing_area)
+            .margin(10)
+            .build_ranged(0.0..10.0, (1.0..100.0).log_scale())
+            .unwrap();
+        let (x, y): (f64, f64) = chart.coord_from_pixel((60, 60)).unwrap();
+        assert!((x - 5.0).abs() < 1e-9 && (y - 10.0).abs() < 1e-9);
+        assert_eq!(chart.coord_from_pixel((5, 60)), None);
+    }
+}
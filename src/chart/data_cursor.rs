@@ -0,0 +1,210 @@
+use std::collections::HashMap;
+
+use crate::drawing::backend::BackendCoord;
+
+/// The size of the grid cells of the index in pixels
+const CELL_SIZE: i32 = 32;
+
+/// The spatial index of the drawn data points, which finds the data point nearest to a pixel.
+///
+/// The index is filled by `ChartContext::draw_series_with_cursor`, which stores the data values
+/// along with their pixel positions. This allows the interactive frontends to implement the
+/// hover tooltips and snapping without mapping the coordinates again.
+pub struct DataCursor<T> {
+    points: Vec<(T, BackendCoord)>,
+    cells: HashMap<(i32, i32), Vec<usize>>,
+}
+
+impl<T> Default for DataCursor<T> {
+    fn default() -> Self {
+        Self {
+            points: vec![],
+            cells: HashMap::new(),
+        }
+    }
+}
+
+/// The index of the data points of all the series drawn on a chart, which is enabled by
+/// `ChartContext::index_points`. Each data point is indexed with the index of its series.
+pub(super) struct SeriesPointIndex<T> {
+    pub(super) cursor: DataCursor<(usize, T)>,
+    /// Copy the data value, which is only known to be `Clone` when the index is enabled
+    pub(super) copy: fn(&T) -> T,
+}
+
+fn cell_of((x, y): BackendCoord) -> (i32, i32) {
+    (x.div_euclid(CELL_SIZE), y.div_euclid(CELL_SIZE))
+}
+
+fn distance((x0, y0): BackendCoord, (x1, y1): BackendCoord) -> f64 {
+    f64::from(x0 - x1).hypot(f64::from(y0 - y1))
+}
+
+impl<T> DataCursor<T> {
+    /// Create an empty index
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of the indexed data points
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Check if the index is empty
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Remove all the data points, e.g. before the series are drawn again
+    pub fn clear(&mut self) {
+        self.points.clear();
+        self.cells.clear();
+    }
+
+    /// Add a data point at the given pixel position
+    pub fn push(&mut self, value: T, pixel: BackendCoord) {
+        self.cells
+            .entry(cell_of(pixel))
+            .or_default()
+            .push(self.points.len());
+        self.points.push((value, pixel));
+    }
+
+    /// Find the data point nearest to the pixel.
+    /// Returns the data value and its distance to the pixel in pixels, or `None` if the index
+    /// is empty. If a few points are equally near, the first indexed one is returned.
+    pub fn nearest_point(&self, pixel: BackendCoord) -> Option<(&T, f64)> {
+        if self.points.is_empty() {
+            return None;
+        }
+
+        let (cx, cy) = cell_of(pixel);
+        let mut best: Option<(usize, f64)> = None;
+        let update = |idx: usize, best: &mut Option<(usize, f64)>| {
+            let dist = distance(self.points[idx].1, pixel);
+            let closer = match *best {
+                Some((best_idx, best_dist)) => {
+                    dist < best_dist || (dist == best_dist && idx < best_idx)
+                }
+                None => true,
+            };
+            if closer {
+                *best = Some((idx, dist));
+            }
+        };
+
+        // Search the rings of cells around the pixel, until no point in the next ring could be
+        // closer than the nearest one found so far. Once a ring has more cells than the index,
+        // scanning all the points is cheaper.
+        let mut found = false;
+        let mut ring = 0;
+        while 8 * ring as usize <= self.cells.len() {
+            if let Some((_, dist)) = best {
+                if dist <= f64::from((ring - 1) * CELL_SIZE) {
+                    found = true;
+                    break;
+                }
+            }
+            for dx in -ring..=ring {
+                for dy in -ring..=ring {
+                    if dx.abs() != ring && dy.abs() != ring {
+                        continue;
+                    }
+                    for &idx in self.cells.get(&(cx + dx, cy + dy)).into_iter().flatten() {
+                        update(idx, &mut best);
+                    }
+                }
+            }
+            ring += 1;
+        }
+
+        if !found {
+            for idx in 0..self.points.len() {
+                update(idx, &mut best);
+            }
+        }
+
+        best.map(|(idx, dist)| (&self.points[idx].0, dist))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_nearest_point() {
+        let mut cursor = DataCursor::new();
+        assert_eq!(cursor.nearest_point((0, 0)), None);
+        for i in 0..100 {
+            cursor.push(i, (i * 10, i * 5));
+        }
+        assert_eq!(cursor.nearest_point((52, 25)), Some((&5, 2.0)));
+        assert_eq!(cursor.nearest_point((-300, -400)), Some((&0, 500.0)));
+        assert_eq!(cursor.nearest_point((990, 500)), Some((&99, 5.0)));
+    }
+
+    #[test]
+    fn test_draw_series_with_cursor() {
+        let root = create_mocked_drawing_area(100, 100, |_| {});
+        let mut chart = ChartBuilder::on(&root)
+            .build_ranged(0..10, 0..10)
+            .expect("Create chart");
+
+        let mut cursor = DataCursor::new();
+        chart
+            .draw_series_with_cursor(
+                PointSeries::<_, _, Circle<_>>::new(vec![(1, 5), (3, 8)], 3, &RED),
+                &mut cursor,
+            )
+            .expect("Drawing failure");
+
+        assert_eq!(cursor.len(), 2);
+        assert_eq!(cursor.nearest_point((31, 20)), Some((&(3, 8), 1.0)));
+    }
+
+    #[test]
+    fn test_chart_nearest_point() {
+        let root = create_mocked_drawing_area(100, 100, |_| {});
+        let mut chart = ChartBuilder::on(&root)
+            .build_ranged(0..10, 0..10)
+            .expect("Create chart");
+
+        // The series drawn before the index is enabled are not indexed
+        chart
+            .draw_series(PointSeries::<_, _, Circle<_>>::new(vec![(5, 5)], 3, &RED))
+            .expect("Drawing failure");
+        assert_eq!(chart.nearest_point((50, 50), 10.0), None);
+
+        chart.index_points();
+        chart
+            .draw_series(PointSeries::<_, _, Circle<_>>::new(
+                vec![(1, 5), (3, 8)],
+                3,
+                &RED,
+            ))
+            .expect("Drawing failure");
+        chart
+            .draw_series(LineSeries::new(vec![(6, 1), (9, 2)], &BLUE))
+            .expect("Drawing failure");
+
+        assert_eq!(chart.nearest_point((31, 20), 5.0), Some((1, &(3, 8))));
+        assert_eq!(chart.nearest_point((62, 88), 5.0), Some((2, &(6, 1))));
+        assert_eq!(chart.nearest_point((62, 70), 5.0), None);
+
+        // The pixel positions are outdated once the range is changed, and the series drawn
+        // afterwards still have the same indices as their annotations
+        chart.set_x_range(0..20);
+        assert_eq!(chart.nearest_point((31, 20), 5.0), None);
+        chart
+            .draw_series(PointSeries::<_, _, Circle<_>>::new(vec![(6, 8)], 3, &RED))
+            .expect("Drawing failure");
+        assert_eq!(chart.series_anno.len(), 4);
+        assert_eq!(chart.nearest_point((31, 20), 5.0), Some((3, &(6, 8))));
+
+        chart.clear_point_index();
+        assert_eq!(chart.nearest_point((31, 20), 5.0), None);
+    }
+}
@@ -0,0 +1,317 @@
+use std::fmt::Debug;
+
+use super::{si_prefix_formatter, ChartBuilder, ChartContext};
+use crate::coord::{AsRangedCoord, Ranged, RangedCoord, RangedCoordf64, Shift};
+use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
+use crate::element::{CandleStick, Drawable, PointCollection};
+use crate::style::{Color, ShapeStyle, GREEN, RED};
+
+/// The chart of a pane of the financial chart
+pub type FinancialPane<'a, DB, X> = ChartContext<'a, DB, RangedCoord<X, RangedCoordf64>>;
+
+/// The financial chart, which splits the drawing area into the price pane with the candlesticks
+/// on the top and the volume pane with the volume bars below. Both panes share the X axis, i.e.
+/// they have the same X range, the same plotting width and the same key points, thus the mesh
+/// lines and the bars of a time are aligned. The X labels are only shown below the volume pane.
+///
+/// ```rust,no_run
+/// use chrono::{Duration, TimeZone, Utc};
+/// use plotters::prelude::*;
+///
+/// let start = Utc.ymd(2019, 10, 1);
+/// let data: Vec<_> = (0..30)
+///     .map(|d| {
+///         let open = 100.0 + (d as f64 / 3.0).sin() * 10.0;
+///         let close = open + (d as f64).cos() * 3.0;
+///         let (high, low) = (open.max(close) + 1.0, open.min(close) - 1.0);
+///         (start + Duration::days(d), open, high, low, close, 1e6 + d as f64 * 1e4)
+///     })
+///     .collect();
+///
+/// let root = BitMapBackend::new("financial.png", (1024, 768)).into_drawing_area();
+/// root.fill(&WHITE).unwrap();
+/// let chart = FinancialChart::new(data.clone())
+///     .volume_ratio(0.3)
+///     .candle_width(15);
+/// let panes = chart.split_area(&root);
+/// let (mut price, _) = chart
+///     .draw(&panes, start - Duration::days(1)..start + Duration::days(30))
+///     .unwrap();
+/// price
+///     .draw_series(LineSeries::new(data.iter().map(|d| (d.0, d.4)), &BLUE))
+///     .unwrap();
+/// ```
+pub struct FinancialChart<X> {
+    /// The times, the open, high, low and close prices and the volumes
+    data: Vec<(X, f64, f64, f64, f64, f64)>,
+    volume_ratio: f64,
+    gain_style: ShapeStyle,
+    loss_style: ShapeStyle,
+    candle_width: u32,
+    x_labels: usize,
+    x_label_area_size: u32,
+    y_label_area_size: u32,
+}
+
+impl<X: Clone + Debug + 'static> FinancialChart<X> {
+    /// Create a new financial chart
+    /// - `data`: The iterator of the times, the open, high, low and close prices and the
+    ///   volumes
+    pub fn new<I: IntoIterator<Item = (X, f64, f64, f64, f64, f64)>>(data: I) -> Self {
+        Self {
+            data: data.into_iter().collect(),
+            volume_ratio: 0.25,
+            gain_style: GREEN.filled(),
+            loss_style: RED.filled(),
+            candle_width: 5,
+            x_labels: 10,
+            x_label_area_size: 30,
+            y_label_area_size: 60,
+        }
+    }
+
+    /// Set the ratio of the height of the volume pane to the whole area, which is 0.25 by
+    /// default
+    pub fn volume_ratio(mut self, ratio: f64) -> Self {
+        self.volume_ratio = ratio.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the styles of the candlesticks and the volume bars
+    /// - `gain_style`: The style if the close price is higher than the open price
+    /// - `loss_style`: The style otherwise
+    pub fn styles<GS: Into<ShapeStyle>, LS: Into<ShapeStyle>>(
+        mut self,
+        gain_style: GS,
+        loss_style: LS,
+    ) -> Self {
+        self.gain_style = gain_style.into();
+        self.loss_style = loss_style.into();
+        self
+    }
+
+    /// Set the width of the candlesticks and the volume bars in pixels
+    pub fn candle_width(mut self, width: u32) -> Self {
+        self.candle_width = width;
+        self
+    }
+
+    /// Set the max number of the X labels, which is also used for the vertical mesh lines of
+    /// both panes
+    pub fn x_labels(mut self, labels: usize) -> Self {
+        self.x_labels = labels;
+        self
+    }
+
+    /// Set the size of the label areas in pixels
+    /// - `x`: The height of the X label area below the volume pane
+    /// - `y`: The width of the Y label areas, which is the same for both panes so that the
+    ///   plotting areas are aligned
+    pub fn label_area_size(mut self, x: u32, y: u32) -> Self {
+        self.x_label_area_size = x;
+        self.y_label_area_size = y;
+        self
+    }
+
+    /// Get the range of the prices with a margin of 5% of the range on both sides
+    fn price_range(&self) -> std::ops::Range<f64> {
+        let (low, high) = self.data.iter().fold(
+            (f64::INFINITY, f64::NEG_INFINITY),
+            |(low, high), &(_, _, h, l, _, _)| (low.min(l), high.max(h)),
+        );
+        if low > high {
+            return 0.0..1.0;
+        }
+        let margin = ((high - low) * 0.05).max(f64::EPSILON);
+        low - margin..high + margin
+    }
+
+    /// Get the range of the volumes, which starts from 0 and leaves 10% space on the top
+    fn volume_range(&self) -> std::ops::Range<f64> {
+        let max = self.data.iter().fold(0.0f64, |max, d| max.max(d.5));
+        0.0..if max > 0.0 { max * 1.1 } else { 1.0 }
+    }
+
+    fn style_of(&self, open: f64, close: f64) -> ShapeStyle {
+        if open < close {
+            self.gain_style.clone()
+        } else {
+            self.loss_style.clone()
+        }
+    }
+
+    /// Split the drawing area into the areas of the price pane and the volume pane, which are
+    /// drawn by `FinancialChart::draw`
+    pub fn split_area<DB: DrawingBackend>(
+        &self,
+        area: &DrawingArea<DB, Shift>,
+    ) -> (DrawingArea<DB, Shift>, DrawingArea<DB, Shift>) {
+        let (_, height) = area.dim_in_pixel();
+        let volume_height = (f64::from(height) * self.volume_ratio).round() as i32;
+        area.split_vertically(height as i32 - volume_height)
+    }
+
+    /// Draw the financial chart on the panes
+    /// - `panes`: The areas of the price pane and the volume pane, see
+    ///   `FinancialChart::split_area`
+    /// - `x_spec`: The specification of the X axis shared by both panes
+    /// - returns the charts of the price pane and the volume pane, which can draw more series,
+    ///   e.g. the moving average of the prices
+    #[allow(clippy::type_complexity)]
+    pub fn draw<'a, DB: DrawingBackend, XS>(
+        &self,
+        (upper, lower): &'a (DrawingArea<DB, Shift>, DrawingArea<DB, Shift>),
+        x_spec: XS,
+    ) -> Result<
+        (
+            FinancialPane<'a, DB, XS::CoordDescType>,
+            FinancialPane<'a, DB, XS::CoordDescType>,
+        ),
+        DrawingAreaErrorKind<DB::ErrorType>,
+    >
+    where
+        XS: AsRangedCoord + Clone,
+        XS::CoordDescType: Ranged<ValueType = X>,
+    {
+        let mut price = ChartBuilder::on(upper)
+            .y_label_area_size(self.y_label_area_size)
+            .build_ranged(x_spec.clone(), self.price_range())?;
+        price
+            .configure_mesh()
+            .x_labels(self.x_labels)
+            .y_desc("Price")
+            .draw()?;
+        price.draw_series(self.data.iter().map(|(x, open, high, low, close, _)| {
+            CandleStick::new(
+                x.clone(),
+                *open,
+                *high,
+                *low,
+                *close,
+                self.gain_style.clone(),
+                self.loss_style.clone(),
+                self.candle_width,
+            )
+        }))?;
+
+        let mut volume = ChartBuilder::on(lower)
+            .x_label_area_size(self.x_label_area_size)
+            .y_label_area_size(self.y_label_area_size)
+            .build_ranged(x_spec, self.volume_range())?;
+        volume
+            .configure_mesh()
+            .x_labels(self.x_labels)
+            .y_labels(3)
+            .y_label_formatter(&si_prefix_formatter)
+            .y_desc("Volume")
+            .draw()?;
+        volume.draw_series(
+            self.data
+                .iter()
+                .map(|(x, open, _, _, close, volume)| VolumeBar {
+                    points: [(x.clone(), 0.0), (x.clone(), *volume)],
+                    style: self.style_of(*open, *close).filled(),
+                    width: self.candle_width,
+                }),
+        )?;
+
+        Ok((price, volume))
+    }
+}
+
+/// The bar of the volume of a time, which is as wide as the candlestick
+struct VolumeBar<X> {
+    points: [(X, f64); 2],
+    style: ShapeStyle,
+    width: u32,
+}
+
+impl<'a, X: 'a> PointCollection<'a, (X, f64)> for &'a VolumeBar<X> {
+    type Borrow = &'a (X, f64);
+    type IntoIter = &'a [(X, f64)];
+    fn point_iter(self) -> &'a [(X, f64)] {
+        &self.points
+    }
+}
+
+impl<X, DB: DrawingBackend> Drawable<DB> for VolumeBar<X> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        match (points.next(), points.next()) {
+            (Some(a), Some(b)) => {
+                let (l, r) = (
+                    self.width as i32 / 2,
+                    self.width as i32 - self.width as i32 / 2,
+                );
+                backend.draw_rect(
+                    (a.0 - l, a.1.min(b.1)),
+                    (a.0 + r, a.1.max(b.1)),
+                    &self.style,
+                    self.style.filled,
+                )
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "svg"))]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_financial_chart() {
+        let data = vec![
+            (1, 10.0, 12.0, 9.0, 11.0, 100.0),
+            (2, 11.0, 11.5, 8.0, 9.0, 200.0),
+        ];
+        let chart = FinancialChart::new(data).candle_width(4);
+        let volume = chart.volume_range();
+        assert!(volume.start == 0.0 && (volume.end - 220.0).abs() < 1e-9);
+        let price = chart.price_range();
+        assert!((price.start - 7.8).abs() < 1e-9 && (price.end - 12.2).abs() < 1e-9);
+
+        let mut buffer = vec![];
+        let panes = {
+            let root = SVGBackend::with_buffer(&mut buffer, (200, 200)).into_drawing_area();
+            let panes = chart.split_area(&root);
+            let (price, volume) = chart.draw(&panes, 0..3).unwrap();
+            let (price, volume) = (price.plotting_area(), volume.plotting_area());
+            vec![
+                (price.get_pixel_range(), price.map_coordinate(&(2, 0.0))),
+                (volume.get_pixel_range(), volume.map_coordinate(&(2, 0.0))),
+            ]
+        };
+
+        let ((price_x, price_y), price_pos) = panes[0].clone();
+        let ((volume_x, volume_y), volume_pos) = panes[1].clone();
+        // The panes share the X axis
+        assert_eq!(price_x, volume_x);
+        assert_eq!(price_pos.0, volume_pos.0);
+        // The volume pane takes 1/4 of the height below the price pane
+        assert!(price_y.end <= volume_y.start);
+        assert_eq!(volume_y.end, 170);
+        assert_eq!(volume_y.start, 150);
+
+        // The second bar is a loss, and its volume is the max volume
+        let content = String::from_utf8(buffer).unwrap();
+        let rects: Vec<_> = content
+            .split("<rect")
+            .skip(1)
+            .map(|rect| &rect[..rect.find('>').unwrap()])
+            .collect();
+        assert_eq!(rects.len(), 4);
+        // The candlesticks and the volume bars are aligned
+        for rect in &rects {
+            assert!(rect.contains(r#"x="151""#) == rect.contains("#FF0000"));
+        }
+        for attr in &[r#"y="151""#, r#"height="19""#, r##"fill="#FF0000""##] {
+            assert!(rects[3].contains(attr));
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use crate::drawing::backend::BackendCoord;
+
+/// The region of a single datum in the rendered image
+#[derive(Clone, Debug, PartialEq)]
+pub struct HitRegion {
+    /// The label of the datum, e.g. the tooltip text
+    pub label: String,
+    /// The upper-left corner of the region in pixels
+    pub upper_left: BackendCoord,
+    /// The bottom-right corner of the region in pixels (inclusive)
+    pub bottom_right: BackendCoord,
+}
+
+/// The hit regions recorded by `ChartContext::draw_series_with_regions`.
+///
+/// The regions can be exported as JSON or as an HTML image map, so that a web page is able to
+/// attach tooltips and click handlers to a static PNG or SVG chart.
+#[derive(Clone, Debug, Default)]
+pub struct HitRegions {
+    regions: Vec<HitRegion>,
+}
+
+impl HitRegions {
+    /// Create an empty set of regions
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get all the recorded regions, in the drawing order
+    pub fn regions(&self) -> &[HitRegion] {
+        &self.regions
+    }
+
+    pub(super) fn push(
+        &mut self,
+        label: String,
+        (upper_left, bottom_right): (BackendCoord, BackendCoord),
+    ) {
+        self.regions.push(HitRegion {
+            label,
+            upper_left,
+            bottom_right,
+        });
+    }
+
+    /// Export the regions as a JSON array of objects with `label`, `x0`, `y0`, `x1` and `y1`
+    pub fn to_json(&self) -> String {
+        let regions: Vec<_> = self
+            .regions
+            .iter()
+            .map(|r| {
+                format!(
+                    "{{\"label\":{},\"x0\":{},\"y0\":{},\"x1\":{},\"y1\":{}}}",
+                    json_string(&r.label),
+                    r.upper_left.0,
+                    r.upper_left.1,
+                    r.bottom_right.0,
+                    r.bottom_right.1
+                )
+            })
+            .collect();
+        format!("[{}]", regions.join(","))
+    }
+
+    /// Export the regions as an HTML image map, which is used by `<img usemap="#name">`.
+    /// The later drawn datum is on the top, thus it comes first in the map.
+    /// - `name`: The name of the map
+    pub fn to_image_map(&self, name: &str) -> String {
+        let mut html = format!("<map name=\"{}\">\n", html_escape(name));
+        for r in self.regions.iter().rev() {
+            html.push_str(&format!(
+                "<area shape=\"rect\" coords=\"{},{},{},{}\" title=\"{}\" alt=\"{}\">\n",
+                r.upper_left.0,
+                r.upper_left.1,
+                r.bottom_right.0 + 1,
+                r.bottom_right.1 + 1,
+                html_escape(&r.label),
+                html_escape(&r.label)
+            ));
+        }
+        html.push_str("</map>\n");
+        html
+    }
+}
+
+/// Quote the string as a JSON string. The `<` is also escaped, so the JSON can be embedded in
+/// a script element safely.
+pub(crate) fn json_string(s: &str) -> String {
+    let mut ret = String::with_capacity(s.len() + 2);
+    ret.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => ret.push_str("\\\""),
+            '\\' => ret.push_str("\\\\"),
+            '\n' => ret.push_str("\\n"),
+            '<' => ret.push_str("\\u003c"),
+            c if (c as u32) < 0x20 => ret.push_str(&format!("\\u{:04x}", c as u32)),
+            c => ret.push(c),
+        }
+    }
+    ret.push('"');
+    ret
+}
+
+fn html_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_json_string() {
+        assert_eq!(json_string("a\"b\\c\n"), "\"a\\\"b\\\\c\\n\"");
+        assert_eq!(json_string("</script>"), "\"\\u003c/script>\"");
+        assert_eq!(json_string("\t"), "\"\\u0009\"");
+    }
+
+    #[test]
+    fn test_hit_regions() {
+        let root = create_mocked_drawing_area(100, 100, |_| {});
+        let mut chart = ChartBuilder::on(&root)
+            .build_ranged(0..10, 0..10)
+            .expect("Create chart");
+
+        let mut regions = HitRegions::new();
+        chart
+            .draw_series_with_regions(
+                vec![(1, 5), (3, 8)]
+                    .into_iter()
+                    .map(|(x, y)| Rectangle::new([(x, 0), (x + 1, y)], RED.filled())),
+                &mut regions,
+                |idx| format!("bar <{}>", idx),
+            )
+            .expect("Drawing failure");
+
+        assert_eq!(regions.regions().len(), 2);
+        assert_eq!(regions.regions()[0].upper_left, (10, 50));
+        assert_eq!(regions.regions()[0].bottom_right, (20, 99));
+        assert_eq!(
+            regions.to_json(),
+            "[{\"label\":\"bar \\u003c0>\",\"x0\":10,\"y0\":50,\"x1\":20,\"y1\":99},\
+             {\"label\":\"bar \\u003c1>\",\"x0\":30,\"y0\":20,\"x1\":40,\"y1\":99}]"
+        );
+        assert!(regions.to_image_map("bars").starts_with(
+            "<map name=\"bars\">\n<area shape=\"rect\" coords=\"30,20,41,100\" \
+             title=\"bar &lt;1&gt;\" alt=\"bar &lt;1&gt;\">\n"
+        ));
+    }
+}
@@ -0,0 +1,190 @@
+use std::fmt::Debug;
+
+use super::{ChartBuilder, ChartContext, MeshStyle};
+use crate::coord::{AsRangedCoord, Ranged, RangedCoord, Shift};
+use crate::drawing::backend::DrawingBackend;
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
+
+/// The subplots stacked vertically whose X axes are linked. All the charts have the same X
+/// range, the same plotting width and the same key points, which are computed only once, and
+/// the X labels are only drawn below the bottom chart. Changing the X range with
+/// `LinkedCharts::set_x_range` changes it for all the charts, thus they are zoomed and panned
+/// together.
+///
+/// ```rust,no_run
+/// use plotters::prelude::*;
+///
+/// let root = BitMapBackend::new("linked.png", (640, 480)).into_drawing_area();
+/// let areas = root.split_evenly((2, 1));
+/// let mut linked = LinkedCharts::build(&areas, 0.0..10.0, vec![-1.0..1.0, 0.0..5.0], 30, 40)
+///     .unwrap();
+/// linked.set_x_range(2.0..8.0);
+/// linked.draw_meshes(10, |_, mesh| {
+///     mesh.disable_x_mesh();
+/// })
+/// .unwrap();
+/// let charts = linked.charts_mut();
+/// charts[0]
+///     .draw_series(LineSeries::new((0..100).map(|x| x as f64 / 10.0).map(|x| (x, x.sin())), &RED))
+///     .unwrap();
+/// charts[1]
+///     .draw_series(LineSeries::new((0..100).map(|x| x as f64 / 10.0).map(|x| (x, x.sqrt())), &BLUE))
+///     .unwrap();
+/// ```
+pub struct LinkedCharts<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> {
+    charts: Vec<ChartContext<'a, DB, RangedCoord<X, Y>>>,
+}
+
+impl<'a, DB: DrawingBackend + 'a, X: Ranged, Y: Ranged> LinkedCharts<'a, DB, X, Y> {
+    /// Build the charts with a linked X axis
+    /// - `areas`: The areas of the charts from the top to the bottom, e.g. the areas returned
+    ///   by `DrawingArea::split_evenly((n, 1))`
+    /// - `x_spec`: The specification of the X axis shared by all the charts
+    /// - `y_specs`: The specifications of the Y axes of the charts, one for each area. The
+    ///   areas without a Y axis are not used.
+    /// - `x_label_area_size`: The height of the X label area below the bottom chart
+    /// - `y_label_area_size`: The width of the Y label areas, which is the same for all the
+    ///   charts so that the plotting areas are aligned
+    pub fn build<XS, YS, I>(
+        areas: &'a [DrawingArea<DB, Shift>],
+        x_spec: XS,
+        y_specs: I,
+        x_label_area_size: u32,
+        y_label_area_size: u32,
+    ) -> Result<Self, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        XS: AsRangedCoord<CoordDescType = X> + Clone,
+        YS: AsRangedCoord<CoordDescType = Y>,
+        I: IntoIterator<Item = YS>,
+    {
+        let areas: Vec<_> = areas.iter().zip(y_specs).collect();
+        let count = areas.len();
+        let mut charts = Vec::with_capacity(count);
+        for (idx, (area, y_spec)) in areas.into_iter().enumerate() {
+            let mut builder = ChartBuilder::on(area);
+            builder.y_label_area_size(y_label_area_size);
+            if idx + 1 == count {
+                builder.x_label_area_size(x_label_area_size);
+            }
+            charts.push(builder.build_ranged(x_spec.clone(), y_spec)?);
+        }
+        Ok(Self { charts })
+    }
+
+    /// Get the charts from the top to the bottom
+    pub fn charts(&self) -> &[ChartContext<'a, DB, RangedCoord<X, Y>>] {
+        &self.charts
+    }
+
+    /// Get the mutable charts from the top to the bottom, e.g. to draw the series
+    pub fn charts_mut(&mut self) -> &mut [ChartContext<'a, DB, RangedCoord<X, Y>>] {
+        &mut self.charts
+    }
+
+    /// Unlink the charts
+    pub fn into_charts(self) -> Vec<ChartContext<'a, DB, RangedCoord<X, Y>>> {
+        self.charts
+    }
+
+    /// Get the key points of the shared X axis, which are computed once for all the charts
+    /// - `max_points`: The max number of the key points
+    pub fn x_key_points(&self, max_points: usize) -> Vec<X::ValueType>
+    where
+        X::ValueType: Clone,
+    {
+        self.charts
+            .first()
+            .map(|chart| {
+                chart
+                    .plotting_area()
+                    .as_coord_spec()
+                    .x_key_points(max_points)
+                    .to_vec()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Get the range of the shared X axis
+    pub fn x_range(&self) -> Option<std::ops::Range<X::ValueType>> {
+        self.charts.first().map(ChartContext::x_range)
+    }
+
+    /// Change the range of the shared X axis of all the charts, see
+    /// `ChartContext::set_x_range`
+    pub fn set_x_range<R: Into<X> + Clone>(&mut self, range: R) {
+        for chart in &mut self.charts {
+            chart.set_x_range(range.clone());
+        }
+    }
+
+    /// Draw the meshes of all the charts with the same X key points
+    /// - `x_labels`: The max number of the X labels, which is also used for the vertical mesh
+    ///   lines of all the charts
+    /// - `configure`: The function that configures the mesh of the chart with the given index,
+    ///   e.g. to set the Y labels or the axis descriptions
+    pub fn draw_meshes<F>(
+        &mut self,
+        x_labels: usize,
+        mut configure: F,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        X::ValueType: Clone + Debug,
+        Y::ValueType: Debug,
+        F: FnMut(usize, &mut MeshStyle<'a, '_, X, Y, DB>),
+    {
+        let key_points = self.x_key_points(x_labels);
+        for (idx, chart) in self.charts.iter_mut().enumerate() {
+            let mut mesh = chart.configure_mesh();
+            mesh.x_key_points(key_points.iter().cloned());
+            configure(idx, &mut mesh);
+            mesh.draw()?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "svg"))]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_linked_charts() {
+        let mut buffer = vec![];
+        {
+            let root = SVGBackend::with_buffer(&mut buffer, (100, 100)).into_drawing_area();
+            let areas = root.split_evenly((2, 1));
+            let mut linked =
+                LinkedCharts::build(&areas, 0..10, vec![0..10, 0..100], 20, 0).unwrap();
+            assert_eq!(linked.charts().len(), 2);
+
+            linked.set_x_range(0..20);
+            assert_eq!(linked.x_range(), Some(0..20));
+            let (top, bottom) = (&linked.charts()[0], &linked.charts()[1]);
+            assert_eq!(top.x_range(), 0..20);
+            assert_eq!(
+                top.plotting_area().get_pixel_range().0,
+                bottom.plotting_area().get_pixel_range().0
+            );
+            assert_eq!(bottom.plotting_area().get_pixel_range().1, 50..80);
+
+            assert_eq!(linked.x_key_points(3), vec![0, 10, 20]);
+            linked
+                .draw_meshes(3, |_, mesh| {
+                    mesh.y_labels(2);
+                })
+                .unwrap();
+        }
+
+        // The X labels are only drawn below the bottom chart, and the label of 20 is out of the
+        // right edge of the image
+        let content = String::from_utf8(buffer).unwrap();
+        let labels: Vec<_> = content.split("<text").skip(1).collect();
+        assert_eq!(labels.len(), 2);
+        for label in labels {
+            let y = label.split(" y=\"").nth(1).unwrap();
+            let y: i32 = y[..y.find('"').unwrap()].parse().unwrap();
+            assert!(y > 80);
+        }
+    }
+}
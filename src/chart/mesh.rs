@@ -75,6 +75,46 @@ where
         self
     }
 
+    /// Set the key points of the X axis, see `MeshStyle::x_key_points`
+    /// - `points`: The key points
+    pub fn x_key_points<I: IntoIterator<Item = X::ValueType>>(&mut self, points: I) -> &mut Self {
+        self.style.x_key_points(points);
+        self
+    }
+
+    /// Set the key points of the Y axis, see `MeshStyle::y_key_points`
+    /// - `points`: The key points
+    pub fn y_key_points<I: IntoIterator<Item = Y::ValueType>>(&mut self, points: I) -> &mut Self {
+        self.style.y_key_points(points);
+        self
+    }
+
+    /// Set the number of minor divisions of the X axis, see `MeshStyle::x_minor_divisions`
+    /// - `value`: The maximum number of minor divisions between two labels
+    pub fn x_minor_divisions(&mut self, value: usize) -> &mut Self {
+        self.style.x_minor_divisions(value);
+        self
+    }
+
+    /// Set the number of minor divisions of the Y axis, see `MeshStyle::y_minor_divisions`
+    /// - `value`: The maximum number of minor divisions between two labels
+    pub fn y_minor_divisions(&mut self, value: usize) -> &mut Self {
+        self.style.y_minor_divisions(value);
+        self
+    }
+
+    /// Draw the minor ticks on the X axis
+    pub fn x_minor_ticks(&mut self) -> &mut Self {
+        self.style.x_minor_ticks();
+        self
+    }
+
+    /// Draw the minor ticks on the Y axis
+    pub fn y_minor_ticks(&mut self) -> &mut Self {
+        self.style.y_minor_ticks();
+        self
+    }
+
     /// Set the axis description's style. If not given, use label style instead.
     /// - `style`: The text style that would be applied to descriptions
     pub fn axis_desc_style<T: Into<TextStyle<'b>>>(&mut self, style: T) -> &mut Self {
@@ -103,6 +143,7 @@ where
 }
 
 /// The struct that is used for tracking the configuration of a mesh of any chart
+#[allow(clippy::type_complexity)]
 pub struct MeshStyle<'a, 'b, X: Ranged, Y: Ranged, DB>
 where
     DB: DrawingBackend,
@@ -122,8 +163,14 @@ where
     pub(super) line_style_2: Option<ShapeStyle>,
     pub(super) axis_style: Option<ShapeStyle>,
     pub(super) label_style: Option<TextStyle<'b>>,
-    pub(super) format_x: &'b dyn Fn(&X::ValueType) -> String,
-    pub(super) format_y: &'b dyn Fn(&Y::ValueType) -> String,
+    pub(super) format_x: Option<&'b dyn Fn(&X::ValueType) -> String>,
+    pub(super) format_y: Option<&'b dyn Fn(&Y::ValueType) -> String>,
+    pub(super) x_key_points: Option<Vec<X::ValueType>>,
+    pub(super) y_key_points: Option<Vec<Y::ValueType>>,
+    pub(super) x_minor_divisions: usize,
+    pub(super) y_minor_divisions: usize,
+    pub(super) draw_x_minor_ticks: bool,
+    pub(super) draw_y_minor_ticks: bool,
     pub(super) target: Option<&'b mut ChartContext<'a, DB, RangedCoord<X, Y>>>,
     pub(super) _pahtom_data: PhantomData<(X, Y)>,
 }
@@ -132,6 +179,8 @@ impl<'a, 'b, X, Y, DB> MeshStyle<'a, 'b, X, Y, DB>
 where
     X: Ranged,
     Y: Ranged,
+    X::ValueType: Debug,
+    Y::ValueType: Debug,
     DB: DrawingBackend,
 {
     /// The offset of x labels. This is used when we want to place the label in the middle of
@@ -201,7 +250,7 @@ where
         self
     }
 
-    /// Set the style for the fine grind grid
+    /// Set the style for the fine grind grid, which is drawn at the minor key points
     /// - `style`: The fine grind grid style
     pub fn line_style_2<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
         self.line_style_2 = Some(style.into());
@@ -215,17 +264,68 @@ where
         self
     }
 
-    /// Set the formatter function for the X label text
+    /// Set the formatter function for the X label text, which replaces the format of the
+    /// coordinate (see `Ranged::format_label`) or the default `Debug` formatting,
+    /// e.g. `&|v| format!("{:.1}%", v * 100.0)`, `&si_prefix_formatter`, `&engineering_formatter`
+    /// or `&scientific_formatter`
     /// - `fmt`: The formatter function
     pub fn x_label_formatter(&mut self, fmt: &'b dyn Fn(&X::ValueType) -> String) -> &mut Self {
-        self.format_x = fmt;
+        self.format_x = Some(fmt);
         self
     }
 
-    /// Set the formatter function for the Y label text
+    /// Set the formatter function for the Y label text, which replaces the format of the
+    /// coordinate (see `Ranged::format_label`) or the default `Debug` formatting,
+    /// e.g. `&|v| format!("{:.1}%", v * 100.0)`, `&si_prefix_formatter`, `&engineering_formatter`
+    /// or `&scientific_formatter`
     /// - `fmt`: The formatter function
     pub fn y_label_formatter(&mut self, fmt: &'b dyn Fn(&Y::ValueType) -> String) -> &mut Self {
-        self.format_y = fmt;
+        self.format_y = Some(fmt);
+        self
+    }
+
+    /// Set the key points of the X axis, which are used for the mesh lines and the labels
+    /// instead of the ones picked by the coordinate, e.g. to pin the ticks at some dates or
+    /// threshold levels. The fine mesh and the minor ticks aren't drawn for the axis with the
+    /// given key points.
+    /// - `points`: The key points
+    pub fn x_key_points<I: IntoIterator<Item = X::ValueType>>(&mut self, points: I) -> &mut Self {
+        self.x_key_points = Some(points.into_iter().collect());
+        self
+    }
+
+    /// Set the key points of the Y axis, see `x_key_points`
+    /// - `points`: The key points
+    pub fn y_key_points<I: IntoIterator<Item = Y::ValueType>>(&mut self, points: I) -> &mut Self {
+        self.y_key_points = Some(points.into_iter().collect());
+        self
+    }
+
+    /// Set the number of minor divisions of the X axis. The minor key points are used for the
+    /// fine grid and the minor ticks between the labels. `0` disables the fine grid of the
+    /// X axis. The default is 10.
+    /// - `value`: The maximum number of minor divisions between two labels
+    pub fn x_minor_divisions(&mut self, value: usize) -> &mut Self {
+        self.x_minor_divisions = value;
+        self
+    }
+
+    /// Set the number of minor divisions of the Y axis, see `x_minor_divisions`
+    /// - `value`: The maximum number of minor divisions between two labels
+    pub fn y_minor_divisions(&mut self, value: usize) -> &mut Self {
+        self.y_minor_divisions = value;
+        self
+    }
+
+    /// Draw the minor ticks on the X axis, which are shorter than the ticks of the labels
+    pub fn x_minor_ticks(&mut self) -> &mut Self {
+        self.draw_x_minor_ticks = true;
+        self
+    }
+
+    /// Draw the minor ticks on the Y axis, which are shorter than the ticks of the labels
+    pub fn y_minor_ticks(&mut self) -> &mut Self {
+        self.draw_y_minor_ticks = true;
         self
     }
 
@@ -236,14 +336,18 @@ where
         self
     }
 
-    /// Set the X axis's description
+    /// Set the X axis's description, which is drawn centered along the axis at the outer edge
+    /// of the label area. The space of the description is reserved, thus the tick labels are
+    /// laid out in the rest of the label area.
     /// - `desc`: The description of the X axis
     pub fn x_desc<T: Into<String>>(&mut self, desc: T) -> &mut Self {
         self.x_desc = Some(desc.into());
         self
     }
 
-    /// Set the Y axis's description
+    /// Set the Y axis's description, which is drawn rotated and centered along the axis at the
+    /// outer edge of the label area. The space of the description is reserved, thus the tick
+    /// labels are laid out in the rest of the label area.
     /// - `desc`: The description of the Y axis
     pub fn y_desc<T: Into<String>>(&mut self, desc: T) -> &mut Self {
         self.y_desc = Some(desc.into());
@@ -284,30 +388,53 @@ where
             .clone()
             .unwrap_or_else(|| label_style.clone());
 
-        target.draw_mesh(
-            (self.n_y_labels * 10, self.n_x_labels * 10),
+        let x_key_points = self.x_key_points.as_ref().map(|points| &points[..]);
+        let y_key_points = self.y_key_points.as_ref().map(|points| &points[..]);
+
+        let (x_minor, y_minor) = target.drawing_area.as_coord_spec().minor_key_points(
+            (self.n_x_labels, self.x_minor_divisions),
+            (self.n_y_labels, self.y_minor_divisions),
+        );
+        let x_minor = if x_key_points.is_some() {
+            &[][..]
+        } else {
+            &x_minor[..]
+        };
+        let y_minor = if y_key_points.is_some() {
+            &[][..]
+        } else {
+            &y_minor[..]
+        };
+
+        target.draw_minor_mesh(
+            (x_minor, y_minor),
+            (self.draw_x_mesh, self.draw_y_mesh),
+            (
+                self.draw_x_axis && self.draw_x_minor_ticks,
+                self.draw_y_axis && self.draw_y_minor_ticks,
+            ),
             &mesh_style_2,
-            &label_style,
-            |_| None,
-            self.draw_x_mesh,
-            self.draw_y_mesh,
-            self.x_label_offset,
-            self.y_label_offset,
-            false,
-            false,
             &axis_style,
-            &axis_desc_style,
-            self.x_desc.clone(),
-            self.y_desc.clone(),
         )?;
 
         target.draw_mesh(
             (self.n_y_labels, self.n_x_labels),
+            (x_key_points, y_key_points),
             &mesh_style_1,
             &label_style,
-            |m| match m {
-                MeshLine::XMesh(_, _, v) => Some((self.format_x)(v)),
-                MeshLine::YMesh(_, _, v) => Some((self.format_y)(v)),
+            |coord, m| match m {
+                MeshLine::XMesh(_, _, v) => Some(match self.format_x {
+                    Some(fmt) => fmt(v),
+                    None => coord
+                        .format_x_label(v)
+                        .unwrap_or_else(|| format!("{:?}", v)),
+                }),
+                MeshLine::YMesh(_, _, v) => Some(match self.format_y {
+                    Some(fmt) => fmt(v),
+                    None => coord
+                        .format_y_label(v)
+                        .unwrap_or_else(|| format!("{:?}", v)),
+                }),
             },
             self.draw_x_mesh,
             self.draw_y_mesh,
@@ -317,8 +444,184 @@ where
             self.draw_y_axis,
             &axis_style,
             &axis_desc_style,
-            None,
-            None,
+            self.x_desc.clone(),
+            self.y_desc.clone(),
         )
     }
 }
+
+/// Format the value with a SI prefix and at most one decimal, e.g. `1.2k`, `3.4M`, `50m` or
+/// `20n`.
+/// This is designed to be used as a label formatter:
+///
+/// ```rust,no_run
+/// use plotters::prelude::*;
+/// use plotters::chart::si_prefix_formatter;
+///
+/// let root = BitMapBackend::new("si.png", (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .set_label_area_size(LabelAreaPosition::Left, 40)
+///     .build_ranged(0.0..1.0, 0.0..5e6)
+///     .unwrap();
+/// chart
+///     .configure_mesh()
+///     .y_label_formatter(&si_prefix_formatter)
+///     .draw()
+///     .unwrap();
+/// ```
+pub fn si_prefix_formatter(value: &f64) -> String {
+    const PREFIXES: [(f64, &str); 8] = [
+        (1e12, "T"),
+        (1e9, "G"),
+        (1e6, "M"),
+        (1e3, "k"),
+        (1.0, ""),
+        (1e-3, "m"),
+        (1e-6, "\u{b5}"),
+        (1e-9, "n"),
+    ];
+
+    let abs = value.abs();
+    if abs == 0.0 || !abs.is_finite() {
+        return format!("{}", value);
+    }
+
+    let (scale, prefix) = PREFIXES
+        .iter()
+        .find(|(scale, _)| abs >= *scale * 0.9995)
+        .unwrap_or(&PREFIXES[PREFIXES.len() - 1]);
+
+    format!(
+        "{}{}",
+        trim_decimals(format!("{:.1}", value / scale)),
+        prefix
+    )
+}
+
+/// Remove the trailing zeros of the decimals and the decimal point if there's no decimal left
+fn trim_decimals(text: String) -> String {
+    if !text.contains('.') {
+        return text;
+    }
+    text.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+/// Format the value as the mantissa with at most two decimals and the exponent which is a
+/// multiple of `step`, the exponent is omitted if it's 0
+fn format_with_exponent(value: f64, step: i32) -> String {
+    let abs = value.abs();
+    if abs == 0.0 || !abs.is_finite() {
+        return format!("{}", value);
+    }
+
+    let mut exp = (abs.log10().floor() as i32).div_euclid(step) * step;
+    // The mantissa may be rounded up to the next exponent, e.g. 999.999 to 1000.00
+    if (abs / 10f64.powi(exp) * 100.0).round() / 100.0 >= 10f64.powi(step) {
+        exp += step;
+    }
+    let mantissa = trim_decimals(format!("{:.2}", value / 10f64.powi(exp)));
+    if exp == 0 {
+        mantissa
+    } else {
+        format!("{}e{}", mantissa, exp)
+    }
+}
+
+/// Format the value in the engineering notation, where the exponent is a multiple of 3, e.g.
+/// `12.5e3` or `250e-6`. This is designed to be used as a label formatter, see
+/// `si_prefix_formatter`.
+pub fn engineering_formatter(value: &f64) -> String {
+    format_with_exponent(*value, 3)
+}
+
+/// Format the value in the scientific notation with at most two decimals, e.g. `1.25e4` or
+/// `2.5e-4`. This is designed to be used as a label formatter, see `si_prefix_formatter`.
+pub fn scientific_formatter(value: &f64) -> String {
+    format_with_exponent(*value, 1)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_si_prefix_formatter() {
+        assert_eq!(si_prefix_formatter(&0.0), "0");
+        assert_eq!(si_prefix_formatter(&12.5), "12.5");
+        assert_eq!(si_prefix_formatter(&1000.0), "1k");
+        assert_eq!(si_prefix_formatter(&1234.0), "1.2k");
+        assert_eq!(si_prefix_formatter(&-3_400_000.0), "-3.4M");
+        assert_eq!(si_prefix_formatter(&999_999.0), "1M");
+        assert_eq!(si_prefix_formatter(&0.05), "50m");
+        assert_eq!(si_prefix_formatter(&2e-6), "2\u{b5}");
+        assert_eq!(si_prefix_formatter(&2e-8), "20n");
+    }
+
+    #[test]
+    fn test_exponent_formatters() {
+        assert_eq!(engineering_formatter(&0.0), "0");
+        assert_eq!(engineering_formatter(&12.5), "12.5");
+        assert_eq!(engineering_formatter(&12_500.0), "12.5e3");
+        assert_eq!(engineering_formatter(&-0.00025), "-250e-6");
+        assert_eq!(engineering_formatter(&999_999.0), "1e6");
+
+        assert_eq!(scientific_formatter(&12_500.0), "1.25e4");
+        assert_eq!(scientific_formatter(&0.00025), "2.5e-4");
+        assert_eq!(scientific_formatter(&9.999), "1e1");
+        assert_eq!(scientific_formatter(&3.0), "3");
+    }
+
+    #[cfg(feature = "svg")]
+    #[test]
+    fn test_axis_desc() {
+        use crate::prelude::*;
+
+        let mut buffer = vec![];
+        {
+            let root = SVGBackend::with_buffer(&mut buffer, (300, 200)).into_drawing_area();
+            let mut chart = ChartBuilder::on(&root)
+                .x_label_area_size(40)
+                .y_label_area_size(50)
+                .build_ranged(0..10, 1000..2000)
+                .expect("Create chart");
+            chart
+                .configure_mesh()
+                .x_desc("Count")
+                .y_desc("Value")
+                .draw()
+                .expect("Drawing mesh");
+        }
+        // The position of each text, which is the start of its baseline
+        let content = String::from_utf8(buffer).unwrap();
+        let texts: Vec<_> = content
+            .split("<text")
+            .skip(1)
+            .map(|node| {
+                let attr = |key: &str| -> i32 {
+                    let value =
+                        &node[node.find(&format!(" {}=\"", key)).unwrap() + key.len() + 3..];
+                    value[..value.find('"').unwrap()].parse().unwrap()
+                };
+                let text = node[node.find('>').unwrap() + 1..node.find('<').unwrap()].trim();
+                (text.to_string(), attr("x"), attr("y"))
+            })
+            .collect();
+        let find = |name: &str| texts.iter().find(|(text, _, _)| text == name).unwrap();
+
+        // The descriptions are at the outer edges of the label areas, the X one is below the
+        // tick labels and the Y one is on the left of them, and none of them overlap
+        let font_size = 12;
+        let (_, _, count_y) = find("Count");
+        let (_, value_x, _) = find("Value");
+        assert_eq!(*count_y, 199);
+        assert_eq!(*value_x, 0);
+        for (text, x, y) in &texts {
+            // The X tick labels are below 1000, and the Y ones are from 1000
+            match text.parse::<i32>() {
+                Ok(v) if v < 1000 => assert!(*y <= count_y - font_size, "{} at {}", text, y),
+                Ok(_) => assert!(value_x + font_size <= *x, "{} at {}", text, x),
+                Err(_) => {}
+            }
+        }
+    }
+}
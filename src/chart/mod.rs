@@ -13,13 +13,32 @@ detailed description for each struct.
 */
 
 mod builder;
+mod cartesian3d;
+mod colorbar;
 mod context;
+mod data_cursor;
 mod dual_coord;
+mod financial;
+mod hit_region;
+mod linked;
 mod mesh;
+mod polar;
+mod realtime;
 mod series;
 
 pub use builder::{ChartBuilder, LabelAreaPosition};
+pub use cartesian3d::Axes3dStyle;
+pub use colorbar::ColorBar;
 pub use context::{ChartContext, SeriesAnno};
+pub use data_cursor::DataCursor;
 pub use dual_coord::DualCoordChartContext;
-pub use mesh::MeshStyle;
+pub use financial::{FinancialChart, FinancialPane};
+pub use hit_region::{HitRegion, HitRegions};
+pub use linked::LinkedCharts;
+pub use mesh::{engineering_formatter, scientific_formatter, si_prefix_formatter, MeshStyle};
+pub use polar::PolarMeshStyle;
+pub use realtime::RealtimePlot;
 pub use series::{SeriesLabelPosition, SeriesLabelStyle};
+
+#[cfg(feature = "html")]
+pub(crate) use hit_region::json_string;
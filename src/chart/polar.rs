@@ -0,0 +1,250 @@
+use std::borrow::Borrow;
+
+use super::context::{ChartContext, SeriesAnno};
+
+use crate::coord::{PolarCoord, Ranged, RangedCoordf64};
+use crate::drawing::backend::DrawingBackend;
+use crate::drawing::DrawingAreaErrorKind;
+use crate::element::{Drawable, Path, PointCollection};
+use crate::error::ErrorContext;
+use crate::style::{Color, FontDesc, RGBColor, ShapeStyle, TextStyle};
+
+/// The number of segments used to draw a full circle of the polar mesh
+const CIRCLE_SEGMENTS: usize = 180;
+
+impl<'a, DB: DrawingBackend> ChartContext<'a, DB, PolarCoord> {
+    /// Initialize a polar mesh configuration object and mesh drawing can be finalized by
+    /// calling the function `PolarMeshStyle::draw`
+    pub fn configure_mesh<'b>(&'b mut self) -> PolarMeshStyle<'a, 'b, DB> {
+        PolarMeshStyle {
+            n_angle_labels: 12,
+            n_radius_labels: 5,
+            line_style: None,
+            axis_style: None,
+            label_style: None,
+            format_angle: &|a| format!("{}", a),
+            format_radius: &|r| format!("{}", r),
+            target: self,
+        }
+    }
+
+    /// Draw a data series on the polar chart, the coordinate of the elements is a tuple of
+    /// `(angle, radius)`
+    pub fn draw_series<E, R, S>(
+        &mut self,
+        series: S,
+    ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        for<'b> &'b E: PointCollection<'b, (f64, f64)>,
+        E: Drawable<DB>,
+        R: Borrow<E>,
+        S: IntoIterator<Item = R>,
+    {
+        let idx = self.series_anno.len();
+        for element in series {
+            self.drawing_area
+                .draw(element.borrow())
+                .map_err(|e| e.context(ErrorContext::Series(idx)))?;
+        }
+        Ok(self.alloc_series_anno())
+    }
+}
+
+/// The style used to draw the mesh of a polar chart, which is made of circles for the radius
+/// and spokes for the angle
+pub struct PolarMeshStyle<'a, 'b, DB: DrawingBackend> {
+    n_angle_labels: usize,
+    n_radius_labels: usize,
+    line_style: Option<ShapeStyle>,
+    axis_style: Option<ShapeStyle>,
+    label_style: Option<TextStyle<'b>>,
+    format_angle: &'b dyn Fn(&f64) -> String,
+    format_radius: &'b dyn Fn(&f64) -> String,
+    target: &'b mut ChartContext<'a, DB, PolarCoord>,
+}
+
+impl<'a, 'b, DB: DrawingBackend> PolarMeshStyle<'a, 'b, DB> {
+    /// Set the number of spokes, which evenly divide the angle range. Each spoke is labelled.
+    /// - `value`: The number of spokes
+    pub fn angle_labels(&mut self, value: usize) -> &mut Self {
+        self.n_angle_labels = value;
+        self
+    }
+
+    /// Set how many circles (and their labels) for the radius at most
+    /// - `value`: The maximum desired number of circles
+    pub fn radius_labels(&mut self, value: usize) -> &mut Self {
+        self.n_radius_labels = value;
+        self
+    }
+
+    /// Set the style for the circles and the spokes
+    /// - `style`: The style of the mesh lines
+    pub fn line_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.line_style = Some(style.into());
+        self
+    }
+
+    /// Set the style for the outer circle
+    /// - `style`: The style of the outer circle
+    pub fn axis_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.axis_style = Some(style.into());
+        self
+    }
+
+    /// Set the style of the labels
+    /// - `style`: The text style for the labels
+    pub fn label_style<T: Into<TextStyle<'b>>>(&mut self, style: T) -> &mut Self {
+        self.label_style = Some(style.into());
+        self
+    }
+
+    /// Set the formatter function for the angle labels
+    /// - `fmt`: The formatter function
+    pub fn angle_label_formatter(&mut self, fmt: &'b dyn Fn(&f64) -> String) -> &mut Self {
+        self.format_angle = fmt;
+        self
+    }
+
+    /// Set the formatter function for the radius labels
+    /// - `fmt`: The formatter function
+    pub fn radius_label_formatter(&mut self, fmt: &'b dyn Fn(&f64) -> String) -> &mut Self {
+        self.format_radius = fmt;
+        self
+    }
+
+    /// Draw the configured mesh on the target plot
+    pub fn draw(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let default_mesh_color = RGBColor(0, 0, 0).mix(0.2);
+        let default_axis_color = RGBColor(0, 0, 0);
+        let default_label_font = FontDesc::new("Arial", 12.0);
+
+        let line_style = self
+            .line_style
+            .clone()
+            .unwrap_or_else(|| (&default_mesh_color).into());
+        let axis_style = self
+            .axis_style
+            .clone()
+            .unwrap_or_else(|| (&default_axis_color).into());
+        let label_style = self
+            .label_style
+            .clone()
+            .unwrap_or_else(|| default_label_font.into());
+
+        let area = &self.target.drawing_area;
+        let coord = area.as_coord_spec().clone();
+        let angle = coord.angle_range();
+        let radius = coord.radius_range();
+
+        let circle = |r: f64| {
+            (0..=CIRCLE_SEGMENTS)
+                .map(|i| {
+                    let a =
+                        angle.start + (angle.end - angle.start) * i as f64 / CIRCLE_SEGMENTS as f64;
+                    (a, r)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // The key points on the center and the outer circle are excluded
+        let radius_points: Vec<_> = RangedCoordf64::from(radius.clone())
+            .key_points(self.n_radius_labels + 2)
+            .into_iter()
+            .filter(|r| *r > radius.start && *r < radius.end)
+            .collect();
+
+        let angle_points: Vec<_> = (0..self.n_angle_labels)
+            .map(|i| {
+                angle.start + (angle.end - angle.start) * i as f64 / self.n_angle_labels as f64
+            })
+            .collect();
+
+        let mesh = |e: DrawingAreaErrorKind<DB::ErrorType>| e.context(ErrorContext::Mesh);
+
+        for r in radius_points.iter() {
+            area.draw(&Path::new(circle(*r), line_style.clone()))
+                .map_err(mesh)?;
+        }
+
+        for a in angle_points.iter() {
+            area.draw(&Path::new(
+                vec![(*a, radius.start), (*a, radius.end)],
+                line_style.clone(),
+            ))
+            .map_err(mesh)?;
+        }
+
+        area.draw(&Path::new(circle(radius.end), axis_style))
+            .map_err(mesh)?;
+
+        let (cx, cy) = coord.center();
+        let (bx, by) = area.get_base_pixel();
+        let text_area = area.strip_coord_spec();
+        let pixel_radius = f64::from(coord.pixel_radius());
+
+        for a in angle_points.iter() {
+            let text = (self.format_angle)(a);
+            let (w, h) = label_style.font.box_size(&text).unwrap_or((0, 0));
+            let (dx, dy) = coord.offset(*a, radius.end);
+            let (ux, uy) = (dx / pixel_radius, dy / pixel_radius);
+            // Place the label outside of the circle, the edge of the text facing the center
+            // touches the gap around the circle
+            let x = cx as f64 + dx + ux * 5.0 + f64::from(w) / 2.0 * (ux - 1.0);
+            let y = cy as f64 + dy + uy * 5.0 + f64::from(h) / 2.0 * (uy - 1.0);
+            text_area
+                .draw_text(
+                    &text,
+                    &label_style,
+                    (x.round() as i32 - bx, y.round() as i32 - by),
+                )
+                .map_err(mesh)?;
+        }
+
+        for r in radius_points.iter() {
+            let text = (self.format_radius)(r);
+            let (_, h) = label_style.font.box_size(&text).unwrap_or((0, 0));
+            let (dx, dy) = coord.offset(angle.start, *r);
+            let (x, y) = (cx + dx.round() as i32, cy + dy.round() as i32);
+            text_area
+                .draw_text(&text, &label_style, (x - bx + 3, y - by - h as i32))
+                .map_err(mesh)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_polar_chart() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.drop_check(|b| {
+                // 4 circles, 12 spokes, the outer circle and the series
+                assert_eq!(b.num_draw_path_call, 4 + 12 + 1 + 1);
+                // 12 angle labels and 4 radius labels
+                assert_eq!(b.num_draw_text_call, 12 + 4);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_polar(0.0..360.0, 0.0..10.0)
+            .expect("Create chart");
+
+        chart.configure_mesh().draw().expect("Drawing mesh");
+
+        chart
+            .draw_series(LineSeries::new(
+                (0..=36).map(|i| (i as f64 * 10.0, i as f64 / 3.6)),
+                &RED,
+            ))
+            .expect("Drawing series");
+
+        let coord = chart.plotting_area().as_coord_spec();
+        assert_eq!(coord.center(), (100, 100));
+        assert_eq!(coord.pixel_radius(), 80);
+    }
+}
@@ -0,0 +1,160 @@
+use std::collections::VecDeque;
+use std::sync::mpsc::{Receiver, TryRecvError};
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+use crate::coord::Shift;
+use crate::drawing::backend::DrawingBackend;
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
+
+/// The redraw loop of a realtime plot.
+///
+/// The data points are received from a channel, and the figure is redrawn with the latest
+/// points whenever new points arrive. The points arriving within the same frame interval are
+/// drawn in a single frame, thus the frame rate is bounded no matter how fast the data comes.
+/// The loop ends once all the senders of the channel are dropped.
+///
+/// ```rust,no_run
+/// use plotters::prelude::*;
+/// use std::sync::mpsc::channel;
+///
+/// let (sender, receiver) = channel();
+/// std::thread::spawn(move || {
+///     for x in 0..1000 {
+///         sender.send((x as f64, (x as f64 / 10.0).sin())).unwrap();
+///     }
+/// });
+///
+/// let root = BitMapBackend::gif("realtime.gif", (640, 480), 100)
+///     .unwrap()
+///     .into_drawing_area();
+/// RealtimePlot::new(receiver)
+///     .history(100)
+///     .max_fps(10)
+///     .run(&root, |root, data| {
+///         root.fill(&WHITE)?;
+///         let (x0, x1) = (data.front().unwrap().0, data.back().unwrap().0);
+///         let mut chart = ChartBuilder::on(root).build_ranged(x0..x1 + 1.0, -1.0..1.0)?;
+///         chart.draw_series(LineSeries::new(data.iter().cloned(), &RED))?;
+///         Ok(())
+///     })
+///     .unwrap();
+/// ```
+pub struct RealtimePlot<T> {
+    receiver: Receiver<T>,
+    data: VecDeque<T>,
+    history: Option<usize>,
+    frame_interval: Duration,
+}
+
+impl<T> RealtimePlot<T> {
+    /// Create a new realtime plot, which takes the data points from the receiver
+    pub fn new(receiver: Receiver<T>) -> Self {
+        Self {
+            receiver,
+            data: VecDeque::new(),
+            history: None,
+            frame_interval: Duration::from_millis(1000 / 30),
+        }
+    }
+
+    /// Set the number of latest points to keep, the older points are dropped.
+    /// By default, all the points are kept.
+    pub fn history(mut self, points: usize) -> Self {
+        self.history = Some(points.max(1));
+        self
+    }
+
+    /// Set the max number of frames drawn in a second, which is 30 by default
+    pub fn max_fps(mut self, fps: u32) -> Self {
+        self.frame_interval = Duration::from_secs(1) / fps.max(1);
+        self
+    }
+
+    fn push(&mut self, value: T) {
+        self.data.push_back(value);
+        if let Some(history) = self.history {
+            while self.data.len() > history {
+                self.data.pop_front();
+            }
+        }
+    }
+
+    /// Run the redraw loop until all the senders are dropped.
+    /// - `area`: The drawing area to draw on, it's presented after each frame
+    /// - `draw`: The function that draws a frame with the latest points
+    pub fn run<DB: DrawingBackend, F>(
+        mut self,
+        area: &DrawingArea<DB, Shift>,
+        mut draw: F,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        F: FnMut(
+            &DrawingArea<DB, Shift>,
+            &VecDeque<T>,
+        ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>,
+    {
+        let mut last_frame: Option<Instant> = None;
+        loop {
+            match self.receiver.recv() {
+                Ok(value) => self.push(value),
+                Err(_) => return Ok(()),
+            }
+
+            if let Some(last_frame) = last_frame {
+                let elapsed = last_frame.elapsed();
+                if elapsed < self.frame_interval {
+                    sleep(self.frame_interval - elapsed);
+                }
+            }
+
+            let mut disconnected = false;
+            loop {
+                match self.receiver.try_recv() {
+                    Ok(value) => self.push(value),
+                    Err(TryRecvError::Empty) => break,
+                    Err(TryRecvError::Disconnected) => {
+                        disconnected = true;
+                        break;
+                    }
+                }
+            }
+
+            last_frame = Some(Instant::now());
+            draw(area, &self.data)?;
+            area.present()?;
+
+            if disconnected {
+                return Ok(());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use std::sync::mpsc::channel;
+
+    #[test]
+    fn test_realtime_plot() {
+        let (sender, receiver) = channel();
+        for x in 0..10 {
+            sender.send(x).unwrap();
+        }
+        drop(sender);
+
+        let mut frames = vec![];
+        let root = create_mocked_drawing_area(100, 100, |_| {});
+        RealtimePlot::new(receiver)
+            .history(3)
+            .run(&root, |_, data| {
+                frames.push(data.iter().cloned().collect::<Vec<_>>());
+                Ok(())
+            })
+            .unwrap();
+
+        assert_eq!(frames, vec![vec![7, 8, 9]]);
+    }
+}
@@ -51,6 +51,7 @@ pub struct SeriesLabelStyle<'a, 'b, DB: DrawingBackend, CT: CoordTranslate> {
     background: ShapeStyle,
     label_font: Option<TextStyle<'b>>,
     margin: u32,
+    columns: usize,
 }
 
 impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, 'b, DB, CT> {
@@ -63,6 +64,7 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
             background: (&TRANSPARENT).into(),
             label_font: None,
             margin: 10,
+            columns: 1,
         }
     }
 
@@ -106,6 +108,14 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
         self
     }
 
+    /// Set the number of columns of the series labels. The labels fill the first column from
+    /// top to bottom, and then the next column.
+    /// `value` - The number of columns
+    pub fn columns(&mut self, value: usize) -> &mut Self {
+        self.columns = value.max(1);
+        self
+    }
+
     /// Draw the series label area
     pub fn draw(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
         let drawing_area = self.target.plotting_area().strip_coord_spec();
@@ -118,8 +128,7 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
             temp.unwrap_or(default_style)
         };
 
-        let mut label_element = MultiLineText::<_, &str>::new((0, 0), &font);
-        let mut funcs = vec![];
+        let mut entries = vec![];
 
         for anno in self.target.series_anno.iter() {
             let label_text = anno.get_label();
@@ -129,30 +138,46 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
                 continue;
             }
 
-            funcs.push(
+            entries.push((
+                label_text,
                 draw_func.unwrap_or_else(|| &|p: BackendCoord| EmptyElement::at(p).into_dyn()),
-            );
-            label_element.push_line(label_text);
+            ));
         }
 
-        let (mut w, mut h) = label_element
-            .estimate_dimension()
-            .map_err(|e| DrawingAreaErrorKind::BackendError(DrawingErrorKind::FontError(e)))?;
+        let rows =
+            (entries.len() / self.columns + usize::from(entries.len() % self.columns != 0)).max(1);
+        let font_error = |e| DrawingAreaErrorKind::BackendError(DrawingErrorKind::FontError(e));
+
+        let mut columns = vec![];
+        for chunk in entries.chunks(rows) {
+            let mut label_element = MultiLineText::<_, &str>::new((0, 0), &font);
+            for (label_text, _) in chunk {
+                label_element.push_line(*label_text);
+            }
+            let dim = label_element.estimate_dimension().map_err(font_error)?;
+            columns.push((label_element, chunk, dim));
+        }
+        if columns.is_empty() {
+            let label_element = MultiLineText::<_, &str>::new((0, 0), &font);
+            let dim = label_element.estimate_dimension().map_err(font_error)?;
+            columns.push((label_element, &[], dim));
+        }
 
         let margin = self.margin as i32;
+        let legend_area_size = self.legend_area_size as i32;
 
-        w += self.legend_area_size as i32 + margin * 2;
-        h += margin * 2;
+        // The columns are separated by the margin
+        let w = columns
+            .iter()
+            .map(|(_, _, (w, _))| w + legend_area_size)
+            .sum::<i32>()
+            + margin * (columns.len() as i32 + 1);
+        let h = columns.iter().map(|(_, _, (_, h))| *h).max().unwrap_or(0) + margin * 2;
 
         let (area_w, area_h) = drawing_area.dim_in_pixel();
 
         let (label_x, label_y) = self.position.layout_label_area((w, h), (area_w, area_h));
 
-        label_element.relocate((
-            label_x + self.legend_area_size as i32 + margin,
-            label_y + margin,
-        ));
-
         drawing_area.draw(&Rectangle::new(
             [(label_x, label_y), (label_x + w, label_y + h)],
             self.background.filled(),
@@ -161,18 +186,76 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
             [(label_x, label_y), (label_x + w, label_y + h)],
             self.border_style.clone(),
         ))?;
-        drawing_area.draw(&label_element)?;
 
-        for (((_, y0), (_, y1)), make_elem) in label_element
-            .compute_line_layout()
-            .map_err(|e| DrawingAreaErrorKind::BackendError(DrawingErrorKind::FontError(e)))?
-            .into_iter()
-            .zip(funcs.into_iter())
-        {
-            let legend_element = make_elem((label_x + margin, (y0 + y1) / 2));
-            drawing_area.draw(&legend_element)?;
+        let mut column_x = label_x + margin;
+        for (mut label_element, chunk, (column_w, _)) in columns {
+            label_element.relocate((column_x + legend_area_size, label_y + margin));
+            drawing_area.draw(&label_element)?;
+
+            for (((_, y0), (_, y1)), (_, make_elem)) in label_element
+                .compute_line_layout()
+                .map_err(font_error)?
+                .into_iter()
+                .zip(chunk.iter())
+            {
+                let legend_element = make_elem((column_x, (y0 + y1) / 2));
+                drawing_area.draw(&legend_element)?;
+            }
+
+            column_x += column_w + legend_area_size + margin;
         }
 
         Ok(())
     }
 }
+
+#[cfg(all(test, feature = "svg"))]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_series_label_columns() {
+        let mut buffer = vec![];
+        {
+            let root = SVGBackend::with_buffer(&mut buffer, (400, 300)).into_drawing_area();
+            let mut chart = ChartBuilder::on(&root)
+                .build_ranged(0..10, 0..10)
+                .expect("Create chart");
+            for name in &["a", "b", "c", "d"] {
+                chart
+                    .draw_series(LineSeries::new(vec![(0, 0), (1, 1)], &RED))
+                    .expect("Drawing series")
+                    .label(*name);
+            }
+            chart
+                .configure_series_labels()
+                .columns(2)
+                .draw()
+                .expect("Drawing labels");
+        }
+
+        let content = String::from_utf8(buffer).unwrap();
+        let pos = |name: &str| {
+            let node = content
+                .split("<text")
+                .skip(1)
+                .find(|node| {
+                    let text = &node[node.find('>').unwrap() + 1..node.find("</text>").unwrap()];
+                    text.trim() == name
+                })
+                .unwrap();
+            let attr = |key: &str| -> i32 {
+                let value = &node[node.find(&format!(" {}=\"", key)).unwrap() + key.len() + 3..];
+                value[..value.find('"').unwrap()].parse().unwrap()
+            };
+            (attr("x"), attr("y"))
+        };
+
+        // The labels fill the first column and then the second one
+        assert_eq!(pos("a").0, pos("b").0);
+        assert_eq!(pos("c").0, pos("d").0);
+        assert!(pos("a").0 < pos("c").0);
+        assert_eq!(pos("a").1, pos("c").1);
+        assert!(pos("a").1 < pos("b").1);
+    }
+}
@@ -0,0 +1,193 @@
+use super::{CoordTranslate, Ranged};
+use crate::drawing::backend::BackendCoord;
+
+/// The resolution used to normalize the values with `Ranged::map`
+const RESOLUTION: i32 = 1 << 20;
+
+/// Map the value on the axis into `[-0.5, 0.5]`
+pub(crate) fn normalize_value<R: Ranged>(axis: &R, value: &R::ValueType) -> f64 {
+    f64::from(axis.map(value, (0, RESOLUTION))) / f64::from(RESOLUTION) - 0.5
+}
+
+/// The projection of a 3D coordinate system onto the screen. The coordinate is placed in a
+/// cube, which is rotated around the vertical axis by the yaw and then tilted toward the
+/// viewer by the pitch, and it's projected orthographically.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Projection3d {
+    /// The angle in radians the cube is tilted by, a positive pitch looks from above
+    pub pitch: f64,
+    /// The angle in radians the cube is rotated by around the vertical axis
+    pub yaw: f64,
+    /// The length of the edges of the cube, relative to the size of the plotting area
+    pub scale: f64,
+}
+
+impl Default for Projection3d {
+    fn default() -> Self {
+        Self {
+            pitch: 0.5,
+            yaw: 0.5,
+            scale: 0.6,
+        }
+    }
+}
+
+/// The 3D cartesian coordinate system, the guest coordinate is a tuple of `(x, y, z)`, where
+/// the Y axis is the vertical one and the Z axis goes into the screen.
+///
+/// The ranges of the axes are mapped onto a cube, which is projected onto the plotting area
+/// with a `Projection3d`.
+pub struct Cartesian3d<X: Ranged, Y: Ranged, Z: Ranged> {
+    logic_x: X,
+    logic_y: Y,
+    logic_z: Z,
+    projection: Projection3d,
+    center: BackendCoord,
+    size: f64,
+}
+
+impl<X: Ranged, Y: Ranged, Z: Ranged> Cartesian3d<X, Y, Z> {
+    /// Create a new 3D coordinate system
+    /// - `logic_x`, `logic_y`, `logic_z`: The ranges of the axes
+    /// - `center`: The center of the cube in the backend coordinate
+    /// - `size`: The size of the plotting area in pixels, which is scaled by the projection
+    pub fn new<IntoX: Into<X>, IntoY: Into<Y>, IntoZ: Into<Z>>(
+        logic_x: IntoX,
+        logic_y: IntoY,
+        logic_z: IntoZ,
+        center: BackendCoord,
+        size: u32,
+    ) -> Self {
+        Self {
+            logic_x: logic_x.into(),
+            logic_y: logic_y.into(),
+            logic_z: logic_z.into(),
+            projection: Projection3d::default(),
+            center,
+            size: f64::from(size),
+        }
+    }
+
+    /// Get the X axis
+    pub fn x_spec(&self) -> &X {
+        &self.logic_x
+    }
+
+    /// Get the Y axis
+    pub fn y_spec(&self) -> &Y {
+        &self.logic_y
+    }
+
+    /// Get the Z axis
+    pub fn z_spec(&self) -> &Z {
+        &self.logic_z
+    }
+
+    /// Get the projection
+    pub fn projection(&self) -> Projection3d {
+        self.projection
+    }
+
+    /// Set the projection
+    pub fn set_projection(&mut self, projection: Projection3d) {
+        self.projection = projection;
+    }
+
+    /// Get the center of the cube in the backend coordinate
+    pub fn center(&self) -> BackendCoord {
+        self.center
+    }
+
+    /// Map the guest coordinate into the cube, each component is in `[-0.5, 0.5]`
+    pub(crate) fn normalize(
+        &self,
+        (x, y, z): &(X::ValueType, Y::ValueType, Z::ValueType),
+    ) -> [f64; 3] {
+        [
+            normalize_value(&self.logic_x, x),
+            normalize_value(&self.logic_y, y),
+            normalize_value(&self.logic_z, z),
+        ]
+    }
+
+    /// Project a vector in the cube onto the screen, the result is the pixel offset and the
+    /// depth, the larger depth is farther from the viewer
+    pub(crate) fn project_vector(&self, [x, y, z]: [f64; 3]) -> (f64, f64, f64) {
+        let Projection3d { pitch, yaw, scale } = self.projection;
+        let edge = self.size * scale;
+
+        let sx = x * yaw.cos() - z * yaw.sin();
+        let d = x * yaw.sin() + z * yaw.cos();
+        let sy = y * pitch.cos() + d * pitch.sin();
+        let depth = d * pitch.cos() - y * pitch.sin();
+
+        (sx * edge, -sy * edge, depth)
+    }
+
+    /// Project a point in the cube onto the screen, the result is the backend coordinate and
+    /// the depth
+    pub(crate) fn project_normalized(&self, point: [f64; 3]) -> (f64, f64, f64) {
+        let (dx, dy, depth) = self.project_vector(point);
+        (
+            f64::from(self.center.0) + dx,
+            f64::from(self.center.1) + dy,
+            depth,
+        )
+    }
+
+    /// Get the depth of the guest coordinate, the larger depth is farther from the viewer
+    pub(crate) fn depth(&self, coord: &(X::ValueType, Y::ValueType, Z::ValueType)) -> f64 {
+        self.project_normalized(self.normalize(coord)).2
+    }
+
+    /// Get the faces of the cube which are the farthest from the viewer, as the normalized
+    /// coordinate of the face on each axis
+    pub(crate) fn back_faces(&self) -> [f64; 3] {
+        let (_, _, dx) = self.project_vector([1.0, 0.0, 0.0]);
+        let (_, _, dy) = self.project_vector([0.0, 1.0, 0.0]);
+        let (_, _, dz) = self.project_vector([0.0, 0.0, 1.0]);
+        let face = |d: f64| if d > 0.0 { 0.5 } else { -0.5 };
+        [face(dx), face(dy), face(dz)]
+    }
+}
+
+impl<X: Ranged, Y: Ranged, Z: Ranged> CoordTranslate for Cartesian3d<X, Y, Z> {
+    type From = (X::ValueType, Y::ValueType, Z::ValueType);
+
+    fn translate(&self, coord: &Self::From) -> BackendCoord {
+        let (x, y, _) = self.project_normalized(self.normalize(coord));
+        (x.round() as i32, y.round() as i32)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::coord::RangedCoordf64;
+
+    #[test]
+    fn test_cartesian3d_translate() {
+        let mut coord: Cartesian3d<RangedCoordf64, RangedCoordf64, RangedCoordf64> =
+            Cartesian3d::new(0.0..1.0, 0.0..1.0, 0.0..1.0, (100, 100), 100);
+        coord.set_projection(Projection3d {
+            pitch: 0.0,
+            yaw: 0.0,
+            scale: 1.0,
+        });
+
+        // Without the rotation, the cube is seen from the front
+        assert_eq!(coord.translate(&(0.5, 0.5, 0.5)), (100, 100));
+        assert_eq!(coord.translate(&(1.0, 1.0, 0.0)), (150, 50));
+        assert_eq!(coord.translate(&(0.0, 0.0, 1.0)), (50, 150));
+        assert!(coord.depth(&(0.0, 0.0, 1.0)) > coord.depth(&(0.0, 0.0, 0.0)));
+
+        // Looking from above, the far edge of the floor is higher
+        coord.set_projection(Projection3d {
+            pitch: std::f64::consts::FRAC_PI_2,
+            yaw: 0.0,
+            scale: 1.0,
+        });
+        assert_eq!(coord.translate(&(0.5, 0.0, 1.0)), (100, 50));
+        assert_eq!(coord.back_faces(), [-0.5, -0.5, 0.5]);
+    }
+}
@@ -0,0 +1,252 @@
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::ops::Range;
+use std::rc::Rc;
+
+use super::{AsRangedCoord, DescreteRanged, Ranged};
+
+/// A value of the categorical axis, which refers to one of the labels of a `RangedCategory`
+#[derive(Clone)]
+pub struct Category<T> {
+    idx: usize,
+    labels: Rc<Vec<T>>,
+}
+
+impl<T> Category<T> {
+    /// Get the index of the category
+    pub fn index(&self) -> usize {
+        self.idx
+    }
+
+    /// Get the label of the category, `None` if it's the end of the axis
+    pub fn label(&self) -> Option<&T> {
+        self.labels.get(self.idx)
+    }
+}
+
+impl<T: PartialEq> PartialEq for Category<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.idx == other.idx
+            && (Rc::ptr_eq(&self.labels, &other.labels) || self.labels == other.labels)
+    }
+}
+
+impl<T: Eq> Eq for Category<T> {}
+
+impl<T> Hash for Category<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.idx.hash(state);
+    }
+}
+
+impl<T: Debug> Debug for Category<T> {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), std::fmt::Error> {
+        fmt.debug_struct("Category")
+            .field("idx", &self.idx)
+            .field("label", &self.label())
+            .finish()
+    }
+}
+
+/// The category is formatted as its label, and the end of the axis is formatted as nothing
+impl<T: Display> Display for Category<T> {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), std::fmt::Error> {
+        match self.label() {
+            Some(label) => write!(fmt, "{}", label),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The categorical coordinate, which maps a list of discrete labels, e.g. country names, to
+/// evenly spaced slots on the axis.
+///
+/// The values on this axis are `Category`s, which are created by `get`. Since the axis is
+/// descrete, it works with the histogram series, which draws a bar over the slot of a category.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let countries = RangedCategory::new(vec!["Canada", "France", "Japan"]);
+/// let data = vec![("France", 67), ("Japan", 126), ("Canada", 38)];
+/// let data: Vec<_> = data
+///     .into_iter()
+///     .map(|(c, v)| (countries.get(&c).unwrap(), v))
+///     .collect();
+///
+/// let root = SVGBackend::new("category.svg", (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .x_label_area_size(30)
+///     .y_label_area_size(30)
+///     .build_ranged(countries, 0..150)
+///     .unwrap();
+/// chart.configure_mesh().x_label_offset(100).draw().unwrap();
+/// chart
+///     .draw_series(Histogram::vertical(&chart).data(data))
+///     .unwrap();
+/// # std::fs::remove_file("category.svg").ok();
+/// ```
+pub struct RangedCategory<T> {
+    labels: Rc<Vec<T>>,
+}
+
+impl<T> RangedCategory<T> {
+    /// Create a new categorical coordinate with the labels in the order they are shown
+    pub fn new<I: IntoIterator<Item = T>>(labels: I) -> Self {
+        Self {
+            labels: Rc::new(labels.into_iter().collect()),
+        }
+    }
+
+    /// Get the category with the given label, `None` if there's no such label
+    pub fn get(&self, label: &T) -> Option<Category<T>>
+    where
+        T: PartialEq,
+    {
+        let idx = self.labels.iter().position(|l| l == label)?;
+        Some(self.category(idx))
+    }
+
+    /// Get all the categories in the order they are shown
+    pub fn values(&self) -> impl Iterator<Item = Category<T>> + '_ {
+        (0..self.labels.len()).map(move |idx| self.category(idx))
+    }
+
+    /// Get the category with the index, which is the end of the axis if it's the number of the
+    /// labels
+    pub(crate) fn category(&self, idx: usize) -> Category<T> {
+        Category {
+            idx,
+            labels: self.labels.clone(),
+        }
+    }
+}
+
+impl<T: Display> Ranged for RangedCategory<T> {
+    type ValueType = Category<T>;
+
+    fn map(&self, value: &Category<T>, limit: (i32, i32)) -> i32 {
+        let size = self.labels.len().max(1) as f64;
+        limit.0 + (f64::from(limit.1 - limit.0) * value.idx as f64 / size).round() as i32
+    }
+
+    fn key_points(&self, max_points: usize) -> Vec<Category<T>> {
+        if max_points == 0 {
+            return vec![];
+        }
+        let step = self.labels.len().saturating_sub(1) / max_points + 1;
+        (0..self.labels.len())
+            .step_by(step)
+            .map(|idx| self.category(idx))
+            .collect()
+    }
+
+    fn range(&self) -> Range<Category<T>> {
+        self.category(0)..self.category(self.labels.len())
+    }
+
+    fn format_label(&self, value: &Category<T>) -> Option<String> {
+        Some(value.to_string())
+    }
+}
+
+impl<T: Display + Eq> DescreteRanged for RangedCategory<T> {
+    fn next_value(this: &Category<T>) -> Category<T> {
+        Category {
+            idx: this.idx + 1,
+            labels: this.labels.clone(),
+        }
+    }
+
+    fn previous_value(this: &Category<T>) -> Category<T> {
+        Category {
+            idx: this.idx.saturating_sub(1),
+            labels: this.labels.clone(),
+        }
+    }
+
+    fn size(&self) -> Option<usize> {
+        Some(self.labels.len())
+    }
+
+    fn index_of(&self, value: &Category<T>) -> Option<usize> {
+        if value.idx < self.labels.len() {
+            Some(value.idx)
+        } else {
+            None
+        }
+    }
+
+    fn value_at(&self, index: usize) -> Option<Category<T>> {
+        if index < self.labels.len() {
+            Some(self.category(index))
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: Display> AsRangedCoord for RangedCategory<T> {
+    type CoordDescType = Self;
+    type Value = Category<T>;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_category_coord() {
+        let coord = RangedCategory::new(vec!["a", "b", "c", "d"]);
+        let b = coord.get(&"b").unwrap();
+        assert_eq!(b.index(), 1);
+        assert_eq!(b.label(), Some(&"b"));
+        assert_eq!(format!("{}", b), "b");
+        assert_eq!(coord.format_label(&b), Some("b".to_string()));
+        assert_eq!(
+            format!("{:?}", b),
+            "Category { idx: 1, label: Some(\"b\") }"
+        );
+        assert_eq!(coord.format_label(&coord.range().end), Some(String::new()));
+        // The categories of the different labels aren't equal
+        let other = RangedCategory::new(vec!["a", "x", "c", "d"]);
+        assert_ne!(other.get(&"x"), Some(b.clone()));
+        assert_eq!(
+            RangedCategory::new(vec!["a", "b", "c", "d"]).get(&"b"),
+            Some(b.clone())
+        );
+        assert_eq!(coord.get(&"e"), None);
+
+        assert_eq!(coord.map(&b, (0, 100)), 25);
+        assert_eq!(coord.map(&RangedCategory::next_value(&b), (0, 100)), 50);
+        assert_eq!(coord.key_points(10).len(), 4);
+        assert_eq!(
+            coord
+                .key_points(2)
+                .iter()
+                .map(|c| c.index())
+                .collect::<Vec<_>>(),
+            vec![0, 2]
+        );
+    }
+
+    #[test]
+    fn test_category_histogram() {
+        let root = create_mocked_drawing_area(100, 100, |m| {
+            m.drop_check(|b| assert_eq!(b.num_draw_rect_call, 2));
+        });
+        let coord = RangedCategory::new(vec!["x".to_string(), "y".to_string()]);
+        let data = vec![
+            (coord.get(&"x".to_string()).unwrap(), 3),
+            (coord.get(&"y".to_string()).unwrap(), 5),
+            (coord.get(&"x".to_string()).unwrap(), 2),
+        ];
+        let mut chart = ChartBuilder::on(&root)
+            .build_ranged(coord, 0..10)
+            .expect("Create chart");
+        chart
+            .draw_series(Histogram::vertical(&chart).data(data))
+            .expect("Drawing failure");
+    }
+}
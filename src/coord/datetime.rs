@@ -1,11 +1,16 @@
 /// The datetime coordinates
-use chrono::{Date, DateTime, Datelike, Duration, NaiveTime, TimeZone, Timelike};
-use std::ops::Range;
+use chrono::{
+    Date, DateTime, Datelike, Duration, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, Offset,
+    TimeZone, Timelike, Utc, Weekday,
+};
+use std::marker::PhantomData;
+use std::ops::{Add, Range};
+use std::time::{SystemTime, UNIX_EPOCH};
 
-use super::{AsRangedCoord, DescreteRanged, Ranged};
+use super::{AsRangedCoord, DescreteRanged, Ranged, ReversableRanged};
 
 /// The trait that describe some time value
-pub trait TimeValue: Eq {
+pub trait TimeValue: Eq + Sized {
     type Tz: TimeZone;
     /// Returns the date that is no later than the time
     fn date_floor(&self) -> Date<Self::Tz>;
@@ -17,29 +22,96 @@ pub trait TimeValue: Eq {
     fn subtract(&self, other: &Self) -> Duration;
     /// Get the timezone information for current value
     fn timezone(&self) -> Self::Tz;
+    /// Returns the number of nanoseconds since the UNIX epoch. The default implementation
+    /// subtracts the epoch from the value, the implementors should override it with a cheaper
+    /// one which doesn't need to clone the value or the timezone.
+    fn timestamp_ns(&self) -> i128 {
+        let epoch_date = self.timezone().ymd(1970, 1, 1);
+        let offset = epoch_date.offset().fix().local_minus_utc();
+        let since_epoch = self.subtract(&Self::earliest_after_date(epoch_date));
+        let secs = since_epoch.num_seconds();
+        let subsec_ns = (since_epoch - Duration::seconds(secs))
+            .num_nanoseconds()
+            .unwrap_or(0);
+        (i128::from(secs) - i128::from(offset)) * 1_000_000_000 + i128::from(subsec_ns)
+    }
+
+    /// Format the time with a strftime-style format string, e.g. `%Y-%m-%d`.
+    /// The local time is formatted, and the date is used for a value without the time.
+    fn format_time(&self, fmt: &str) -> String {
+        self.date_floor().naive_local().format(fmt).to_string()
+    }
 
     /// Map the coord
     fn map_coord(value: &Self, begin: &Self, end: &Self, limit: (i32, i32)) -> i32 {
-        let total_span = end.subtract(begin);
-        let value_span = value.subtract(begin);
+        map_timestamp(
+            value.timestamp_ns(),
+            (begin.timestamp_ns(), end.timestamp_ns()),
+            limit,
+        )
+    }
+}
 
-        // First, lets try the nanoseconds precision
-        if let Some(total_ns) = total_span.num_nanoseconds() {
-            if let Some(value_ns) = value_span.num_nanoseconds() {
-                return (f64::from(limit.1 - limit.0) * value_ns as f64 / total_ns as f64) as i32
-                    + limit.0;
-            }
-        }
+/// Map the timestamp to the coordinate, `range` is the timestamps of the beginning and the end of
+/// the axis, which the coordinates compute once and cache.
+fn map_timestamp(value: i128, range: (i128, i128), limit: (i32, i32)) -> i32 {
+    let total_ns = (range.1 - range.0) as f64;
+    let value_ns = (value - range.0) as f64;
+
+    (f64::from(limit.1 - limit.0) * value_ns / total_ns) as i32 + limit.0
+}
 
-        // If it overflows, it means we have a timespan nearly 300 years, we are safe to ignore the
-        // porition less than 1 day.
-        let total_days = total_span.num_days() as f64;
-        let value_days = value_span.num_days() as f64;
+/// Get the position of the pixel on the axis from 0 to 1, which is the reverse of the mapping,
+/// `None` if the pixel is out of the axis
+fn pixel_ratio(input: i32, limit: (i32, i32)) -> Option<f64> {
+    if limit.0 == limit.1 || input < limit.0.min(limit.1) || input > limit.0.max(limit.1) {
+        return None;
+    }
+    Some(f64::from(input - limit.0) / f64::from(limit.1 - limit.0))
+}
+
+/// Get the part of the span in nanoseconds at the ratio. The nanoseconds overflow `i64` for the
+/// spans longer than about 292 years, then the part is measured in milliseconds instead.
+fn part_of_span(span_ns: i128, ratio: f64) -> Option<Duration> {
+    let ns = span_ns as f64 * ratio;
+    if ns.abs() < i64::MAX as f64 {
+        return Some(Duration::nanoseconds(ns as i64));
+    }
+    let ms = ns / 1e6;
+    if ms.abs() < i64::MAX as f64 {
+        return Some(Duration::milliseconds(ms as i64));
+    }
+    None
+}
 
-        (f64::from(limit.1 - limit.0) * value_days / total_days) as i32 + limit.0
+/// The default label format of a date and time axis, which is chosen by the span of the axis
+fn default_time_format(span: Duration) -> &'static str {
+    let span = if span < Duration::zero() { -span } else { span };
+    if span >= Duration::days(3) {
+        "%Y-%m-%d"
+    } else if span >= Duration::days(1) {
+        "%m-%d %H:%M"
+    } else if span >= Duration::minutes(10) {
+        "%H:%M"
+    } else if span >= Duration::seconds(10) {
+        "%H:%M:%S"
+    } else {
+        "%H:%M:%S%.3f"
     }
 }
 
+/// Compute the key points of a descending range with the ascending coordinate `reversed`.
+/// The key points are reversed, so that they come in the direction of the axis.
+fn reversed_key_points<R: Ranged>(reversed: R, max_points: usize) -> Vec<R::ValueType> {
+    let mut ret = reversed.key_points(max_points);
+    ret.reverse();
+    ret
+}
+
+fn timestamp_range<T: TimeValue>(range: &Range<T>) -> (i128, i128) {
+    (range.start.timestamp_ns(), range.end.timestamp_ns())
+}
+
 impl<Z: TimeZone> TimeValue for Date<Z> {
     type Tz = Z;
     fn date_floor(&self) -> Date<Z> {
@@ -52,11 +124,14 @@ impl<Z: TimeZone> TimeValue for Date<Z> {
         date
     }
     fn subtract(&self, other: &Date<Z>) -> Duration {
-        self.clone() - other.clone()
+        self.naive_utc() - other.naive_utc()
     }
     fn timezone(&self) -> Self::Tz {
         self.timezone()
     }
+    fn timestamp_ns(&self) -> i128 {
+        i128::from(self.naive_utc().and_hms(0, 0, 0).timestamp()) * 1_000_000_000
+    }
 }
 
 impl<Z: TimeZone> TimeValue for DateTime<Z> {
@@ -76,19 +151,117 @@ impl<Z: TimeZone> TimeValue for DateTime<Z> {
     }
 
     fn subtract(&self, other: &DateTime<Z>) -> Duration {
-        self.clone() - other.clone()
+        self.naive_utc() - other.naive_utc()
     }
     fn timezone(&self) -> Self::Tz {
         self.timezone()
     }
+    fn timestamp_ns(&self) -> i128 {
+        i128::from(self.timestamp()) * 1_000_000_000 + i128::from(self.timestamp_subsec_nanos())
+    }
+    fn format_time(&self, fmt: &str) -> String {
+        self.naive_local().format(fmt).to_string()
+    }
+}
+
+/// The naive date is treated as a date in UTC
+impl TimeValue for NaiveDate {
+    type Tz = Utc;
+    fn date_floor(&self) -> Date<Utc> {
+        Date::from_utc(*self, Utc)
+    }
+    fn date_ceil(&self) -> Date<Utc> {
+        Date::from_utc(*self, Utc)
+    }
+    fn earliest_after_date(date: Date<Utc>) -> Self {
+        date.naive_utc()
+    }
+    fn subtract(&self, other: &NaiveDate) -> Duration {
+        *self - *other
+    }
+    fn timezone(&self) -> Utc {
+        Utc
+    }
+    fn timestamp_ns(&self) -> i128 {
+        i128::from(self.and_hms(0, 0, 0).timestamp()) * 1_000_000_000
+    }
+}
+
+/// The naive date and time is treated as a date and time in UTC
+impl TimeValue for NaiveDateTime {
+    type Tz = Utc;
+    fn date_floor(&self) -> Date<Utc> {
+        Date::from_utc(self.date(), Utc)
+    }
+    fn date_ceil(&self) -> Date<Utc> {
+        if self.time().num_seconds_from_midnight() > 0 {
+            Date::from_utc(self.date(), Utc) + Duration::days(1)
+        } else {
+            Date::from_utc(self.date(), Utc)
+        }
+    }
+    fn earliest_after_date(date: Date<Utc>) -> NaiveDateTime {
+        date.naive_utc().and_hms(0, 0, 0)
+    }
+    fn subtract(&self, other: &NaiveDateTime) -> Duration {
+        *self - *other
+    }
+    fn timezone(&self) -> Utc {
+        Utc
+    }
+    fn timestamp_ns(&self) -> i128 {
+        i128::from(self.timestamp()) * 1_000_000_000 + i128::from(self.timestamp_subsec_nanos())
+    }
+    fn format_time(&self, fmt: &str) -> String {
+        self.format(fmt).to_string()
+    }
+}
+
+/// The system time is treated as a date and time in UTC
+impl TimeValue for SystemTime {
+    type Tz = Utc;
+    fn date_floor(&self) -> Date<Utc> {
+        DateTime::<Utc>::from(*self).date_floor()
+    }
+    fn date_ceil(&self) -> Date<Utc> {
+        DateTime::<Utc>::from(*self).date_ceil()
+    }
+    fn earliest_after_date(date: Date<Utc>) -> SystemTime {
+        date.and_hms(0, 0, 0).into()
+    }
+    fn subtract(&self, other: &SystemTime) -> Duration {
+        Duration::nanoseconds((self.timestamp_ns() - other.timestamp_ns()) as i64)
+    }
+    fn timezone(&self) -> Utc {
+        Utc
+    }
+    fn timestamp_ns(&self) -> i128 {
+        match self.duration_since(UNIX_EPOCH) {
+            Ok(after) => after.as_nanos() as i128,
+            Err(before) => -(before.duration().as_nanos() as i128),
+        }
+    }
+    fn format_time(&self, fmt: &str) -> String {
+        DateTime::<Utc>::from(*self).format_time(fmt)
+    }
 }
 
 /// The ranged coordinate for date
-pub struct RangedDate<Z: TimeZone>(Date<Z>, Date<Z>);
+pub struct RangedDate<Z: TimeZone>(Date<Z>, Date<Z>, (i128, i128), Option<String>);
+
+impl<Z: TimeZone> RangedDate<Z> {
+    /// Set the strftime-style format of the labels, e.g. `%Y-%m`, which is `%Y-%m-%d` by default
+    /// - `fmt`: The format string
+    pub fn label_format<S: Into<String>>(mut self, fmt: S) -> Self {
+        self.3 = Some(fmt.into());
+        self
+    }
+}
 
 impl<Z: TimeZone> From<Range<Date<Z>>> for RangedDate<Z> {
     fn from(range: Range<Date<Z>>) -> Self {
-        Self(range.start, range.end)
+        let timestamps = timestamp_range(&range);
+        Self(range.start, range.end, timestamps, None)
     }
 }
 
@@ -100,10 +273,14 @@ impl<Z: TimeZone> Ranged for RangedDate<Z> {
     }
 
     fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
-        TimeValue::map_coord(value, &self.0, &self.1, limit)
+        map_timestamp(value.timestamp_ns(), self.2, limit)
     }
 
     fn key_points(&self, max_points: usize) -> Vec<Self::ValueType> {
+        if self.2 .0 > self.2 .1 {
+            let reversed = RangedDate::from(self.1.clone()..self.0.clone());
+            return reversed_key_points(reversed, max_points);
+        }
         let mut ret = vec![];
 
         let total_days = (self.1.clone() - self.0.clone()).num_days();
@@ -131,6 +308,19 @@ impl<Z: TimeZone> Ranged for RangedDate<Z> {
 
         ret
     }
+
+    fn format_label(&self, value: &Date<Z>) -> Option<String> {
+        Some(value.format_time(self.3.as_deref().unwrap_or("%Y-%m-%d")))
+    }
+}
+
+impl<Z: TimeZone> ReversableRanged for RangedDate<Z> {
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<Date<Z>> {
+        let days = self.1.subtract(&self.0).num_days() as f64 * pixel_ratio(input, limit)?;
+        self.0
+            .clone()
+            .checked_add_signed(Duration::days(days.round() as i64))
+    }
 }
 
 impl<Z: TimeZone> DescreteRanged for RangedDate<Z> {
@@ -141,6 +331,30 @@ impl<Z: TimeZone> DescreteRanged for RangedDate<Z> {
     fn previous_value(this: &Date<Z>) -> Date<Z> {
         this.clone() - Duration::days(1)
     }
+
+    fn size(&self) -> Option<usize> {
+        // A descending range can't index its values
+        let days = self.1.subtract(&self.0).num_days();
+        if days < 0 {
+            return None;
+        }
+        Some(days as usize + 1)
+    }
+
+    fn index_of(&self, value: &Date<Z>) -> Option<usize> {
+        let days = value.subtract(&self.0).num_days();
+        if days < 0 || days as usize >= self.size()? {
+            return None;
+        }
+        Some(days as usize)
+    }
+
+    fn value_at(&self, index: usize) -> Option<Date<Z>> {
+        if index >= self.size()? {
+            return None;
+        }
+        Some(self.0.clone() + Duration::days(index as i64))
+    }
 }
 
 impl<Z: TimeZone> AsRangedCoord for Range<Date<Z>> {
@@ -149,7 +363,7 @@ impl<Z: TimeZone> AsRangedCoord for Range<Date<Z>> {
 }
 
 /// Indicatets the coord has a monthly resolution
-pub struct Monthly<T: TimeValue>(Range<T>);
+pub struct Monthly<T: TimeValue>(Range<T>, (i128, i128));
 
 impl<T: TimeValue + Clone> AsRangedCoord for Monthly<T> {
     type CoordDescType = Monthly<T>;
@@ -164,10 +378,17 @@ impl<T: TimeValue + Clone> Ranged for Monthly<T> {
     }
 
     fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
-        T::map_coord(value, &self.0.start, &self.0.end, limit)
+        map_timestamp(value.timestamp_ns(), self.1, limit)
     }
 
     fn key_points(&self, max_points: usize) -> Vec<Self::ValueType> {
+        if self.1 .0 > self.1 .1 {
+            let reversed = Monthly(
+                self.0.end.clone()..self.0.start.clone(),
+                (self.1 .1, self.1 .0),
+            );
+            return reversed_key_points(reversed, max_points);
+        }
         let start_date = self.0.start.date_ceil();
         let end_date = self.0.end.date_floor();
 
@@ -256,6 +477,10 @@ impl<T: TimeValue + Clone> Ranged for Monthly<T> {
             self.0.start.timezone(),
         )
     }
+
+    fn format_label(&self, value: &T) -> Option<String> {
+        Some(value.format_time("%Y-%m"))
+    }
 }
 
 impl<T: TimeValue + Clone> DescreteRanged for Monthly<T> {
@@ -282,8 +507,131 @@ impl<T: TimeValue + Clone> DescreteRanged for Monthly<T> {
     }
 }
 
+/// Indicates the coord has a quarterly resolution, the key points are the first days of the
+/// quarters, which are labeled like `2019 Q3`
+pub struct Quarterly<T: TimeValue>(Range<T>, (i128, i128), u32);
+
+impl<T: TimeValue> Quarterly<T> {
+    /// Set the first month of the fiscal year, which is January by default. The fiscal year is
+    /// named by the calendar year it ends in, e.g. the quarter from October 2019 is `2020 Q1` if
+    /// the fiscal year starts in October.
+    /// - `month`: The first month of the fiscal year, from 1 to 12
+    pub fn fiscal_year_start(mut self, month: u32) -> Self {
+        self.2 = month.clamp(1, 12);
+        self
+    }
+
+    /// Get the index of the quarter that the month is in, counting from the first quarter of
+    /// the fiscal year starting in the year 0
+    fn quarter_of(&self, year: i32, month: u32) -> i32 {
+        (year * 12 + month as i32 - self.2 as i32).div_euclid(3)
+    }
+
+    /// Get the year and the month of the first month of the quarter
+    fn first_month_of(&self, quarter: i32) -> (i32, u32) {
+        let month = quarter * 3 + self.2 as i32 - 1;
+        (month.div_euclid(12), month.rem_euclid(12) as u32 + 1)
+    }
+}
+
+impl<T: TimeValue + Clone> AsRangedCoord for Quarterly<T> {
+    type CoordDescType = Quarterly<T>;
+    type Value = T;
+}
+
+impl<T: TimeValue + Clone> Ranged for Quarterly<T> {
+    type ValueType = T;
+
+    fn range(&self) -> Range<T> {
+        self.0.start.clone()..self.0.end.clone()
+    }
+
+    fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
+        map_timestamp(value.timestamp_ns(), self.1, limit)
+    }
+
+    fn key_points(&self, max_points: usize) -> Vec<Self::ValueType> {
+        if self.1 .0 > self.1 .1 {
+            let reversed = Quarterly(
+                self.0.end.clone()..self.0.start.clone(),
+                (self.1 .1, self.1 .0),
+                self.2,
+            );
+            return reversed_key_points(reversed, max_points);
+        }
+        let start_date = self.0.start.date_ceil();
+        let end_date = self.0.end.date_floor();
+
+        let mut first = self.quarter_of(start_date.year(), start_date.month());
+        if start_date.day() != 1 || self.first_month_of(first).1 != start_date.month() {
+            first += 1;
+        }
+        let last = self.quarter_of(end_date.year(), end_date.month());
+        if last < first || max_points == 0 {
+            return vec![];
+        }
+
+        // The quarters, the halves and then the whole numbers of the fiscal years
+        let total = (last - first + 1) as usize;
+        let mut step = 1;
+        for try_step in [1, 2, 4, 8, 20, 40].iter().copied() {
+            step = try_step;
+            if total / try_step <= max_points {
+                break;
+            }
+        }
+        while total / step > max_points {
+            step *= 10;
+        }
+
+        let tz = self.0.start.timezone();
+        (first..=last)
+            .filter(|quarter| quarter.rem_euclid(step as i32) == 0)
+            .map(|quarter| {
+                let (year, month) = self.first_month_of(quarter);
+                T::earliest_after_date(tz.ymd(year, month, 1))
+            })
+            .collect()
+    }
+
+    fn format_label(&self, value: &T) -> Option<String> {
+        let date = value.date_floor();
+        let quarter = self.quarter_of(date.year(), date.month());
+        let year = quarter.div_euclid(4) + if self.2 == 1 { 0 } else { 1 };
+        Some(format!("{} Q{}", year, quarter.rem_euclid(4) + 1))
+    }
+}
+
+/// Move the date by the number of months, the day is clamped to the end of the month
+fn add_months<Z: TimeZone>(date: &Date<Z>, months: i32) -> Date<Z> {
+    let month = date.year() * 12 + date.month0() as i32 + months;
+    let (year, month) = (month.div_euclid(12), month.rem_euclid(12) as u32 + 1);
+    (1..=date.day())
+        .rev()
+        .find_map(|day| date.timezone().ymd_opt(year, month, day).single())
+        .unwrap()
+}
+
+/// Get the first day of the calendar quarter that the date is in
+fn quarter_start<Z: TimeZone>(date: &Date<Z>) -> Date<Z> {
+    let month = date.month0() - date.month0() % 3 + 1;
+    date.timezone().ymd(date.year(), month, 1)
+}
+
+// The values are the first days of the calendar quarters, since the fiscal year start isn't known
+// without the coord
+impl<T: TimeValue + Clone> DescreteRanged for Quarterly<T> {
+    fn next_value(this: &T) -> T {
+        T::earliest_after_date(add_months(&quarter_start(&this.date_ceil()), 3))
+    }
+
+    fn previous_value(this: &T) -> T {
+        T::earliest_after_date(add_months(&quarter_start(&this.date_floor()), -3))
+    }
+}
+
 /// Indicate the coord has a yearly resolution
-pub struct Yearly<T: TimeValue>(Range<T>);
+pub struct Yearly<T: TimeValue>(Range<T>, (i128, i128));
 
 impl<T: TimeValue + Clone> AsRangedCoord for Yearly<T> {
     type CoordDescType = Yearly<T>;
@@ -335,10 +683,17 @@ impl<T: TimeValue + Clone> Ranged for Yearly<T> {
     }
 
     fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
-        T::map_coord(value, &self.0.start, &self.0.end, limit)
+        map_timestamp(value.timestamp_ns(), self.1, limit)
     }
 
     fn key_points(&self, max_points: usize) -> Vec<Self::ValueType> {
+        if self.1 .0 > self.1 .1 {
+            let reversed = Yearly(
+                self.0.end.clone()..self.0.start.clone(),
+                (self.1 .1, self.1 .0),
+            );
+            return reversed_key_points(reversed, max_points);
+        }
         let start_date = self.0.start.date_ceil();
         let end_date = self.0.end.date_floor();
 
@@ -366,6 +721,10 @@ impl<T: TimeValue + Clone> Ranged for Yearly<T> {
             self.0.start.timezone(),
         )
     }
+
+    fn format_label(&self, value: &T) -> Option<String> {
+        Some(value.format_time("%Y"))
+    }
 }
 
 impl<T: TimeValue + Clone> DescreteRanged for Yearly<T> {
@@ -378,11 +737,106 @@ impl<T: TimeValue + Clone> DescreteRanged for Yearly<T> {
     }
 }
 
+/// Indicates the coord has a weekly resolution, the key points are the first days of the weeks
+pub struct Weekly<T: TimeValue>(Range<T>, (i128, i128), Weekday);
+
+impl<T: TimeValue> Weekly<T> {
+    /// Set the first day of the week, which is Monday by default
+    /// - `day`: The day that the key points are aligned to
+    pub fn week_start(mut self, day: Weekday) -> Self {
+        self.2 = day;
+        self
+    }
+}
+
+impl<T: TimeValue + Clone> AsRangedCoord for Weekly<T> {
+    type CoordDescType = Weekly<T>;
+    type Value = T;
+}
+
+impl<T: TimeValue + Clone> Ranged for Weekly<T> {
+    type ValueType = T;
+
+    fn range(&self) -> Range<T> {
+        self.0.start.clone()..self.0.end.clone()
+    }
+
+    fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
+        map_timestamp(value.timestamp_ns(), self.1, limit)
+    }
+
+    fn key_points(&self, max_points: usize) -> Vec<Self::ValueType> {
+        if self.1 .0 > self.1 .1 {
+            let reversed = Weekly(
+                self.0.end.clone()..self.0.start.clone(),
+                (self.1 .1, self.1 .0),
+                self.2,
+            );
+            return reversed_key_points(reversed, max_points);
+        }
+        let start_date = self.0.start.date_ceil();
+        let end_date = self.0.end.date_floor();
+
+        let days_to_week_start =
+            (7 + self.2.num_days_from_monday() - start_date.weekday().num_days_from_monday()) % 7;
+        let first_date = start_date + Duration::days(i64::from(days_to_week_start));
+
+        let total_weeks = (end_date - first_date.clone()).num_weeks();
+        if total_weeks < 0 || max_points == 0 {
+            return vec![];
+        }
+
+        let week_per_point = (total_weeks as usize + max_points) / max_points;
+
+        (0..=(total_weeks as usize / week_per_point))
+            .map(|idx| {
+                T::earliest_after_date(
+                    first_date.clone() + Duration::weeks((idx * week_per_point) as i64),
+                )
+            })
+            .collect()
+    }
+
+    fn format_label(&self, value: &T) -> Option<String> {
+        Some(value.format_time("%Y-%m-%d"))
+    }
+}
+
+impl<T: TimeValue + Clone> DescreteRanged for Weekly<T> {
+    fn next_value(this: &T) -> T {
+        T::earliest_after_date(this.date_floor() + Duration::weeks(1))
+    }
+
+    fn previous_value(this: &T) -> T {
+        T::earliest_after_date(this.date_ceil() - Duration::weeks(1))
+    }
+}
+
+/// The trait that converts a normal date coord into a weekly one
+pub trait IntoWeekly<T: TimeValue> {
+    /// Make the coord weekly, the weeks start on Monday unless `Weekly::week_start` is used
+    fn weekly(self) -> Weekly<T>;
+}
+
+impl<T: TimeValue> IntoWeekly<T> for Range<T> {
+    fn weekly(self) -> Weekly<T> {
+        let timestamps = timestamp_range(&self);
+        Weekly(self, timestamps, Weekday::Mon)
+    }
+}
+
 /// The trait that converts a normal date coord into a yearly one
 pub trait IntoMonthly<T: TimeValue> {
     fn monthly(self) -> Monthly<T>;
 }
 
+/// The trait that converts a normal date coord into a quarterly one
+pub trait IntoQuarterly<T: TimeValue> {
+    /// Make the coord quarterly, the fiscal year starts in January unless
+    /// `Quarterly::fiscal_year_start` is used
+    fn quarterly(self) -> Quarterly<T>;
+}
+
 /// The trait that converts a normal date coord into a yearly one
 pub trait IntoYearly<T: TimeValue> {
     fn yearly(self) -> Yearly<T>;
@@ -390,31 +844,93 @@ pub trait IntoYearly<T: TimeValue> {
 
 impl<T: TimeValue> IntoMonthly<T> for Range<T> {
     fn monthly(self) -> Monthly<T> {
-        Monthly(self)
+        let timestamps = timestamp_range(&self);
+        Monthly(self, timestamps)
+    }
+}
+
+impl<T: TimeValue> IntoQuarterly<T> for Range<T> {
+    fn quarterly(self) -> Quarterly<T> {
+        let timestamps = timestamp_range(&self);
+        Quarterly(self, timestamps, 1)
     }
 }
 
 impl<T: TimeValue> IntoYearly<T> for Range<T> {
     fn yearly(self) -> Yearly<T> {
-        Yearly(self)
+        let timestamps = timestamp_range(&self);
+        Yearly(self, timestamps)
     }
 }
 
-/// The ranged coordinate for the date and time
-pub struct RangedDateTime<Z: TimeZone>(DateTime<Z>, DateTime<Z>);
+/// The unit of the descrete values of `RangedDateTime` and `RangedDuration`, which is the step
+/// of the histograms drawn on them
+pub trait TimeUnit {
+    /// The length of the unit
+    fn duration() -> Duration;
+}
+
+macro_rules! make_time_unit {
+    ($doc:expr, $name:ident, $duration:expr) => {
+        #[doc = $doc]
+        pub struct $name;
+
+        impl TimeUnit for $name {
+            fn duration() -> Duration {
+                $duration
+            }
+        }
+    };
+}
+
+make_time_unit!("The time unit of a second", Second, Duration::seconds(1));
+make_time_unit!("The time unit of a minute", Minute, Duration::minutes(1));
+make_time_unit!("The time unit of an hour", Hour, Duration::hours(1));
+make_time_unit!(
+    "The time unit of a day, which is a calendar day of the local time",
+    Day,
+    Duration::days(1)
+);
+
+/// The ranged coordinate for the date and time. Its descrete values are the multiples of the
+/// time unit `U` since the local midnight, which is a second by default.
+pub struct RangedDateTime<Z: TimeZone, U: TimeUnit = Second>(
+    DateTime<Z>,
+    DateTime<Z>,
+    (i128, i128),
+    Option<String>,
+    PhantomData<U>,
+);
+
+impl<Z: TimeZone, U: TimeUnit> RangedDateTime<Z, U> {
+    /// Set the strftime-style format of the labels, e.g. `%H:%M:%S`. By default, the format is
+    /// chosen by the span of the axis, from `%Y-%m-%d` for days to `%H:%M:%S%.3f` for seconds.
+    /// - `fmt`: The format string
+    pub fn label_format<S: Into<String>>(mut self, fmt: S) -> Self {
+        self.3 = Some(fmt.into());
+        self
+    }
+
+    /// Change the unit of the descrete values, e.g. `with_unit::<Hour>()` to draw a histogram
+    /// of the hours
+    pub fn with_unit<V: TimeUnit>(self) -> RangedDateTime<Z, V> {
+        RangedDateTime(self.0, self.1, self.2, self.3, PhantomData)
+    }
+}
 
 impl<Z: TimeZone> AsRangedCoord for Range<DateTime<Z>> {
     type CoordDescType = RangedDateTime<Z>;
     type Value = Date<Z>;
 }
 
-impl<Z: TimeZone> From<Range<DateTime<Z>>> for RangedDateTime<Z> {
+impl<Z: TimeZone, U: TimeUnit> From<Range<DateTime<Z>>> for RangedDateTime<Z, U> {
     fn from(range: Range<DateTime<Z>>) -> Self {
-        Self(range.start, range.end)
+        let timestamps = timestamp_range(&range);
+        Self(range.start, range.end, timestamps, None, PhantomData)
     }
 }
 
-impl<Z: TimeZone> Ranged for RangedDateTime<Z> {
+impl<Z: TimeZone, U: TimeUnit> Ranged for RangedDateTime<Z, U> {
     type ValueType = DateTime<Z>;
 
     fn range(&self) -> Range<DateTime<Z>> {
@@ -422,122 +938,909 @@ impl<Z: TimeZone> Ranged for RangedDateTime<Z> {
     }
 
     fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
-        TimeValue::map_coord(value, &self.0, &self.1, limit)
+        map_timestamp(value.timestamp_ns(), self.2, limit)
     }
 
     fn key_points(&self, max_points: usize) -> Vec<Self::ValueType> {
+        if self.2 .0 > self.2 .1 {
+            let reversed = RangedDateTime::<Z, U>::from(self.1.clone()..self.0.clone());
+            return reversed_key_points(reversed, max_points);
+        }
         let total_span = self.1.clone() - self.0.clone();
 
         if let Some(total_ns) = total_span.num_nanoseconds() {
             if let Some(actual_ns_per_point) =
                 compute_period_per_point(total_ns as u64, max_points, true)
             {
-                let start_time_ns = u64::from(self.0.time().num_seconds_from_midnight())
-                    * 1_000_000_000
-                    + u64::from(self.0.time().nanosecond());
-
-                let mut start_time = self
-                    .0
-                    .date_floor()
-                    .and_time(
-                        NaiveTime::from_hms(0, 0, 0)
-                            + Duration::nanoseconds(if start_time_ns % actual_ns_per_point > 0 {
-                                start_time_ns
-                                    + (actual_ns_per_point - start_time_ns % actual_ns_per_point)
-                            } else {
-                                start_time_ns
-                            } as i64),
-                    )
-                    .unwrap();
-
-                let mut ret = vec![];
-
-                while start_time < self.1 {
-                    ret.push(start_time.clone());
-                    start_time = start_time + Duration::nanoseconds(actual_ns_per_point as i64);
-                }
-
+                let mut ret = local_key_points(&self.range(), actual_ns_per_point as i64);
+                ret.retain(|time| *time < self.1);
                 return ret;
             }
         }
 
         // Otherwise, it actually behaves like a date
-        let date_range = RangedDate(self.0.date_ceil(), self.1.date_floor());
+        let date_range = RangedDate::from(self.0.date_ceil()..self.1.date_floor());
 
         date_range
             .key_points(max_points)
             .into_iter()
-            .map(|x| x.and_hms(0, 0, 0))
+            .filter_map(|x| x.and_hms_opt(0, 0, 0))
             .collect()
     }
-}
 
-/// The coordinate that for duration of time
-pub struct RangedDuration(Duration, Duration);
-
-impl AsRangedCoord for Range<Duration> {
-    type CoordDescType = RangedDuration;
-    type Value = Duration;
+    fn format_label(&self, value: &DateTime<Z>) -> Option<String> {
+        let fmt = match self.3 {
+            Some(ref fmt) => fmt.as_str(),
+            None => default_time_format(self.1.clone() - self.0.clone()),
+        };
+        Some(value.format_time(fmt))
+    }
 }
 
-impl From<Range<Duration>> for RangedDuration {
-    fn from(range: Range<Duration>) -> Self {
-        Self(range.start, range.end)
+impl<Z: TimeZone, U: TimeUnit> ReversableRanged for RangedDateTime<Z, U> {
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<DateTime<Z>> {
+        let offset = part_of_span(self.2 .1 - self.2 .0, pixel_ratio(input, limit)?)?;
+        self.0.clone().checked_add_signed(offset)
     }
 }
 
-impl Ranged for RangedDuration {
-    type ValueType = Duration;
+// The adjacent values are found by flooring a time in the middle of the adjacent unit, since the
+// local units, e.g. the calendar days, are longer or shorter than the unit when the clocks change
+impl<Z: TimeZone, U: TimeUnit> DescreteRanged for RangedDateTime<Z, U> {
+    fn next_value(this: &DateTime<Z>) -> DateTime<Z> {
+        let floor = floor_to_unit(this, U::duration());
+        floor_to_unit(&(floor + U::duration() * 3 / 2), U::duration())
+    }
 
-    fn range(&self) -> Range<Duration> {
-        self.0..self.1
+    fn previous_value(this: &DateTime<Z>) -> DateTime<Z> {
+        let floor = floor_to_unit(this, U::duration());
+        if floor == *this {
+            floor_to_unit(&(floor - U::duration() / 2), U::duration())
+        } else {
+            floor
+        }
     }
 
-    fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
-        let total_span = self.1 - self.0;
-        let value_span = *value - self.0;
+    fn floor_value(this: &DateTime<Z>) -> DateTime<Z> {
+        floor_to_unit(this, U::duration())
+    }
+}
 
-        if let Some(total_ns) = total_span.num_nanoseconds() {
-            if let Some(value_ns) = value_span.num_nanoseconds() {
-                return limit.0
-                    + (f64::from(limit.1 - limit.0) * value_ns as f64 / total_ns as f64 + 1e-10)
-                        as i32;
+/// Round the time down to a multiple of `unit` since the local midnight. If the local time of
+/// the result is repeated because the clocks go back, the latest one no later than the time is
+/// used.
+fn floor_to_unit<Z: TimeZone>(time: &DateTime<Z>, unit: Duration) -> DateTime<Z> {
+    let local = time.naive_local();
+    let unit = unit.num_seconds();
+    let since_midnight = i64::from(local.num_seconds_from_midnight());
+    let floor = local.date().and_hms(0, 0, 0) + Duration::seconds(since_midnight / unit * unit);
+    match time.timezone().from_local_datetime(&floor) {
+        LocalResult::Single(floor) => floor,
+        LocalResult::Ambiguous(early, late) => {
+            if late <= *time {
+                late
+            } else {
+                early
             }
-            return limit.1;
         }
+        // The round local time is skipped by the transition
+        LocalResult::None => time.clone() - (local - floor),
+    }
+}
 
-        let total_days = total_span.num_days();
-        let value_days = value_span.num_days();
+/// Generate the key points every `step_ns` nanoseconds of the local time since the local
+/// midnight, which are on the round local times even if the UTC offset changes within the
+/// range, e.g. on a DST transition. The local times skipped by the transition are dropped, and
+/// both of the repeated ones are kept.
+fn local_key_points<Z: TimeZone>(range: &Range<DateTime<Z>>, step_ns: i64) -> Vec<DateTime<Z>> {
+    let tz = range.start.timezone();
+    let offsets = (
+        range.start.offset().fix().local_minus_utc(),
+        range.end.offset().fix().local_minus_utc(),
+    );
+    // The local times of the range are bounded by the UTC times with the offsets applied
+    let first = range.start.naive_utc() + Duration::seconds(i64::from(offsets.0.min(offsets.1)));
+    let last = range.end.naive_utc() + Duration::seconds(i64::from(offsets.0.max(offsets.1)));
+
+    let midnight = first.date().and_hms(0, 0, 0);
+    let since_midnight = (first - midnight).num_nanoseconds().unwrap_or(0);
+    let mut local =
+        midnight + Duration::nanoseconds((since_midnight + step_ns - 1) / step_ns * step_ns);
 
-        limit.0
-            + (f64::from(limit.1 - limit.0) * value_days as f64 / total_days as f64 + 1e-10) as i32
+    let mut ret = vec![];
+    while local <= last {
+        match tz.from_local_datetime(&local) {
+            LocalResult::Single(time) => ret.push(time),
+            LocalResult::Ambiguous(early, late) => {
+                ret.push(early);
+                ret.push(late);
+            }
+            LocalResult::None => {}
+        }
+        local += Duration::nanoseconds(step_ns);
     }
+    ret.retain(|time| range.start <= *time && *time <= range.end);
+    ret.sort();
+    ret
+}
 
-    fn key_points(&self, max_points: usize) -> Vec<Self::ValueType> {
-        let total_span = self.1 - self.0;
-
-        if let Some(total_ns) = total_span.num_nanoseconds() {
-            if let Some(period) = compute_period_per_point(total_ns as u64, max_points, false) {
-                let mut start_ns = self.0.num_nanoseconds().unwrap();
+/// Generate the key points that are multiples of the unit since the local midnight.
+/// The step is the first of `steps` (in units) that generates no more than `max_points` points,
+/// or a whole number of days if none does.
+fn generate_sub_day_key_points<Z: TimeZone>(
+    range: &Range<DateTime<Z>>,
+    unit: Duration,
+    steps: &[i64],
+    max_points: usize,
+) -> Vec<DateTime<Z>> {
+    let total = (range.end.clone() - range.start.clone()).num_seconds();
+    if total < 0 || max_points == 0 {
+        return vec![];
+    }
 
-                if start_ns as u64 % period > 0 {
-                    if start_ns > 0 {
-                        start_ns += period as i64 - (start_ns % period as i64);
-                    } else {
-                        start_ns -= start_ns % period as i64;
-                    }
-                }
+    let max_points = max_points as i64;
+    let unit = unit.num_seconds();
+    let step = steps
+        .iter()
+        .map(|s| s * unit)
+        .find(|step| total / step < max_points)
+        .unwrap_or_else(|| (total / max_points + 86400) / 86400 * 86400);
 
-                let mut current = Duration::nanoseconds(start_ns);
-                let mut ret = vec![];
+    local_key_points(range, step * 1_000_000_000)
+}
 
-                while current < self.1 {
-                    ret.push(current);
-                    current = current + Duration::nanoseconds(period as i64);
-                }
+macro_rules! make_sub_day_coord {
+    ($doc:expr, $into_doc:expr, $name:ident, $into:ident, $method:ident, $unit:expr, $steps:expr) => {
+        #[doc = $doc]
+        pub struct $name<T: TimeValue>(Range<T>, (i128, i128));
 
-                return ret;
-            }
+        impl<Z: TimeZone> AsRangedCoord for $name<DateTime<Z>> {
+            type CoordDescType = $name<DateTime<Z>>;
+            type Value = DateTime<Z>;
+        }
+
+        impl<Z: TimeZone> Ranged for $name<DateTime<Z>> {
+            type ValueType = DateTime<Z>;
+
+            fn range(&self) -> Range<DateTime<Z>> {
+                self.0.start.clone()..self.0.end.clone()
+            }
+
+            fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
+                map_timestamp(value.timestamp_ns(), self.1, limit)
+            }
+
+            fn key_points(&self, max_points: usize) -> Vec<Self::ValueType> {
+                if self.1 .0 > self.1 .1 {
+                    let reversed = $name(
+                        self.0.end.clone()..self.0.start.clone(),
+                        (self.1 .1, self.1 .0),
+                    );
+                    return reversed_key_points(reversed, max_points);
+                }
+                generate_sub_day_key_points(&self.0, $unit, $steps, max_points)
+            }
+
+            fn format_label(&self, value: &DateTime<Z>) -> Option<String> {
+                let span = self.0.end.clone() - self.0.start.clone();
+                Some(value.format_time(default_time_format(span)))
+            }
+        }
+
+        impl<Z: TimeZone> DescreteRanged for $name<DateTime<Z>> {
+            fn next_value(this: &DateTime<Z>) -> DateTime<Z> {
+                floor_to_unit(this, $unit) + $unit
+            }
+
+            fn previous_value(this: &DateTime<Z>) -> DateTime<Z> {
+                let floor = floor_to_unit(this, $unit);
+                if floor == *this {
+                    floor - $unit
+                } else {
+                    floor
+                }
+            }
+        }
+
+        #[doc = $into_doc]
+        pub trait $into<T: TimeValue> {
+            fn $method(self) -> $name<T>;
+        }
+
+        impl<Z: TimeZone> $into<DateTime<Z>> for Range<DateTime<Z>> {
+            fn $method(self) -> $name<DateTime<Z>> {
+                let timestamps = timestamp_range(&self);
+                $name(self, timestamps)
+            }
+        }
+    };
+}
+
+make_sub_day_coord!(
+    "Indicates the coord has an hourly resolution, the key points are on round hours",
+    "The trait that converts a date and time coord into an hourly one",
+    Hourly,
+    IntoHourly,
+    hourly,
+    Duration::hours(1),
+    &[1, 2, 3, 6, 12, 24]
+);
+
+make_sub_day_coord!(
+    "Indicates the coord has a minutely resolution, the key points are on round minutes",
+    "The trait that converts a date and time coord into a minutely one",
+    Minutely,
+    IntoMinutely,
+    minutely,
+    Duration::minutes(1),
+    &[1, 2, 5, 10, 15, 30, 60, 120, 180, 360, 720, 1440]
+);
+
+/// The calendar of the holidays, which are excluded from a `BusinessDays` coordinate along with
+/// the weekends. It's implemented for a list of the dates and the functions checking a date.
+pub trait HolidayCalendar {
+    /// Check if the date is a holiday
+    fn is_holiday(&self, date: NaiveDate) -> bool;
+}
+
+impl HolidayCalendar for Vec<NaiveDate> {
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.contains(&date)
+    }
+}
+
+impl<F: Fn(NaiveDate) -> bool> HolidayCalendar for F {
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        self(date)
+    }
+}
+
+/// The coordinate of the business days, which maps each trading day to a segment of the same
+/// width and leaves out the weekends and the holidays, so the market data has no flat gaps.
+/// The key points are the trading days, the first ones of the weeks or the months if there are
+/// too many days. The values on a weekend or a holiday are mapped to the start of the next
+/// trading day.
+///
+/// The range should be ascending.
+///
+/// ```rust,no_run
+/// use chrono::{NaiveDate, TimeZone, Utc};
+/// use plotters::coord::IntoBusinessDays;
+/// use plotters::prelude::*;
+///
+/// let holidays = vec![NaiveDate::from_ymd(2019, 12, 25), NaiveDate::from_ymd(2020, 1, 1)];
+/// let x_axis = (Utc.ymd(2019, 12, 2)..Utc.ymd(2020, 1, 10))
+///     .business_days()
+///     .holidays(holidays);
+///
+/// let root = BitMapBackend::new("business-days.png", (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .x_label_area_size(30)
+///     .build_ranged(x_axis, 0.0..100.0)
+///     .unwrap();
+/// chart.configure_mesh().draw().unwrap();
+/// ```
+pub struct BusinessDays<T: TimeValue> {
+    range: Range<T>,
+    /// The trading days from the date of the start to the date of the end
+    days: Vec<Date<T::Tz>>,
+    calendar: Option<Box<dyn HolidayCalendar>>,
+    format: Option<String>,
+}
+
+impl<T: TimeValue + Clone> BusinessDays<T> {
+    /// Set the calendar of the holidays, the weekends are always excluded
+    /// - `calendar`: The calendar, e.g. a list of the dates or a function checking a date
+    pub fn holidays<C: HolidayCalendar + 'static>(mut self, calendar: C) -> Self {
+        self.calendar = Some(Box::new(calendar));
+        self.update_days();
+        self
+    }
+
+    /// Set the strftime-style format of the labels, which is `%Y-%m-%d` by default
+    /// - `fmt`: The format string
+    pub fn label_format<S: Into<String>>(mut self, fmt: S) -> Self {
+        self.format = Some(fmt.into());
+        self
+    }
+
+    fn is_trading_day(&self, date: &Date<T::Tz>) -> bool {
+        let naive = date.naive_local();
+        let holiday = match &self.calendar {
+            Some(calendar) => calendar.is_holiday(naive),
+            None => false,
+        };
+        naive.weekday().num_days_from_monday() < 5 && !holiday
+    }
+
+    fn update_days(&mut self) {
+        let mut date = self.range.start.date_floor();
+        let end = self.range.end.date_floor();
+        let mut days = vec![];
+        while date <= end {
+            if self.is_trading_day(&date) {
+                days.push(date.clone());
+            }
+            date += Duration::days(1);
+        }
+        self.days = days;
+    }
+
+    /// Get the position of the value in the number of the trading days from the first one
+    fn position(&self, value: &T) -> f64 {
+        let date = value.date_floor();
+        let idx = match self.days.binary_search(&date) {
+            Ok(idx) | Err(idx) => idx,
+        };
+        if self.days.get(idx) != Some(&date) {
+            return idx as f64;
+        }
+        let since_midnight = value.subtract(&T::earliest_after_date(date)).num_seconds();
+        idx as f64 + (since_midnight as f64 / 86400.0).clamp(0.0, 1.0)
+    }
+}
+
+impl<T: TimeValue + Clone> AsRangedCoord for BusinessDays<T> {
+    type CoordDescType = BusinessDays<T>;
+    type Value = T;
+}
+
+impl<T: TimeValue + Clone> Ranged for BusinessDays<T> {
+    type ValueType = T;
+
+    fn range(&self) -> Range<T> {
+        self.range.clone()
+    }
+
+    fn map(&self, value: &T, limit: (i32, i32)) -> i32 {
+        let start = self.position(&self.range.start);
+        let span = self.position(&self.range.end) - start;
+        if span <= 0.0 {
+            return limit.0;
+        }
+        let ratio = (self.position(value) - start) / span;
+        limit.0 + (f64::from(limit.1 - limit.0) * ratio).round() as i32
+    }
+
+    fn key_points(&self, max_points: usize) -> Vec<T> {
+        let (start, end) = (
+            self.range.start.timestamp_ns(),
+            self.range.end.timestamp_ns(),
+        );
+        let days: Vec<_> = self
+            .days
+            .iter()
+            .map(|day| T::earliest_after_date(day.clone()))
+            .filter(|day| (start..=end).contains(&day.timestamp_ns()))
+            .collect();
+        if max_points == 0 {
+            return vec![];
+        }
+        if days.len() <= max_points {
+            return days;
+        }
+
+        // The first trading days of the weeks, and then of the months
+        let weeks = first_days_of(&days, |date| date.iso_week().week0());
+        if weeks.len() <= max_points {
+            return weeks;
+        }
+        let months = first_days_of(&days, |date| date.month0());
+        let month_per_point =
+            months.len() / max_points + usize::from(months.len() % max_points != 0);
+        months.into_iter().step_by(month_per_point).collect()
+    }
+
+    fn format_label(&self, value: &T) -> Option<String> {
+        Some(value.format_time(self.format.as_deref().unwrap_or("%Y-%m-%d")))
+    }
+}
+
+/// Pick the days whose period, e.g. the week or the month, differs from the previous day
+fn first_days_of<T: TimeValue + Clone, P: Fn(NaiveDate) -> u32>(days: &[T], period: P) -> Vec<T> {
+    let mut last = None;
+    days.iter()
+        .filter(|day| {
+            let current = Some(period(day.date_floor().naive_local()));
+            let first = current != last;
+            last = current;
+            first
+        })
+        .cloned()
+        .collect()
+}
+
+/// The trait that converts a date or date and time range into a business day coord
+pub trait IntoBusinessDays<T: TimeValue> {
+    /// Make the coord skip the weekends, and the holidays set by `BusinessDays::holidays`
+    fn business_days(self) -> BusinessDays<T>;
+}
+
+impl<T: TimeValue + Clone> IntoBusinessDays<T> for Range<T> {
+    fn business_days(self) -> BusinessDays<T> {
+        let mut coord = BusinessDays {
+            range: self,
+            days: vec![],
+            calendar: None,
+            format: None,
+        };
+        coord.update_days();
+        coord
+    }
+}
+
+const SECONDS_PER_DAY: f64 = 86400.0;
+
+/// The time coordinate which leaves out some windows of every day, e.g. the hours when the
+/// market is closed, so that the windows take no space on the axis. The key points never land
+/// inside the windows, the key points of the days are at the first time of the days which isn't
+/// left out.
+///
+/// ```rust,no_run
+/// use chrono::{NaiveTime, TimeZone, Utc};
+/// use plotters::coord::IntoDailyGaps;
+/// use plotters::prelude::*;
+///
+/// let start = Utc.ymd(2019, 12, 2).and_hms(9, 30, 0);
+/// let x_axis = (start..Utc.ymd(2019, 12, 6).and_hms(16, 0, 0))
+///     .skip_daily(vec![NaiveTime::from_hms(16, 0, 0)..NaiveTime::from_hms(9, 30, 0)]);
+///
+/// let root = BitMapBackend::new("daily-gaps.png", (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .x_label_area_size(30)
+///     .build_ranged(x_axis, 0.0..100.0)
+///     .unwrap();
+/// chart.configure_mesh().draw().unwrap();
+/// ```
+pub struct DailyGaps<T: TimeValue> {
+    range: Range<T>,
+    /// The windows left out, in seconds from the midnight, which are sorted and disjoint
+    gaps: Vec<(f64, f64)>,
+    format: Option<String>,
+}
+
+impl<T: TimeValue + Clone + Add<Duration, Output = T>> DailyGaps<T> {
+    /// Set the strftime-style format of the labels, which is chosen by the span of the axis by
+    /// default, see `RangedDateTime::label_format`
+    /// - `fmt`: The format string
+    pub fn label_format<S: Into<String>>(mut self, fmt: S) -> Self {
+        self.format = Some(fmt.into());
+        self
+    }
+
+    fn in_gap(&self, seconds: f64) -> bool {
+        self.gaps.iter().any(|&(a, b)| a <= seconds && seconds < b)
+    }
+
+    /// Get the number of seconds in a day that aren't left out
+    fn seconds_per_day(&self) -> f64 {
+        SECONDS_PER_DAY - self.gaps.iter().map(|(a, b)| b - a).sum::<f64>()
+    }
+
+    /// Get the number of seconds before the time of the day that aren't left out
+    fn seconds_before(&self, seconds: f64) -> f64 {
+        seconds
+            - self
+                .gaps
+                .iter()
+                .map(|&(a, b)| (seconds - a).clamp(0.0, b - a))
+                .sum::<f64>()
+    }
+
+    /// Get the first time of the day which isn't left out
+    fn first_second(&self) -> Option<f64> {
+        let mut seconds = 0.0;
+        for &(a, b) in &self.gaps {
+            if a <= seconds && seconds < b {
+                seconds = b;
+            }
+        }
+        Some(seconds).filter(|s| *s < SECONDS_PER_DAY)
+    }
+
+    /// Get the date and the number of seconds from the midnight of the value
+    fn split_time(value: &T) -> (Date<T::Tz>, f64) {
+        let date = value.date_floor();
+        let since_midnight = value.subtract(&T::earliest_after_date(date.clone()));
+        let seconds = since_midnight.num_nanoseconds().unwrap_or(0) as f64 / 1e9;
+        (date, seconds)
+    }
+
+    /// Get the number of seconds which aren't left out from a fixed date to the value
+    fn position(&self, value: &T) -> f64 {
+        let (date, seconds) = Self::split_time(value);
+        let days = date.naive_local().num_days_from_ce();
+        f64::from(days) * self.seconds_per_day() + self.seconds_before(seconds)
+    }
+
+    /// Get the key points every `step` seconds from the midnight of each day
+    fn points_every(&self, step: u32) -> Vec<T> {
+        let (start, end) = (
+            self.range.start.timestamp_ns(),
+            self.range.end.timestamp_ns(),
+        );
+        let mut date = self.range.start.date_floor();
+        let mut ret = vec![];
+        while T::earliest_after_date(date.clone()).timestamp_ns() <= end {
+            let midnight = T::earliest_after_date(date.clone());
+            for seconds in (0..SECONDS_PER_DAY as u32).step_by(step as usize) {
+                if self.in_gap(f64::from(seconds)) {
+                    continue;
+                }
+                let point = midnight.clone() + Duration::seconds(i64::from(seconds));
+                if (start..=end).contains(&point.timestamp_ns()) {
+                    ret.push(point);
+                }
+            }
+            date += Duration::days(1);
+        }
+        ret
+    }
+
+    /// Get the key points at the first times of every `step` days
+    fn points_every_days(&self, step: u32) -> Vec<T> {
+        let first = match self.first_second() {
+            Some(first) => Duration::nanoseconds((first * 1e9) as i64),
+            None => return vec![],
+        };
+        let (start, end) = (
+            self.range.start.timestamp_ns(),
+            self.range.end.timestamp_ns(),
+        );
+        let mut date = self.range.start.date_floor();
+        let mut ret = vec![];
+        loop {
+            let point = T::earliest_after_date(date.clone()) + first;
+            if point.timestamp_ns() > end {
+                return ret;
+            }
+            if point.timestamp_ns() >= start {
+                ret.push(point);
+            }
+            date += Duration::days(i64::from(step));
+        }
+    }
+}
+
+impl<T: TimeValue + Clone + Add<Duration, Output = T>> AsRangedCoord for DailyGaps<T> {
+    type CoordDescType = DailyGaps<T>;
+    type Value = T;
+}
+
+impl<T: TimeValue + Clone + Add<Duration, Output = T>> Ranged for DailyGaps<T> {
+    type ValueType = T;
+
+    fn range(&self) -> Range<T> {
+        self.range.clone()
+    }
+
+    fn map(&self, value: &T, limit: (i32, i32)) -> i32 {
+        let start = self.position(&self.range.start);
+        let span = self.position(&self.range.end) - start;
+        if span <= 0.0 {
+            return limit.0;
+        }
+        let ratio = (self.position(value) - start) / span;
+        limit.0 + (f64::from(limit.1 - limit.0) * ratio).round() as i32
+    }
+
+    fn key_points(&self, max_points: usize) -> Vec<T> {
+        const STEPS: &[u32] = &[
+            1, 2, 5, 10, 15, 30, 60, 120, 300, 600, 900, 1800, 3600, 7200, 10800, 21600, 43200,
+        ];
+        const DAY_STEPS: &[u32] = &[1, 2, 7, 14, 28, 56, 112, 364];
+
+        let span = self.position(&self.range.end) - self.position(&self.range.start);
+        if max_points == 0 || span <= 0.0 {
+            return vec![];
+        }
+
+        // Skip the steps which obviously give too many points, before trying them out. The steps
+        // giving less than two points a day are skipped too, the days are used instead.
+        for &step in STEPS {
+            if span / f64::from(step) > max_points as f64
+                || f64::from(step) * 2.0 > self.seconds_per_day()
+            {
+                continue;
+            }
+            let points = self.points_every(step);
+            if points.len() <= max_points {
+                return points;
+            }
+        }
+        let days = span / self.seconds_per_day().max(1.0);
+        for &step in DAY_STEPS {
+            if days / f64::from(step) > max_points as f64 {
+                continue;
+            }
+            let points = self.points_every_days(step);
+            if points.len() <= max_points {
+                return points;
+            }
+        }
+        vec![]
+    }
+
+    /// The format is chosen by the span without the windows left out, which is the span of the
+    /// time shown on the axis
+    fn format_label(&self, value: &T) -> Option<String> {
+        let span = self.position(&self.range.end) - self.position(&self.range.start);
+        let span = Duration::seconds(span as i64);
+        Some(
+            value.format_time(
+                self.format
+                    .as_deref()
+                    .unwrap_or_else(|| default_time_format(span)),
+            ),
+        )
+    }
+}
+
+/// The trait that converts a time range into a coord leaving out some windows of every day
+pub trait IntoDailyGaps<T: TimeValue> {
+    /// Leave out the windows of every day from the coord
+    /// - `windows`: The windows of the time of the day, a window whose end is earlier than its
+    ///   start wraps around the midnight, e.g. `16:00..09:30` is the night
+    fn skip_daily<I: IntoIterator<Item = Range<NaiveTime>>>(self, windows: I) -> DailyGaps<T>;
+}
+
+impl<T: TimeValue> IntoDailyGaps<T> for Range<T> {
+    fn skip_daily<I: IntoIterator<Item = Range<NaiveTime>>>(self, windows: I) -> DailyGaps<T> {
+        let seconds = |time: NaiveTime| {
+            f64::from(time.num_seconds_from_midnight()) + f64::from(time.nanosecond()) / 1e9
+        };
+        let mut gaps = vec![];
+        for window in windows {
+            let (a, b) = (seconds(window.start), seconds(window.end));
+            if a <= b {
+                gaps.push((a, b));
+            } else {
+                gaps.push((a, SECONDS_PER_DAY));
+                gaps.push((0.0, b));
+            }
+        }
+
+        // Merge the overlapping windows, so that no second is left out twice
+        gaps.sort_by(|x, y| x.partial_cmp(y).unwrap());
+        let mut merged: Vec<(f64, f64)> = vec![];
+        for (a, b) in gaps.into_iter().filter(|(a, b)| a < b) {
+            match merged.last_mut() {
+                Some(last) if a <= last.1 => last.1 = last.1.max(b),
+                _ => merged.push((a, b)),
+            }
+        }
+
+        DailyGaps {
+            range: self,
+            gaps: merged,
+            format: None,
+        }
+    }
+}
+
+/// The ranged coordinate for the naive date, which works like a `RangedDate` in UTC
+pub struct RangedNaiveDate(RangedDate<Utc>);
+
+impl RangedNaiveDate {
+    /// Set the strftime-style format of the labels, see `RangedDate::label_format`
+    /// - `fmt`: The format string
+    pub fn label_format<S: Into<String>>(self, fmt: S) -> Self {
+        Self(self.0.label_format(fmt))
+    }
+}
+
+impl AsRangedCoord for Range<NaiveDate> {
+    type CoordDescType = RangedNaiveDate;
+    type Value = NaiveDate;
+}
+
+impl From<Range<NaiveDate>> for RangedNaiveDate {
+    fn from(range: Range<NaiveDate>) -> Self {
+        Self((range.start.date_floor()..range.end.date_floor()).into())
+    }
+}
+
+impl Ranged for RangedNaiveDate {
+    type ValueType = NaiveDate;
+
+    fn range(&self) -> Range<NaiveDate> {
+        let range = self.0.range();
+        range.start.naive_utc()..range.end.naive_utc()
+    }
+
+    fn map(&self, value: &NaiveDate, limit: (i32, i32)) -> i32 {
+        self.0.map(&value.date_floor(), limit)
+    }
+
+    fn key_points(&self, max_points: usize) -> Vec<NaiveDate> {
+        self.0
+            .key_points(max_points)
+            .into_iter()
+            .map(|x| x.naive_utc())
+            .collect()
+    }
+
+    fn format_label(&self, value: &NaiveDate) -> Option<String> {
+        self.0.format_label(&value.date_floor())
+    }
+}
+
+impl ReversableRanged for RangedNaiveDate {
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<NaiveDate> {
+        self.0.unmap(input, limit).map(|x| x.naive_utc())
+    }
+}
+
+impl DescreteRanged for RangedNaiveDate {
+    fn next_value(this: &NaiveDate) -> NaiveDate {
+        *this + Duration::days(1)
+    }
+
+    fn previous_value(this: &NaiveDate) -> NaiveDate {
+        *this - Duration::days(1)
+    }
+
+    fn size(&self) -> Option<usize> {
+        self.0.size()
+    }
+
+    fn index_of(&self, value: &NaiveDate) -> Option<usize> {
+        self.0.index_of(&value.date_floor())
+    }
+
+    fn value_at(&self, index: usize) -> Option<NaiveDate> {
+        self.0.value_at(index).map(|x| x.naive_utc())
+    }
+}
+
+/// The ranged coordinate for the naive date and time, which works like a `RangedDateTime` in
+/// UTC
+pub struct RangedNaiveDateTime(RangedDateTime<Utc>);
+
+impl RangedNaiveDateTime {
+    /// Set the strftime-style format of the labels, see `RangedDateTime::label_format`
+    /// - `fmt`: The format string
+    pub fn label_format<S: Into<String>>(self, fmt: S) -> Self {
+        Self(self.0.label_format(fmt))
+    }
+}
+
+impl AsRangedCoord for Range<NaiveDateTime> {
+    type CoordDescType = RangedNaiveDateTime;
+    type Value = NaiveDateTime;
+}
+
+impl From<Range<NaiveDateTime>> for RangedNaiveDateTime {
+    fn from(range: Range<NaiveDateTime>) -> Self {
+        Self((DateTime::from_utc(range.start, Utc)..DateTime::from_utc(range.end, Utc)).into())
+    }
+}
+
+impl Ranged for RangedNaiveDateTime {
+    type ValueType = NaiveDateTime;
+
+    fn range(&self) -> Range<NaiveDateTime> {
+        let range = self.0.range();
+        range.start.naive_utc()..range.end.naive_utc()
+    }
+
+    fn map(&self, value: &NaiveDateTime, limit: (i32, i32)) -> i32 {
+        map_timestamp(value.timestamp_ns(), self.0 .2, limit)
+    }
+
+    fn key_points(&self, max_points: usize) -> Vec<NaiveDateTime> {
+        self.0
+            .key_points(max_points)
+            .into_iter()
+            .map(|x| x.naive_utc())
+            .collect()
+    }
+
+    fn format_label(&self, value: &NaiveDateTime) -> Option<String> {
+        self.0.format_label(&DateTime::from_utc(*value, Utc))
+    }
+}
+
+impl ReversableRanged for RangedNaiveDateTime {
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<NaiveDateTime> {
+        self.0.unmap(input, limit).map(|x| x.naive_utc())
+    }
+}
+
+/// The coordinate that for duration of time. Its descrete values are the multiples of the time
+/// unit `U`, which is a second by default. The labels are like `1h 30m` or `250ms` unless a
+/// formatter is set by `RangedDuration::label_formatter`.
+#[allow(clippy::type_complexity)]
+pub struct RangedDuration<U: TimeUnit = Second>(
+    Duration,
+    Duration,
+    PhantomData<U>,
+    Option<Box<dyn Fn(&Duration) -> String + Send + Sync>>,
+);
+
+impl<U: TimeUnit> RangedDuration<U> {
+    /// Change the unit of the descrete values, see `RangedDateTime::with_unit`
+    pub fn with_unit<V: TimeUnit>(self) -> RangedDuration<V> {
+        RangedDuration(self.0, self.1, PhantomData, self.3)
+    }
+
+    /// Set the function that formats the labels
+    /// - `formatter`: The function that formats the duration
+    pub fn label_formatter<F: Fn(&Duration) -> String + Send + Sync + 'static>(
+        mut self,
+        formatter: F,
+    ) -> Self {
+        self.3 = Some(Box::new(formatter));
+        self
+    }
+}
+
+impl AsRangedCoord for Range<Duration> {
+    type CoordDescType = RangedDuration;
+    type Value = Duration;
+}
+
+impl<U: TimeUnit> From<Range<Duration>> for RangedDuration<U> {
+    fn from(range: Range<Duration>) -> Self {
+        Self(range.start, range.end, PhantomData, None)
+    }
+}
+
+impl<U: TimeUnit> Ranged for RangedDuration<U> {
+    type ValueType = Duration;
+
+    fn range(&self) -> Range<Duration> {
+        self.0..self.1
+    }
+
+    fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
+        let total_span = self.1 - self.0;
+        let value_span = *value - self.0;
+
+        if let Some(total_ns) = total_span.num_nanoseconds() {
+            if let Some(value_ns) = value_span.num_nanoseconds() {
+                return limit.0
+                    + (f64::from(limit.1 - limit.0) * value_ns as f64 / total_ns as f64 + 1e-10)
+                        as i32;
+            }
+            return limit.1;
+        }
+
+        let total_days = total_span.num_days();
+        let value_days = value_span.num_days();
+
+        limit.0
+            + (f64::from(limit.1 - limit.0) * value_days as f64 / total_days as f64 + 1e-10) as i32
+    }
+
+    fn key_points(&self, max_points: usize) -> Vec<Self::ValueType> {
+        if self.0 > self.1 {
+            return reversed_key_points(RangedDuration::<U>::from(self.1..self.0), max_points);
+        }
+        let total_span = self.1 - self.0;
+
+        if let Some(total_ns) = total_span.num_nanoseconds() {
+            if let Some(period) = compute_period_per_point(total_ns as u64, max_points, false) {
+                let mut start_ns = self.0.num_nanoseconds().unwrap();
+
+                if start_ns as u64 % period > 0 {
+                    if start_ns > 0 {
+                        start_ns += period as i64 - (start_ns % period as i64);
+                    } else {
+                        start_ns -= start_ns % period as i64;
+                    }
+                }
+
+                let mut current = Duration::nanoseconds(start_ns);
+                let mut ret = vec![];
+
+                while current < self.1 {
+                    ret.push(current);
+                    current = current + Duration::nanoseconds(period as i64);
+                }
+
+                return ret;
+            }
         }
 
         let begin_days = self.0.num_days();
@@ -577,8 +1880,282 @@ impl Ranged for RangedDuration {
 
         ret
     }
+
+    fn format_label(&self, value: &Duration) -> Option<String> {
+        if let Some(ref formatter) = self.3 {
+            return Some(formatter(value));
+        }
+        let span = if self.1 > self.0 {
+            self.1 - self.0
+        } else {
+            self.0 - self.1
+        };
+        Some(format_duration(*value, span / 100))
+    }
+}
+
+impl<U: TimeUnit> ReversableRanged for RangedDuration<U> {
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<Duration> {
+        let span = self.1 - self.0;
+        let span_ns = match span.num_nanoseconds() {
+            Some(ns) => i128::from(ns),
+            None => i128::from(span.num_milliseconds()) * 1_000_000,
+        };
+        self.0
+            .checked_add(&part_of_span(span_ns, pixel_ratio(input, limit)?)?)
+    }
+}
+
+/// Format the duration with the units from days to nanoseconds, e.g. `1h 30m`. The duration is
+/// rounded to the largest unit no longer than `precision`, so the labels of an axis only show
+/// the units that the ticks differ in.
+fn format_duration(value: Duration, precision: Duration) -> String {
+    const UNITS: [(&str, u64); 7] = [
+        ("d", 86_400_000_000_000),
+        ("h", 3_600_000_000_000),
+        ("m", 60_000_000_000),
+        ("s", 1_000_000_000),
+        ("ms", 1_000_000),
+        ("us", 1_000),
+        ("ns", 1),
+    ];
+
+    let total_ns = match value.num_nanoseconds() {
+        Some(ns) => ns,
+        None => return format!("{}d", value.num_days()),
+    };
+    let precision = precision
+        .num_nanoseconds()
+        .map_or(u64::MAX, |ns| ns.max(1) as u64);
+    let smallest = UNITS
+        .iter()
+        .map(|(_, unit)| *unit)
+        .find(|unit| *unit <= precision)
+        .unwrap_or(1);
+
+    let mut rest = (total_ns.unsigned_abs() + smallest / 2) / smallest * smallest;
+    let mut parts = vec![];
+    for (name, unit) in UNITS.iter().filter(|(_, unit)| *unit >= smallest) {
+        if rest >= *unit {
+            parts.push(format!("{}{}", rest / unit, name));
+            rest %= unit;
+        }
+    }
+
+    match (parts.is_empty(), total_ns < 0) {
+        (true, _) => "0".to_string(),
+        (false, true) => format!("-{}", parts.join(" ")),
+        (false, false) => parts.join(" "),
+    }
+}
+
+/// Round the duration down to a multiple of the unit
+fn floor_duration(this: &Duration, unit: Duration) -> Duration {
+    let mut secs = this.num_seconds();
+    if Duration::seconds(secs) > *this {
+        secs -= 1;
+    }
+    let unit = unit.num_seconds();
+    Duration::seconds(secs.div_euclid(unit) * unit)
+}
+
+impl<U: TimeUnit> DescreteRanged for RangedDuration<U> {
+    fn next_value(this: &Duration) -> Duration {
+        floor_duration(this, U::duration()) + U::duration()
+    }
+
+    fn previous_value(this: &Duration) -> Duration {
+        let floor = floor_duration(this, U::duration());
+        if floor == *this {
+            floor - U::duration()
+        } else {
+            floor
+        }
+    }
+
+    fn floor_value(this: &Duration) -> Duration {
+        floor_duration(this, U::duration())
+    }
+}
+
+/// The ranged coordinate for the system time, which works like a `RangedDateTime` in UTC
+pub struct RangedSystemTime(RangedDateTime<Utc>);
+
+impl RangedSystemTime {
+    /// Set the strftime-style format of the labels, see `RangedDateTime::label_format`
+    /// - `fmt`: The format string
+    pub fn label_format<S: Into<String>>(self, fmt: S) -> Self {
+        Self(self.0.label_format(fmt))
+    }
 }
 
+impl AsRangedCoord for Range<SystemTime> {
+    type CoordDescType = RangedSystemTime;
+    type Value = SystemTime;
+}
+
+impl From<Range<SystemTime>> for RangedSystemTime {
+    fn from(range: Range<SystemTime>) -> Self {
+        Self((DateTime::<Utc>::from(range.start)..DateTime::<Utc>::from(range.end)).into())
+    }
+}
+
+impl Ranged for RangedSystemTime {
+    type ValueType = SystemTime;
+
+    fn range(&self) -> Range<SystemTime> {
+        let range = self.0.range();
+        range.start.into()..range.end.into()
+    }
+
+    fn map(&self, value: &SystemTime, limit: (i32, i32)) -> i32 {
+        map_timestamp(value.timestamp_ns(), self.0 .2, limit)
+    }
+
+    fn key_points(&self, max_points: usize) -> Vec<SystemTime> {
+        self.0
+            .key_points(max_points)
+            .into_iter()
+            .map(SystemTime::from)
+            .collect()
+    }
+
+    fn format_label(&self, value: &SystemTime) -> Option<String> {
+        self.0.format_label(&DateTime::from(*value))
+    }
+}
+
+/// The coordinate for the standard library duration, which works like a `RangedDuration`
+pub struct RangedStdDuration(Range<std::time::Duration>, (i128, i128));
+
+impl AsRangedCoord for Range<std::time::Duration> {
+    type CoordDescType = RangedStdDuration;
+    type Value = std::time::Duration;
+}
+
+impl From<Range<std::time::Duration>> for RangedStdDuration {
+    fn from(range: Range<std::time::Duration>) -> Self {
+        let timestamps = (range.start.as_nanos() as i128, range.end.as_nanos() as i128);
+        Self(range, timestamps)
+    }
+}
+
+impl Ranged for RangedStdDuration {
+    type ValueType = std::time::Duration;
+
+    fn range(&self) -> Range<std::time::Duration> {
+        self.0.clone()
+    }
+
+    fn map(&self, value: &std::time::Duration, limit: (i32, i32)) -> i32 {
+        map_timestamp(value.as_nanos() as i128, self.1, limit)
+    }
+
+    fn key_points(&self, max_points: usize) -> Vec<std::time::Duration> {
+        // The durations which are too long for chrono don't have any key points
+        match (
+            Duration::from_std(self.0.start),
+            Duration::from_std(self.0.end),
+        ) {
+            (Ok(start), Ok(end)) => RangedDuration::<Second>::from(start..end)
+                .key_points(max_points)
+                .into_iter()
+                .filter_map(|x| x.to_std().ok())
+                .collect(),
+            _ => vec![],
+        }
+    }
+}
+
+macro_rules! make_epoch_coord {
+    ($doc:expr, $into_doc:expr, $name:ident, $into:ident, $method:ident, $to_time:expr, $from_time:expr) => {
+        #[doc = $doc]
+        pub struct $name(Range<i64>, RangedDateTime<Utc>);
+
+        impl $name {
+            /// Set the strftime-style format of the labels, see `RangedDateTime::label_format`
+            /// - `fmt`: The format string
+            pub fn label_format<S: Into<String>>(self, fmt: S) -> Self {
+                Self(self.0, self.1.label_format(fmt))
+            }
+        }
+
+        impl From<Range<i64>> for $name {
+            fn from(range: Range<i64>) -> Self {
+                let times = $to_time(range.start)..$to_time(range.end);
+                Self(range, times.into())
+            }
+        }
+
+        impl Ranged for $name {
+            type ValueType = i64;
+
+            fn range(&self) -> Range<i64> {
+                self.0.clone()
+            }
+
+            fn map(&self, value: &i64, limit: (i32, i32)) -> i32 {
+                let range = (i128::from(self.0.start), i128::from(self.0.end));
+                map_timestamp(i128::from(*value), range, limit)
+            }
+
+            fn key_points(&self, max_points: usize) -> Vec<i64> {
+                self.1
+                    .key_points(max_points)
+                    .iter()
+                    .map($from_time)
+                    .collect()
+            }
+
+            fn format_label(&self, value: &i64) -> Option<String> {
+                self.1.format_label(&$to_time(*value))
+            }
+        }
+
+        #[doc = $into_doc]
+        pub trait $into {
+            fn $method(self) -> $name;
+        }
+
+        impl $into for Range<i64> {
+            fn $method(self) -> $name {
+                self.into()
+            }
+        }
+    };
+}
+
+/// Convert the timestamp to the time, the timestamps out of the range of chrono are clamped to
+/// the UNIX epoch
+fn epoch_time(result: LocalResult<DateTime<Utc>>) -> DateTime<Utc> {
+    result
+        .single()
+        .unwrap_or_else(|| Utc.timestamp_opt(0, 0).unwrap())
+}
+
+make_epoch_coord!(
+    "The coordinate of the UNIX timestamps in seconds, which maps the integers directly and only \
+     uses the date and time to compute the key points and the labels",
+    "The trait that converts a range of the UNIX timestamps in seconds into a time coordinate",
+    RangedEpochSeconds,
+    IntoEpochSeconds,
+    epoch_seconds,
+    |secs| epoch_time(Utc.timestamp_opt(secs, 0)),
+    |time: &DateTime<Utc>| time.timestamp()
+);
+
+make_epoch_coord!(
+    "The coordinate of the UNIX timestamps in milliseconds, which maps the integers directly and \
+     only uses the date and time to compute the key points and the labels",
+    "The trait that converts a range of the UNIX timestamps in milliseconds into a time \
+     coordinate",
+    RangedEpochMillis,
+    IntoEpochMillis,
+    epoch_millis,
+    |millis| epoch_time(Utc.timestamp_millis_opt(millis)),
+    |time: &DateTime<Utc>| time.timestamp_millis()
+);
+
 #[allow(clippy::inconsistent_digit_grouping)]
 fn compute_period_per_point(total_ns: u64, max_points: usize, sub_daily: bool) -> Option<u64> {
     let min_ns_per_point = total_ns as f64 / max_points as f64;
@@ -648,3 +2225,637 @@ fn compute_period_per_point(total_ns: u64, max_points: usize, sub_daily: bool) -
         None
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use chrono::{FixedOffset, Utc};
+
+    /// The timezone of central Europe in 2019, which is UTC+1, and UTC+2 from 2019-03-31 01:00
+    /// to 2019-10-27 01:00 UTC
+    #[derive(Clone, Copy, Debug)]
+    struct Cet;
+
+    impl Cet {
+        fn offset_at(utc: &NaiveDateTime) -> FixedOffset {
+            let summer = NaiveDate::from_ymd(2019, 3, 31).and_hms(1, 0, 0)
+                ..NaiveDate::from_ymd(2019, 10, 27).and_hms(1, 0, 0);
+            FixedOffset::east(if summer.contains(utc) { 7200 } else { 3600 })
+        }
+    }
+
+    impl TimeZone for Cet {
+        type Offset = FixedOffset;
+        fn from_offset(_: &FixedOffset) -> Self {
+            Cet
+        }
+        fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<FixedOffset> {
+            self.offset_from_local_datetime(&local.and_hms(0, 0, 0))
+        }
+        fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<FixedOffset> {
+            let valid: Vec<_> = [7200, 3600]
+                .iter()
+                .map(|secs| FixedOffset::east(*secs))
+                .filter(|offset| Self::offset_at(&(*local - *offset)) == *offset)
+                .collect();
+            match valid[..] {
+                [offset] => LocalResult::Single(offset),
+                [early, late] => LocalResult::Ambiguous(early, late),
+                _ => LocalResult::None,
+            }
+        }
+        fn offset_from_utc_date(&self, utc: &NaiveDate) -> FixedOffset {
+            Self::offset_at(&utc.and_hms(0, 0, 0))
+        }
+        fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> FixedOffset {
+            Self::offset_at(utc)
+        }
+    }
+
+    #[test]
+    fn test_date_range_map() {
+        let coord: RangedDate<_> = (Utc.ymd(2019, 1, 1)..Utc.ymd(2019, 1, 11)).into();
+
+        assert_eq!(coord.map(&Utc.ymd(2019, 1, 1), (0, 100)), 0);
+        assert_eq!(coord.map(&Utc.ymd(2019, 1, 6), (0, 100)), 50);
+        assert_eq!(coord.map(&Utc.ymd(2019, 1, 11), (0, 100)), 100);
+
+        let coord = (Utc.ymd(2019, 1, 1)..Utc.ymd(2020, 1, 1)).monthly();
+
+        assert_eq!(coord.map(&Utc.ymd(2019, 7, 2), (0, 365)), 182);
+    }
+
+    #[test]
+    fn test_descending_date_range() {
+        let coord: RangedDate<_> = (Utc.ymd(2019, 1, 11)..Utc.ymd(2019, 1, 1)).into();
+
+        assert_eq!(coord.map(&Utc.ymd(2019, 1, 11), (0, 100)), 0);
+        assert_eq!(coord.map(&Utc.ymd(2019, 1, 1), (0, 100)), 100);
+
+        let key_points = coord.key_points(20);
+        assert_eq!(key_points.len(), 11);
+        assert_eq!(key_points[0], Utc.ymd(2019, 1, 11));
+        assert_eq!(key_points[10], Utc.ymd(2019, 1, 1));
+
+        let coord = (Utc.ymd(2020, 1, 1)..Utc.ymd(2019, 1, 1)).monthly();
+        let key_points = coord.key_points(20);
+        assert_eq!(key_points[0], Utc.ymd(2020, 1, 1));
+        assert_eq!(key_points[key_points.len() - 1], Utc.ymd(2019, 1, 1));
+
+        let coord: RangedDateTime<_> =
+            (Utc.ymd(2019, 1, 1).and_hms(10, 0, 0)..Utc.ymd(2019, 1, 1).and_hms(0, 0, 0)).into();
+        let key_points = coord.key_points(20);
+        assert!(key_points.windows(2).all(|w| w[0] > w[1]));
+    }
+
+    #[test]
+    fn test_monthly_centric() {
+        // The months are not evenly spaced, thus each one is centered with its previous month
+        use crate::coord::IntoCentric;
+        let months = (Utc.ymd(2019, 1, 1)..Utc.ymd(2019, 4, 1)).monthly();
+        let (feb, mar) = (Utc.ymd(2019, 2, 1), Utc.ymd(2019, 3, 1));
+        let mid = (months.map(&feb, (0, 900)) + months.map(&mar, (0, 900))) / 2;
+        let coord = months.into_centric();
+        assert_eq!(coord.map(&mar, (0, 900)), mid);
+    }
+
+    #[test]
+    fn test_descending_date_index() {
+        use crate::coord::IntoCentric;
+
+        // A descending range can't index its values, even if it's one day short
+        for days in 1..4 {
+            let coord: RangedDate<_> =
+                (Utc.ymd(2019, 1, 11)..Utc.ymd(2019, 1, 11) - Duration::days(days)).into();
+            assert_eq!(coord.size(), None);
+            assert_eq!(coord.index_of(&Utc.ymd(2019, 1, 10)), None);
+            assert_eq!(coord.value_at(0), None);
+        }
+
+        let coord = (Utc.ymd(2019, 1, 11)..Utc.ymd(2019, 1, 1)).into_centric();
+        assert_eq!(coord.map(&Utc.ymd(2019, 1, 6), (0, 100)), 55);
+
+        let coord: RangedNaiveDate =
+            (NaiveDate::from_ymd(2019, 1, 2)..NaiveDate::from_ymd(2019, 1, 1)).into();
+        assert_eq!(coord.size(), None);
+    }
+
+    #[test]
+    fn test_weekly_key_points() {
+        // 2019-01-01 is a Tuesday
+        let coord = (Utc.ymd(2019, 1, 1)..Utc.ymd(2019, 2, 1)).weekly();
+        assert_eq!(
+            coord.key_points(10),
+            vec![
+                Utc.ymd(2019, 1, 7),
+                Utc.ymd(2019, 1, 14),
+                Utc.ymd(2019, 1, 21),
+                Utc.ymd(2019, 1, 28)
+            ]
+        );
+        assert_eq!(
+            coord.key_points(2),
+            vec![Utc.ymd(2019, 1, 7), Utc.ymd(2019, 1, 21)]
+        );
+
+        let coord = (Utc.ymd(2019, 1, 1)..Utc.ymd(2019, 2, 1))
+            .weekly()
+            .week_start(Weekday::Sun);
+        assert_eq!(coord.key_points(10)[0], Utc.ymd(2019, 1, 6));
+
+        assert_eq!(
+            Weekly::<Date<Utc>>::next_value(&Utc.ymd(2019, 1, 1)),
+            Utc.ymd(2019, 1, 8)
+        );
+        assert_eq!(
+            Weekly::<Date<Utc>>::previous_value(&Utc.ymd(2019, 1, 8)),
+            Utc.ymd(2019, 1, 1)
+        );
+    }
+
+    #[test]
+    fn test_quarterly_key_points() {
+        let coord = (Utc.ymd(2019, 2, 1)..Utc.ymd(2020, 12, 31)).quarterly();
+        let key_points = coord.key_points(10);
+        assert_eq!(key_points.len(), 7);
+        assert_eq!(key_points[0], Utc.ymd(2019, 4, 1));
+        assert_eq!(coord.format_label(&key_points[0]).unwrap(), "2019 Q2");
+        assert_eq!(
+            coord.format_label(&Utc.ymd(2019, 12, 31)).unwrap(),
+            "2019 Q4"
+        );
+
+        let key_points = coord.key_points(4);
+        assert_eq!(
+            key_points,
+            vec![
+                Utc.ymd(2019, 7, 1),
+                Utc.ymd(2020, 1, 1),
+                Utc.ymd(2020, 7, 1)
+            ]
+        );
+
+        let coord = (Utc.ymd(2019, 1, 1)..Utc.ymd(2020, 1, 1))
+            .quarterly()
+            .fiscal_year_start(10);
+        let key_points = coord.key_points(10);
+        assert_eq!(key_points[0], Utc.ymd(2019, 1, 1));
+        assert_eq!(coord.format_label(&key_points[0]).unwrap(), "2019 Q2");
+        assert_eq!(
+            coord.format_label(&Utc.ymd(2019, 10, 1)).unwrap(),
+            "2020 Q1"
+        );
+
+        let coord = (Utc.ymd(2019, 1, 1)..Utc.ymd(2020, 1, 1))
+            .quarterly()
+            .fiscal_year_start(2);
+        assert_eq!(coord.key_points(10)[0], Utc.ymd(2019, 2, 1));
+
+        assert_eq!(
+            Quarterly::next_value(&Utc.ymd(2019, 11, 30)),
+            Utc.ymd(2020, 1, 1)
+        );
+        assert_eq!(
+            Quarterly::next_value(&Utc.ymd(2019, 10, 1)),
+            Utc.ymd(2020, 1, 1)
+        );
+        assert_eq!(
+            Quarterly::previous_value(&Utc.ymd(2019, 1, 15)),
+            Utc.ymd(2018, 10, 1)
+        );
+        assert_eq!(
+            Quarterly::previous_value(&Utc.ymd(2019, 4, 1)),
+            Utc.ymd(2019, 1, 1)
+        );
+    }
+
+    #[test]
+    fn test_hourly_minutely_key_points() {
+        let start = Utc.ymd(2019, 1, 1).and_hms(9, 20, 0);
+        let end = Utc.ymd(2019, 1, 1).and_hms(17, 0, 0);
+
+        let coord = (start..end).hourly();
+        let key_points = coord.key_points(5);
+        assert_eq!(key_points[0], Utc.ymd(2019, 1, 1).and_hms(10, 0, 0));
+        assert_eq!(key_points[1], Utc.ymd(2019, 1, 1).and_hms(12, 0, 0));
+        assert_eq!(key_points.len(), 4);
+
+        let coord = (start..start + Duration::minutes(50)).minutely();
+        let key_points = coord.key_points(6);
+        assert_eq!(key_points[0], Utc.ymd(2019, 1, 1).and_hms(9, 20, 0));
+        assert_eq!(key_points[1], Utc.ymd(2019, 1, 1).and_hms(9, 30, 0));
+        assert_eq!(key_points.len(), 6);
+
+        let coord =
+            (Utc.ymd(2019, 1, 1).and_hms(0, 0, 0)..Utc.ymd(2019, 1, 11).and_hms(0, 0, 0)).hourly();
+        let key_points = coord.key_points(5);
+        assert_eq!(key_points[1], Utc.ymd(2019, 1, 4).and_hms(0, 0, 0));
+
+        let time = Utc.ymd(2019, 1, 1).and_hms(9, 20, 0);
+        assert_eq!(
+            Hourly::next_value(&time),
+            Utc.ymd(2019, 1, 1).and_hms(10, 0, 0)
+        );
+        assert_eq!(
+            Hourly::previous_value(&time),
+            Utc.ymd(2019, 1, 1).and_hms(9, 0, 0)
+        );
+        assert_eq!(
+            Minutely::previous_value(&time),
+            Utc.ymd(2019, 1, 1).and_hms(9, 19, 0)
+        );
+    }
+
+    #[test]
+    fn test_key_points_across_dst() {
+        let local_hours = |points: Vec<DateTime<Cet>>| -> Vec<_> {
+            points
+                .iter()
+                .map(|p| {
+                    assert_eq!((p.minute(), p.second()), (0, 0));
+                    p.hour()
+                })
+                .collect()
+        };
+
+        // The clocks go forward from 02:00 to 03:00
+        let coord =
+            (Cet.ymd(2019, 3, 31).and_hms(0, 0, 0)..Cet.ymd(2019, 3, 31).and_hms(6, 0, 0)).hourly();
+        assert_eq!(local_hours(coord.key_points(10)), vec![0, 1, 3, 4, 5, 6]);
+
+        // The clocks go back from 03:00 to 02:00, thus there are two 02:00
+        let coord = (Cet.ymd(2019, 10, 27).and_hms(0, 0, 0)
+            ..Cet.ymd(2019, 10, 27).and_hms(4, 0, 0))
+            .hourly();
+        let key_points = coord.key_points(10);
+        assert_eq!(key_points[2] - key_points[1], Duration::hours(1));
+        assert_eq!(key_points[3] - key_points[2], Duration::hours(1));
+        assert_eq!(local_hours(key_points), vec![0, 1, 2, 2, 3, 4]);
+
+        let late = Cet
+            .from_local_datetime(&NaiveDate::from_ymd(2019, 10, 27).and_hms(2, 30, 0))
+            .latest()
+            .unwrap();
+        let floor = Hourly::previous_value(&late);
+        assert_eq!((floor.hour(), late - floor), (2, Duration::minutes(30)));
+
+        // The ticks stay at the same local times on both sides of the transition
+        let coord: RangedDateTime<_> =
+            (Cet.ymd(2019, 3, 30).and_hms(0, 0, 0)..Cet.ymd(2019, 4, 1).and_hms(0, 0, 0)).into();
+        assert_eq!(local_hours(coord.key_points(10)), vec![0, 8, 16, 0, 8, 16]);
+    }
+
+    #[test]
+    fn test_naive_range_map() {
+        let coord: RangedNaiveDate =
+            (NaiveDate::from_ymd(2019, 1, 1)..NaiveDate::from_ymd(2019, 1, 11)).into();
+        assert_eq!(coord.map(&NaiveDate::from_ymd(2019, 1, 6), (0, 100)), 50);
+        assert_eq!(coord.key_points(20).len(), 11);
+        assert_eq!(coord.index_of(&NaiveDate::from_ymd(2019, 1, 3)), Some(2));
+
+        let start = NaiveDate::from_ymd(2019, 1, 1).and_hms(0, 0, 0);
+        let coord: RangedNaiveDateTime = (start..start + Duration::hours(10)).into();
+        assert_eq!(coord.map(&(start + Duration::minutes(150)), (0, 100)), 25);
+        assert_eq!(coord.key_points(20)[0], start);
+
+        let coord = (NaiveDate::from_ymd(2019, 1, 1)..NaiveDate::from_ymd(2020, 1, 1)).monthly();
+        assert_eq!(coord.key_points(12)[1], NaiveDate::from_ymd(2019, 2, 1));
+    }
+
+    #[test]
+    fn test_time_label_format() {
+        let coord: RangedDate<_> = (Utc.ymd(2019, 1, 1)..Utc.ymd(2019, 3, 1)).into();
+        assert_eq!(
+            coord.format_label(&Utc.ymd(2019, 1, 5)).unwrap(),
+            "2019-01-05"
+        );
+        let coord = coord.label_format("%d/%m");
+        assert_eq!(coord.format_label(&Utc.ymd(2019, 1, 5)).unwrap(), "05/01");
+
+        let coord = (Utc.ymd(2019, 1, 1)..Utc.ymd(2020, 1, 1)).monthly();
+        assert_eq!(coord.format_label(&Utc.ymd(2019, 2, 1)).unwrap(), "2019-02");
+
+        let start = Utc.ymd(2019, 1, 1).and_hms(0, 0, 0);
+        let time = Utc.ymd(2019, 1, 1).and_hms(12, 30, 15);
+        let coord: RangedDateTime<_> = (start..start + Duration::hours(10)).into();
+        assert_eq!(coord.format_label(&time).unwrap(), "12:30");
+        let coord: RangedDateTime<_> = (start..start + Duration::seconds(30)).into();
+        assert_eq!(coord.format_label(&time).unwrap(), "12:30:15");
+        let coord: RangedDateTime<_> = (start + Duration::days(2)..start).into();
+        assert_eq!(coord.format_label(&time).unwrap(), "01-01 12:30");
+
+        let coord: RangedNaiveDateTime = (start.naive_utc()..time.naive_utc()).into();
+        let coord = coord.label_format("%H");
+        assert_eq!(coord.format_label(&time.naive_utc()).unwrap(), "12");
+    }
+
+    #[test]
+    fn test_datetime_range_map() {
+        let coord: RangedDateTime<_> =
+            (Utc.ymd(2019, 1, 1).and_hms(0, 0, 0)..Utc.ymd(2019, 1, 1).and_hms(10, 0, 0)).into();
+
+        assert_eq!(
+            coord.map(&Utc.ymd(2019, 1, 1).and_hms_milli(2, 30, 0, 0), (0, 100)),
+            25
+        );
+        assert_eq!(
+            coord.map(&Utc.ymd(2019, 1, 1).and_hms(10, 0, 0), (0, 100)),
+            100
+        );
+        assert_eq!(
+            coord.unmap(25, (0, 100)),
+            Some(Utc.ymd(2019, 1, 1).and_hms(2, 30, 0))
+        );
+        assert_eq!(coord.unmap(-1, (0, 100)), None);
+
+        let coord: RangedDuration = (Duration::zero()..Duration::hours(10)).into();
+        assert_eq!(coord.unmap(75, (100, 0)), Some(Duration::hours(10) / 4));
+
+        // The nanoseconds of the spans over about 292 years overflow i64
+        let coord: RangedDateTime<_> =
+            (Utc.ymd(1500, 1, 1).and_hms(0, 0, 0)..Utc.ymd(2500, 1, 1).and_hms(0, 0, 0)).into();
+        assert_eq!(
+            coord.unmap(100, (0, 100)),
+            Some(Utc.ymd(2500, 1, 1).and_hms(0, 0, 0))
+        );
+        let coord: RangedDuration = (Duration::zero()..Duration::days(365_000)).into();
+        assert_eq!(coord.unmap(50, (0, 100)), Some(Duration::days(182_500)));
+
+        let coord: RangedDate<_> = (Utc.ymd(2019, 1, 1)..Utc.ymd(2019, 1, 11)).into();
+        assert_eq!(coord.unmap(34, (0, 100)), Some(Utc.ymd(2019, 1, 4)));
+        assert_eq!(coord.unmap(101, (0, 100)), None);
+        let coord: RangedNaiveDate =
+            (NaiveDate::from_ymd(2019, 1, 1)..NaiveDate::from_ymd(2019, 1, 11)).into();
+        assert_eq!(
+            coord.unmap(100, (0, 100)),
+            Some(NaiveDate::from_ymd(2019, 1, 11))
+        );
+    }
+
+    #[test]
+    fn test_std_time_range_map() {
+        let start = SystemTime::from(Utc.ymd(2019, 1, 1).and_hms(0, 0, 0));
+        let hours = |h: u64| std::time::Duration::from_secs(h * 3600);
+        let coord: RangedSystemTime = (start..start + hours(10)).into();
+        assert_eq!(coord.map(&(start + hours(5)), (0, 100)), 50);
+        let key_points = coord.key_points(5);
+        assert_eq!(key_points.len(), 5);
+        assert_eq!(key_points[1], start + hours(2));
+        assert_eq!(coord.format_label(&key_points[1]).unwrap(), "02:00");
+
+        let before_epoch = UNIX_EPOCH - hours(1);
+        assert_eq!(before_epoch.timestamp_ns(), -3_600_000_000_000);
+
+        let coord: RangedStdDuration = (hours(0)..hours(10)).into();
+        assert_eq!(coord.map(&hours(5), (0, 100)), 50);
+        let key_points = coord.key_points(5);
+        assert_eq!(key_points.len(), 5);
+        assert_eq!(key_points[1], hours(2));
+    }
+
+    #[test]
+    fn test_epoch_coord() {
+        let start = Utc.ymd(2019, 1, 1).and_hms(0, 0, 0).timestamp();
+        let coord = (start..start + 10 * 3600).epoch_seconds();
+        assert_eq!(coord.map(&(start + 5 * 3600), (0, 100)), 50);
+        let key_points = coord.key_points(5);
+        assert_eq!(key_points.len(), 5);
+        assert_eq!(key_points[1], start + 2 * 3600);
+        assert_eq!(coord.format_label(&key_points[1]).unwrap(), "02:00");
+
+        let coord = (start * 1000..(start + 3) * 1000)
+            .epoch_millis()
+            .label_format("%S");
+        assert_eq!(coord.map(&(start * 1000 + 1500), (0, 300)), 150);
+        assert_eq!(
+            coord.key_points(3),
+            vec![start * 1000, (start + 1) * 1000, (start + 2) * 1000]
+        );
+        assert_eq!(coord.format_label(&(start * 1000 + 2000)).unwrap(), "02");
+    }
+
+    #[test]
+    fn test_descrete_datetime_duration() {
+        let time = Utc.ymd(2019, 1, 1).and_hms(9, 20, 30);
+        assert_eq!(
+            RangedDateTime::<Utc>::next_value(&time),
+            Utc.ymd(2019, 1, 1).and_hms(9, 20, 31)
+        );
+        assert_eq!(
+            RangedDateTime::<Utc, Hour>::next_value(&time),
+            Utc.ymd(2019, 1, 1).and_hms(10, 0, 0)
+        );
+        assert_eq!(
+            RangedDateTime::<Utc, Day>::previous_value(&time),
+            Utc.ymd(2019, 1, 1).and_hms(0, 0, 0)
+        );
+        assert_eq!(
+            RangedDateTime::<Utc, Minute>::previous_value(&Utc.ymd(2019, 1, 1).and_hms(9, 20, 0)),
+            Utc.ymd(2019, 1, 1).and_hms(9, 19, 0)
+        );
+
+        let coord: RangedDateTime<_> = (time..time + Duration::hours(10)).into();
+        let coord = coord.with_unit::<Hour>();
+        assert_eq!(coord.map(&(time + Duration::hours(5)), (0, 100)), 50);
+
+        let coord: RangedDuration<Minute> = (Duration::zero()..Duration::hours(10)).into();
+        assert_eq!(coord.key_points(5).len(), 5);
+        assert_eq!(
+            RangedDuration::<Minute>::next_value(&Duration::seconds(90)),
+            Duration::minutes(2)
+        );
+        assert_eq!(
+            RangedDuration::<Minute>::previous_value(&Duration::seconds(-30)),
+            Duration::minutes(-1)
+        );
+        assert_eq!(
+            RangedDuration::<Second>::previous_value(&Duration::seconds(3)),
+            Duration::seconds(2)
+        );
+    }
+
+    #[test]
+    fn test_descrete_datetime_dst() {
+        // 2019-03-31 is 23 hours long and 2019-10-27 is 25 hours long in central Europe
+        let midnight = |m, d| Cet.ymd(2019, m, d).and_hms(0, 0, 0);
+        assert_eq!(
+            RangedDateTime::<Cet, Day>::next_value(&midnight(3, 31)),
+            midnight(4, 1)
+        );
+        assert_eq!(
+            RangedDateTime::<Cet, Day>::next_value(&midnight(10, 27)),
+            midnight(10, 28)
+        );
+        assert_eq!(
+            RangedDateTime::<Cet, Day>::previous_value(&midnight(10, 28)),
+            midnight(10, 27)
+        );
+        assert_eq!(
+            RangedDateTime::<Cet, Day>::floor_value(&Cet.ymd(2019, 10, 27).and_hms(23, 30, 0)),
+            midnight(10, 27)
+        );
+
+        // The hours are still an hour long, and the repeated hour is a value of its own
+        let early = Cet.from_utc_datetime(&NaiveDate::from_ymd(2019, 10, 27).and_hms(0, 0, 0));
+        let late = early + Duration::hours(1);
+        assert_eq!(late.naive_local(), early.naive_local());
+        assert_eq!(RangedDateTime::<Cet, Hour>::next_value(&early), late);
+        assert_eq!(RangedDateTime::<Cet, Hour>::previous_value(&late), early);
+        assert_eq!(
+            RangedDateTime::<Cet, Hour>::next_value(&Cet.ymd(2019, 3, 31).and_hms(1, 30, 0)),
+            Cet.ymd(2019, 3, 31).and_hms(3, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_duration_label_format() {
+        let coord: RangedDuration = (Duration::zero()..Duration::hours(10)).into();
+        assert_eq!(
+            coord.format_label(&Duration::minutes(90)).unwrap(),
+            "1h 30m"
+        );
+        assert_eq!(
+            coord.format_label(&Duration::seconds(5430)).unwrap(),
+            "1h 31m"
+        );
+        assert_eq!(coord.format_label(&Duration::zero()).unwrap(), "0");
+
+        let coord: RangedDuration = (Duration::zero()..Duration::seconds(1)).into();
+        assert_eq!(
+            coord.format_label(&Duration::milliseconds(250)).unwrap(),
+            "250ms"
+        );
+        assert_eq!(
+            coord.format_label(&Duration::milliseconds(-1500)).unwrap(),
+            "-1s 500ms"
+        );
+
+        let coord: RangedDuration = (Duration::days(10)..Duration::zero()).into();
+        assert_eq!(coord.format_label(&Duration::days(3)).unwrap(), "3d");
+
+        let coord = coord.label_formatter(|d| format!("{} days", d.num_days()));
+        assert_eq!(coord.format_label(&Duration::days(3)).unwrap(), "3 days");
+
+        // The coordinate can be sent to another thread with its formatter
+        fn assert_send_sync<T: Send + Sync>(_: &T) {}
+        assert_send_sync(&coord);
+    }
+
+    #[test]
+    fn test_business_days() {
+        let holidays = vec![NaiveDate::from_ymd(2019, 12, 25)];
+        // 2019-12-20 is a Friday, and the range ends on the next Friday
+        let coord = (Utc.ymd(2019, 12, 20)..Utc.ymd(2019, 12, 27))
+            .business_days()
+            .holidays(holidays);
+
+        // The trading days are 12-20, 12-23, 12-24, 12-26 and 12-27
+        assert_eq!(coord.map(&Utc.ymd(2019, 12, 20), (0, 400)), 0);
+        assert_eq!(coord.map(&Utc.ymd(2019, 12, 21), (0, 400)), 100);
+        assert_eq!(coord.map(&Utc.ymd(2019, 12, 23), (0, 400)), 100);
+        assert_eq!(coord.map(&Utc.ymd(2019, 12, 26), (0, 400)), 300);
+        assert_eq!(coord.map(&Utc.ymd(2019, 12, 27), (0, 400)), 400);
+
+        let days: Vec<_> = coord
+            .key_points(10)
+            .into_iter()
+            .map(|d| coord.format_label(&d).unwrap())
+            .collect();
+        assert_eq!(
+            days,
+            vec![
+                "2019-12-20",
+                "2019-12-23",
+                "2019-12-24",
+                "2019-12-26",
+                "2019-12-27"
+            ]
+        );
+        assert_eq!(coord.key_points(2).len(), 2);
+
+        // The hours are mapped within the trading day
+        let start = Utc.ymd(2019, 12, 20).and_hms(0, 0, 0);
+        let coord = (start..start + Duration::days(4)).business_days();
+        assert_eq!(coord.map(&(start + Duration::hours(12)), (0, 100)), 25);
+        assert_eq!(coord.map(&(start + Duration::days(1)), (0, 100)), 50);
+        let is_monday = |date: NaiveDate| date.weekday() == Weekday::Mon;
+        let coord = coord.holidays(is_monday);
+        assert_eq!(coord.map(&(start + Duration::hours(12)), (0, 100)), 50);
+    }
+
+    #[test]
+    fn test_daily_gaps() {
+        let start = Utc.ymd(2019, 12, 2).and_hms(9, 30, 0);
+        let coord = (start..Utc.ymd(2019, 12, 3).and_hms(16, 0, 0)).skip_daily(vec![
+            NaiveTime::from_hms(16, 0, 0)..NaiveTime::from_hms(9, 30, 0),
+            NaiveTime::from_hms(12, 0, 0)..NaiveTime::from_hms(12, 30, 0),
+        ]);
+
+        // There're 6 hours of each day left
+        let at = |d, h, m| Utc.ymd(2019, 12, d).and_hms(h, m, 0);
+        assert_eq!(coord.map(&at(2, 9, 30), (0, 120)), 0);
+        assert_eq!(coord.map(&at(2, 12, 15), (0, 120)), 25);
+        assert_eq!(coord.map(&at(2, 16, 0), (0, 120)), 60);
+        assert_eq!(coord.map(&at(2, 20, 0), (0, 120)), 60);
+        assert_eq!(coord.map(&at(3, 9, 30), (0, 120)), 60);
+        assert_eq!(coord.map(&at(3, 16, 0), (0, 120)), 120);
+
+        let points = coord.key_points(10);
+        assert!(!points.is_empty() && points.len() <= 10);
+        assert!(points.iter().all(|p| {
+            let time = p.time();
+            time >= NaiveTime::from_hms(9, 30, 0)
+                && time < NaiveTime::from_hms(16, 0, 0)
+                && !(time >= NaiveTime::from_hms(12, 0, 0) && time < NaiveTime::from_hms(12, 30, 0))
+        }));
+
+        // The key points of the days are at the opening time
+        let coord = (start..start + Duration::days(30)).skip_daily(vec![
+            NaiveTime::from_hms(16, 0, 0)..NaiveTime::from_hms(9, 30, 0),
+        ]);
+        let points = coord.key_points(5);
+        assert!(!points.is_empty() && points.len() <= 5);
+        assert!(points
+            .iter()
+            .all(|p| p.time() == NaiveTime::from_hms(9, 30, 0)));
+    }
+
+    #[test]
+    fn test_default_timestamp() {
+        // Implements the required methods only, thus uses the default timestamp
+        #[derive(PartialEq, Eq)]
+        struct Time(DateTime<FixedOffset>);
+
+        impl TimeValue for Time {
+            type Tz = FixedOffset;
+            fn date_floor(&self) -> Date<FixedOffset> {
+                self.0.date_floor()
+            }
+            fn date_ceil(&self) -> Date<FixedOffset> {
+                self.0.date_ceil()
+            }
+            fn earliest_after_date(date: Date<FixedOffset>) -> Self {
+                Time(DateTime::earliest_after_date(date))
+            }
+            fn subtract(&self, other: &Self) -> Duration {
+                self.0.subtract(&other.0)
+            }
+            fn timezone(&self) -> FixedOffset {
+                self.0.timezone()
+            }
+        }
+
+        for offset in [-5 * 3600, 0, 9 * 3600].iter() {
+            let tz = FixedOffset::east(*offset);
+            for time in [
+                tz.ymd(2019, 12, 2).and_hms_nano(9, 30, 0, 123_456_789),
+                tz.ymd(1960, 1, 1).and_hms(0, 0, 1),
+                tz.ymd(1970, 1, 1).and_hms(0, 0, 0),
+            ]
+            .iter()
+            {
+                assert_eq!(Time(*time).timestamp_ns(), time.timestamp_ns());
+            }
+        }
+    }
+}
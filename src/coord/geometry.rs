@@ -0,0 +1,88 @@
+/// The point types from the geometry libraries, which can be converted into the tuple
+/// coordinate used by the 2D cartesian coordinate system.
+/// This allows feeding the points to series such as `LineSeries::from_points` without
+/// mapping them to tuples first.
+pub trait IntoTupleCoord {
+    /// The tuple coordinate type
+    type Tuple;
+    /// Convert the point into the tuple coordinate
+    fn into_tuple(self) -> Self::Tuple;
+}
+
+#[cfg(feature = "nalgebra")]
+impl<T: nalgebra::Scalar> IntoTupleCoord for nalgebra::Point2<T> {
+    type Tuple = (T, T);
+    fn into_tuple(self) -> (T, T) {
+        (self.x, self.y)
+    }
+}
+
+#[cfg(feature = "euclid")]
+impl<T, U> IntoTupleCoord for euclid::Point2D<T, U> {
+    type Tuple = (T, T);
+    fn into_tuple(self) -> (T, T) {
+        (self.x, self.y)
+    }
+}
+
+/// The iterator adaptor that converts the points from the underlying iterator into
+/// tuple coordinates
+pub struct TupleCoords<I>(I);
+
+impl<I: Iterator> TupleCoords<I>
+where
+    I::Item: IntoTupleCoord,
+{
+    /// Create a new adaptor from an iterator of points
+    pub fn new<T: IntoIterator<IntoIter = I>>(iter: T) -> Self {
+        TupleCoords(iter.into_iter())
+    }
+}
+
+impl<I: Iterator> Iterator for TupleCoords<I>
+where
+    I::Item: IntoTupleCoord,
+{
+    type Item = <I::Item as IntoTupleCoord>::Tuple;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(IntoTupleCoord::into_tuple)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "nalgebra")]
+    #[test]
+    fn test_nalgebra_points() {
+        use crate::element::PointCollection;
+        use crate::series::LineSeries;
+        use crate::style::BLACK;
+
+        let points = vec![
+            nalgebra::Point2::new(1.0, 2.0),
+            nalgebra::Point2::new(3.0, 4.0),
+        ];
+        let coords = TupleCoords::new(points).collect::<Vec<_>>();
+        assert_eq!(coords, vec![(1.0, 2.0), (3.0, 4.0)]);
+
+        let points = vec![
+            nalgebra::Point2::new(1.0, 2.0),
+            nalgebra::Point2::new(3.0, 4.0),
+        ];
+        let path = LineSeries::from_points(points, &BLACK).next().unwrap();
+        assert_eq!((&path).point_iter(), &[(1.0, 2.0), (3.0, 4.0)]);
+    }
+
+    #[cfg(feature = "euclid")]
+    #[test]
+    fn test_euclid_points() {
+        let points = vec![euclid::point2::<_, euclid::UnknownUnit>(1, 2)];
+        let coords = TupleCoords::new(points).collect::<Vec<_>>();
+        assert_eq!(coords, vec![(1, 2)]);
+    }
+}
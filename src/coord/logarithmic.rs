@@ -1,4 +1,4 @@
-use super::{AsRangedCoord, Ranged, RangedCoordf64};
+use super::{AsRangedCoord, Ranged, RangedCoordf64, ReversableRanged};
 use std::marker::PhantomData;
 use std::ops::Range;
 
@@ -72,59 +72,133 @@ pub struct LogCoord<V: LogScalable> {
     marker: PhantomData<V>,
 }
 
+impl<V: LogScalable> LogCoord<V> {
+    /// Get the lower and upper bound of the range as floating point numbers
+    fn bounds(&self) -> (f64, f64) {
+        let (a, b) = (self.logic.start.as_f64(), self.logic.end.as_f64());
+        (a.min(b), a.max(b))
+    }
+}
+
 impl<V: LogScalable> Ranged for LogCoord<V> {
     type ValueType = V;
 
     fn map(&self, value: &V, limit: (i32, i32)) -> i32 {
         let value = value.as_f64();
-        let value = value.max(self.logic.start.as_f64()).ln();
-        self.linear.map(&value, limit)
+        // The non-positive values can't be presented in log scale, thus they are put on the
+        // lower bound of the axis
+        let value = if value > 0.0 { value } else { self.bounds().0 };
+        self.linear.map(&value.ln(), limit)
     }
 
+    /// The key points are the powers of ten. If there's room for more points, the decades
+    /// are subdivided by 1-2-5 and then by 1 to 9. If there are too many decades, only every
+    /// n-th power of ten is used.
     fn key_points(&self, max_points: usize) -> Vec<Self::ValueType> {
-        let tier_1 = (self.logic.end.as_f64() / self.logic.start.as_f64())
-            .log10()
-            .abs()
-            .floor() as usize;
-        let tier_2_density = if max_points < tier_1 {
-            0
-        } else {
-            let density = 1 + (max_points - tier_1) / tier_1;
-            let mut exp = 1;
-            while exp * 10 <= density {
-                exp *= 10;
-            }
-            exp - 1
-        };
-
-        let mut multiplier = 10.0;
-        let mut cnt = 1;
-        while max_points < tier_1 / cnt {
-            multiplier *= 10.0;
-            cnt += 1;
+        let (lower, upper) = self.bounds();
+        if max_points == 0 || lower.is_nan() || lower <= 0.0 || !upper.is_finite() {
+            return vec![];
         }
 
-        let mut ret = vec![];
-        let mut val = (10f64).powf(self.logic.start.as_f64().log10().ceil());
+        let (exp0, exp1) = (lower.log10().floor() as i32, upper.log10().ceil() as i32);
+        let candidates = |multipliers: &[f64]| {
+            let mut ret = vec![];
+            for exp in exp0..=exp1 {
+                for m in multipliers {
+                    let v = m * 10f64.powi(exp);
+                    if v >= lower * (1.0 - 1e-9) && v <= upper * (1.0 + 1e-9) {
+                        ret.push(v);
+                    }
+                }
+            }
+            ret
+        };
 
-        while val <= self.logic.end.as_f64() {
-            ret.push(V::from_f64(val));
-            for i in 1..=tier_2_density {
-                let v = val
-                    * (1.0
-                        + multiplier / f64::from(tier_2_density as u32 + 1) * f64::from(i as u32));
-                if v > self.logic.end.as_f64() {
+        let mut ret = candidates(&[1.0]);
+        if ret.len() > max_points {
+            let step = ret.len() / max_points + usize::from(ret.len() % max_points != 0);
+            ret = ret.into_iter().step_by(step).collect();
+        } else {
+            for multipliers in &[
+                &[1.0, 2.0, 5.0][..],
+                &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0][..],
+            ] {
+                let finer = candidates(multipliers);
+                if finer.len() > max_points {
                     break;
                 }
-                ret.push(V::from_f64(v));
+                ret = finer;
             }
-            val *= multiplier;
         }
 
-        ret
+        ret.into_iter().map(V::from_f64).collect()
     }
 
     fn range(&self) -> Range<V> {
         self.logic.clone()
     }
 }
+
+impl<V: LogScalable> ReversableRanged for LogCoord<V> {
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<V> {
+        self.linear
+            .unmap(input, limit)
+            .map(|v| V::from_f64(v.exp()))
+    }
+}
+
+/// The trait that converts a range into the log scaled range, e.g. `(1.0..1e6).log_scale()`
+pub trait IntoLogRange {
+    type ValueType: LogScalable;
+    /// Make the range log scaled
+    fn log_scale(self) -> LogRange<Self::ValueType>;
+}
+
+impl<V: LogScalable> IntoLogRange for Range<V> {
+    type ValueType = V;
+    fn log_scale(self) -> LogRange<V> {
+        LogRange(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_log_key_points() {
+        let coord: LogCoord<f64> = (1.0..1000.0).log_scale().into();
+        assert_eq!(coord.key_points(5), vec![1.0, 10.0, 100.0, 1000.0]);
+        assert_eq!(
+            coord.key_points(10),
+            vec![1.0, 2.0, 5.0, 10.0, 20.0, 50.0, 100.0, 200.0, 500.0, 1000.0]
+        );
+
+        let coord: LogCoord<f64> = (1e-3..1e3).log_scale().into();
+        assert_eq!(coord.key_points(3), vec![1e-3, 1.0, 1e3]);
+
+        let coord: LogCoord<f64> = (3.0..8.0).log_scale().into();
+        assert_eq!(coord.key_points(3), vec![5.0]);
+        assert_eq!(coord.key_points(10), vec![3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+    }
+
+    #[test]
+    fn test_log_map() {
+        let coord: LogCoord<f64> = (1.0..100.0).log_scale().into();
+        assert_eq!(coord.map(&1.0, (0, 100)), 0);
+        assert_eq!(coord.map(&10.0, (0, 100)), 50);
+        assert_eq!(coord.map(&100.0, (0, 100)), 100);
+        // The y axis is mapped with the reversed pixel range
+        assert_eq!(coord.map(&10.0, (100, 0)), 50);
+        assert_eq!(coord.map(&100.0, (100, 0)), 0);
+        assert_eq!(coord.map(&0.0, (100, 0)), 100);
+
+        let coord: LogCoord<f64> = (100.0..1.0).log_scale().into();
+        assert_eq!(coord.map(&100.0, (0, 100)), 0);
+        assert_eq!(coord.map(&0.0, (0, 100)), 100);
+
+        let coord: LogCoord<f64> = (1.0..100.0).log_scale().into();
+        assert!((coord.unmap(50, (0, 100)).unwrap() - 10.0).abs() < 1e-9);
+        assert_eq!(coord.unmap(101, (0, 100)), None);
+    }
+}
@@ -22,26 +22,49 @@ Also, the ranged axis can be decereted, and this is required by the histogram se
 */
 use crate::drawing::backend::BackendCoord;
 
+mod cartesian3d;
+mod category;
 #[cfg(feature = "chrono")]
 mod datetime;
+#[cfg(any(feature = "nalgebra", feature = "euclid"))]
+mod geometry;
 mod logarithmic;
 mod numeric;
+mod percentage;
+mod polar;
 mod ranged;
 
+pub(crate) use cartesian3d::normalize_value;
+pub use cartesian3d::{Cartesian3d, Projection3d};
+pub use category::{Category, RangedCategory};
+
 #[cfg(feature = "chrono")]
-pub use datetime::{IntoMonthly, IntoYearly, RangedDate, RangedDateTime, RangedDuration};
+pub use datetime::{
+    BusinessDays, DailyGaps, Day, HolidayCalendar, Hour, Hourly, IntoBusinessDays, IntoDailyGaps,
+    IntoEpochMillis, IntoEpochSeconds, IntoHourly, IntoMinutely, IntoMonthly, IntoQuarterly,
+    IntoWeekly, IntoYearly, Minute, Minutely, Quarterly, RangedDate, RangedDateTime,
+    RangedDuration, RangedEpochMillis, RangedEpochSeconds, RangedNaiveDate, RangedNaiveDateTime,
+    RangedStdDuration, RangedSystemTime, Second, TimeUnit, Weekly,
+};
 pub use numeric::{
     RangedCoordf32, RangedCoordf64, RangedCoordi32, RangedCoordi64, RangedCoordu32, RangedCoordu64,
 };
 pub use ranged::{
-    AsRangedCoord, DescreteRanged, IntoCentric, IntoPartialAxis, MeshLine, Ranged, RangedCoord,
-    ReversableRanged,
+    AsRangedCoord, DescreteRanged, IntoCentric, IntoPartialAxis, IntoSegmented, IntoWithKeyPoints,
+    MeshLine, Ranged, RangedCoord, ReversableRanged, SegmentValue, SegmentedCoord, WithKeyPoints,
 };
 
 #[cfg(feature = "make_partial_axis")]
 pub use ranged::make_partial_axis;
 
-pub use logarithmic::{LogCoord, LogRange, LogScalable};
+pub use polar::PolarCoord;
+
+pub use logarithmic::{IntoLogRange, LogCoord, LogRange, LogScalable};
+
+pub use percentage::{IntoPercentage, Percentage};
+
+#[cfg(any(feature = "nalgebra", feature = "euclid"))]
+pub use geometry::{IntoTupleCoord, TupleCoords};
 
 /// The trait that translates some customized object to the backend coordinate
 pub trait CoordTranslate {
@@ -1,15 +1,110 @@
+use std::convert::TryFrom;
 use std::ops::Range;
 
 use super::{AsRangedCoord, DescreteRanged, Ranged, ReversableRanged};
 
+/// The arithmetic of the axis values, which is done in a type wide enough that the offsets
+/// don't overflow or lose precision for the wide or descending ranges
+trait AxisValue: Copy {
+    /// Get the offset from `base` to `self`
+    fn offset_from(self, base: Self) -> f64;
+    /// Get the value at the offset from `self`, the fraction of an integer is truncated
+    fn offset_by(self, offset: f64) -> Self;
+}
+
+/// The exact distance between the integer axis values, see `AxisValue`
+trait IntegerAxisValue: AxisValue {
+    /// Get the distance from `base` to `self`, `None` if `self` is before `base` or the
+    /// distance doesn't fit in `usize`
+    fn distance_from(self, base: Self) -> Option<usize>;
+    /// Get the value after `self` by the distance, which should be in the range of the type
+    fn advance(self, distance: usize) -> Self;
+}
+
+macro_rules! impl_float_axis_value {
+    ($type:ty) => {
+        impl AxisValue for $type {
+            fn offset_from(self, base: Self) -> f64 {
+                self as f64 - base as f64
+            }
+            fn offset_by(self, offset: f64) -> Self {
+                (self as f64 + offset) as $type
+            }
+        }
+    };
+}
+
+macro_rules! impl_integer_axis_value {
+    ($type:ty, $unsigned:ty) => {
+        impl AxisValue for $type {
+            fn offset_from(self, base: Self) -> f64 {
+                // The difference always fits in the unsigned type of the same width
+                if self >= base {
+                    self.wrapping_sub(base) as $unsigned as f64
+                } else {
+                    -(base.wrapping_sub(self) as $unsigned as f64)
+                }
+            }
+            fn offset_by(self, offset: f64) -> Self {
+                let distance = offset.abs() as $unsigned as $type;
+                if offset >= 0.0 {
+                    self.wrapping_add(distance)
+                } else {
+                    self.wrapping_sub(distance)
+                }
+            }
+        }
+        impl IntegerAxisValue for $type {
+            fn distance_from(self, base: Self) -> Option<usize> {
+                if self < base {
+                    return None;
+                }
+                usize::try_from(self.wrapping_sub(base) as $unsigned).ok()
+            }
+            fn advance(self, distance: usize) -> Self {
+                self.wrapping_add(distance as $unsigned as $type)
+            }
+        }
+    };
+}
+
+impl_float_axis_value!(f32);
+impl_float_axis_value!(f64);
+impl_integer_axis_value!(u32, u32);
+impl_integer_axis_value!(i32, u32);
+impl_integer_axis_value!(u64, u64);
+impl_integer_axis_value!(i64, u64);
+impl_integer_axis_value!(u128, u128);
+impl_integer_axis_value!(i128, u128);
+impl_integer_axis_value!(usize, usize);
+impl_integer_axis_value!(isize, usize);
+
 macro_rules! impl_descrete_trait {
     ($name:ident) => {
         impl DescreteRanged for $name {
             fn next_value(this: &Self::ValueType) -> Self::ValueType {
-                return *this + 1;
+                return this.saturating_add(1);
             }
             fn previous_value(this: &Self::ValueType) -> Self::ValueType {
-                return *this - 1;
+                return this.saturating_sub(1);
+            }
+            fn floor_value(this: &Self::ValueType) -> Self::ValueType {
+                return *this;
+            }
+            fn size(&self) -> Option<usize> {
+                return self.1.distance_from(self.0)?.checked_add(1);
+            }
+            fn index_of(&self, value: &Self::ValueType) -> Option<usize> {
+                if *value > self.1 {
+                    return None;
+                }
+                return value.distance_from(self.0);
+            }
+            fn value_at(&self, index: usize) -> Option<Self::ValueType> {
+                if index > self.1.distance_from(self.0)? {
+                    return None;
+                }
+                return Some(self.0.advance(index));
             }
         }
     };
@@ -26,6 +121,9 @@ macro_rules! impl_ranged_type_trait {
 
 macro_rules! make_numeric_coord {
     ($type:ty, $name:ident, $key_points:ident, $doc: expr) => {
+        make_numeric_coord!($type, $name, $key_points, $doc, |_, v| Some(v.to_string()));
+    };
+    ($type:ty, $name:ident, $key_points:ident, $doc: expr, $format_label:expr) => {
         #[doc = $doc]
         pub struct $name($type, $type);
         impl From<Range<$type>> for $name {
@@ -36,7 +134,8 @@ macro_rules! make_numeric_coord {
         impl Ranged for $name {
             type ValueType = $type;
             fn map(&self, v: &$type, limit: (i32, i32)) -> i32 {
-                let logic_length = (*v - self.0) as f64 / (self.1 - self.0) as f64;
+                // The range may be descending and the type may be unsigned, see `AxisValue`
+                let logic_length = v.offset_from(self.0) / self.1.offset_from(self.0);
                 let actual_length = limit.1 - limit.0;
 
                 if actual_length == 0 {
@@ -46,11 +145,21 @@ macro_rules! make_numeric_coord {
                 return limit.0 + (actual_length as f64 * logic_length + 1e-3).floor() as i32;
             }
             fn key_points(&self, max_points: usize) -> Vec<$type> {
+                if self.0 > self.1 {
+                    // For a descending range, the key points come in the descending order
+                    let mut ret = $key_points((self.1, self.0), max_points);
+                    ret.reverse();
+                    return ret;
+                }
                 $key_points((self.0, self.1), max_points)
             }
             fn range(&self) -> Range<$type> {
                 return self.0..self.1;
             }
+            fn format_label(&self, v: &$type) -> Option<String> {
+                let format_label: fn(($type, $type), &$type) -> Option<String> = $format_label;
+                format_label((self.0, self.1), v)
+            }
         }
 
         impl ReversableRanged for $name {
@@ -61,7 +170,7 @@ macro_rules! make_numeric_coord {
 
                 let logical_offset = (p - min) as f64 / (max - min) as f64;
 
-                return Some(((self.1 - self.0) as f64 * logical_offset + self.0 as f64) as $type);
+                return Some(self.0.offset_by(self.1.offset_from(self.0) * logical_offset));
             }
         }
     };
@@ -70,62 +179,10 @@ macro_rules! make_numeric_coord {
 macro_rules! gen_key_points_comp {
     (float, $name:ident, $type:ty) => {
         fn $name(range: ($type, $type), max_points: usize) -> Vec<$type> {
-            if max_points == 0 {
-                return vec![];
-            }
-
-            let range = (range.0 as f64, range.1 as f64);
-            let mut scale = (10f64).powf((range.1 - range.0).log(10.0).floor());
-            let mut digits = -(range.1 - range.0).log(10.0).floor() as i32 + 1;
-            fn rem_euclid(a: f64, b: f64) -> f64 {
-                if b > 0.0 {
-                    a - (a / b).floor() * b
-                } else {
-                    a - (a / b).ceil() * b
-                }
-            }
-
-            // At this point we need to make sure that the loop invariant:
-            // The scale must yield number of points than reqested
-            if 1 + ((range.1 - range.0) / scale).floor() as usize > max_points {
-                scale *= 10.0;
-            }
-
-            'outer: loop {
-                let old_scale = scale;
-                for nxt in [2.0, 5.0, 10.0].iter() {
-                    let new_left = range.0 + scale / nxt - rem_euclid(range.0, scale / nxt);
-                    let new_right = range.1 - rem_euclid(range.1, scale / nxt);
-
-                    let npoints = 1 + ((new_right - new_left) / old_scale * nxt) as usize;
-
-                    if npoints > max_points {
-                        break 'outer;
-                    }
-
-                    scale = old_scale / nxt;
-                }
-                scale = old_scale / 10.0;
-                if scale < 1.0 {
-                    digits += 1;
-                }
-            }
-
-            let mut ret = vec![];
-            let mut left = range.0 + scale - rem_euclid(range.0, scale);
-            let right = range.1 - rem_euclid(range.1, scale);
-            while left <= right {
-                let size = (10f64).powf(digits as f64 + 1.0);
-                let new_left = (left * size).abs() + 1e-3;
-                if left < 0.0 {
-                    left = -new_left.round() / size;
-                } else {
-                    left = new_left.round() / size;
-                }
-                ret.push(left as $type);
-                left += scale;
-            }
-            return ret;
+            compute_float_key_points((range.0 as f64, range.1 as f64), max_points)
+                .into_iter()
+                .map(|v| v as $type)
+                .collect()
         }
     };
     (integer, $name:ident, $type:ty) => {
@@ -162,6 +219,70 @@ macro_rules! gen_key_points_comp {
     };
 }
 
+/// Compute the key points of a floating point range, which are the multiples of the smallest
+/// 1-2-5 times power of ten step that generates no more than `max_points` points. The points
+/// are computed from the integer multiples of the step, thus they are the closest floating
+/// point numbers to the round decimals, e.g. `0.3` rather than `0.30000000000000004`.
+fn compute_float_key_points(range: (f64, f64), max_points: usize) -> Vec<f64> {
+    let (lower, upper) = (range.0.min(range.1), range.0.max(range.1));
+    if max_points == 0 || !lower.is_finite() || !upper.is_finite() {
+        return vec![];
+    }
+    if lower == upper {
+        return vec![lower];
+    }
+
+    let count = |step: f64| (upper / step + 1e-9).floor() - (lower / step - 1e-9).ceil() + 1.0;
+    let mut exp = (upper - lower).log10().floor() as i32 - (max_points as f64).log10() as i32 - 1;
+    let (multiplier, exp) = 'search: loop {
+        for multiplier in &[1, 2, 5] {
+            if count(f64::from(*multiplier) * 10f64.powi(exp)) <= max_points as f64 {
+                break 'search (*multiplier, exp);
+            }
+        }
+        exp += 1;
+    };
+
+    // The step is multiplier / 10^-exp, dividing the exact integer by the exact power of ten
+    // gives the closest floating point number to the decimal
+    let step = f64::from(multiplier) * 10f64.powi(exp);
+    let value_of = |k: f64| {
+        if exp < 0 {
+            k * f64::from(multiplier) / 10f64.powi(-exp)
+        } else {
+            k * step
+        }
+    };
+    let first = (lower / step - 1e-9).ceil();
+    let last = (upper / step + 1e-9).floor();
+    (0..=(last - first) as i64)
+        .map(|idx| value_of(first + idx as f64))
+        .collect()
+}
+
+/// Format the label of a floating point value with the precision of a thousandth of the range,
+/// the trailing zeros are removed, e.g. `0.3` and `1` for the range from 0 to 2
+fn format_float_label(range: (f64, f64), value: f64) -> Option<String> {
+    let span = (range.1 - range.0).abs();
+    let digits = if span > 0.0 && span.is_finite() {
+        3 - span.log10().floor() as i32
+    } else {
+        3
+    };
+    // The values which are too large or too small for the fixed point are formatted as usual
+    if !value.is_finite() || value.abs() >= 1e15 || digits > 12 {
+        return Some(format!("{:?}", value));
+    }
+
+    let text = format!("{:.*}", digits.max(0) as usize, value);
+    let text = if text.contains('.') {
+        text.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        &text
+    };
+    Some(if text == "-0" { "0" } else { text }.to_string())
+}
+
 gen_key_points_comp!(float, compute_f32_key_points, f32);
 gen_key_points_comp!(float, compute_f64_key_points, f64);
 gen_key_points_comp!(integer, compute_i32_key_points, i32);
@@ -177,13 +298,18 @@ make_numeric_coord!(
     f32,
     RangedCoordf32,
     compute_f32_key_points,
-    "The ranged coordinate for type f32"
+    "The ranged coordinate for type f32",
+    |range: (f32, f32), v: &f32| format_float_label(
+        (f64::from(range.0), f64::from(range.1)),
+        f64::from(*v)
+    )
 );
 make_numeric_coord!(
     f64,
     RangedCoordf64,
     compute_f64_key_points,
-    "The ranged coordinate for type f64"
+    "The ranged coordinate for type f64",
+    |range: (f64, f64), v: &f64| format_float_label(range, *v)
 );
 make_numeric_coord!(
     u32,
@@ -272,6 +398,34 @@ mod test {
         assert!(kp.len() == 0);
     }
 
+    #[test]
+    fn test_float_key_points() {
+        let kp = compute_f64_key_points((0.0, 1.0), 11);
+        assert_eq!(kp.len(), 11);
+        assert_eq!(kp[3], 0.3);
+        assert_eq!(kp[10], 1.0);
+
+        let kp = compute_f32_key_points((0.1, 0.7), 4);
+        assert_eq!(kp, vec![0.2, 0.4, 0.6]);
+
+        let kp = compute_f64_key_points((-1.2, 1.2), 5);
+        assert_eq!(kp, vec![-1.0, -0.5, 0.0, 0.5, 1.0]);
+
+        let kp = compute_f64_key_points((1234.5, 98765.4), 10);
+        assert_eq!(kp[0], 10000.0);
+        assert_eq!(kp.len(), 9);
+
+        let coord: RangedCoordf32 = (0f32..2f32).into();
+        assert_eq!(coord.format_label(&0.3).unwrap(), "0.3");
+        assert_eq!(coord.format_label(&1.0).unwrap(), "1");
+        assert_eq!(coord.format_label(&-0.0001).unwrap(), "0");
+
+        let coord: RangedCoordf64 = (0.0..1e-3).into();
+        assert_eq!(coord.format_label(&2.5e-4).unwrap(), "0.00025");
+        let coord: RangedCoordf64 = (0.0..1e20).into();
+        assert_eq!(coord.format_label(&1e20).unwrap(), "1e20");
+    }
+
     #[test]
     fn test_linear_coord_map() {
         let coord: RangedCoordu32 = (0..20).into();
@@ -284,6 +438,55 @@ mod test {
         assert_eq!(coord.map(&5.0, (0, 100)), 25);
     }
 
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_descending_coord() {
+        let coord: RangedCoordf64 = (10.0..0.0).into();
+        assert_eq!(coord.map(&10.0, (0, 100)), 0);
+        assert_eq!(coord.map(&2.5, (0, 100)), 75);
+        let kp = coord.key_points(11);
+        assert_eq!(kp[0], 10.0);
+        assert!(kp.windows(2).all(|w| w[0] > w[1]));
+
+        let coord: RangedCoordu32 = (20..0).into();
+        assert_eq!(coord.map(&5, (0, 100)), 75);
+        assert_eq!(coord.key_points(11)[0], 20);
+        assert_eq!(coord.unmap(75, (0, 100)), Some(5));
+    }
+
+    #[test]
+    fn test_wide_integer_coord() {
+        let base = 1_600_000_000_000_000_000i64;
+        let coord: RangedCoordi64 = (base..base + 100).into();
+        assert_eq!(coord.map(&(base + 50), (0, 100)), 50);
+        assert_eq!(coord.unmap(25, (0, 100)), Some(base + 25));
+
+        let coord: RangedCoordi32 = (i32::MIN..i32::MAX).into();
+        assert_eq!(coord.map(&0, (0, 100)), 50);
+        assert_eq!(coord.map(&i32::MAX, (0, 100)), 100);
+        assert_eq!(coord.unmap(0, (0, 100)), Some(i32::MIN));
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_wide_descrete_coord() {
+        let coord: RangedCoordi32 = (i32::MIN..i32::MAX).into();
+        assert_eq!(coord.index_of(&i32::MAX), usize::try_from(u32::MAX).ok());
+        assert_eq!(coord.value_at(3_000_000_000), Some(i32::MIN + 1_000_000_000 + 2_000_000_000));
+
+        let coord: RangedCoordi128 = (i128::MIN..i128::MAX).into();
+        assert_eq!(coord.size(), None);
+        assert_eq!(coord.index_of(&i128::MAX), None);
+
+        // A descending range has no values
+        let coord: RangedCoordu32 = (20..0).into();
+        assert_eq!(coord.size(), None);
+        assert_eq!(coord.index_of(&5), None);
+        assert_eq!(coord.value_at(0), None);
+        let coord = (20u32..0).into_centric();
+        assert_eq!(coord.map(&5, (0, 100)), 77);
+    }
+
     #[test]
     fn test_linear_coord_system() {
         let _coord =
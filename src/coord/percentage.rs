@@ -0,0 +1,149 @@
+use super::{Ranged, RangedCoordf64, ReversableRanged};
+use std::ops::Range;
+
+/// The percentage coordinate, whose values are the fractions, e.g. 0.25 is labeled as `25%`.
+/// The key points are on the round percentages.
+///
+/// ```rust,no_run
+/// use plotters::prelude::*;
+///
+/// let root = BitMapBackend::new("percentage.png", (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .y_label_area_size(40)
+///     .build_ranged(0..10, (0.0..0.6).percentage().clamped())
+///     .unwrap();
+/// chart.configure_mesh().draw().unwrap();
+/// ```
+pub struct Percentage {
+    linear: RangedCoordf64,
+    clamped: bool,
+}
+
+impl Percentage {
+    /// Clamp the coordinate to the range from 0% to 100%, the values out of it are drawn on the
+    /// boundaries
+    pub fn clamped(self) -> Self {
+        let range = self.linear.range();
+        let clamp = |v: f64| v.clamp(0.0, 1.0);
+        Self {
+            linear: (clamp(range.start)..clamp(range.end)).into(),
+            clamped: true,
+        }
+    }
+
+    fn bounds(&self) -> (f64, f64) {
+        let range = self.linear.range();
+        (range.start.min(range.end), range.start.max(range.end))
+    }
+}
+
+impl From<Range<f64>> for Percentage {
+    fn from(range: Range<f64>) -> Self {
+        Self {
+            linear: range.into(),
+            clamped: false,
+        }
+    }
+}
+
+impl Ranged for Percentage {
+    type ValueType = f64;
+
+    fn map(&self, value: &f64, limit: (i32, i32)) -> i32 {
+        let value = if self.clamped {
+            let (lower, upper) = self.bounds();
+            value.clamp(lower, upper)
+        } else {
+            *value
+        };
+        self.linear.map(&value, limit)
+    }
+
+    /// The key points are the multiples of the first step of 0.1%, 0.2%, 0.5%, 1%, 2%, 5%, 10%,
+    /// 25%, 50% and 100% that generates no more than `max_points` points, or the multiples of
+    /// 100% if none does
+    fn key_points(&self, max_points: usize) -> Vec<f64> {
+        const STEPS: [f64; 10] = [0.1, 0.2, 0.5, 1.0, 2.0, 5.0, 10.0, 25.0, 50.0, 100.0];
+
+        let (lower, upper) = (self.bounds().0 * 100.0, self.bounds().1 * 100.0);
+        if max_points == 0 || !lower.is_finite() || !upper.is_finite() {
+            return vec![];
+        }
+
+        let count = |step: f64| ((upper / step).floor() - (lower / step).ceil()) as usize + 1;
+        let mut step = STEPS
+            .iter()
+            .copied()
+            .find(|step| count(*step) <= max_points)
+            .unwrap_or(100.0);
+        while count(step) > max_points {
+            step *= 10.0;
+        }
+
+        let first = (lower / step - 1e-9).ceil() as i64;
+        let last = (upper / step + 1e-9).floor() as i64;
+        let mut ret: Vec<_> = (first..=last).map(|k| k as f64 * step / 100.0).collect();
+
+        let range = self.linear.range();
+        if range.start > range.end {
+            ret.reverse();
+        }
+        ret
+    }
+
+    fn range(&self) -> Range<f64> {
+        self.linear.range()
+    }
+
+    fn format_label(&self, value: &f64) -> Option<String> {
+        let text = format!("{:.2}", value * 100.0);
+        let text = text.trim_end_matches('0').trim_end_matches('.');
+        Some(format!("{}%", if text == "-0" { "0" } else { text }))
+    }
+}
+
+impl ReversableRanged for Percentage {
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<f64> {
+        self.linear.unmap(input, limit)
+    }
+}
+
+/// The trait that converts a range of the fractions into a percentage coordinate
+pub trait IntoPercentage {
+    /// Make the percentage coordinate, see `Percentage`
+    fn percentage(self) -> Percentage;
+}
+
+impl IntoPercentage for Range<f64> {
+    fn percentage(self) -> Percentage {
+        self.into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_percentage_coord() {
+        let coord = (0.0..0.6).percentage();
+        assert_eq!(coord.map(&0.3, (0, 100)), 50);
+        let key_points = coord.key_points(8);
+        assert_eq!(key_points.len(), 7);
+        assert_eq!(coord.format_label(&key_points[1]).unwrap(), "10%");
+        assert_eq!(coord.key_points(3), vec![0.0, 0.25, 0.5]);
+        assert_eq!(coord.format_label(&0.125).unwrap(), "12.5%");
+        assert_eq!(coord.format_label(&0.0).unwrap(), "0%");
+
+        let coord = (0.9..0.0).percentage();
+        assert_eq!(coord.key_points(3), vec![0.5, 0.0]);
+
+        let coord = (-0.5..1.5).percentage().clamped();
+        assert_eq!(coord.range(), 0.0..1.0);
+        assert_eq!(coord.map(&2.0, (0, 100)), 100);
+        assert_eq!(coord.map(&-1.0, (0, 100)), 0);
+
+        let coord = (0.0..0.01).percentage();
+        assert_eq!(coord.format_label(&coord.key_points(6)[1]).unwrap(), "0.2%");
+    }
+}
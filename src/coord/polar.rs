@@ -0,0 +1,129 @@
+use std::ops::Range;
+
+use super::{CoordTranslate, ReverseCoordTranslate};
+use crate::drawing::backend::BackendCoord;
+
+/// The polar coordinate system, the guest coordinate is a tuple of `(angle, radius)`.
+///
+/// The angle range is mapped onto a full turn, which starts at the top of the circle and goes
+/// clockwise. Thus with the angle range `0.0..360.0` the angle is in degrees like a compass,
+/// and with `0.0..5.0` five values are evenly placed around the circle, like a radar chart.
+/// The radius range is mapped from the center to the edge of the circle.
+#[derive(Clone, Debug)]
+pub struct PolarCoord {
+    angle: Range<f64>,
+    radius: Range<f64>,
+    center: BackendCoord,
+    pixel_radius: f64,
+}
+
+impl PolarCoord {
+    /// Create a new polar coordinate system
+    /// - `angle`: The angle range, which is mapped onto a full turn
+    /// - `radius`: The radius range
+    /// - `center`: The center of the circle in the backend coordinate
+    /// - `pixel_radius`: The radius of the circle in pixels
+    pub fn new(
+        angle: Range<f64>,
+        radius: Range<f64>,
+        center: BackendCoord,
+        pixel_radius: u32,
+    ) -> Self {
+        Self {
+            angle,
+            radius,
+            center,
+            pixel_radius: f64::from(pixel_radius),
+        }
+    }
+
+    /// Get the angle range
+    pub fn angle_range(&self) -> Range<f64> {
+        self.angle.clone()
+    }
+
+    /// Get the radius range
+    pub fn radius_range(&self) -> Range<f64> {
+        self.radius.clone()
+    }
+
+    /// Get the center of the circle in the backend coordinate
+    pub fn center(&self) -> BackendCoord {
+        self.center
+    }
+
+    /// Get the radius of the circle in pixels
+    pub fn pixel_radius(&self) -> u32 {
+        self.pixel_radius as u32
+    }
+
+    /// Map the guest coordinate to the pixel offset from the center
+    pub(crate) fn offset(&self, angle: f64, radius: f64) -> (f64, f64) {
+        let turn = (angle - self.angle.start) / (self.angle.end - self.angle.start);
+        let theta = turn * 2.0 * std::f64::consts::PI;
+        let r = (radius - self.radius.start) / (self.radius.end - self.radius.start)
+            * self.pixel_radius;
+        let r = r.max(0.0);
+        (r * theta.sin(), -r * theta.cos())
+    }
+}
+
+impl CoordTranslate for PolarCoord {
+    type From = (f64, f64);
+
+    fn translate(&self, &(angle, radius): &(f64, f64)) -> BackendCoord {
+        let (dx, dy) = self.offset(angle, radius);
+        (
+            self.center.0 + dx.round() as i32,
+            self.center.1 + dy.round() as i32,
+        )
+    }
+}
+
+impl ReverseCoordTranslate for PolarCoord {
+    fn reverse_translate(&self, (x, y): BackendCoord) -> Option<(f64, f64)> {
+        let (dx, dy) = (f64::from(x - self.center.0), f64::from(y - self.center.1));
+        let r = (dx * dx + dy * dy).sqrt();
+        if r > self.pixel_radius {
+            return None;
+        }
+        let mut theta = dx.atan2(-dy);
+        if theta < 0.0 {
+            theta += 2.0 * std::f64::consts::PI;
+        }
+        let turn = theta / (2.0 * std::f64::consts::PI);
+        Some((
+            self.angle.start + turn * (self.angle.end - self.angle.start),
+            self.radius.start + r / self.pixel_radius * (self.radius.end - self.radius.start),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_polar_translate() {
+        let coord = PolarCoord::new(0.0..360.0, 0.0..10.0, (100, 100), 50);
+        assert_eq!(coord.translate(&(0.0, 10.0)), (100, 50));
+        assert_eq!(coord.translate(&(90.0, 10.0)), (150, 100));
+        assert_eq!(coord.translate(&(180.0, 5.0)), (100, 125));
+        assert_eq!(coord.translate(&(270.0, 0.0)), (100, 100));
+
+        let coord = PolarCoord::new(0.0..4.0, 0.0..1.0, (0, 0), 10);
+        assert_eq!(coord.translate(&(1.0, 1.0)), (10, 0));
+    }
+
+    #[test]
+    fn test_polar_reverse_translate() {
+        let coord = PolarCoord::new(0.0..360.0, 0.0..10.0, (100, 100), 50);
+        let (angle, radius) = coord.reverse_translate((150, 100)).unwrap();
+        assert!((angle - 90.0).abs() < 1e-9);
+        assert!((radius - 10.0).abs() < 1e-9);
+        let (angle, radius) = coord.reverse_translate((75, 100)).unwrap();
+        assert!((angle - 270.0).abs() < 1e-9);
+        assert!((radius - 5.0).abs() < 1e-9);
+        assert_eq!(coord.reverse_translate((200, 200)), None);
+    }
+}
@@ -2,7 +2,10 @@ use super::{CoordTranslate, ReverseCoordTranslate};
 use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 use crate::style::ShapeStyle;
 
+use std::cell::RefCell;
+use std::fmt::Debug;
 use std::ops::Range;
+use std::rc::Rc;
 
 /// The trait that indicates we have a ordered and ranged value
 /// Which is used to describe the axis
@@ -16,6 +19,19 @@ pub trait Ranged {
     /// This function gives the key points that we can draw a grid based on this
     fn key_points(&self, max_points: usize) -> Vec<Self::ValueType>;
 
+    /// This function gives the minor key points, which are used for the minor ticks and the
+    /// fine grid between the major key points. By default, this is the key points with
+    /// `per_major` times as many points.
+    /// - `max_points`: The maximum number of major key points
+    /// - `per_major`: The maximum number of minor divisions between two major key points,
+    ///   `0` means there's no minor key points
+    fn minor_key_points(&self, max_points: usize, per_major: usize) -> Vec<Self::ValueType> {
+        if max_points * per_major == 0 {
+            return vec![];
+        }
+        self.key_points(max_points * per_major)
+    }
+
     /// Get the range of this value
     fn range(&self) -> Range<Self::ValueType>;
 
@@ -23,6 +39,12 @@ pub trait Ranged {
     fn axis_pixel_range(&self, limit: (i32, i32)) -> Range<i32> {
         limit.0..limit.1
     }
+
+    /// Format the value as a tick label. `None` means the coordinate has no preferred
+    /// format, and the mesh formats the value with `Debug`.
+    fn format_label(&self, _value: &Self::ValueType) -> Option<String> {
+        None
+    }
 }
 
 /// The trait indicates the ranged value can be map reversely, which means
@@ -32,12 +54,36 @@ pub trait ReversableRanged: Ranged {
     fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<Self::ValueType>;
 }
 
+/// The key points computed previously, keyed by the max number of points
+type KeyPointCache<T> = RefCell<Vec<(usize, Rc<Vec<T>>)>>;
+
+/// Get the key points from the cache, or compute them if this is the first time we ask
+fn cached_key_points<R: Ranged>(
+    coord: &R,
+    cache: &KeyPointCache<R::ValueType>,
+    max_points: usize,
+) -> Rc<Vec<R::ValueType>> {
+    let mut cache = cache.borrow_mut();
+
+    if let Some((_, points)) = cache.iter().find(|(n, _)| *n == max_points) {
+        return points.clone();
+    }
+
+    let points = Rc::new(coord.key_points(max_points));
+    cache.push((max_points, points.clone()));
+    points
+}
+
 /// The coordinate described by two ranged value
 pub struct RangedCoord<X: Ranged, Y: Ranged> {
     logic_x: X,
     logic_y: Y,
     back_x: (i32, i32),
     back_y: (i32, i32),
+    /// The mesh is drawn in multiple passes (grid, labels and ticks) and the key point
+    /// computation may be expensive, so we only compute them once per coordinate
+    x_key_points: KeyPointCache<X::ValueType>,
+    y_key_points: KeyPointCache<Y::ValueType>,
 }
 
 impl<X: Ranged, Y: Ranged> RangedCoord<X, Y> {
@@ -52,42 +98,99 @@ impl<X: Ranged, Y: Ranged> RangedCoord<X, Y> {
             logic_y: logic_y.into(),
             back_x: (actual.0.start, actual.0.end),
             back_y: (actual.1.start, actual.1.end),
+            x_key_points: RefCell::new(vec![]),
+            y_key_points: RefCell::new(vec![]),
         }
     }
 
+    /// Get the minor key points of both axes, see `Ranged::minor_key_points`
+    /// - `x`: The maximum number of major key points and minor divisions of the X axis
+    /// - `y`: The maximum number of major key points and minor divisions of the Y axis
+    pub fn minor_key_points(
+        &self,
+        (x_points, x_per_major): (usize, usize),
+        (y_points, y_per_major): (usize, usize),
+    ) -> (Vec<X::ValueType>, Vec<Y::ValueType>) {
+        (
+            self.logic_x.minor_key_points(x_points, x_per_major),
+            self.logic_y.minor_key_points(y_points, y_per_major),
+        )
+    }
+
     /// Draw the mesh for the coordinate system
     pub fn draw_mesh<E, DrawMesh: FnMut(MeshLine<X, Y>) -> Result<(), E>>(
         &self,
         h_limit: usize,
         v_limit: usize,
+        draw_mesh: DrawMesh,
+    ) -> Result<(), E> {
+        self.draw_mesh_with_key_points(h_limit, v_limit, (None, None), draw_mesh)
+    }
+
+    /// Draw the mesh for the coordinate system, the given key points are used instead of the
+    /// ones computed by the axis
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn draw_mesh_with_key_points<E, DrawMesh: FnMut(MeshLine<X, Y>) -> Result<(), E>>(
+        &self,
+        h_limit: usize,
+        v_limit: usize,
+        (x_points, y_points): (Option<&[X::ValueType]>, Option<&[Y::ValueType]>),
         mut draw_mesh: DrawMesh,
     ) -> Result<(), E> {
-        let (xkp, ykp) = (
-            self.logic_x.key_points(v_limit),
-            self.logic_y.key_points(h_limit),
-        );
+        let (x_cached, y_cached);
+        let xkp = match x_points {
+            Some(points) => points,
+            None => {
+                x_cached = cached_key_points(&self.logic_x, &self.x_key_points, v_limit);
+                &x_cached[..]
+            }
+        };
+        let ykp = match y_points {
+            Some(points) => points,
+            None => {
+                y_cached = cached_key_points(&self.logic_y, &self.y_key_points, h_limit);
+                &y_cached[..]
+            }
+        };
 
-        for logic_x in xkp {
-            let x = self.logic_x.map(&logic_x, self.back_x);
+        for logic_x in xkp.iter() {
+            let x = self.logic_x.map(logic_x, self.back_x);
             draw_mesh(MeshLine::XMesh(
                 (x, self.back_y.0),
                 (x, self.back_y.1),
-                &logic_x,
+                logic_x,
             ))?;
         }
 
-        for logic_y in ykp {
-            let y = self.logic_y.map(&logic_y, self.back_y);
+        for logic_y in ykp.iter() {
+            let y = self.logic_y.map(logic_y, self.back_y);
             draw_mesh(MeshLine::YMesh(
                 (self.back_x.0, y),
                 (self.back_x.1, y),
-                &logic_y,
+                logic_y,
             ))?;
         }
 
         Ok(())
     }
 
+    /// Get the key points of the X axis, which are cached for the later uses
+    pub(crate) fn x_key_points(&self, max_points: usize) -> Rc<Vec<X::ValueType>> {
+        cached_key_points(&self.logic_x, &self.x_key_points, max_points)
+    }
+
+    /// Replace the X axis, the cached key points of the old axis are dropped
+    pub(crate) fn set_x_spec(&mut self, logic_x: X) {
+        self.logic_x = logic_x;
+        self.x_key_points.borrow_mut().clear();
+    }
+
+    /// Replace the Y axis, the cached key points of the old axis are dropped
+    pub(crate) fn set_y_spec(&mut self, logic_y: Y) {
+        self.logic_y = logic_y;
+        self.y_key_points.borrow_mut().clear();
+    }
+
     /// Get the range of X axis
     pub fn get_x_range(&self) -> Range<X::ValueType> {
         self.logic_x.range()
@@ -105,6 +208,16 @@ impl<X: Ranged, Y: Ranged> RangedCoord<X, Y> {
     pub fn get_y_axis_pixel_range(&self) -> Range<i32> {
         self.logic_y.axis_pixel_range(self.back_y)
     }
+
+    /// Format the X value as a tick label, see `Ranged::format_label`
+    pub fn format_x_label(&self, value: &X::ValueType) -> Option<String> {
+        self.logic_x.format_label(value)
+    }
+
+    /// Format the Y value as a tick label, see `Ranged::format_label`
+    pub fn format_y_label(&self, value: &Y::ValueType) -> Option<String> {
+        self.logic_y.format_label(value)
+    }
 }
 
 impl<X: Ranged, Y: Ranged> CoordTranslate for RangedCoord<X, Y> {
@@ -159,8 +272,38 @@ where
 
     /// Get the largest value that is smaller than `this` value
     fn previous_value(this: &Self::ValueType) -> Self::ValueType;
+
+    /// Get the largest value that is no larger than `this` value, i.e. the descrete value that
+    /// `this` value falls in, which the histograms aggregate the data by
+    fn floor_value(this: &Self::ValueType) -> Self::ValueType {
+        Self::previous_value(&Self::next_value(this))
+    }
+
+    /// Get the number of values in the range, `None` if the coordinate can't index its values.
+    /// A coordinate which can index its values allows the wrappers to precompute a lookup table
+    /// from the index to the pixel.
+    fn size(&self) -> Option<usize> {
+        None
+    }
+
+    /// Get the index of the value, `None` if the value is out of the range or the coordinate
+    /// can't index its values
+    fn index_of(&self, _value: &Self::ValueType) -> Option<usize> {
+        None
+    }
+
+    /// Get the value with the given index, this is the reverse of `index_of`
+    fn value_at(&self, _index: usize) -> Option<Self::ValueType> {
+        None
+    }
 }
 
+/// The max number of values we build the lookup table for
+const LOOKUP_TABLE_SIZE_LIMIT: usize = 1 << 20;
+
+/// The table from the index of a descrete value to the pixel, built for a given pixel range
+type LookupTable = RefCell<Option<((i32, i32), Vec<i32>)>>;
+
 /// The trait for the type that can be converted into a ranged coordinate axis
 pub trait AsRangedCoord: Sized {
     type CoordDescType: Ranged + From<Self>;
@@ -176,17 +319,55 @@ where
     type Value = T::ValueType;
 }
 
-pub struct CentricDescreteRange<D: DescreteRanged>(D)
+pub struct CentricDescreteRange<D: DescreteRanged>(D, LookupTable)
 where
     <D as Ranged>::ValueType: Eq;
 
+impl<D: DescreteRanged> CentricDescreteRange<D>
+where
+    <D as Ranged>::ValueType: Eq,
+{
+    fn map_centric(&self, value: &D::ValueType, limit: (i32, i32)) -> i32 {
+        let prev = <D as DescreteRanged>::previous_value(value);
+        // There's no previous value at the start of the type, e.g. 0 of an unsigned type
+        if prev == *value {
+            return self.0.map(value, limit);
+        }
+        (self.0.map(&prev, limit) + self.0.map(value, limit)) / 2
+    }
+
+    /// Map the value with the lookup table, which is built on the first call with a pixel range.
+    /// Returns `None` if the inner coordinate can't index its values.
+    fn lookup(&self, value: &D::ValueType, limit: (i32, i32)) -> Option<i32> {
+        let idx = self.0.index_of(value)?;
+        let mut table = self.1.borrow_mut();
+
+        let outdated = match table.as_ref() {
+            Some((l, _)) => *l != limit,
+            None => true,
+        };
+
+        if outdated {
+            let size = self.0.size().filter(|&s| s <= LOOKUP_TABLE_SIZE_LIMIT)?;
+            let pixels = (0..size)
+                .map(|i| self.0.value_at(i).map(|v| self.map_centric(&v, limit)))
+                .collect::<Option<Vec<_>>>()?;
+            *table = Some((limit, pixels));
+        }
+
+        table
+            .as_ref()
+            .and_then(|(_, pixels)| pixels.get(idx).cloned())
+    }
+}
+
 pub trait IntoCentric: AsRangedCoord
 where
     Self::CoordDescType: DescreteRanged,
     <Self::CoordDescType as Ranged>::ValueType: Eq,
 {
     fn into_centric(self) -> CentricDescreteRange<Self::CoordDescType> {
-        CentricDescreteRange(self.into())
+        CentricDescreteRange(self.into(), RefCell::new(None))
     }
 }
 
@@ -204,17 +385,25 @@ where
     type ValueType = <D as Ranged>::ValueType;
 
     fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
-        let prev = <D as DescreteRanged>::previous_value(&value);
-        (self.0.map(&prev, limit) + self.0.map(value, limit)) / 2
+        self.lookup(value, limit)
+            .unwrap_or_else(|| self.map_centric(value, limit))
     }
 
     fn key_points(&self, max_points: usize) -> Vec<Self::ValueType> {
         self.0.key_points(max_points)
     }
 
+    fn minor_key_points(&self, max_points: usize, per_major: usize) -> Vec<Self::ValueType> {
+        self.0.minor_key_points(max_points, per_major)
+    }
+
     fn range(&self) -> Range<Self::ValueType> {
         self.0.range()
     }
+
+    fn format_label(&self, value: &Self::ValueType) -> Option<String> {
+        self.0.format_label(value)
+    }
 }
 
 impl<D: DescreteRanged> DescreteRanged for CentricDescreteRange<D>
@@ -228,6 +417,22 @@ where
     fn previous_value(this: &Self::ValueType) -> Self::ValueType {
         <D as DescreteRanged>::previous_value(this)
     }
+
+    fn floor_value(this: &Self::ValueType) -> Self::ValueType {
+        <D as DescreteRanged>::floor_value(this)
+    }
+
+    fn size(&self) -> Option<usize> {
+        self.0.size()
+    }
+
+    fn index_of(&self, value: &Self::ValueType) -> Option<usize> {
+        self.0.index_of(value)
+    }
+
+    fn value_at(&self, index: usize) -> Option<Self::ValueType> {
+        self.0.value_at(index)
+    }
 }
 
 impl<D: DescreteRanged> AsRangedCoord for CentricDescreteRange<D>
@@ -238,6 +443,125 @@ where
     type Value = <Self as Ranged>::ValueType;
 }
 
+/// The value of a segmented coordinate, see `IntoSegmented::into_segmented`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SegmentValue<T> {
+    /// The boundary where the segment of the value starts
+    Exact(T),
+    /// The center of the segment of the value
+    CenterOf(T),
+    /// The end of the last segment
+    Last,
+}
+
+/// The descrete coordinate whose values are split into segments, each of them starts at the
+/// value and ends at the next value. Unlike `CentricDescreteRange`, which moves the values to
+/// the centers, this lets the element pick either the boundary or the center of the segment,
+/// and the key points are the centers, so the tick labels are placed in the middle of the bars.
+pub struct SegmentedCoord<D: DescreteRanged>(D)
+where
+    <D as Ranged>::ValueType: Eq;
+
+pub trait IntoSegmented: AsRangedCoord
+where
+    Self::CoordDescType: DescreteRanged,
+    <Self::CoordDescType as Ranged>::ValueType: Eq,
+{
+    /// Split the descrete coordinate into segments, which works with any descrete coordinate,
+    /// e.g. integers, dates, `Monthly` and categories.
+    ///
+    /// ```rust,no_run
+    /// use plotters::prelude::*;
+    ///
+    /// let root = BitMapBackend::new("segmented.png", (640, 480)).into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&root)
+    ///     .x_label_area_size(30)
+    ///     .build_ranged((0u32..5u32).into_segmented(), 0u32..10u32)
+    ///     .unwrap();
+    /// chart.configure_mesh().disable_x_mesh().draw().unwrap();
+    /// chart
+    ///     .draw_series([3u32, 7, 4, 9, 2].iter().enumerate().map(|(x, y)| {
+    ///         let x = x as u32;
+    ///         Rectangle::new(
+    ///             [(SegmentValue::Exact(x), *y), (SegmentValue::Exact(x + 1), 0)],
+    ///             BLUE.filled(),
+    ///         )
+    ///     }))
+    ///     .unwrap();
+    /// ```
+    fn into_segmented(self) -> SegmentedCoord<Self::CoordDescType> {
+        SegmentedCoord(self.into())
+    }
+}
+
+impl<T: AsRangedCoord> IntoSegmented for T
+where
+    T::CoordDescType: DescreteRanged,
+    <Self::CoordDescType as Ranged>::ValueType: Eq,
+{
+}
+
+impl<D: DescreteRanged> Ranged for SegmentedCoord<D>
+where
+    <D as Ranged>::ValueType: Eq,
+{
+    type ValueType = SegmentValue<<D as Ranged>::ValueType>;
+
+    fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
+        match value {
+            SegmentValue::Exact(value) => self.0.map(value, limit),
+            SegmentValue::CenterOf(value) => {
+                let this = self.0.map(value, limit);
+                let next = self.0.map(&<D as DescreteRanged>::next_value(value), limit);
+                this + (next - this) / 2
+            }
+            SegmentValue::Last => self.0.map(&self.0.range().end, limit),
+        }
+    }
+
+    /// The key points are the centers of the segments, and the end of the range is dropped,
+    /// since there's no segment starting there
+    fn key_points(&self, max_points: usize) -> Vec<Self::ValueType> {
+        let end = self.0.range().end;
+        self.0
+            .key_points(max_points)
+            .into_iter()
+            .filter(|value| *value != end)
+            .map(SegmentValue::CenterOf)
+            .collect()
+    }
+
+    fn minor_key_points(&self, max_points: usize, per_major: usize) -> Vec<Self::ValueType> {
+        self.0
+            .minor_key_points(max_points, per_major)
+            .into_iter()
+            .map(SegmentValue::Exact)
+            .collect()
+    }
+
+    fn range(&self) -> Range<Self::ValueType> {
+        let range = self.0.range();
+        SegmentValue::Exact(range.start)..SegmentValue::Exact(range.end)
+    }
+
+    fn format_label(&self, value: &Self::ValueType) -> Option<String> {
+        match value {
+            SegmentValue::Exact(value) | SegmentValue::CenterOf(value) => {
+                self.0.format_label(value)
+            }
+            SegmentValue::Last => Some(String::new()),
+        }
+    }
+}
+
+impl<D: DescreteRanged> AsRangedCoord for SegmentedCoord<D>
+where
+    <D as Ranged>::ValueType: Eq,
+{
+    type CoordDescType = Self;
+    type Value = <Self as Ranged>::ValueType;
+}
+
 pub struct PartialAxis<R: Ranged>(R, Range<R::ValueType>);
 
 pub trait IntoPartialAxis: AsRangedCoord {
@@ -265,10 +589,18 @@ where
         self.0.key_points(max_points)
     }
 
+    fn minor_key_points(&self, max_points: usize, per_major: usize) -> Vec<Self::ValueType> {
+        self.0.minor_key_points(max_points, per_major)
+    }
+
     fn range(&self) -> Range<Self::ValueType> {
         self.0.range()
     }
 
+    fn format_label(&self, value: &Self::ValueType) -> Option<String> {
+        self.0.format_label(value)
+    }
+
     fn axis_pixel_range(&self, limit: (i32, i32)) -> Range<i32> {
         let left = self.map(&self.1.start, limit);
         let right = self.map(&self.1.end, limit);
@@ -289,6 +621,22 @@ where
     fn previous_value(this: &Self::ValueType) -> Self::ValueType {
         <R as DescreteRanged>::previous_value(this)
     }
+
+    fn floor_value(this: &Self::ValueType) -> Self::ValueType {
+        <R as DescreteRanged>::floor_value(this)
+    }
+
+    fn size(&self) -> Option<usize> {
+        self.0.size()
+    }
+
+    fn index_of(&self, value: &Self::ValueType) -> Option<usize> {
+        self.0.index_of(value)
+    }
+
+    fn value_at(&self, index: usize) -> Option<Self::ValueType> {
+        self.0.value_at(index)
+    }
 }
 
 impl<R: Ranged> AsRangedCoord for PartialAxis<R>
@@ -299,6 +647,103 @@ where
     type Value = <Self as Ranged>::ValueType;
 }
 
+/// The axis that uses the given key points for the mesh and labels, instead of picking them
+/// automatically
+pub struct WithKeyPoints<R: Ranged>(R, Vec<R::ValueType>);
+
+pub trait IntoWithKeyPoints: AsRangedCoord {
+    /// Pin the key points of the axis, e.g. at some dates or threshold levels.
+    /// The key points are used regardless of the max number of the key points.
+    /// - `points`: The key points
+    fn with_key_points<I: IntoIterator<Item = <Self::CoordDescType as Ranged>::ValueType>>(
+        self,
+        points: I,
+    ) -> WithKeyPoints<Self::CoordDescType> {
+        WithKeyPoints(self.into(), points.into_iter().collect())
+    }
+}
+
+impl<R: AsRangedCoord> IntoWithKeyPoints for R {}
+
+impl<R: Ranged> Ranged for WithKeyPoints<R>
+where
+    R::ValueType: Clone,
+{
+    type ValueType = R::ValueType;
+
+    fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
+        self.0.map(value, limit)
+    }
+
+    fn key_points(&self, _max_points: usize) -> Vec<Self::ValueType> {
+        self.1.clone()
+    }
+
+    /// The given key points are used as they are, thus there's no minor key points
+    fn minor_key_points(&self, _max_points: usize, _per_major: usize) -> Vec<Self::ValueType> {
+        vec![]
+    }
+
+    fn range(&self) -> Range<Self::ValueType> {
+        self.0.range()
+    }
+
+    fn axis_pixel_range(&self, limit: (i32, i32)) -> Range<i32> {
+        self.0.axis_pixel_range(limit)
+    }
+
+    fn format_label(&self, value: &Self::ValueType) -> Option<String> {
+        self.0.format_label(value)
+    }
+}
+
+impl<R: ReversableRanged> ReversableRanged for WithKeyPoints<R>
+where
+    R::ValueType: Clone,
+{
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<Self::ValueType> {
+        self.0.unmap(input, limit)
+    }
+}
+
+impl<R: DescreteRanged> DescreteRanged for WithKeyPoints<R>
+where
+    R: Ranged,
+    <R as Ranged>::ValueType: Eq + Clone,
+{
+    fn next_value(this: &Self::ValueType) -> Self::ValueType {
+        <R as DescreteRanged>::next_value(this)
+    }
+
+    fn previous_value(this: &Self::ValueType) -> Self::ValueType {
+        <R as DescreteRanged>::previous_value(this)
+    }
+
+    fn floor_value(this: &Self::ValueType) -> Self::ValueType {
+        <R as DescreteRanged>::floor_value(this)
+    }
+
+    fn size(&self) -> Option<usize> {
+        self.0.size()
+    }
+
+    fn index_of(&self, value: &Self::ValueType) -> Option<usize> {
+        self.0.index_of(value)
+    }
+
+    fn value_at(&self, index: usize) -> Option<Self::ValueType> {
+        self.0.value_at(index)
+    }
+}
+
+impl<R: Ranged> AsRangedCoord for WithKeyPoints<R>
+where
+    <R as Ranged>::ValueType: Clone,
+{
+    type CoordDescType = Self;
+    type Value = <Self as Ranged>::ValueType;
+}
+
 #[cfg(feature = "make_partial_axis")]
 pub fn make_partial_axis<T>(
     axis_range: Range<T>,
@@ -322,3 +767,145 @@ where
 
     Some(PartialAxis(full_range.into(), axis_range.range()))
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::cell::Cell;
+
+    struct CountingRanged<'a>(&'a Cell<usize>);
+
+    impl Ranged for CountingRanged<'_> {
+        type ValueType = i32;
+        fn map(&self, value: &i32, limit: (i32, i32)) -> i32 {
+            limit.0 + value
+        }
+        fn key_points(&self, max_points: usize) -> Vec<i32> {
+            self.0.set(self.0.get() + 1);
+            (0..max_points as i32).collect()
+        }
+        fn range(&self) -> Range<i32> {
+            0..100
+        }
+    }
+
+    #[test]
+    fn test_key_points_cache() {
+        let (x_count, y_count) = (Cell::new(0), Cell::new(0));
+        let coord: RangedCoord<CountingRanged, CountingRanged> = RangedCoord::new(
+            CountingRanged(&x_count),
+            CountingRanged(&y_count),
+            (0..100, 0..100),
+        );
+
+        for &(h, v) in &[(10, 5), (100, 50), (10, 5)] {
+            let mut lines = 0;
+            coord
+                .draw_mesh(h, v, |_| {
+                    lines += 1;
+                    Ok::<(), ()>(())
+                })
+                .unwrap();
+            assert_eq!(lines, h + v);
+        }
+
+        assert_eq!(x_count.get(), 2);
+        assert_eq!(y_count.get(), 2);
+    }
+
+    #[test]
+    fn test_draw_mesh_with_key_points() {
+        let (x_count, y_count) = (Cell::new(0), Cell::new(0));
+        let coord: RangedCoord<CountingRanged, CountingRanged> = RangedCoord::new(
+            CountingRanged(&x_count),
+            CountingRanged(&y_count),
+            (0..100, 0..100),
+        );
+
+        let mut lines = vec![];
+        coord
+            .draw_mesh_with_key_points(10, 10, (Some(&[3, 7]), None), |line| {
+                lines.push(match line {
+                    MeshLine::XMesh(from, _, _) => from.0,
+                    MeshLine::YMesh(from, _, _) => -from.1,
+                });
+                Ok::<(), ()>(())
+            })
+            .unwrap();
+
+        assert_eq!(lines.len(), 12);
+        assert_eq!(lines[..2], [3, 7]);
+        assert_eq!(x_count.get(), 0);
+        assert_eq!(y_count.get(), 1);
+    }
+
+    #[test]
+    fn test_with_key_points() {
+        let coord = (0.0..10.0).with_key_points(vec![1.5, 7.25]);
+        assert_eq!(coord.key_points(10), vec![1.5, 7.25]);
+        assert_eq!(coord.map(&5.0, (0, 100)), 50);
+        assert_eq!(coord.unmap(50, (0, 100)), Some(5.0));
+    }
+
+    #[test]
+    fn test_minor_key_points() {
+        let coord: RangedCoord<crate::coord::RangedCoordi32, crate::coord::RangedCoordi32> =
+            RangedCoord::new(0..10, 0..100, (0..100, 0..100));
+        let (x, y) = coord.minor_key_points((5, 2), (5, 0));
+        assert_eq!(x, (0..=10).collect::<Vec<_>>());
+        assert!(y.is_empty());
+
+        let coord = (0..10).with_key_points(vec![3, 7]);
+        assert!(coord.minor_key_points(10, 10).is_empty());
+    }
+
+    #[test]
+    fn test_centric_lookup_table() {
+        let coord = (0..10).into_centric();
+
+        assert_eq!(coord.size(), Some(11));
+        assert_eq!(coord.index_of(&3), Some(3));
+        assert_eq!(coord.index_of(&11), None);
+
+        for v in -1..12 {
+            assert_eq!(coord.map(&v, (0, 100)), coord.map_centric(&v, (0, 100)));
+            assert_eq!(coord.map(&v, (100, 0)), coord.map_centric(&v, (100, 0)));
+        }
+    }
+
+    #[test]
+    fn test_centric_unsigned_zero() {
+        // 0 of an unsigned type has no previous value, and the max has no next value
+        let coord = (0u32..10u32).into_centric();
+        assert_eq!(coord.map(&0, (0, 100)), 0);
+        assert_eq!(coord.map(&1, (0, 100)), 5);
+        assert_eq!(
+            crate::coord::RangedCoordu32::next_value(&u32::MAX),
+            u32::MAX
+        );
+    }
+
+    #[test]
+    fn test_segmented_coord() {
+        let coord = (0u32..10u32).into_segmented();
+
+        assert_eq!(coord.map(&SegmentValue::Exact(0), (0, 100)), 0);
+        assert_eq!(coord.map(&SegmentValue::CenterOf(0), (0, 100)), 5);
+        assert_eq!(coord.map(&SegmentValue::CenterOf(9), (0, 100)), 95);
+        assert_eq!(coord.map(&SegmentValue::Last, (0, 100)), 100);
+        assert_eq!(coord.map(&SegmentValue::CenterOf(3), (100, 0)), 65);
+
+        let key_points = coord.key_points(20);
+        assert_eq!(key_points.len(), 10);
+        assert!(key_points
+            .iter()
+            .all(|v| matches!(v, SegmentValue::CenterOf(_))));
+        assert_eq!(coord.format_label(&key_points[3]).unwrap(), "3");
+
+        let categories = crate::coord::RangedCategory::new(vec!["a", "b", "c", "d"]);
+        let b = SegmentValue::CenterOf(categories.get(&"b").unwrap());
+        let categories = categories.into_segmented();
+        assert_eq!(categories.map(&b, (0, 80)), 30);
+        assert_eq!(categories.key_points(10).len(), 4);
+    }
+}
@@ -0,0 +1,170 @@
+use std::ops::Range;
+
+#[cfg(feature = "chrono")]
+use chrono::{Date, DateTime, Duration, NaiveDate, NaiveDateTime, TimeZone};
+
+/// The value type whose range can be padded, which is implemented for the numbers and the dates
+/// and times
+pub trait Paddable: PartialOrd + Clone {
+    /// Move the bounds of the range outwards by `ratio` of the span. The range of a single value
+    /// is widened by one unit of the type, i.e. 1 for the numbers and a day for the dates and
+    /// times, so that it can still be used as an axis.
+    fn pad(range: Range<Self>, ratio: f64) -> Range<Self>;
+}
+
+macro_rules! impl_paddable {
+    (float, $($t:ty),*) => {$(
+        impl Paddable for $t {
+            fn pad(range: Range<$t>, ratio: f64) -> Range<$t> {
+                if range.start == range.end {
+                    return range.start - 1.0..range.end + 1.0;
+                }
+                let pad = ((range.end - range.start) as f64 * ratio) as $t;
+                range.start - pad..range.end + pad
+            }
+        }
+    )*};
+    (integer, $($t:ty),*) => {$(
+        impl Paddable for $t {
+            fn pad(range: Range<$t>, ratio: f64) -> Range<$t> {
+                let pad = if range.start == range.end {
+                    1
+                } else {
+                    // The span may not fit in the type, e.g. from a negative to a positive value
+                    ((range.end as f64 - range.start as f64) * ratio).ceil() as $t
+                };
+                range.start.saturating_sub(pad)..range.end.saturating_add(pad)
+            }
+        }
+    )*};
+}
+
+impl_paddable!(float, f32, f64);
+impl_paddable!(integer, u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// Pad the range with the duration computed from the span in nanoseconds, or in days if it's
+/// too long for nanoseconds
+#[cfg(feature = "chrono")]
+fn padding_of(span: Duration, ratio: f64) -> Duration {
+    if span.is_zero() {
+        return Duration::days(1);
+    }
+    match span.num_nanoseconds() {
+        Some(ns) => Duration::nanoseconds((ns as f64 * ratio) as i64),
+        None => Duration::days((span.num_days() as f64 * ratio) as i64),
+    }
+}
+
+#[cfg(feature = "chrono")]
+macro_rules! impl_paddable_time {
+    ($(impl$(<$z:ident>)? for $t:ty),*) => {$(
+        impl$(<$z: TimeZone>)? Paddable for $t {
+            fn pad(range: Range<$t>, ratio: f64) -> Range<$t> {
+                let pad = padding_of(range.end.clone() - range.start.clone(), ratio);
+                range.start - pad..range.end + pad
+            }
+        }
+    )*};
+}
+
+#[cfg(feature = "chrono")]
+impl_paddable_time!(
+    impl<Z> for Date<Z>,
+    impl<Z> for DateTime<Z>,
+    impl for NaiveDate,
+    impl for NaiveDateTime
+);
+
+/// Get the range from the smallest to the largest value, `None` if there's no value. The values
+/// that can't be compared, such as NaN, are ignored.
+///
+/// ```rust
+/// use plotters::data::fitting_range;
+///
+/// assert_eq!(fitting_range(&[3.0, 1.0, 2.0]), Some(1.0..3.0));
+/// ```
+pub fn fitting_range<'a, T: PartialOrd + Clone + 'a, I: IntoIterator<Item = &'a T>>(
+    iter: I,
+) -> Option<Range<T>> {
+    let mut range: Option<Range<T>> = None;
+    for value in iter {
+        if value.partial_cmp(value).is_none() {
+            continue;
+        }
+        range = Some(match range {
+            None => value.clone()..value.clone(),
+            Some(range) if *value < range.start => value.clone()..range.end,
+            Some(range) if *value > range.end => range.start..value.clone(),
+            Some(range) => range,
+        });
+    }
+    range
+}
+
+/// Get the ranges of the X and Y axes that fit all the points, padded by `padding` of the span
+/// on both sides, e.g. 0.05 for 5%. Returns `None` if there's no point.
+///
+/// ```rust,no_run
+/// use chrono::{Duration, TimeZone, Utc};
+/// use plotters::data::fit_chart_ranges;
+/// use plotters::prelude::*;
+///
+/// let start = Utc.ymd(2019, 1, 1);
+/// let data: Vec<_> = (0..30).map(|d| (start + Duration::days(d), (d * d) as f64)).collect();
+/// let (x_range, y_range) = fit_chart_ranges(data.iter().cloned(), 0.05).unwrap();
+///
+/// let root = BitMapBackend::new("fit.png", (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .x_label_area_size(30)
+///     .y_label_area_size(40)
+///     .build_ranged(x_range, y_range)
+///     .unwrap();
+/// chart.draw_series(LineSeries::new(data, &RED)).unwrap();
+/// ```
+pub fn fit_chart_ranges<X: Paddable, Y: Paddable, I: IntoIterator<Item = (X, Y)>>(
+    points: I,
+    padding: f64,
+) -> Option<(Range<X>, Range<Y>)> {
+    let (xs, ys): (Vec<_>, Vec<_>) = points.into_iter().unzip();
+    let x_range = fitting_range(&xs)?;
+    let y_range = fitting_range(&ys)?;
+    Some((X::pad(x_range, padding), Y::pad(y_range, padding)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_fitting_range() {
+        assert_eq!(fitting_range(&[3, 1, 2]), Some(1..3));
+        assert_eq!(fitting_range(&[2.0, f64::NAN, -1.0]), Some(-1.0..2.0));
+        assert_eq!(fitting_range::<f64, _>(&[]), None);
+
+        let (x, y) = fit_chart_ranges(vec![(0u32, 1.0f64), (10, 3.0), (5, 2.0)], 0.05).unwrap();
+        assert_eq!(x, 0..11);
+        assert!((y.start - 0.9).abs() < 1e-9 && (y.end - 3.1).abs() < 1e-9);
+        assert_eq!(
+            fit_chart_ranges(vec![(1, 1.0)], 0.1),
+            Some((0..2, 0.0..2.0))
+        );
+        assert_eq!(
+            fit_chart_ranges(vec![(i32::MIN + 1, 0), (i32::MAX - 1, 0)], 0.1),
+            Some((i32::MIN..i32::MAX, -1..1))
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_fit_date_ranges() {
+        use chrono::Utc;
+        let start = Utc.ymd(2019, 1, 1);
+        let data = (0..10).map(|d| (start + Duration::days(d), d));
+        let (x, y) = fit_chart_ranges(data, 0.2).unwrap();
+        assert_eq!(
+            x,
+            start - Duration::hours(43)..start + Duration::days(9) + Duration::hours(43)
+        );
+        assert_eq!(y, -2..11);
+    }
+}
@@ -0,0 +1,210 @@
+/// The iterator adaptor which reduces a series to roughly the resolution of the chart while
+/// keeping its visual shape, since drawing millions of points into a chart a thousand pixels
+/// wide is wasteful.
+///
+/// There are two strategies:
+/// - `Downsample::lttb` uses the Largest-Triangle-Three-Buckets algorithm, which picks the
+///   points forming the largest triangles with their neighbours, and gives a smooth looking
+///   line with the given number of points.
+/// - `Downsample::min_max` splits the X range into buckets, typically one for each pixel, and
+///   keeps the minimum and the maximum of each bucket, so that no spike is lost.
+///
+/// The data are expected to be sorted by X and finite.
+///
+/// ```rust,no_run
+/// use plotters::data::Downsample;
+/// use plotters::prelude::*;
+///
+/// let data: Vec<(f64, f64)> = (0..1_000_000).map(|x| (x as f64, (x as f64).sin())).collect();
+/// let root = BitMapBackend::new("downsample.png", (1024, 768)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_ranged(0.0..1e6, -1.0..1.0)
+///     .unwrap();
+/// chart
+///     .draw_series(LineSeries::new(Downsample::lttb(data, 1000), &RED))
+///     .unwrap();
+/// ```
+pub struct Downsample<X, Y> {
+    points: std::vec::IntoIter<(X, Y)>,
+}
+
+impl<X: Copy + Into<f64>, Y: Copy + Into<f64>> Downsample<X, Y> {
+    /// Downsample the data with the Largest-Triangle-Three-Buckets algorithm
+    /// - `data`: The points of the series
+    /// - `threshold`: The number of points to keep, the data is kept as it is if it doesn't
+    ///   have more points than this or the threshold is less than 3
+    pub fn lttb<I: IntoIterator<Item = (X, Y)>>(data: I, threshold: usize) -> Self {
+        let data: Vec<_> = data.into_iter().collect();
+        if threshold < 3 || data.len() <= threshold {
+            return Self::from_points(data);
+        }
+
+        let value = |idx: usize| (data[idx].0.into(), data[idx].1.into());
+        // The first and last points are always kept, the rest are split into the buckets
+        let bucket_size = (data.len() - 2) as f64 / (threshold - 2) as f64;
+        let bucket = |idx: usize| {
+            let begin = (idx as f64 * bucket_size) as usize + 1;
+            let end = ((idx + 1) as f64 * bucket_size) as usize + 1;
+            begin..end.min(data.len() - 1)
+        };
+
+        let mut selected = Vec::with_capacity(threshold);
+        selected.push(0);
+        let mut last = 0;
+        for idx in 0..threshold - 2 {
+            // The third vertex of the triangle is the average of the next bucket
+            let next = if idx + 1 < threshold - 2 {
+                bucket(idx + 1)
+            } else {
+                data.len() - 1..data.len()
+            };
+            let n = next.len() as f64;
+            let (sum_x, sum_y) = next.fold((0.0, 0.0), |(sx, sy), i| {
+                let (x, y) = value(i);
+                (sx + x, sy + y)
+            });
+            let (cx, cy) = (sum_x / n, sum_y / n);
+            let (ax, ay) = value(last);
+
+            let mut best = None;
+            let mut best_area = -1.0;
+            for i in bucket(idx) {
+                let (bx, by) = value(i);
+                let area = ((ax - cx) * (by - ay) - (ax - bx) * (cy - ay)).abs();
+                if area > best_area {
+                    best_area = area;
+                    best = Some(i);
+                }
+            }
+            if let Some(best) = best {
+                selected.push(best);
+                last = best;
+            }
+        }
+        selected.push(data.len() - 1);
+
+        Self::select(data, &selected)
+    }
+
+    /// Downsample the data by keeping the minimum and the maximum of each bucket
+    /// - `data`: The points of the series
+    /// - `buckets`: The number of buckets the X range is split into, which is usually the width
+    ///   of the chart in pixels. At most two points are kept for each bucket, plus the first and
+    ///   the last point.
+    pub fn min_max<I: IntoIterator<Item = (X, Y)>>(data: I, buckets: usize) -> Self {
+        let data: Vec<_> = data.into_iter().collect();
+        if buckets == 0 || data.len() <= buckets * 2 + 2 {
+            return Self::from_points(data);
+        }
+
+        let (x_min, x_max) = data
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |r, p| {
+                let x = p.0.into();
+                (r.0.min(x), r.1.max(x))
+            });
+        let span = x_max - x_min;
+
+        // The indices of the minimum and the maximum in each bucket
+        let mut extremes: Vec<Option<(usize, usize)>> = vec![None; buckets];
+        for (idx, (x, y)) in data.iter().enumerate() {
+            let slot = if span > 0.0 {
+                ((((*x).into() - x_min) / span * buckets as f64) as usize).min(buckets - 1)
+            } else {
+                0
+            };
+            let y: f64 = (*y).into();
+            extremes[slot] = Some(match extremes[slot] {
+                None => (idx, idx),
+                Some((lo, hi)) => (
+                    if y < data[lo].1.into() { idx } else { lo },
+                    if y > data[hi].1.into() { idx } else { hi },
+                ),
+            });
+        }
+
+        let mut selected = vec![0, data.len() - 1];
+        for (lo, hi) in extremes.into_iter().flatten() {
+            selected.push(lo);
+            selected.push(hi);
+        }
+        // Keep the points in the original order, so the line doesn't go back and forth
+        selected.sort_unstable();
+        selected.dedup();
+
+        Self::select(data, &selected)
+    }
+
+    fn from_points(points: Vec<(X, Y)>) -> Self {
+        Self {
+            points: points.into_iter(),
+        }
+    }
+
+    /// Keep the points at the indices, which must be sorted
+    fn select(data: Vec<(X, Y)>, indices: &[usize]) -> Self {
+        let mut indices = indices.iter().peekable();
+        let points = data
+            .into_iter()
+            .enumerate()
+            .filter(|(idx, _)| {
+                if indices.peek() == Some(&idx) {
+                    indices.next();
+                    true
+                } else {
+                    false
+                }
+            })
+            .map(|(_, p)| p)
+            .collect();
+        Self::from_points(points)
+    }
+}
+
+impl<X, Y> Iterator for Downsample<X, Y> {
+    type Item = (X, Y);
+    fn next(&mut self) -> Option<(X, Y)> {
+        self.points.next()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_lttb() {
+        let data: Vec<_> = (0..100)
+            .map(|x| (f64::from(x), if x == 37 { 10.0 } else { 0.0 }))
+            .collect();
+
+        let result: Vec<_> = Downsample::lttb(data.clone(), 10).collect();
+        assert_eq!(result.len(), 10);
+        assert_eq!(result[0], (0.0, 0.0));
+        assert_eq!(result[9], (99.0, 0.0));
+        // The spike forms the largest triangle, so it's kept
+        assert!(result.contains(&(37.0, 10.0)));
+        assert!(result.windows(2).all(|w| w[0].0 < w[1].0));
+
+        // Short data is kept as it is
+        assert_eq!(Downsample::lttb(data.clone(), 200).count(), 100);
+        assert_eq!(Downsample::lttb(data, 2).count(), 100);
+    }
+
+    #[test]
+    fn test_min_max() {
+        let data: Vec<_> = (0..1000)
+            .map(|x| (x, f64::from(x % 10) - if x == 503 { 100.0 } else { 0.0 }))
+            .collect();
+
+        let result: Vec<_> = Downsample::min_max(data, 10).collect();
+        // The minimum and the maximum of each bucket, plus the last point
+        assert_eq!(result.len(), 21);
+        assert_eq!(result[0], (0, 0.0));
+        assert_eq!(result[20], (999, 9.0));
+        assert!(result.contains(&(503, -97.0)));
+        assert!(result.windows(2).all(|w| w[0].0 < w[1].0));
+
+        assert_eq!(Downsample::min_max(vec![(0, 0.0), (1, 1.0)], 10).count(), 2);
+    }
+}
@@ -0,0 +1,200 @@
+use std::collections::HashMap;
+
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, TimeZone, Utc};
+
+use crate::element::PointElement;
+use crate::series::{LineSeries, PointSeries};
+use crate::style::ShapeStyle;
+
+/// The error of looking up the columns in a data frame
+#[derive(Debug, Clone)]
+pub enum DataFrameError {
+    /// There's no column with the given name, or the column has a different type
+    NoSuchColumn(String),
+    /// The columns used as X and Y have different length
+    LengthMismatch(usize, usize),
+    /// The timestamp in the column is out of the range of the datetime
+    InvalidTimestamp(String, i64),
+}
+
+impl std::fmt::Display for DataFrameError {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            DataFrameError::NoSuchColumn(name) => write!(fmt, "No such column: {}", name),
+            DataFrameError::LengthMismatch(x, y) => {
+                write!(fmt, "Column length mismatch: {} and {}", x, y)
+            }
+            DataFrameError::InvalidTimestamp(name, ts) => {
+                write!(fmt, "Invalid timestamp in column {}: {}", name, ts)
+            }
+        }
+    }
+}
+
+impl std::error::Error for DataFrameError {}
+
+type FrameResult<T> = Result<T, DataFrameError>;
+
+type NumericPoints = Vec<(f64, f64)>;
+
+#[cfg(feature = "chrono")]
+type DateTimePoints = Vec<(DateTime<Utc>, f64)>;
+
+/// The minimal column access of a data frame. By implementing this trait for a data frame
+/// type, series can be created by the column names.
+pub trait DataFrame {
+    /// Get the values of a numeric column
+    /// - `name`: The name of the column
+    /// - **returns**: The values, or `None` if there's no such numeric column
+    fn numeric_column(&self, name: &str) -> Option<Vec<f64>>;
+
+    /// Get the values of a datetime column, in milliseconds since the UNIX epoch
+    /// - `name`: The name of the column
+    /// - **returns**: The timestamps, or `None` if there's no such datetime column
+    fn timestamp_column(&self, _name: &str) -> Option<Vec<i64>> {
+        None
+    }
+
+    /// Zip two numeric columns into a list of points
+    /// - `x`: The name of the column used as X
+    /// - `y`: The name of the column used as Y
+    fn numeric_points(&self, x: &str, y: &str) -> FrameResult<NumericPoints> {
+        let xs = get_column(self.numeric_column(x), x)?;
+        let ys = get_column(self.numeric_column(y), y)?;
+        zip_columns(xs, ys)
+    }
+
+    /// Zip a datetime column and a numeric column into a list of points, which can be used
+    /// with the `RangedDateTime` coordinate
+    /// - `x`: The name of the datetime column used as X
+    /// - `y`: The name of the numeric column used as Y
+    #[cfg(feature = "chrono")]
+    fn datetime_points(&self, x: &str, y: &str) -> FrameResult<DateTimePoints> {
+        let xs = get_column(self.timestamp_column(x), x)?
+            .into_iter()
+            .map(|ts| {
+                Utc.timestamp_opt(ts.div_euclid(1000), ts.rem_euclid(1000) as u32 * 1_000_000)
+                    .single()
+                    .ok_or_else(|| DataFrameError::InvalidTimestamp(x.to_string(), ts))
+            })
+            .collect::<FrameResult<_>>()?;
+        let ys = get_column(self.numeric_column(y), y)?;
+        zip_columns(xs, ys)
+    }
+
+    /// Create a line series from two numeric columns
+    fn line_series<S: Into<ShapeStyle>>(
+        &self,
+        x: &str,
+        y: &str,
+        style: S,
+    ) -> FrameResult<LineSeries<(f64, f64), NumericPoints>> {
+        Ok(LineSeries::new(self.numeric_points(x, y)?, style))
+    }
+
+    /// Create a point series from two numeric columns
+    fn point_series<'a, E: PointElement<(f64, f64)>, S: Into<ShapeStyle>>(
+        &self,
+        x: &str,
+        y: &str,
+        size: u32,
+        style: S,
+    ) -> FrameResult<PointSeries<'a, (f64, f64), NumericPoints, E>> {
+        Ok(PointSeries::new(self.numeric_points(x, y)?, size, style))
+    }
+
+    /// Create a line series from a datetime column and a numeric column
+    #[cfg(feature = "chrono")]
+    fn datetime_line_series<S: Into<ShapeStyle>>(
+        &self,
+        x: &str,
+        y: &str,
+        style: S,
+    ) -> FrameResult<LineSeries<(DateTime<Utc>, f64), DateTimePoints>> {
+        Ok(LineSeries::new(self.datetime_points(x, y)?, style))
+    }
+}
+
+fn get_column<T>(column: Option<Vec<T>>, name: &str) -> FrameResult<Vec<T>> {
+    column.ok_or_else(|| DataFrameError::NoSuchColumn(name.to_string()))
+}
+
+fn zip_columns<X, Y>(xs: Vec<X>, ys: Vec<Y>) -> FrameResult<Vec<(X, Y)>> {
+    if xs.len() != ys.len() {
+        return Err(DataFrameError::LengthMismatch(xs.len(), ys.len()));
+    }
+    Ok(xs.into_iter().zip(ys).collect())
+}
+
+/// The simplest data frame, a map from the column names to the numeric columns
+impl DataFrame for HashMap<String, Vec<f64>> {
+    fn numeric_column(&self, name: &str) -> Option<Vec<f64>> {
+        self.get(name).cloned()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::element::PointCollection;
+    use crate::style::BLACK;
+
+    struct Frame;
+
+    impl DataFrame for Frame {
+        fn numeric_column(&self, name: &str) -> Option<Vec<f64>> {
+            match name {
+                "value" => Some(vec![1.0, 2.0]),
+                "short" => Some(vec![1.0]),
+                _ => None,
+            }
+        }
+        fn timestamp_column(&self, name: &str) -> Option<Vec<i64>> {
+            match name {
+                "time" => Some(vec![0, 1500]),
+                "invalid" => Some(vec![0, i64::MAX]),
+                _ => None,
+            }
+        }
+    }
+
+    #[test]
+    fn test_numeric_columns() {
+        let mut frame = HashMap::new();
+        frame.insert("x".to_string(), vec![0.0, 1.0, 2.0]);
+        frame.insert("y".to_string(), vec![3.0, 4.0, 5.0]);
+
+        let path = frame.line_series("x", "y", &BLACK).unwrap().next().unwrap();
+        assert_eq!((&path).point_iter(), &[(0.0, 3.0), (1.0, 4.0), (2.0, 5.0)]);
+
+        match frame.numeric_points("x", "z") {
+            Err(DataFrameError::NoSuchColumn(name)) => assert_eq!(name, "z"),
+            _ => panic!("Column z should be missing"),
+        }
+    }
+
+    #[test]
+    fn test_length_mismatch() {
+        match Frame.numeric_points("value", "short") {
+            Err(DataFrameError::LengthMismatch(2, 1)) => {}
+            _ => panic!("The columns should have different length"),
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_datetime_columns() {
+        let points = Frame.datetime_points("time", "value").unwrap();
+        assert_eq!(points[0], (Utc.timestamp(0, 0), 1.0));
+        assert_eq!(points[1], (Utc.timestamp(1, 500_000_000), 2.0));
+        assert!(Frame.datetime_points("value", "value").is_err());
+
+        match Frame.datetime_points("invalid", "value") {
+            Err(DataFrameError::InvalidTimestamp(name, ts)) => {
+                assert_eq!((name.as_str(), ts), ("invalid", i64::MAX))
+            }
+            _ => panic!("The timestamp should be invalid"),
+        }
+    }
+}
@@ -6,3 +6,11 @@ Such as, downsampling, etc.
 use crate::drawing::backend::DrawingBackend;
 use crate::drawing::coord::RangedCoord;
 use crate::chart::ChartContext;*/
+
+mod data_range;
+mod downsample;
+mod frame;
+
+pub use data_range::{fit_chart_ranges, fitting_range, Paddable};
+pub use downsample::Downsample;
+pub use frame::{DataFrame, DataFrameError};
@@ -1,7 +1,9 @@
 /// The abstraction of a drawing area
 use super::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use super::BoundsBackend;
 use crate::coord::{CoordTranslate, MeshLine, Ranged, RangedCoord, Shift};
 use crate::element::{Drawable, PointCollection};
+use crate::error::ErrorContext;
 use crate::style::{Color, FontDesc, TextStyle};
 
 use std::borrow::Borrow;
@@ -129,6 +131,35 @@ pub enum DrawingAreaErrorKind<E: Error + Send + Sync> {
     SharingError,
     /// The error caused by invalid layout
     LayoutError,
+    /// The error with the context describing where it happened, see `PlottersError`.
+    /// The drawing APIs wrap the other kinds with this variant, thus use `root_kind` to match
+    /// the kind of the underlying error.
+    Context(ErrorContext, Box<DrawingAreaErrorKind<E>>),
+}
+
+impl<E: Error + Send + Sync> DrawingAreaErrorKind<E> {
+    /// Attach the context to the error
+    pub fn context(self, context: ErrorContext) -> Self {
+        DrawingAreaErrorKind::Context(context, Box::new(self))
+    }
+
+    /// Get the underlying error with all the context stripped, which is never `Context`
+    pub fn root_kind(&self) -> &Self {
+        let mut error = self;
+        while let DrawingAreaErrorKind::Context(_, inner) = error {
+            error = inner;
+        }
+        error
+    }
+
+    /// Strip all the context and return the underlying error, which is never `Context`
+    pub fn into_root_kind(self) -> Self {
+        let mut error = self;
+        while let DrawingAreaErrorKind::Context(_, inner) = error {
+            error = *inner;
+        }
+        error
+    }
 }
 
 impl<E: Error + Send + Sync> std::fmt::Display for DrawingAreaErrorKind<E> {
@@ -139,6 +170,7 @@ impl<E: Error + Send + Sync> std::fmt::Display for DrawingAreaErrorKind<E> {
                 write!(fmt, "Mulitple backend operation in progress")
             }
             DrawingAreaErrorKind::LayoutError => write!(fmt, "Bad layout"),
+            DrawingAreaErrorKind::Context(ctx, e) => write!(fmt, "{}: {}", ctx, e),
         }
     }
 }
@@ -189,6 +221,27 @@ impl<DB: DrawingBackend, X: Ranged, Y: Ranged> DrawingArea<DB, RangedCoord<X, Y>
         })
     }
 
+    /// Draw the mesh on a area, the given key points are used instead of the ones computed by
+    /// the axis
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn draw_mesh_with_key_points<DrawFunc>(
+        &self,
+        mut draw_func: DrawFunc,
+        y_count_max: usize,
+        x_count_max: usize,
+        key_points: (Option<&[X::ValueType]>, Option<&[Y::ValueType]>),
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        DrawFunc: FnMut(&mut DB, MeshLine<X, Y>) -> Result<(), DrawingErrorKind<DB::ErrorType>>,
+    {
+        self.backend_ops(move |b| {
+            self.coord
+                .draw_mesh_with_key_points(y_count_max, x_count_max, key_points, |line| {
+                    draw_func(b, line)
+                })
+        })
+    }
+
     /// Get the range of X of the guest coordinate for current drawing area
     pub fn get_x_range(&self) -> Range<X::ValueType> {
         self.coord.get_x_range()
@@ -275,6 +328,7 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
                 true,
             )
         })
+        .map_err(|e| e.context(ErrorContext::BackendOp("fill")))
     }
 
     /// Draw a single pixel
@@ -287,9 +341,22 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
         self.backend_ops(|b| b.draw_pixel(pos, &color.to_rgba()))
     }
 
+    /// Draw a batch of pixels with a single backend call
+    pub fn draw_pixels<ColorType: Color, I: IntoIterator<Item = (CT::From, ColorType)>>(
+        &self,
+        pixels: I,
+    ) -> Result<(), DrawingAreaError<DB>> {
+        let pixels: Vec<_> = pixels
+            .into_iter()
+            .map(|(pos, color)| (self.coord.translate(&pos), color.to_rgba()))
+            .collect();
+        self.backend_ops(|b| b.draw_pixels(&pixels[..]))
+    }
+
     /// Present all the pending changes to the backend
     pub fn present(&self) -> Result<(), DrawingAreaError<DB>> {
         self.backend_ops(|b| b.present())
+            .map_err(|e| e.context(ErrorContext::BackendOp("present")))
     }
 
     /// Draw an high-level element
@@ -298,11 +365,55 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
         &'a E: PointCollection<'a, CT::From>,
         E: Drawable<DB>,
     {
+        let backend_coords = element
+            .point_iter()
+            .into_iter()
+            .map(|p| self.coord.translate(p.borrow()));
+        let clip = ((self.rect.x0, self.rect.y0), (self.rect.x1, self.rect.y1));
+        self.backend_ops(move |b| element.draw_clipped(backend_coords, clip, b))
+            .map_err(|e| e.context(ErrorContext::BackendOp("draw")))
+    }
+
+    /// Start a group of shapes with an accessible description, see
+    /// `DrawingBackend::begin_group`. Every group must be ended with `end_group`.
+    pub fn begin_group(&self, description: &str) -> Result<(), DrawingAreaError<DB>> {
+        self.backend_ops(|b| b.begin_group(description))
+    }
+
+    /// End the group started by the last `begin_group` call
+    pub fn end_group(&self) -> Result<(), DrawingAreaError<DB>> {
+        self.backend_ops(|b| b.end_group())
+    }
+
+    /// Find out the region that an element covers without drawing it.
+    /// This is useful for making the elements interactive, see `HitRegions`.
+    /// - `element`: The element to measure
+    /// - **returns**: The upper-left and bottom-right corners (both inclusive) of the region
+    ///   in the backend coordinate, or `None` if the element covers nothing
+    pub fn element_bounds<'a, E>(
+        &self,
+        element: &'a E,
+    ) -> Result<Option<(BackendCoord, BackendCoord)>, DrawingAreaError<DB>>
+    where
+        &'a E: PointCollection<'a, CT::From>,
+        E: Drawable<BoundsBackend>,
+    {
+        let size = match self.backend.try_borrow() {
+            Ok(backend) => backend.get_size(),
+            Err(_) => return Err(DrawingAreaErrorKind::SharingError),
+        };
+        let mut bounds = BoundsBackend::new(size);
         let backend_coords = element.point_iter().into_iter().map(|p| {
             let b = p.borrow();
             self.rect.truncate(self.coord.translate(b))
         });
-        self.backend_ops(move |b| element.draw(backend_coords, b))
+        match element.draw(backend_coords, &mut bounds) {
+            Ok(()) => Ok(bounds.take_bounds()),
+            Err(DrawingErrorKind::FontError(e)) => Err(DrawingAreaErrorKind::BackendError(
+                DrawingErrorKind::FontError(e),
+            )),
+            Err(DrawingErrorKind::DrawingError(e)) => match e {},
+        }
     }
 
     /// Map coordinate to the backend coordinate
@@ -484,6 +595,7 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
                 &style.color,
             )
         })
+        .map_err(|e| e.context(ErrorContext::BackendOp("draw_text")))
     }
 }
 
@@ -491,6 +603,16 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
     pub fn into_coord_spec(self) -> CT {
         self.coord
     }
+
+    /// Get a reference to the coordinate specification applied to the drawing area
+    pub fn as_coord_spec(&self) -> &CT {
+        &self.coord
+    }
+
+    /// Get a mutable reference to the coordinate specification applied to the drawing area
+    pub(crate) fn as_coord_spec_mut(&mut self) -> &mut CT {
+        &mut self.coord
+    }
 }
 
 #[cfg(test)]
@@ -713,4 +835,28 @@ mod drawing_area_tests {
             .fill(&WHITE)
             .expect("Drawing Failure");
     }
+
+    #[test]
+    fn test_draw_pixels() {
+        let drawing_area = create_mocked_drawing_area(1024, 768, |m| {
+            m.check_draw_pixel(|c, p| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(p, (13, 21));
+            });
+            m.check_draw_pixel(|c, p| {
+                assert_eq!(c, BLUE.to_rgba());
+                assert_eq!(p, (3, 1));
+            });
+
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_pixels_call, 1);
+                assert_eq!(b.draw_count, 1);
+            });
+        });
+
+        drawing_area
+            .margin(1, 2, 3, 4)
+            .draw_pixels(vec![((10, 20), RED), ((0, 0), BLUE)])
+            .expect("Drawing Failure");
+    }
 }
@@ -1,4 +1,4 @@
-use crate::style::{Color, FontDesc, FontError, RGBAColor, ShapeStyle};
+use crate::style::{Color, FontDesc, FontError, RGBAColor, RGBColor, ShapeStyle};
 use std::error::Error;
 
 /// A coordiante in the image
@@ -36,6 +36,14 @@ pub trait BackendStyle {
     fn stroke_width(&self) -> u32 {
         1
     }
+
+    /// The dash pattern of the stroke, which is the alternating lengths of the dashes and the
+    /// gaps in pixels, and the distance into the pattern at which the stroke starts.
+    /// The stroke is solid if the pattern is empty. The pattern applies to the lines, the paths
+    /// and the outlines of the rectangles.
+    fn stroke_dash(&self) -> (&[u32], u32) {
+        (&[], 0)
+    }
 }
 
 impl<T: Color> BackendStyle for T {
@@ -53,6 +61,14 @@ impl BackendStyle for ShapeStyle {
     fn stroke_width(&self) -> u32 {
         self.stroke_width
     }
+    fn stroke_dash(&self) -> (&[u32], u32) {
+        (&self.dash_pattern[..], self.dash_offset)
+    }
+}
+
+/// Get the number of bytes of a RGB bitmap with the given size, `None` on overflow
+pub(crate) fn bitmap_len((w, h): (u32, u32)) -> Option<usize> {
+    (w as usize).checked_mul(h as usize)?.checked_mul(3)
 }
 
 ///  The drawing backend trait, which implemenets the low-level drawing APIs.
@@ -88,6 +104,52 @@ pub trait DrawingBackend: Sized {
         color: &RGBAColor,
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>>;
 
+    /// Draw a batch of pixels on the drawing backend.
+    /// The default implementation draws the pixels one by one, and the backend may override
+    /// it to avoid the per-pixel overhead, which is important for large scatter plots.
+    /// - `pixels`: The backend pixel-based coordinates and the colors of the pixels
+    fn draw_pixels(
+        &mut self,
+        pixels: &[(BackendCoord, RGBAColor)],
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        for (point, color) in pixels {
+            self.draw_pixel(*point, color)?;
+        }
+        Ok(())
+    }
+
+    /// Copy a bitmap onto the drawing backend.
+    /// The default implementation draws the bitmap pixel by pixel, and the backend may
+    /// override it with a native image, e.g. an embedded image of a vector graphics format.
+    /// - `pos`: The upper-left corner of the bitmap
+    /// - `(iw, ih)`: The width and the height of the bitmap in pixels
+    /// - `src`: The pixels of the bitmap in RGB, row by row. Nothing is drawn if it's shorter
+    ///   than `iw * ih` pixels.
+    fn blit_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        (iw, ih): (u32, u32),
+        src: &[u8],
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        match bitmap_len((iw, ih)) {
+            Some(len) if src.len() >= len => {}
+            _ => return Ok(()),
+        }
+        let pixels: Vec<_> = src
+            .chunks_exact(3)
+            .take(iw as usize * ih as usize)
+            .enumerate()
+            .map(|(idx, rgb)| {
+                let (x, y) = (idx % iw as usize, idx / iw as usize);
+                (
+                    (pos.0 + x as i32, pos.1 + y as i32),
+                    RGBColor(rgb[0], rgb[1], rgb[2]).to_rgba(),
+                )
+            })
+            .collect();
+        self.draw_pixels(&pixels)
+    }
+
     /// Draw a line on the drawing backend
     /// - `from`: The start point of the line
     /// - `to`: The end point of the line
@@ -98,6 +160,9 @@ pub trait DrawingBackend: Sized {
         to: BackendCoord,
         style: &S,
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if !style.stroke_dash().0.is_empty() {
+            return super::rasterizer::draw_dashed_path(self, &[from, to], style);
+        }
         super::rasterizer::draw_line(self, from, to, style)
     }
 
@@ -113,6 +178,15 @@ pub trait DrawingBackend: Sized {
         style: &S,
         fill: bool,
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if !fill && !style.stroke_dash().0.is_empty() {
+            let (x0, y0) = upper_left;
+            let (x1, y1) = bottom_right;
+            return super::rasterizer::draw_dashed_path(
+                self,
+                &[(x0, y0), (x1, y0), (x1, y1), (x0, y1), (x0, y0)],
+                style,
+            );
+        }
         super::rasterizer::draw_rect(self, upper_left, bottom_right, style, fill)
     }
 
@@ -128,6 +202,11 @@ pub trait DrawingBackend: Sized {
             return Ok(());
         }
 
+        if !style.stroke_dash().0.is_empty() {
+            let p: Vec<_> = path.into_iter().collect();
+            return super::rasterizer::draw_dashed_path(self, &p[..], style);
+        }
+
         if style.stroke_width() == 1 {
             let mut begin: Option<BackendCoord> = None;
             for end in path.into_iter() {
@@ -193,6 +272,19 @@ pub trait DrawingBackend: Sized {
         }
     }
 
+    /// Start a group of shapes with an accessible description, e.g. a data series.
+    /// The backends that support accessibility, such as SVG, emit the description for the
+    /// screen readers, and the other backends simply ignore it.
+    /// - `description`: The description of the group
+    fn begin_group(&mut self, _description: &str) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        Ok(())
+    }
+
+    /// End the group started by the last `begin_group` call
+    fn end_group(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        Ok(())
+    }
+
     /// Estimate the size of the text if rendered on this backend.
     /// This is important because some of the backend may not have font ability.
     /// Thus this allows those backend reports proper value rather than ask the
@@ -1,27 +1,181 @@
-use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use crate::drawing::backend::{
+    bitmap_len, BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind,
+};
+use crate::drawing::rasterizer;
+use crate::drawing::Metadata;
 use crate::style::{Color, RGBAColor};
-use image::{ImageError, Rgb, RgbImage};
+use image::{DynamicImage, ImageBuffer, ImageError, ImageOutputFormat, Rgb, RgbImage};
 
+use std::ops::{Deref, DerefMut};
 use std::path::Path;
 
+#[cfg(feature = "rayon")]
+use crate::coord::Shift;
+#[cfg(feature = "rayon")]
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
+#[cfg(feature = "rayon")]
+use crate::style::FontDesc;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+#[cfg(feature = "rayon")]
+use std::cell::RefCell;
+#[cfg(feature = "rayon")]
+use std::rc::Rc;
+
+/// The bounding box of the modified pixels, described by its upper-left and bottom-right corners
+type DirtyRegion = (BackendCoord, BackendCoord);
+
+fn merge_dirty_region(a: Option<DirtyRegion>, b: Option<DirtyRegion>) -> Option<DirtyRegion> {
+    match (a, b) {
+        (Some((a0, a1)), Some((b0, b1))) => Some((
+            (a0.0.min(b0.0), a0.1.min(b0.1)),
+            (a1.0.max(b1.0), a1.1.max(b1.1)),
+        )),
+        (a, None) => a,
+        (None, b) => b,
+    }
+}
+
+/// Encode the string as Latin-1 as PNG text chunks require, the other chars are replaced by `?`
+fn latin1(s: &str) -> Vec<u8> {
+    s.chars()
+        .map(|c| if (c as u32) < 256 { c as u8 } else { b'?' })
+        .collect()
+}
+
+/// Append a PNG chunk with its length and CRC
+fn write_png_chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = out.len();
+    out.extend_from_slice(kind);
+    out.extend_from_slice(data);
+
+    let mut crc = 0xffff_ffffu32;
+    for byte in &out[start..] {
+        crc ^= u32::from(*byte);
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    out.extend_from_slice(&(!crc).to_be_bytes());
+}
+
+/// The storage of the pixels, which is either owned by the backend or borrowed from the caller.
+/// When it's borrowed, the backend rasterizes directly into the caller's buffer.
+enum PixelBuffer<'a> {
+    Owned(Vec<u8>),
+    Borrowed(&'a mut [u8]),
+}
+
+impl Deref for PixelBuffer<'_> {
+    type Target = [u8];
+    fn deref(&self) -> &[u8] {
+        match self {
+            PixelBuffer::Owned(buf) => &buf[..],
+            PixelBuffer::Borrowed(buf) => buf,
+        }
+    }
+}
+
+impl DerefMut for PixelBuffer<'_> {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        match self {
+            PixelBuffer::Owned(buf) => &mut buf[..],
+            PixelBuffer::Borrowed(buf) => buf,
+        }
+    }
+}
+
+type BitMapImage<'a> = ImageBuffer<Rgb<u8>, PixelBuffer<'a>>;
+
+fn size_of_image((w, h): (u32, u32)) -> usize {
+    w as usize * h as usize * 3
+}
+
+fn create_image<C: Deref<Target = [u8]>>((w, h): (u32, u32), buf: C) -> ImageBuffer<Rgb<u8>, C> {
+    ImageBuffer::from_raw(w, h, buf).expect("Buffer size doesn't match the image size")
+}
+
+#[cfg(feature = "gif")]
+pub use gif_support::GifOptions;
+
 #[cfg(feature = "gif")]
 mod gif_support {
     use super::*;
-    use gif::{Encoder as GifEncoder, Frame as GifFrame, Repeat, SetParameter};
+    use gif::{DisposalMethod, Encoder as GifEncoder, Frame as GifFrame, Repeat, SetParameter};
     use std::fs::File;
 
+    /// The options of a GIF animation, see `BitMapBackend::gif_with_options`
+    ///
+    /// ```rust,no_run
+    /// use plotters::prelude::*;
+    ///
+    /// let options = GifOptions::new(100).repeat(2).hold_last_frame(2000);
+    /// let root = BitMapBackend::gif_with_options("animation.gif", (640, 480), options)
+    ///     .unwrap()
+    ///     .into_drawing_area();
+    /// ```
+    #[derive(Clone, Debug)]
+    pub struct GifOptions {
+        frame_delay: u32,
+        /// The times to repeat, `None` for looping forever
+        repeat: Option<u16>,
+        final_hold: u32,
+    }
+
+    impl GifOptions {
+        /// Create the options of an animation which loops forever
+        /// - `frame_delay`: The amount of time for each frame to display, in milliseconds
+        pub fn new(frame_delay: u32) -> Self {
+            Self {
+                frame_delay,
+                repeat: None,
+                final_hold: 0,
+            }
+        }
+
+        /// Play the animation once and then repeat it for the given times, rather than looping
+        /// forever
+        pub fn repeat(mut self, times: u16) -> Self {
+            self.repeat = Some(times);
+            self
+        }
+
+        /// Display the last frame longer than the others
+        /// - `hold`: The extra amount of time for the last frame to display, in milliseconds
+        pub fn hold_last_frame(mut self, hold: u32) -> Self {
+            self.final_hold = hold;
+            self
+        }
+    }
+
+    /// Convert the delay in milliseconds into the units of 10 milliseconds GIF uses
+    fn gif_delay(delay: u32) -> u16 {
+        ((delay + 5) / 10).min(u32::from(u16::MAX)) as u16
+    }
+
     pub(super) struct GifFile {
         encoder: GifEncoder<File>,
         height: u32,
         width: u32,
-        delay: u32,
+        delay: u16,
+        final_hold: u16,
+        /// The region drawn in the last frame, `None` if no frame has been written yet
+        last_region: Option<Option<DirtyRegion>>,
+        /// The last frame, which is written once the next frame comes or the animation ends,
+        /// since the last frame may be held longer
+        pending: Option<GifFrame<'static>>,
     }
 
     impl GifFile {
         pub(super) fn new<T: AsRef<Path>>(
             path: T,
             dim: (u32, u32),
-            delay: u32,
+            options: &GifOptions,
         ) -> Result<Self, ImageError> {
             let mut encoder = GifEncoder::new(
                 File::create(path.as_ref()).map_err(ImageError::IoError)?,
@@ -30,41 +184,339 @@ mod gif_support {
                 &[],
             )?;
 
-            encoder.set(Repeat::Infinite)?;
+            encoder.set(match options.repeat {
+                Some(times) => Repeat::Finite(times),
+                None => Repeat::Infinite,
+            })?;
 
             Ok(Self {
                 encoder,
                 width: dim.0,
                 height: dim.1,
-                delay: (delay + 5) / 10,
+                delay: gif_delay(options.frame_delay),
+                final_hold: gif_delay(options.final_hold),
+                last_region: None,
+                pending: None,
             })
         }
 
-        pub(super) fn flush_frame(&mut self, img: &mut RgbImage) -> Result<(), ImageError> {
-            let mut new_img = RgbImage::new(self.width, self.height);
-            std::mem::swap(&mut new_img, img);
+        /// Set the delay of the frames written from now on, in milliseconds
+        pub(super) fn set_delay(&mut self, delay: u32) {
+            self.delay = gif_delay(delay);
+        }
 
-            let mut frame = GifFrame::from_rgb_speed(
-                self.width as u16,
-                self.height as u16,
-                &new_img.into_raw(),
-                10,
-            );
+        /// Write the last frame, held for the extra time
+        pub(super) fn finish(&mut self) -> Result<(), ImageError> {
+            if let Some(mut frame) = self.pending.take() {
+                frame.delay = frame.delay.saturating_add(self.final_hold);
+                self.encoder.write_frame(&frame)?;
+            }
+            Ok(())
+        }
+
+        /// Write the frame to the GIF file.
+        ///
+        /// Since each frame starts from a blank buffer or the restored background, only the pixels
+        /// changed in either the last frame or this frame can be different from the frame currently
+        /// displayed. Thus only the area covering both dirty regions is encoded, and the remaining
+        /// part is kept from the previous frame.
+        pub(super) fn flush_frame(
+            &mut self,
+            img: &[u8],
+            dirty: Option<DirtyRegion>,
+        ) -> Result<(), ImageError> {
+            let region = match self.last_region {
+                None => Some(((0, 0), (self.width as i32 - 1, self.height as i32 - 1))),
+                Some(last) => merge_dirty_region(last, dirty),
+            };
+            self.last_region = Some(dirty);
+
+            // Even if nothing has changed, we still need a frame for the delay
+            let ((left, top), (right, bottom)) = region.unwrap_or(((0, 0), (0, 0)));
+            let (width, height) = ((right - left + 1) as u32, (bottom - top + 1) as u32);
+
+            let mut frame = if (width, height) == (self.width, self.height) {
+                GifFrame::from_rgb_speed(width as u16, height as u16, img, 10)
+            } else {
+                let row_size = self.width as usize * 3;
+                let frame_img: Vec<_> = (top..=bottom)
+                    .flat_map(|y| {
+                        let row = &img[y as usize * row_size..(y + 1) as usize * row_size];
+                        row[left as usize * 3..(right + 1) as usize * 3].iter()
+                    })
+                    .cloned()
+                    .collect();
+                GifFrame::from_rgb_speed(width as u16, height as u16, &frame_img, 10)
+            };
+
+            frame.left = left as u16;
+            frame.top = top as u16;
+            frame.delay = self.delay;
+            frame.dispose = DisposalMethod::Keep;
+
+            if let Some(last) = self.pending.replace(frame) {
+                self.encoder.write_frame(&last)?;
+            }
+
+            Ok(())
+        }
+    }
+}
+
+mod apng_support {
+    use super::*;
+    use std::fs::File;
+    use std::io::{BufWriter, Seek, SeekFrom, Write};
+
+    /// Find the chunks of the kind in a PNG file, and concatenate their data
+    fn png_chunk_data(png: &[u8], kind: &[u8; 4]) -> Vec<u8> {
+        let mut data = vec![];
+        // Skip the signature
+        let mut pos = 8;
+        while pos + 12 <= png.len() {
+            let len = u32::from_be_bytes([png[pos], png[pos + 1], png[pos + 2], png[pos + 3]]);
+            let begin = pos + 8;
+            let end = begin + len as usize;
+            if &png[pos + 4..begin] == kind {
+                data.extend_from_slice(&png[begin..end]);
+            }
+            pos = end + 4;
+        }
+        data
+    }
+
+    /// The delay in milliseconds, which is stored as a 16-bit numerator over 1000
+    fn apng_delay(delay: u32) -> u16 {
+        delay.min(u32::from(u16::MAX)) as u16
+    }
+
+    /// The animated PNG file, which keeps the full 24-bit color unlike GIF.
+    ///
+    /// The number of frames is written in the `acTL` chunk before any frame, thus the chunk
+    /// is written with no frame first and patched once the animation is finished.
+    pub(super) struct ApngFile {
+        writer: BufWriter<File>,
+        width: u32,
+        height: u32,
+        delay: u16,
+        frames: u32,
+        /// The sequence number of the next `fcTL` or `fdAT` chunk
+        sequence: u32,
+        /// The offset of the `acTL` chunk in the file
+        actl_pos: u64,
+    }
+
+    impl ApngFile {
+        pub(super) fn new<T: AsRef<Path>>(
+            path: T,
+            dim: (u32, u32),
+            delay: u32,
+        ) -> std::io::Result<Self> {
+            let mut header = b"\x89PNG\r\n\x1a\n".to_vec();
+            let mut ihdr = vec![];
+            ihdr.extend_from_slice(&dim.0.to_be_bytes());
+            ihdr.extend_from_slice(&dim.1.to_be_bytes());
+            // 8 bits RGB, deflate, adaptive filtering, no interlace
+            ihdr.extend_from_slice(&[8, 2, 0, 0, 0]);
+            write_png_chunk(&mut header, b"IHDR", &ihdr);
+            let actl_pos = header.len() as u64;
+            write_png_chunk(&mut header, b"acTL", &Self::actl(0));
+
+            let mut writer = BufWriter::new(File::create(path)?);
+            writer.write_all(&header)?;
+
+            Ok(Self {
+                writer,
+                width: dim.0,
+                height: dim.1,
+                delay: apng_delay(delay),
+                frames: 0,
+                sequence: 0,
+                actl_pos,
+            })
+        }
+
+        /// Set the delay of the frames written from now on, in milliseconds
+        pub(super) fn set_delay(&mut self, delay: u32) {
+            self.delay = apng_delay(delay);
+        }
+
+        /// The data of the `acTL` chunk, which loops forever
+        fn actl(frames: u32) -> [u8; 8] {
+            let mut data = [0; 8];
+            data[..4].copy_from_slice(&frames.to_be_bytes());
+            data
+        }
+
+        /// Write the frame to the file
+        pub(super) fn flush_frame(&mut self, img: &[u8]) -> std::io::Result<()> {
+            let mut png = vec![];
+            image::png::PNGEncoder::new(&mut png).encode(
+                img,
+                self.width,
+                self.height,
+                image::RGB(8),
+            )?;
+            let image_data = png_chunk_data(&png, b"IDAT");
+
+            let mut chunks = vec![];
+            let mut fctl = vec![];
+            fctl.extend_from_slice(&self.sequence.to_be_bytes());
+            fctl.extend_from_slice(&self.width.to_be_bytes());
+            fctl.extend_from_slice(&self.height.to_be_bytes());
+            // The offset of the frame
+            fctl.extend_from_slice(&[0; 8]);
+            // The delay in milliseconds
+            fctl.extend_from_slice(&self.delay.to_be_bytes());
+            fctl.extend_from_slice(&1000u16.to_be_bytes());
+            // Keep the frame when it's done, and replace the pixels rather than blending
+            fctl.extend_from_slice(&[0, 0]);
+            write_png_chunk(&mut chunks, b"fcTL", &fctl);
+            self.sequence += 1;
+
+            // The first frame is also the default image shown by the decoders without APNG
+            // support, which is stored in the IDAT chunk
+            if self.frames == 0 {
+                write_png_chunk(&mut chunks, b"IDAT", &image_data);
+            } else {
+                let mut fdat = self.sequence.to_be_bytes().to_vec();
+                fdat.extend(image_data);
+                write_png_chunk(&mut chunks, b"fdAT", &fdat);
+                self.sequence += 1;
+            }
+
+            self.writer.write_all(&chunks)?;
+            self.frames += 1;
+            Ok(())
+        }
+
+        /// Finish the file and write the number of frames
+        pub(super) fn finish(&mut self) -> std::io::Result<()> {
+            if self.frames == 0 {
+                let blank = vec![0; size_of_image((self.width, self.height))];
+                self.flush_frame(&blank)?;
+            }
+
+            let mut iend = vec![];
+            write_png_chunk(&mut iend, b"IEND", &[]);
+            self.writer.write_all(&iend)?;
+
+            let mut actl = vec![];
+            write_png_chunk(&mut actl, b"acTL", &Self::actl(self.frames));
+            self.writer.seek(SeekFrom::Start(self.actl_pos))?;
+            self.writer.write_all(&actl)?;
+            self.writer.flush()
+        }
+    }
+}
+
+#[cfg(feature = "video")]
+mod video_support {
+    use super::*;
+    use std::io::{Error, ErrorKind, Write};
+    use std::process::{Child, Command, Stdio};
+
+    /// The video file encoded by `ffmpeg`, the raw frames are piped into the process
+    pub(super) struct VideoFile {
+        process: Child,
+    }
+
+    impl VideoFile {
+        pub(super) fn new<T: AsRef<Path>>(
+            path: T,
+            dim: (u32, u32),
+            fps: u32,
+        ) -> std::io::Result<Self> {
+            let mut command = Command::new("ffmpeg");
+            command
+                .args(&["-y", "-loglevel", "error", "-f", "rawvideo"])
+                .args(&["-pixel_format", "rgb24"])
+                .arg("-video_size")
+                .arg(format!("{}x{}", dim.0, dim.1))
+                .arg("-framerate")
+                .arg(fps.max(1).to_string())
+                .args(&["-i", "-"])
+                // Most of the players only support YUV 4:2:0, which requires an even size
+                .args(&[
+                    "-vf",
+                    "pad=ceil(iw/2)*2:ceil(ih/2)*2",
+                    "-pix_fmt",
+                    "yuv420p",
+                ])
+                .arg(path.as_ref());
+            Self::spawn(command)
+        }
 
-            frame.delay = self.delay as u16;
+        /// Start the encoder process, which reads the frames from the standard input
+        pub(super) fn spawn(mut command: Command) -> std::io::Result<Self> {
+            let process = command.stdin(Stdio::piped()).spawn()?;
+            Ok(Self { process })
+        }
 
-            self.encoder.write_frame(&frame)?;
+        /// Write the frame to the encoder
+        pub(super) fn flush_frame(&mut self, img: &[u8]) -> std::io::Result<()> {
+            match &mut self.process.stdin {
+                Some(stdin) => stdin.write_all(img),
+                None => Err(Error::new(ErrorKind::BrokenPipe, "The video is finished")),
+            }
+        }
 
+        /// Close the input and wait for the encoder to finish the file
+        pub(super) fn finish(&mut self) -> std::io::Result<()> {
+            drop(self.process.stdin.take());
+            let status = self.process.wait()?;
+            if !status.success() {
+                return Err(Error::new(
+                    ErrorKind::Other,
+                    format!("The video encoder failed: {}", status),
+                ));
+            }
             Ok(())
         }
     }
 }
 
+/// Blend the color into the RGB pixel
+fn blend_into(pixel: &mut [u8], color: &RGBAColor) {
+    let alpha = color.alpha();
+    let rgb = color.rgb();
+    let new_color = [rgb.0, rgb.1, rgb.2];
+    if alpha >= 1.0 {
+        pixel.copy_from_slice(&new_color);
+    } else {
+        pixel.iter_mut().zip(&new_color).for_each(|(old, new)| {
+            *old = (f64::from(*old) * (1.0 - alpha) + f64::from(*new) * alpha).min(255.0) as u8;
+        });
+    }
+}
+
 enum Target<'a> {
     File(&'a Path),
-    Buffer(&'a mut Vec<u8>),
+    Buffer,
+    /// The buffer that receives the encoded image
+    EncodedBuffer(&'a mut Vec<u8>, ImageOutputFormat),
     #[cfg(feature = "gif")]
     Gif(Box<gif_support::GifFile>),
+    Apng(Box<apng_support::ApngFile>),
+    #[cfg(feature = "video")]
+    Video(Box<video_support::VideoFile>),
+    /// A scratch layer which is never written anywhere, used by the parallel rasterizer
+    #[cfg(feature = "rayon")]
+    Layer,
+}
+
+impl Target<'_> {
+    /// Check if the target is an animation, whose frames are drawn on a blank buffer
+    fn is_animation(&self) -> bool {
+        match self {
+            #[cfg(feature = "gif")]
+            Target::Gif(_) => true,
+            Target::Apng(_) => true,
+            #[cfg(feature = "video")]
+            Target::Video(_) => true,
+            _ => false,
+        }
+    }
 }
 
 /// The backend that drawing a bitmap
@@ -72,9 +524,21 @@ pub struct BitMapBackend<'a> {
     /// The path to the image
     target: Target<'a>,
     /// The image object
-    img: RgbImage,
+    img: BitMapImage<'a>,
     /// Flag indicates if the bitmap has been saved
     saved: bool,
+    /// The region that has been modified since the last time the frame was presented
+    dirty: Option<DirtyRegion>,
+    /// If the lines should be anti-aliased
+    anti_aliasing: bool,
+    /// The metadata written into the PNG file
+    metadata: Metadata,
+    /// The frame restored after each frame is presented, see `keep_background`, and the region
+    /// that has been modified since then
+    background: Option<(Vec<u8>, Option<DirtyRegion>)>,
+    /// If the frame has been presented, then the buffer is prepared for the next frame once
+    /// the backend is prepared again, thus the presented frame is kept in the buffer until then
+    presented: bool,
 }
 
 impl<'a> BitMapBackend<'a> {
@@ -82,8 +546,16 @@ impl<'a> BitMapBackend<'a> {
     pub fn new<T: AsRef<Path> + ?Sized>(path: &'a T, dimension: (u32, u32)) -> Self {
         Self {
             target: Target::File(path.as_ref()),
-            img: RgbImage::new(dimension.0, dimension.1),
+            img: create_image(
+                dimension,
+                PixelBuffer::Owned(vec![0; size_of_image(dimension)]),
+            ),
             saved: false,
+            dirty: None,
+            anti_aliasing: true,
+            metadata: Metadata::new(),
+            background: None,
+            presented: false,
         }
     }
 
@@ -101,113 +573,1545 @@ impl<'a> BitMapBackend<'a> {
         path: T,
         dimension: (u32, u32),
         frame_delay: u32,
+    ) -> Result<Self, ImageError> {
+        Self::gif_with_options(path, dimension, GifOptions::new(frame_delay))
+    }
+
+    /// Create a new bitmap backend that generate GIF animation with the options, such as the
+    /// loop count and the extra time to hold the last frame, see `BitMapBackend::gif`
+    ///
+    /// - `path`: The path to the GIF file to create
+    /// - `dimension`: The size of the GIF image
+    /// - `options`: The options of the animation
+    #[cfg(feature = "gif")]
+    pub fn gif_with_options<T: AsRef<Path>>(
+        path: T,
+        dimension: (u32, u32),
+        options: GifOptions,
     ) -> Result<Self, ImageError> {
         Ok(Self {
             target: Target::Gif(Box::new(gif_support::GifFile::new(
-                path,
-                dimension,
-                frame_delay,
+                path, dimension, &options,
             )?)),
-            img: RgbImage::new(dimension.0, dimension.1),
+            img: create_image(
+                dimension,
+                PixelBuffer::Owned(vec![0; size_of_image(dimension)]),
+            ),
             saved: false,
+            dirty: None,
+            anti_aliasing: true,
+            metadata: Metadata::new(),
+            background: None,
+            presented: false,
         })
     }
 
-    /// Create a new bitmap backend which only lives in-memory
-    pub fn with_buffer(buf: &'a mut Vec<u8>, dimension: (u32, u32)) -> Self {
+    /// Create a new bitmap backend that generate animated PNG, which keeps the full 24-bit
+    /// color of the frames unlike GIF.
+    ///
+    /// Just like the GIF animation, use `present` function to flush each frame into the file.
+    /// The decoders without APNG support show the first frame as a still image.
+    ///
+    /// - `path`: The path to the PNG file to create
+    /// - `dimension`: The size of the image
+    /// - `frame_delay`: The amount of time for each frame to display, in milliseconds
+    pub fn apng<T: AsRef<Path>>(
+        path: T,
+        dimension: (u32, u32),
+        frame_delay: u32,
+    ) -> Result<Self, ImageError> {
+        Ok(Self {
+            target: Target::Apng(Box::new(
+                apng_support::ApngFile::new(path, dimension, frame_delay)
+                    .map_err(ImageError::IoError)?,
+            )),
+            img: create_image(
+                dimension,
+                PixelBuffer::Owned(vec![0; size_of_image(dimension)]),
+            ),
+            saved: false,
+            dirty: None,
+            anti_aliasing: true,
+            metadata: Metadata::new(),
+            background: None,
+            presented: false,
+        })
+    }
+
+    /// Create a new bitmap backend that generate video, such as MP4 or WebM, which is much
+    /// smaller than GIF for long and high resolution animations.
+    ///
+    /// The frames are piped into `ffmpeg`, which must be found in `PATH`, and the format is
+    /// decided by the extension of the file. Just like the GIF animation, use `present`
+    /// function to flush each frame into the video.
+    ///
+    /// - `path`: The path to the video file to create
+    /// - `dimension`: The size of the video
+    /// - `fps`: The number of frames per second
+    #[cfg(feature = "video")]
+    pub fn video<T: AsRef<Path>>(
+        path: T,
+        dimension: (u32, u32),
+        fps: u32,
+    ) -> Result<Self, ImageError> {
+        let video = video_support::VideoFile::new(path, dimension, fps);
+        Ok(Self::with_video(
+            video.map_err(ImageError::IoError)?,
+            dimension,
+        ))
+    }
+
+    #[cfg(feature = "video")]
+    fn with_video(video: video_support::VideoFile, dimension: (u32, u32)) -> Self {
         Self {
-            target: Target::Buffer(buf),
-            img: RgbImage::new(dimension.0, dimension.1),
+            target: Target::Video(Box::new(video)),
+            img: create_image(
+                dimension,
+                PixelBuffer::Owned(vec![0; size_of_image(dimension)]),
+            ),
             saved: false,
+            dirty: None,
+            anti_aliasing: true,
+            metadata: Metadata::new(),
+            background: None,
+            presented: false,
         }
     }
-}
 
-impl<'a> DrawingBackend for BitMapBackend<'a> {
-    type ErrorType = ImageError;
+    /// Create a new bitmap backend which only lives in-memory
+    ///
+    /// The backend rasterizes directly into the buffer, which is laid out as RGB bytes row by
+    /// row, thus presenting a frame doesn't copy anything. The buffer is resized to the size of
+    /// the image and cleared, so the previous content is always discarded.
+    ///
+    /// - `buf`: The buffer to draw on
+    /// - `dimension`: The size of the image
+    pub fn with_buffer(buf: &'a mut Vec<u8>, dimension: (u32, u32)) -> Self {
+        buf.clear();
+        buf.resize(size_of_image(dimension), 0);
 
-    fn get_size(&self) -> (u32, u32) {
-        (self.img.width(), self.img.height())
+        Self {
+            target: Target::Buffer,
+            img: create_image(dimension, PixelBuffer::Borrowed(&mut buf[..])),
+            saved: false,
+            dirty: None,
+            anti_aliasing: true,
+            metadata: Metadata::new(),
+            background: None,
+            presented: false,
+        }
     }
 
-    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<ImageError>> {
-        self.saved = false;
-        Ok(())
+    /// Create a new bitmap backend which encodes the image into the buffer when the frame is
+    /// presented, e.g. a web service is able to respond with the PNG image without touching
+    /// the file system. The previous content of the buffer is replaced.
+    ///
+    /// - `buf`: The buffer to receive the encoded image
+    /// - `dimension`: The size of the image
+    /// - `format`: The image format, e.g. `ImageOutputFormat::PNG` or `ImageOutputFormat::JPEG(90)`
+    pub fn with_encoded_buffer<F: Into<ImageOutputFormat>>(
+        buf: &'a mut Vec<u8>,
+        dimension: (u32, u32),
+        format: F,
+    ) -> Self {
+        Self {
+            target: Target::EncodedBuffer(buf, format.into()),
+            img: create_image(
+                dimension,
+                PixelBuffer::Owned(vec![0; size_of_image(dimension)]),
+            ),
+            saved: false,
+            dirty: None,
+            anti_aliasing: true,
+            metadata: Metadata::new(),
+            background: None,
+            presented: false,
+        }
     }
 
-    fn present(&mut self) -> Result<(), DrawingErrorKind<ImageError>> {
+    /// Set the amount of time for the frames presented from now on to display, in milliseconds.
+    /// This has no effect unless the backend generates GIF or APNG animation.
+    pub fn set_frame_delay(&mut self, delay: u32) {
         match &mut self.target {
-            Target::File(path) => {
-                self.img
-                    .save(&path)
-                    .map_err(|x| DrawingErrorKind::DrawingError(ImageError::IoError(x)))?;
-                self.saved = true;
-                Ok(())
-            }
-            Target::Buffer(target) => {
-                let mut actual_img = RgbImage::new(1, 1);
-                std::mem::swap(&mut actual_img, &mut self.img);
-                target.clear();
-                target.append(&mut actual_img.into_raw());
-                Ok(())
-            }
             #[cfg(feature = "gif")]
-            Target::Gif(target) => {
-                target
-                    .flush_frame(&mut self.img)
-                    .map_err(DrawingErrorKind::DrawingError)?;
-                self.saved = true;
-                Ok(())
-            }
+            Target::Gif(target) => target.set_delay(delay),
+            Target::Apng(target) => target.set_delay(delay),
+            _ => {}
         }
     }
 
-    fn draw_pixel(
-        &mut self,
-        point: BackendCoord,
-        color: &RGBAColor,
-    ) -> Result<(), DrawingErrorKind<ImageError>> {
-        if point.0 as u32 >= self.img.width()
-            || point.0 < 0
-            || point.1 as u32 >= self.img.height()
-            || point.1 < 0
-        {
-            return Ok(());
+    /// Set if the shapes should be anti-aliased, which is enabled by default.
+    /// The lines, the circles and the edges of the polygons are blended by the coverage of the
+    /// pixels. When it's disabled, the 1px lines are drawn with the integer Bresenham algorithm
+    /// and the edges of the other shapes are not blended, which is faster but more jagged.
+    pub fn anti_aliasing(mut self, value: bool) -> Self {
+        self.anti_aliasing = value;
+        self
+    }
+
+    /// Set the metadata of the image, which is written as the `tEXt` chunks when the image is
+    /// saved or encoded as PNG. The other formats don't carry the metadata.
+    pub fn metadata(mut self, metadata: Metadata) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    /// Encode the image as PNG with the metadata
+    fn encode_png_with_metadata(&self) -> std::io::Result<Vec<u8>> {
+        let mut png = vec![];
+        image::png::PNGEncoder::new(&mut png).encode(
+            &self.img,
+            self.img.width(),
+            self.img.height(),
+            image::RGB(8),
+        )?;
+
+        // The text chunks must be placed before the IEND chunk, which is the last 12 bytes
+        let mut chunks = vec![];
+        for (key, value) in self.metadata.entries() {
+            let mut data = latin1(key);
+            data.push(0);
+            data.extend(latin1(value));
+            write_png_chunk(&mut chunks, b"tEXt", &data);
         }
+        let iend = png.len() - 12;
+        png.splice(iend..iend, chunks);
+
+        Ok(png)
+    }
 
+    /// Blend the color into the pixel, the point must be inside the image
+    fn blend_pixel(&mut self, point: BackendCoord, color: &RGBAColor) {
+        let idx = (point.1 as usize * self.img.width() as usize + point.0 as usize) * 3;
+        let buf: &mut [u8] = &mut self.img;
+        blend_into(&mut buf[idx..idx + 3], color);
+    }
+
+    /// Blend the color into a horizontal span of pixels from `x0` to `x1` (both inclusive) by
+    /// writing the row slice directly. The span is clipped to the image.
+    fn fill_span(&mut self, y: i32, x0: i32, x1: i32, color: &RGBAColor) {
+        let (w, h) = (self.img.width() as i32, self.img.height() as i32);
+        let (x0, x1) = (x0.min(x1).max(0), x0.max(x1).min(w - 1));
         let alpha = color.alpha();
+
+        if y < 0 || y >= h || x0 > x1 || alpha <= 0.0 {
+            return;
+        }
+
+        self.mark_dirty(Some(((x0, y), (x1, y))));
+
         let rgb = color.rgb();
+        let new_color = [rgb.0, rgb.1, rgb.2];
+        let row_begin = (y * w) as usize * 3;
+        let buf: &mut [u8] = &mut self.img;
+        let span = &mut buf[row_begin + x0 as usize * 3..row_begin + (x1 as usize + 1) * 3];
 
         if alpha >= 1.0 {
-            self.img.put_pixel(
-                point.0 as u32,
-                point.1 as u32,
-                Rgb {
-                    data: [rgb.0, rgb.1, rgb.2],
-                },
-            );
+            span.chunks_mut(3)
+                .for_each(|pixel| pixel.copy_from_slice(&new_color));
         } else {
-            let pixel = self.img.get_pixel_mut(point.0 as u32, point.1 as u32);
-
-            let new_color = [rgb.0, rgb.1, rgb.2];
+            span.chunks_mut(3)
+                .for_each(|pixel| blend_into(pixel, color));
+        }
+    }
 
-            pixel
-                .data
-                .iter_mut()
-                .zip(&new_color)
-                .for_each(|(old, new)| {
-                    *old = (f64::from(*old) * (1.0 - alpha) + f64::from(*new) * alpha).min(255.0)
-                        as u8;
-                });
+    /// Fill the polygon with the anti-aliased edges, the vertices are in the continuous space
+    fn fill_coverage_polygon(
+        &mut self,
+        vert: &[(f64, f64)],
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<ImageError>> {
+        if color.alpha() <= 0.0 {
+            return Ok(());
         }
-        Ok(())
+        let size = (self.img.width() as i32, self.img.height() as i32);
+        rasterizer::polygon_coverage(vert, size, |y, x0, x1, c| {
+            self.fill_span(y, x0, x1, &color.mix(c));
+            Ok(())
+        })
     }
-}
 
-impl Drop for BitMapBackend<'_> {
-    fn drop(&mut self) {
-        if !self.saved {
-            self.present().expect("Unable to save the bitmap");
+    /// Get the region that has been modified since the last time the frame was presented.
+    ///
+    /// The region is described by the upper-left and bottom-right corners (both inclusive) of
+    /// the bounding box of all the pixels drawn. For a realtime application, this can be used
+    /// to only re-blit the changed part of the frame to the screen.
+    /// Returns `None` if nothing has been drawn.
+    pub fn dirty_region(&self) -> Option<(BackendCoord, BackendCoord)> {
+        self.dirty
+    }
+
+    /// Keep the current frame as the background of the following frames, which makes the live
+    /// charts cheaper: the mesh and the labels are drawn once and kept as the background, and
+    /// the chart context is kept alive to draw only the latest data for each frame.
+    ///
+    /// Once a frame is presented, it's kept in the buffer until the backend is prepared for the
+    /// next frame. Then the pixels drawn in that frame are restored from the background rather
+    /// than cleared, and marked dirty. Thus a GIF animation only encodes the
+    /// region the series cover, and a realtime application only re-blits that region.
+    ///
+    /// ```rust,no_run
+    /// use plotters::prelude::*;
+    /// use std::cell::RefCell;
+    /// use std::rc::Rc;
+    ///
+    /// let backend = BitMapBackend::gif("live.gif", (640, 480), 100).unwrap();
+    /// let backend = Rc::new(RefCell::new(backend));
+    /// let root = DrawingArea::from(&backend);
+    /// root.fill(&WHITE).unwrap();
+    /// let mut chart = ChartBuilder::on(&root)
+    ///     .x_label_area_size(30)
+    ///     .y_label_area_size(30)
+    ///     .build_ranged(0.0..100.0, -1.0..1.0)
+    ///     .unwrap();
+    /// chart.configure_mesh().draw().unwrap();
+    /// backend.borrow_mut().keep_background();
+    ///
+    /// let mut data = vec![];
+    /// for x in 0..100 {
+    ///     data.push((x as f64, (x as f64 / 10.0).sin()));
+    ///     chart
+    ///         .draw_series(LineSeries::new(data.iter().cloned(), &RED))
+    ///         .unwrap();
+    ///     root.present().unwrap();
+    /// }
+    /// ```
+    pub fn keep_background(&mut self) {
+        self.background = Some((self.img.to_vec(), None));
+    }
+
+    /// Stop restoring the background after each frame, see `keep_background`
+    pub fn clear_background(&mut self) {
+        self.background = None;
+    }
+
+    /// Copy the current frame into an image, which can be post-processed with the `image` crate.
+    ///
+    /// Since the drawing area owns the backend, share the backend with
+    /// `DrawingArea::from(&Rc<RefCell<BitMapBackend>>)` to access it after drawing.
+    pub fn to_image(&self) -> RgbImage {
+        create_image((self.img.width(), self.img.height()), self.img.to_vec())
+    }
+
+    /// Copy the current frame into a dynamic image, see `BitMapBackend::to_image`
+    pub fn to_dynamic_image(&self) -> DynamicImage {
+        DynamicImage::ImageRgb8(self.to_image())
+    }
+
+    /// Take the current frame as an image, the pixels are moved out of the backend when they
+    /// are owned by it, otherwise they are copied.
+    /// Note the frame is taken instead of being presented, thus nothing is written to the
+    /// file or the GIF animation.
+    pub fn into_image(mut self) -> RgbImage {
+        let dimension = (self.img.width(), self.img.height());
+        self.saved = true;
+        let img = std::mem::replace(
+            &mut self.img,
+            create_image((0, 0), PixelBuffer::Owned(vec![])),
+        );
+        match img.into_raw() {
+            PixelBuffer::Owned(buf) => create_image(dimension, buf),
+            PixelBuffer::Borrowed(buf) => create_image(dimension, buf.to_vec()),
         }
     }
+
+    /// Rasterize a group of independent layers concurrently and composite them onto this bitmap.
+    ///
+    /// Each layer is drawn by a worker thread on its own copy of the current frame, thus the
+    /// drawing function should not rely on anything drawn by other layers. Once all the layers
+    /// are done, the pixels changed by each layer are copied back in the layer order, which
+    /// means a later layer covers the earlier ones. Note that a translucent pixel is blended
+    /// against the frame before this call, rather than the layers below it.
+    ///
+    /// - `layers`: The number of layers to draw
+    /// - `draw`: The function that draws the layer with the given index on a drawing area
+    #[cfg(feature = "rayon")]
+    pub fn draw_layers_parallel<F>(
+        &mut self,
+        layers: usize,
+        draw: F,
+    ) -> Result<(), DrawingAreaErrorKind<ImageError>>
+    where
+        F: Fn(
+                usize,
+                DrawingArea<BitMapBackend, Shift>,
+            ) -> Result<(), DrawingAreaErrorKind<ImageError>>
+            + Sync
+            + Send,
+    {
+        self.begin_frame();
+        let dimension = (self.img.width(), self.img.height());
+        let base = self.img.to_vec();
+        let base_anti_aliasing = self.anti_aliasing;
+
+        let rendered: Vec<_> = (0..layers)
+            .into_par_iter()
+            .map(|idx| {
+                let backend = Rc::new(RefCell::new(BitMapBackend {
+                    target: Target::Layer,
+                    img: create_image(dimension, PixelBuffer::Owned(base.clone())),
+                    saved: false,
+                    dirty: None,
+                    anti_aliasing: base_anti_aliasing,
+                    metadata: Metadata::new(),
+                    background: None,
+                    presented: false,
+                }));
+                draw(idx, DrawingArea::from(&backend))?;
+                let mut backend = Rc::try_unwrap(backend)
+                    .map_err(|_| DrawingAreaErrorKind::SharingError)?
+                    .into_inner();
+                let dirty = backend.dirty;
+                let img = std::mem::replace(
+                    &mut backend.img,
+                    create_image((0, 0), PixelBuffer::Owned(vec![])),
+                );
+                match img.into_raw() {
+                    PixelBuffer::Owned(buf) => Ok((buf, dirty)),
+                    PixelBuffer::Borrowed(buf) => Ok((buf.to_vec(), dirty)),
+                }
+            })
+            .collect();
+
+        for layer in rendered {
+            let (layer, dirty) = layer?;
+            self.mark_dirty(dirty);
+            self.img
+                .chunks_mut(3)
+                .zip(layer.chunks(3).zip(base.chunks(3)))
+                .for_each(|(current, (new, orig))| {
+                    if new != orig {
+                        current.copy_from_slice(new);
+                    }
+                });
+        }
+
+        self.saved = false;
+        Ok(())
+    }
+
+    /// Rasterize the bitmap in horizontal bands concurrently, which speeds up the drawing of
+    /// a large image.
+    ///
+    /// The drawing function is called once for each band with a drawing area of the size of
+    /// the whole bitmap, so it should draw the whole image and produce the same result on each
+    /// call. Only the rows in the band are rasterized and kept, and the bands are copied back
+    /// once all of them are done. Unlike `draw_layers_parallel`, the bands never overlap, so
+    /// the result is the same as drawing the image on this backend directly.
+    ///
+    /// - `bands`: The number of bands to split the bitmap into
+    /// - `draw`: The function that draws the image on a drawing area
+    #[cfg(feature = "rayon")]
+    pub fn draw_bands_parallel<F>(
+        &mut self,
+        bands: usize,
+        draw: F,
+    ) -> Result<(), DrawingAreaErrorKind<ImageError>>
+    where
+        F: Fn(DrawingArea<BitMapBand, Shift>) -> Result<(), DrawingAreaErrorKind<ImageError>>
+            + Sync
+            + Send,
+    {
+        self.begin_frame();
+        let (width, height) = (self.img.width(), self.img.height());
+        let bands = bands.max(1).min(height.max(1) as usize) as u32;
+        let row_size = width as usize * 3;
+        let base_anti_aliasing = self.anti_aliasing;
+        let base: &[u8] = &self.img;
+
+        let rendered: Vec<_> = (0..bands)
+            .into_par_iter()
+            .map(|idx| {
+                let (top, bottom) = (height * idx / bands, height * (idx + 1) / bands);
+                let rows = base[top as usize * row_size..bottom as usize * row_size].to_vec();
+                let band = Rc::new(RefCell::new(BitMapBand {
+                    inner: BitMapBackend {
+                        target: Target::Layer,
+                        img: create_image((width, bottom - top), PixelBuffer::Owned(rows)),
+                        saved: false,
+                        dirty: None,
+                        anti_aliasing: base_anti_aliasing,
+                        metadata: Metadata::new(),
+                        background: None,
+                        presented: false,
+                    },
+                    top: top as i32,
+                    size: (width, height),
+                }));
+                draw(DrawingArea::from(&band))?;
+                let mut band = Rc::try_unwrap(band)
+                    .map_err(|_| DrawingAreaErrorKind::SharingError)?
+                    .into_inner();
+                let dirty = band
+                    .inner
+                    .dirty
+                    .map(|((x0, y0), (x1, y1))| ((x0, y0 + band.top), (x1, y1 + band.top)));
+                let img = std::mem::replace(
+                    &mut band.inner.img,
+                    create_image((0, 0), PixelBuffer::Owned(vec![])),
+                );
+                match img.into_raw() {
+                    PixelBuffer::Owned(buf) => Ok((top, buf, dirty)),
+                    PixelBuffer::Borrowed(buf) => Ok((top, buf.to_vec(), dirty)),
+                }
+            })
+            .collect();
+
+        for band in rendered {
+            let (top, rows, dirty) = band?;
+            self.mark_dirty(dirty);
+            let begin = top as usize * row_size;
+            let buf: &mut [u8] = &mut self.img;
+            buf[begin..begin + rows.len()].copy_from_slice(&rows);
+        }
+
+        self.saved = false;
+        Ok(())
+    }
+}
+
+/// A horizontal band of a bitmap, which is drawn by `BitMapBackend::draw_bands_parallel`.
+/// The band reports the size of the whole bitmap, but only the rows in the band are kept.
+#[cfg(feature = "rayon")]
+pub struct BitMapBand {
+    inner: BitMapBackend<'static>,
+    top: i32,
+    size: (u32, u32),
+}
+
+#[cfg(feature = "rayon")]
+impl BitMapBand {
+    /// Move the point from the whole bitmap into the band
+    fn shift(&self, (x, y): BackendCoord) -> BackendCoord {
+        (x, y - self.top)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl DrawingBackend for BitMapBand {
+    type ErrorType = ImageError;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.size
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<ImageError>> {
+        self.inner.ensure_prepared()
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<ImageError>> {
+        self.inner.present()
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<ImageError>> {
+        let point = self.shift(point);
+        self.inner.draw_pixel(point, color)
+    }
+
+    fn draw_pixels(
+        &mut self,
+        pixels: &[(BackendCoord, RGBAColor)],
+    ) -> Result<(), DrawingErrorKind<ImageError>> {
+        let pixels: Vec<_> = pixels
+            .iter()
+            .map(|(point, color)| (self.shift(*point), color.clone()))
+            .collect();
+        self.inner.draw_pixels(&pixels)
+    }
+
+    fn blit_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        size: (u32, u32),
+        src: &[u8],
+    ) -> Result<(), DrawingErrorKind<ImageError>> {
+        let pos = self.shift(pos);
+        self.inner.blit_bitmap(pos, size, src)
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<ImageError>> {
+        let (from, to) = (self.shift(from), self.shift(to));
+        self.inner.draw_line(from, to, style)
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<ImageError>> {
+        let (upper_left, bottom_right) = (self.shift(upper_left), self.shift(bottom_right));
+        self.inner.draw_rect(upper_left, bottom_right, style, fill)
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<ImageError>> {
+        let top = self.top;
+        self.inner
+            .draw_path(path.into_iter().map(|(x, y)| (x, y - top)), style)
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<ImageError>> {
+        let center = self.shift(center);
+        self.inner.draw_circle(center, radius, style, fill)
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<ImageError>> {
+        let top = self.top;
+        self.inner
+            .fill_polygon(vert.into_iter().map(|(x, y)| (x, y - top)), style)
+    }
+
+    fn draw_text<'b>(
+        &mut self,
+        text: &str,
+        font: &FontDesc<'b>,
+        pos: BackendCoord,
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<ImageError>> {
+        let pos = self.shift(pos);
+        self.inner.draw_text(text, font, pos, color)
+    }
+}
+
+impl<'a> BitMapBackend<'a> {
+    /// Write the frame to the target
+    fn write_frame(&mut self) -> Result<(), DrawingErrorKind<ImageError>> {
+        match &mut self.target {
+            Target::File(path) => {
+                let path = *path;
+                let is_png = matches!(
+                    path.extension().and_then(|ext| ext.to_str()),
+                    Some(ext) if ext.eq_ignore_ascii_case("png")
+                );
+                if is_png && !self.metadata.is_empty() {
+                    self.encode_png_with_metadata()
+                        .and_then(|png| std::fs::write(path, png))
+                } else {
+                    self.img.save(path)
+                }
+                .map_err(|x| DrawingErrorKind::DrawingError(ImageError::IoError(x)))?;
+                self.saved = true;
+                self.dirty = None;
+                Ok(())
+            }
+            Target::EncodedBuffer(_, ImageOutputFormat::PNG) => {
+                let png = self
+                    .encode_png_with_metadata()
+                    .map_err(|x| DrawingErrorKind::DrawingError(ImageError::IoError(x)))?;
+                if let Target::EncodedBuffer(buf, _) = &mut self.target {
+                    **buf = png;
+                }
+                self.saved = true;
+                self.dirty = None;
+                Ok(())
+            }
+            Target::EncodedBuffer(buf, format) => {
+                buf.clear();
+                DynamicImage::ImageRgb8(create_image(
+                    (self.img.width(), self.img.height()),
+                    self.img.to_vec(),
+                ))
+                .write_to(*buf, format.clone())
+                .map_err(DrawingErrorKind::DrawingError)?;
+                self.saved = true;
+                self.dirty = None;
+                Ok(())
+            }
+            Target::Buffer => {
+                // The image is drawn in the buffer directly, so there's nothing to copy
+                self.saved = true;
+                self.dirty = None;
+                Ok(())
+            }
+            #[cfg(feature = "gif")]
+            Target::Gif(target) => {
+                target
+                    .flush_frame(&self.img, self.dirty.take())
+                    .map_err(DrawingErrorKind::DrawingError)?;
+                self.saved = true;
+                Ok(())
+            }
+            Target::Apng(target) => {
+                target
+                    .flush_frame(&self.img)
+                    .map_err(|x| DrawingErrorKind::DrawingError(ImageError::IoError(x)))?;
+                self.saved = true;
+                self.dirty = None;
+                Ok(())
+            }
+            #[cfg(feature = "video")]
+            Target::Video(target) => {
+                target
+                    .flush_frame(&self.img)
+                    .map_err(|x| DrawingErrorKind::DrawingError(ImageError::IoError(x)))?;
+                self.saved = true;
+                self.dirty = None;
+                Ok(())
+            }
+            #[cfg(feature = "rayon")]
+            Target::Layer => {
+                self.saved = true;
+                Ok(())
+            }
+        }
+    }
+
+    /// Mark the region as modified
+    fn mark_dirty(&mut self, region: Option<DirtyRegion>) {
+        self.dirty = merge_dirty_region(self.dirty, region);
+        if let Some((_, changed)) = &mut self.background {
+            *changed = merge_dirty_region(*changed, region);
+        }
+    }
+
+    /// Prepare the buffer for the next frame, if the last frame has been presented
+    fn begin_frame(&mut self) {
+        if self.presented {
+            self.presented = false;
+            self.reset_frame();
+        }
+    }
+
+    /// Prepare the buffer for the next frame once the frame is presented
+    fn reset_frame(&mut self) {
+        let width = self.img.width() as usize;
+        let buf: &mut [u8] = &mut self.img;
+        match &mut self.background {
+            Some((background, changed)) => {
+                // Only the pixels drawn since the background was kept differ from it
+                if let Some(((x0, y0), (x1, y1))) = *changed {
+                    for y in y0 as usize..=y1 as usize {
+                        let begin = (y * width + x0 as usize) * 3;
+                        let end = (y * width + x1 as usize + 1) * 3;
+                        buf[begin..end].copy_from_slice(&background[begin..end]);
+                    }
+                    self.dirty = merge_dirty_region(self.dirty, changed.take());
+                }
+            }
+            None if self.target.is_animation() => buf.iter_mut().for_each(|v| *v = 0),
+            None => {}
+        }
+    }
+}
+
+impl<'a> DrawingBackend for BitMapBackend<'a> {
+    type ErrorType = ImageError;
+
+    fn get_size(&self) -> (u32, u32) {
+        (self.img.width(), self.img.height())
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<ImageError>> {
+        self.saved = false;
+        self.begin_frame();
+        Ok(())
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<ImageError>> {
+        self.write_frame()?;
+        self.presented = true;
+        Ok(())
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<ImageError>> {
+        if point.0 as u32 >= self.img.width()
+            || point.0 < 0
+            || point.1 as u32 >= self.img.height()
+            || point.1 < 0
+        {
+            return Ok(());
+        }
+
+        if color.alpha() <= 0.0 {
+            return Ok(());
+        }
+
+        self.mark_dirty(Some((point, point)));
+        self.blend_pixel(point, color);
+        Ok(())
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if !style.stroke_dash().0.is_empty() {
+            return rasterizer::draw_dashed_path(self, &[from, to], style);
+        }
+
+        if style.stroke_width() == 1 {
+            // The scanlines of the filled shapes, which can be written as a whole row
+            if from.1 == to.1 {
+                self.fill_span(from.1, from.0, to.0, &style.as_color());
+                return Ok(());
+            }
+            if from.0 == to.0 {
+                for y in from.1.min(to.1)..=from.1.max(to.1) {
+                    self.fill_span(y, from.0, from.0, &style.as_color());
+                }
+                return Ok(());
+            }
+        }
+
+        if self.anti_aliasing && style.stroke_width() != 1 {
+            // The wide line is the rectangle around the line, which is filled with the exact
+            // corners rather than the ones rounded to the pixels
+            let v = (f64::from(to.0 - from.0), f64::from(to.1 - from.1));
+            let l = (v.0 * v.0 + v.1 * v.1).sqrt();
+            if l < 1e-5 {
+                return Ok(());
+            }
+            let r = f64::from(style.stroke_width()) / 2.0;
+            let (nx, ny) = (v.1 / l * r, -v.0 / l * r);
+            let (fx, fy) = (f64::from(from.0), f64::from(from.1));
+            let (tx, ty) = (f64::from(to.0), f64::from(to.1));
+            return self.fill_coverage_polygon(
+                &[
+                    (fx + nx, fy + ny),
+                    (tx + nx, ty + ny),
+                    (tx - nx, ty - ny),
+                    (fx - nx, fy - ny),
+                ],
+                &style.as_color(),
+            );
+        }
+
+        if self.anti_aliasing || style.stroke_width() != 1 {
+            return rasterizer::draw_line(self, from, to, style);
+        }
+
+        let color = style.as_color();
+        rasterizer::bresenham(from, to, |p| self.draw_pixel(p, &color))
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if !fill {
+            if !style.stroke_dash().0.is_empty() {
+                let (x0, y0) = upper_left;
+                let (x1, y1) = bottom_right;
+                return rasterizer::draw_dashed_path(
+                    self,
+                    &[(x0, y0), (x1, y0), (x1, y1), (x0, y1), (x0, y0)],
+                    style,
+                );
+            }
+            return rasterizer::draw_rect(self, upper_left, bottom_right, style, false);
+        }
+
+        let color = style.as_color();
+        let (w, h) = (self.img.width() as i32, self.img.height() as i32);
+        let (x0, x1) = (
+            upper_left.0.min(bottom_right.0).max(0),
+            upper_left.0.max(bottom_right.0).min(w - 1),
+        );
+        let (y0, y1) = (
+            upper_left.1.min(bottom_right.1).max(0),
+            upper_left.1.max(bottom_right.1).min(h - 1),
+        );
+        if x0 > x1 || y0 > y1 || color.alpha() <= 0.0 {
+            return Ok(());
+        }
+
+        if color.alpha() < 1.0 {
+            for y in y0..=y1 {
+                self.fill_span(y, x0, x1, &color);
+            }
+            return Ok(());
+        }
+
+        // The opaque rectangle is filled by writing the first row and copying it to the others
+        self.fill_span(y0, x0, x1, &color);
+        self.mark_dirty(Some(((x0, y0), (x1, y1))));
+        let row_size = w as usize * 3;
+        let (begin, end) = (x0 as usize * 3, (x1 as usize + 1) * 3);
+        let first = y0 as usize * row_size;
+        let buf: &mut [u8] = &mut self.img;
+        for y in y0 + 1..=y1 {
+            buf.copy_within(first + begin..first + end, y as usize * row_size + begin);
+        }
+        Ok(())
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let color = style.as_color();
+        if color.alpha() <= 0.0 {
+            return Ok(());
+        }
+        let size = (self.img.width() as i32, self.img.height() as i32);
+        let stroke_width = if fill {
+            None
+        } else {
+            Some(f64::from(style.stroke_width()))
+        };
+        rasterizer::circle_coverage(
+            center,
+            f64::from(radius),
+            stroke_width,
+            size,
+            |y, x0, x1, c| {
+                // Without anti-aliasing, the pixels covered at least by half are drawn
+                if self.anti_aliasing {
+                    self.fill_span(y, x0, x1, &color.mix(c));
+                } else if c >= 0.5 {
+                    self.fill_span(y, x0, x1, &color);
+                }
+                Ok(())
+            },
+        )
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let vert: Vec<_> = vert.into_iter().collect();
+        if !self.anti_aliasing {
+            return rasterizer::fill_polygon(self, &vert[..], style);
+        }
+
+        let vert: Vec<_> = vert
+            .into_iter()
+            .map(|(x, y)| (f64::from(x), f64::from(y)))
+            .collect();
+        self.fill_coverage_polygon(&vert[..], &style.as_color())
+    }
+
+    fn draw_pixels(
+        &mut self,
+        pixels: &[(BackendCoord, RGBAColor)],
+    ) -> Result<(), DrawingErrorKind<ImageError>> {
+        let (w, h) = (self.img.width() as i32, self.img.height() as i32);
+
+        // Sort the pixels by rows, so each row is written at once and the memory is accessed
+        // sequentially. The sort is stable, so the overlapping pixels are blended in order.
+        let mut sorted: Vec<_> = pixels
+            .iter()
+            .filter(|((x, y), color)| *x >= 0 && *x < w && *y >= 0 && *y < h && color.alpha() > 0.0)
+            .collect();
+        if sorted.is_empty() {
+            return Ok(());
+        }
+        sorted.sort_by_key(|((x, y), _)| (*y, *x));
+
+        let (min_x, max_x) = sorted
+            .iter()
+            .fold((w, 0), |(a, b), ((x, _), _)| (a.min(*x), b.max(*x)));
+        let (top, bottom) = ((sorted[0].0).1, (sorted[sorted.len() - 1].0).1);
+        self.mark_dirty(Some(((min_x, top), (max_x, bottom))));
+
+        let row_size = w as usize * 3;
+        let buf: &mut [u8] = &mut self.img;
+        let mut begin = 0;
+        while begin < sorted.len() {
+            // The run of the pixels on the same row, which are already inside the image
+            let y = (sorted[begin].0).1 as usize;
+            let end = begin
+                + sorted[begin..]
+                    .iter()
+                    .take_while(|((_, py), _)| *py as usize == y)
+                    .count();
+            let row = &mut buf[y * row_size..(y + 1) * row_size];
+            for ((x, _), color) in &sorted[begin..end] {
+                let x = *x as usize * 3;
+                blend_into(&mut row[x..x + 3], color);
+            }
+            begin = end;
+        }
+        Ok(())
+    }
+
+    fn blit_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        (iw, ih): (u32, u32),
+        src: &[u8],
+    ) -> Result<(), DrawingErrorKind<ImageError>> {
+        let (w, h) = (self.img.width() as i32, self.img.height() as i32);
+        let (x0, y0) = (pos.0.max(0), pos.1.max(0));
+        let (x1, y1) = ((pos.0 + iw as i32).min(w), (pos.1 + ih as i32).min(h));
+        if x0 >= x1 || y0 >= y1 {
+            return Ok(());
+        }
+        match bitmap_len((iw, ih)) {
+            Some(len) if src.len() >= len => {}
+            _ => return Ok(()),
+        }
+
+        // Copy the part of each row inside the image at once
+        let len = (x1 - x0) as usize * 3;
+        let buf: &mut [u8] = &mut self.img;
+        for y in y0..y1 {
+            let src_begin = ((y - pos.1) as usize * iw as usize + (x0 - pos.0) as usize) * 3;
+            let dst_begin = (y as usize * w as usize + x0 as usize) * 3;
+            buf[dst_begin..dst_begin + len].copy_from_slice(&src[src_begin..src_begin + len]);
+        }
+
+        self.mark_dirty(Some(((x0, y0), (x1 - 1, y1 - 1))));
+        Ok(())
+    }
+}
+
+impl Drop for BitMapBackend<'_> {
+    fn drop(&mut self) {
+        if !self.saved {
+            self.present().expect("Unable to save the bitmap");
+        }
+        #[cfg(feature = "gif")]
+        {
+            if let Target::Gif(target) = &mut self.target {
+                target.finish().expect("Unable to save the bitmap");
+            }
+        }
+        match &mut self.target {
+            Target::Apng(target) => target.finish().expect("Unable to save the bitmap"),
+            #[cfg(feature = "video")]
+            Target::Video(target) => target.finish().expect("Unable to save the bitmap"),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(all(test, feature = "rayon"))]
+#[test]
+fn test_draw_bands_parallel() {
+    use crate::prelude::*;
+
+    fn draw<DB: DrawingBackend>(
+        root: DrawingArea<DB, Shift>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        root.fill(&WHITE)?;
+        root.draw(&Circle::new((20, 20), 15, RED.filled()))?;
+        root.draw(&Path::new(vec![(0, 0), (39, 39)], &BLUE))?;
+        root.draw(&Text::new("plot", (5, 10), ("Arial", 12).into_font()))
+    }
+
+    let mut expected = vec![];
+    draw(BitMapBackend::with_buffer(&mut expected, (40, 40)).into_drawing_area())
+        .expect("Drawing Failure");
+
+    let mut buffer = vec![];
+    {
+        let mut backend = BitMapBackend::with_buffer(&mut buffer, (40, 40));
+        backend
+            .draw_bands_parallel(3, draw)
+            .expect("Drawing Failure");
+        assert_eq!(backend.dirty_region(), Some(((0, 0), (39, 39))));
+    }
+
+    // The bands are seamless
+    assert!(buffer == expected);
+}
+
+#[cfg(all(test, feature = "rayon"))]
+#[test]
+fn test_draw_layers_parallel() {
+    use crate::prelude::*;
+    let mut buffer = vec![];
+    {
+        let mut backend = BitMapBackend::with_buffer(&mut buffer, (10, 10));
+        backend
+            .draw_layers_parallel(2, |idx, area| {
+                if idx == 0 {
+                    area.draw(&Rectangle::new([(0, 0), (5, 5)], RED.filled()))
+                } else {
+                    area.draw(&Rectangle::new([(3, 3), (8, 8)], BLUE.filled()))
+                }
+            })
+            .expect("Drawing Failure");
+    }
+
+    let pixel = |x: usize, y: usize| &buffer[(y * 10 + x) * 3..(y * 10 + x + 1) * 3];
+
+    assert_eq!(pixel(1, 1), &[255, 0, 0]);
+    assert_eq!(pixel(4, 4), &[0, 0, 255]);
+    assert_eq!(pixel(7, 7), &[0, 0, 255]);
+    assert_eq!(pixel(9, 9), &[0, 0, 0]);
+}
+
+#[test]
+fn test_dirty_region() {
+    use crate::prelude::*;
+    let mut buffer = vec![];
+    let mut backend = BitMapBackend::with_buffer(&mut buffer, (20, 20));
+    assert_eq!(backend.dirty_region(), None);
+
+    backend.draw_pixel((3, 15), &RED.to_rgba()).unwrap();
+    backend.draw_pixel((100, 100), &RED.to_rgba()).unwrap();
+    backend.draw_pixel((10, 5), &RED.mix(0.0)).unwrap();
+    assert_eq!(backend.dirty_region(), Some(((3, 15), (3, 15))));
+
+    backend
+        .draw_rect((5, 2), (8, 7), &BLUE.to_rgba(), true)
+        .unwrap();
+    assert_eq!(backend.dirty_region(), Some(((3, 2), (8, 15))));
+
+    backend.present().unwrap();
+    assert_eq!(backend.dirty_region(), None);
+}
+
+#[test]
+fn test_keep_background() {
+    use crate::prelude::*;
+    let mut buffer = vec![];
+    let pixel = |buffer: &[u8], x: usize, y: usize| buffer[(y * 20 + x) * 3];
+    {
+        let mut backend = BitMapBackend::with_buffer(&mut buffer, (20, 20));
+        backend
+            .draw_rect((0, 0), (19, 19), &WHITE.to_rgba(), true)
+            .unwrap();
+        backend.keep_background();
+        backend.present().unwrap();
+
+        backend.ensure_prepared().unwrap();
+        backend.draw_pixel((3, 4), &BLUE.to_rgba()).unwrap();
+        backend.present().unwrap();
+        // The presented frame is kept in the buffer until the next frame is prepared
+        assert_eq!(pixel(&backend.img, 3, 4), 0);
+        assert_eq!(backend.dirty_region(), None);
+
+        // Then the pixel drawn in the last frame is restored and marked dirty
+        backend.ensure_prepared().unwrap();
+        assert_eq!(pixel(&backend.img, 3, 4), 255);
+        assert_eq!(backend.dirty_region(), Some(((3, 4), (3, 4))));
+
+        backend.draw_pixel((5, 6), &BLUE.to_rgba()).unwrap();
+        assert_eq!(backend.dirty_region(), Some(((3, 4), (5, 6))));
+        backend.present().unwrap();
+        backend.ensure_prepared().unwrap();
+        assert_eq!(backend.dirty_region(), Some(((5, 6), (5, 6))));
+        backend.draw_pixel((7, 8), &BLUE.to_rgba()).unwrap();
+        backend.present().unwrap();
+    }
+    // The last frame is kept in the buffer
+    assert_eq!(pixel(&buffer, 7, 8), 0);
+    assert_eq!(pixel(&buffer, 5, 6), 255);
+}
+
+#[test]
+fn test_draw_pixels() {
+    use crate::prelude::*;
+    let mut buffer = vec![];
+    {
+        let mut backend = BitMapBackend::with_buffer(&mut buffer, (10, 10));
+        backend
+            .draw_pixels(&[
+                ((1, 2), RED.to_rgba()),
+                ((-1, 2), RED.to_rgba()),
+                ((5, 10), RED.to_rgba()),
+                ((8, 7), BLUE.to_rgba()),
+                ((3, 3), GREEN.mix(0.0)),
+                // The overlapping pixels are blended in the order they're given, even though
+                // the pixels are written row by row
+                ((0, 7), WHITE.to_rgba()),
+                ((0, 5), WHITE.to_rgba()),
+                ((0, 5), BLUE.mix(0.5)),
+            ])
+            .unwrap();
+        assert_eq!(backend.dirty_region(), Some(((0, 2), (8, 7))));
+    }
+
+    let pixel = |x: usize, y: usize| &buffer[(y * 10 + x) * 3..(y * 10 + x + 1) * 3];
+
+    assert_eq!(pixel(1, 2), &[255, 0, 0]);
+    assert_eq!(pixel(8, 7), &[0, 0, 255]);
+    assert_eq!(pixel(3, 3), &[0, 0, 0]);
+    assert_eq!(pixel(0, 7), &[255, 255, 255]);
+    assert_eq!(pixel(0, 5), &[127, 127, 255]);
+}
+
+#[test]
+fn test_bresenham_line() {
+    use crate::prelude::*;
+    let mut buffer = vec![];
+    {
+        let mut backend = BitMapBackend::with_buffer(&mut buffer, (10, 10)).anti_aliasing(false);
+        backend.draw_line((0, 0), (9, 3), &WHITE).unwrap();
+    }
+
+    let lit: Vec<_> = (0..100)
+        .filter(|idx| buffer[idx * 3] != 0)
+        .map(|idx| (idx % 10, idx / 10))
+        .collect();
+
+    assert_eq!(lit.len(), 10);
+    assert!(buffer.iter().all(|&v| v == 0 || v == 255));
+    assert!(lit.contains(&(0, 0)) && lit.contains(&(9, 3)));
+}
+
+#[test]
+fn test_anti_aliased_circle() {
+    use crate::prelude::*;
+    let draw = |anti_aliasing: bool| {
+        let mut buffer = vec![];
+        {
+            let mut backend =
+                BitMapBackend::with_buffer(&mut buffer, (11, 11)).anti_aliasing(anti_aliasing);
+            backend
+                .draw_circle((5, 5), 4, &WHITE.filled(), true)
+                .unwrap();
+        }
+        buffer
+    };
+
+    // The pixels on the edge are partially covered
+    let buffer = draw(true);
+    let pixel = |x: usize, y: usize| buffer[(y * 11 + x) * 3];
+    assert_eq!(pixel(5, 5), 255);
+    assert!(pixel(9, 5) > 0 && pixel(9, 5) < 255);
+    assert_eq!(pixel(0, 0), 0);
+
+    let buffer = draw(false);
+    assert!(buffer.iter().all(|&v| v == 0 || v == 255));
+}
+
+#[test]
+fn test_draw_into_buffer() {
+    use crate::prelude::*;
+    let mut buffer = vec![255; 10 * 10 * 3];
+    {
+        let root = BitMapBackend::with_buffer(&mut buffer, (10, 10)).into_drawing_area();
+        root.draw_pixel((1, 1), &RED).unwrap();
+        root.present().unwrap();
+        root.draw_pixel((2, 2), &BLUE).unwrap();
+    }
+
+    let pixel = |x: usize, y: usize| &buffer[(y * 10 + x) * 3..(y * 10 + x + 1) * 3];
+
+    // The previous content of the buffer is discarded
+    assert_eq!(buffer.len(), 300);
+    assert_eq!(pixel(0, 0), &[0, 0, 0]);
+    assert_eq!(pixel(1, 1), &[255, 0, 0]);
+    assert_eq!(pixel(2, 2), &[0, 0, 255]);
+}
+
+#[test]
+fn test_scanline_polygon_fill() {
+    use crate::prelude::*;
+    let mut buffer = vec![];
+    {
+        // The polygon is wider than high, and it's still filled row by row
+        let mut backend = BitMapBackend::with_buffer(&mut buffer, (50, 20)).anti_aliasing(false);
+        backend
+            .fill_polygon(vec![(0, 0), (40, 10), (0, 10)], &WHITE.to_rgba())
+            .unwrap();
+    }
+
+    let pixel = |x: usize, y: usize| buffer[(y * 50 + x) * 3];
+    for y in 1..10 {
+        // The right edge of the row is at x = 4y
+        assert_eq!(pixel(0, y), 255);
+        assert_eq!(pixel(4 * y - 1, y), 255);
+        assert_eq!(pixel(4 * y + 2, y), 0);
+    }
+    assert_eq!(pixel(20, 11), 0);
+}
+
+#[test]
+fn test_scanline_fill() {
+    use crate::prelude::*;
+    let mut buffer = vec![];
+    {
+        let mut backend = BitMapBackend::with_buffer(&mut buffer, (10, 10));
+        backend
+            .draw_rect((-5, 2), (3, 4), &WHITE.to_rgba(), true)
+            .unwrap();
+        backend
+            .draw_rect((2, 3), (20, 3), &RED.mix(0.5), true)
+            .unwrap();
+        assert_eq!(backend.dirty_region(), Some(((0, 2), (9, 4))));
+        backend
+            .draw_rect((6, 9), (5, 6), &BLUE.to_rgba(), true)
+            .unwrap();
+        backend
+            .draw_rect((7, 7), (8, 8), &GREEN.to_rgba(), false)
+            .unwrap();
+    }
+
+    let pixel = |x: usize, y: usize| &buffer[(y * 10 + x) * 3..(y * 10 + x + 1) * 3];
+
+    assert_eq!(pixel(0, 2), &[255, 255, 255]);
+    assert_eq!(pixel(3, 4), &[255, 255, 255]);
+    assert_eq!(pixel(4, 4), &[0, 0, 0]);
+    assert_eq!(pixel(2, 3), &[255, 127, 127]);
+    assert_eq!(pixel(9, 3), &[127, 0, 0]);
+    assert_eq!(pixel(0, 5), &[0, 0, 0]);
+    // The rows of the opaque rectangle are copied from the first one
+    assert_eq!(pixel(5, 6), &[0, 0, 255]);
+    assert_eq!(pixel(6, 9), &[0, 0, 255]);
+    assert_eq!(pixel(4, 7), &[0, 0, 0]);
+    // The outline isn't filled
+    assert_eq!(pixel(7, 7), &[0, 255, 0]);
+    assert_eq!(pixel(8, 8), &[0, 255, 0]);
+    assert_eq!(pixel(9, 9), &[0, 0, 0]);
+}
+
+#[test]
+fn test_blit_bitmap() {
+    let mut buffer = vec![];
+    {
+        let mut backend = BitMapBackend::with_buffer(&mut buffer, (10, 10));
+        // A 3x2 image with the pixels numbered, the left column and the bottom row are out of
+        // the image
+        let src: Vec<u8> = (0..6).flat_map(|v| vec![v * 10; 3]).collect();
+        backend.blit_bitmap((-1, 9), (3, 2), &src).unwrap();
+        assert_eq!(backend.dirty_region(), Some(((0, 9), (1, 9))));
+    }
+
+    let pixel = |x: usize, y: usize| &buffer[(y * 10 + x) * 3..(y * 10 + x + 1) * 3];
+
+    assert_eq!(pixel(0, 9), &[10, 10, 10]);
+    assert_eq!(pixel(1, 9), &[20, 20, 20]);
+    assert_eq!(pixel(2, 9), &[0, 0, 0]);
+    assert_eq!(pixel(0, 8), &[0, 0, 0]);
+}
+
+#[test]
+fn test_blit_short_bitmap() {
+    let mut buffer = vec![];
+    {
+        let mut backend = BitMapBackend::with_buffer(&mut buffer, (10, 10));
+        // The source has 5 pixels only, thus nothing is drawn
+        backend.blit_bitmap((0, 0), (3, 2), &[255; 15]).unwrap();
+        // The size overflows
+        backend
+            .blit_bitmap((0, 0), (u32::MAX, u32::MAX), &[255; 15])
+            .unwrap();
+        assert_eq!(backend.dirty_region(), None);
+    }
+    assert!(buffer.iter().all(|v| *v == 0));
+}
+
+#[test]
+fn test_into_image() {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    let path = std::env::temp_dir().join("plotters_test_into_image.png");
+    let backend = Rc::new(RefCell::new(BitMapBackend::new(&path, (10, 10))));
+    {
+        let root = DrawingArea::from(&backend);
+        root.draw_pixel((1, 2), &RED).unwrap();
+    }
+
+    assert_eq!(
+        backend.borrow().to_image().get_pixel(1, 2).data,
+        [255, 0, 0]
+    );
+    assert_eq!(
+        backend
+            .borrow()
+            .to_dynamic_image()
+            .to_rgb()
+            .get_pixel(1, 2)
+            .data,
+        [255, 0, 0]
+    );
+
+    let backend = Rc::try_unwrap(backend).ok().unwrap().into_inner();
+    let img = backend.into_image();
+    assert_eq!(img.dimensions(), (10, 10));
+    assert_eq!(img.get_pixel(1, 2).data, [255, 0, 0]);
+    assert!(!path.exists());
+}
+
+#[cfg(test)]
+#[test]
+fn test_png_metadata() {
+    use crate::prelude::*;
+    let path = std::env::temp_dir().join("plotters_test_png_metadata.png");
+    {
+        let root = BitMapBackend::new(&path, (10, 10))
+            .metadata(Metadata::new().title("Sales").entry("Source", "db"))
+            .into_drawing_area();
+        root.fill(&RED).unwrap();
+    }
+    let png = std::fs::read(&path).unwrap();
+    let find = |pattern: &[u8]| png.windows(pattern.len()).any(|w| w == pattern);
+    assert!(find(b"tEXtTitle\0Sales"));
+    assert!(find(b"tEXtSource\0db"));
+
+    // The decoder checks the CRC of the chunks
+    let img = image::open(&path).unwrap().to_rgb();
+    assert_eq!(img.get_pixel(5, 5), &Rgb([255, 0, 0]));
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[cfg(test)]
+#[test]
+fn test_encoded_buffer() {
+    use crate::prelude::*;
+    let mut png = vec![];
+    let mut jpeg = vec![];
+    {
+        let root = BitMapBackend::with_encoded_buffer(&mut png, (10, 10), ImageOutputFormat::PNG)
+            .metadata(Metadata::new().title("Sales"))
+            .into_drawing_area();
+        root.fill(&RED).unwrap();
+        let root =
+            BitMapBackend::with_encoded_buffer(&mut jpeg, (10, 10), ImageOutputFormat::JPEG(90))
+                .into_drawing_area();
+        root.fill(&BLUE).unwrap();
+    }
+
+    let pattern = b"tEXtTitle\0Sales";
+    assert!(png.windows(pattern.len()).any(|w| w == pattern));
+    let img = image::load_from_memory(&png).unwrap().to_rgb();
+    assert_eq!(img.get_pixel(5, 5), &Rgb([255, 0, 0]));
+
+    assert!(jpeg.starts_with(&[0xff, 0xd8]));
+    let img = image::load_from_memory(&jpeg).unwrap().to_rgb();
+    assert!(img.get_pixel(5, 5).data[2] > 200);
+}
+
+#[cfg(all(test, feature = "gif"))]
+#[test]
+fn test_gif_options() {
+    use crate::prelude::*;
+    use gif::SetParameter;
+    let path = std::env::temp_dir().join("plotters_test_gif_options.gif");
+    {
+        let options = GifOptions::new(100).repeat(2).hold_last_frame(1000);
+        let mut backend = BitMapBackend::gif_with_options(&path, (10, 10), options).unwrap();
+        backend.draw_pixel((1, 1), &RED.to_rgba()).unwrap();
+        backend.present().unwrap();
+        backend.set_frame_delay(200);
+        backend.draw_pixel((2, 2), &RED.to_rgba()).unwrap();
+        backend.present().unwrap();
+        backend.draw_pixel((3, 3), &RED.to_rgba()).unwrap();
+        backend.present().unwrap();
+    }
+
+    let data = std::fs::read(&path).unwrap();
+    // The loop count of the NETSCAPE extension
+    let find = |pattern: &[u8]| data.windows(pattern.len()).any(|w| w == pattern);
+    assert!(find(b"NETSCAPE2.0\x03\x01\x02\x00"));
+
+    let mut decoder = gif::Decoder::new(&data[..]);
+    decoder.set(gif::ColorOutput::RGBA);
+    let mut reader = decoder.read_info().unwrap();
+    let mut delays = vec![];
+    while let Some(frame) = reader.read_next_frame().unwrap() {
+        delays.push(frame.delay);
+    }
+    // The last frame is held for the extra time
+    assert_eq!(delays, vec![10, 20, 120]);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[cfg(test)]
+#[test]
+fn test_apng() {
+    use crate::prelude::*;
+    let path = std::env::temp_dir().join("plotters_test_apng.png");
+    {
+        let mut backend = BitMapBackend::apng(&path, (10, 10), 100).unwrap();
+        backend
+            .draw_rect((0, 0), (9, 9), &RGBColor(1, 2, 3).to_rgba(), true)
+            .unwrap();
+        backend.present().unwrap();
+        backend.set_frame_delay(200);
+        backend.draw_pixel((2, 2), &RED.to_rgba()).unwrap();
+        backend.present().unwrap();
+    }
+
+    let png = std::fs::read(&path).unwrap();
+    let mut chunks = vec![];
+    let mut pos = 8;
+    while pos < png.len() {
+        let len = u32::from_be_bytes([png[pos], png[pos + 1], png[pos + 2], png[pos + 3]]);
+        chunks.push((png[pos + 4..pos + 8].to_vec(), pos + 8));
+        pos += len as usize + 12;
+    }
+    let kinds: Vec<_> = chunks.iter().map(|(k, _)| &k[..]).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            &b"IHDR"[..],
+            b"acTL",
+            b"fcTL",
+            b"IDAT",
+            b"fcTL",
+            b"fdAT",
+            b"IEND"
+        ]
+    );
+    // The number of frames, and the delay of the second frame
+    assert_eq!(&png[chunks[1].1..chunks[1].1 + 4], &[0, 0, 0, 2]);
+    assert_eq!(&png[chunks[4].1 + 20..chunks[4].1 + 24], &[0, 200, 3, 232]);
+
+    // The first frame keeps the exact color, and the CRC of the chunks are checked
+    let img = image::open(&path).unwrap().to_rgb();
+    assert_eq!(img.get_pixel(5, 5), &Rgb([1, 2, 3]));
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[cfg(all(test, unix, feature = "video"))]
+#[test]
+fn test_video() {
+    use crate::prelude::*;
+    let path = std::env::temp_dir().join("plotters_test_video.raw");
+    {
+        // Capture the raw frames piped into the encoder
+        let mut command = std::process::Command::new("sh");
+        command.arg("-c").arg(format!("cat > '{}'", path.display()));
+        let video = video_support::VideoFile::spawn(command).unwrap();
+        let mut backend = BitMapBackend::with_video(video, (4, 3));
+        backend.ensure_prepared().unwrap();
+        backend.draw_pixel((1, 1), &RED.to_rgba()).unwrap();
+        backend.present().unwrap();
+        backend.ensure_prepared().unwrap();
+        backend.draw_pixel((2, 1), &BLUE.to_rgba()).unwrap();
+        backend.present().unwrap();
+    }
+
+    let raw = std::fs::read(&path).unwrap();
+    assert_eq!(raw.len(), 4 * 3 * 3 * 2);
+    // The second frame starts with a blank buffer
+    assert_eq!(&raw[15..21], &[255, 0, 0, 0, 0, 0]);
+    assert_eq!(&raw[36 + 15..36 + 21], &[0, 0, 0, 0, 0, 255]);
+    std::fs::remove_file(&path).unwrap();
 }
@@ -0,0 +1,80 @@
+use crate::drawing::backend::{BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind};
+use crate::style::{Color, RGBAColor};
+
+use std::convert::Infallible;
+
+/// The backend that draws nothing, but tracks the bounding box of all the pixels drawn on it.
+/// This is used to find out the region an element covers, see `HitRegions`.
+pub struct BoundsBackend {
+    size: (u32, u32),
+    bounds: Option<(BackendCoord, BackendCoord)>,
+}
+
+impl BoundsBackend {
+    /// Create a new backend
+    /// - `size`: The size of the backend, the pixels out of it are ignored
+    pub fn new(size: (u32, u32)) -> Self {
+        Self { size, bounds: None }
+    }
+
+    /// Take the upper-left and bottom-right corners (both inclusive) of the bounding box of
+    /// the pixels drawn since the last call, `None` if nothing is drawn
+    pub fn take_bounds(&mut self) -> Option<(BackendCoord, BackendCoord)> {
+        self.bounds.take()
+    }
+
+    /// Extend the bounding box to cover the box from `(x0, y0)` to `(x1, y1)`, clipped to the
+    /// size of the backend
+    fn extend(&mut self, (x0, y0): BackendCoord, (x1, y1): BackendCoord) {
+        let (w, h) = (self.size.0 as i32, self.size.1 as i32);
+        let (x0, x1) = (x0.min(x1).max(0), x0.max(x1).min(w - 1));
+        let (y0, y1) = (y0.min(y1).max(0), y0.max(y1).min(h - 1));
+        if x0 > x1 || y0 > y1 {
+            return;
+        }
+        self.bounds = Some(match self.bounds {
+            Some(((a0, b0), (a1, b1))) => ((a0.min(x0), b0.min(y0)), (a1.max(x1), b1.max(y1))),
+            None => ((x0, y0), (x1, y1)),
+        });
+    }
+}
+
+impl DrawingBackend for BoundsBackend {
+    type ErrorType = Infallible;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.size
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<Infallible>> {
+        Ok(())
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<Infallible>> {
+        Ok(())
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<Infallible>> {
+        if color.alpha() > 0.0 {
+            self.extend(point, point);
+        }
+        Ok(())
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        _fill: bool,
+    ) -> Result<(), DrawingErrorKind<Infallible>> {
+        if style.as_color().alpha() > 0.0 {
+            self.extend(upper_left, bottom_right);
+        }
+        Ok(())
+    }
+}
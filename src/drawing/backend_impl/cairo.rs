@@ -0,0 +1,308 @@
+/*!
+The drawing backend based on Cairo, which is used to draw on a GTK widget or any surface
+supported by Cairo
+*/
+use cairo::{Context as CairoContext, FontSlant, FontWeight, LineCap, LineJoin};
+
+use crate::drawing::backend::{BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind};
+use crate::style::{Color, FontDesc, RGBAColor};
+
+/// The drawing backend that draws on a Cairo context, e.g. the one given by the `draw` signal
+/// of a gtk-rs `DrawingArea`. The backend draws in the user space of the context, thus the
+/// transformation of the context is applied to the chart.
+pub struct CairoBackend<'a> {
+    context: &'a CairoContext,
+    size: (u32, u32),
+    init_flag: bool,
+}
+
+/// The strokes are drawn on the center of the pixels, otherwise a 1 pixel wide line is
+/// blurred into 2 pixels
+fn stroke_point((x, y): BackendCoord) -> (f64, f64) {
+    (f64::from(x) + 0.5, f64::from(y) + 0.5)
+}
+
+impl<'a> CairoBackend<'a> {
+    /// Create a new Cairo drawing backend
+    /// - `context`: The Cairo context to draw on
+    /// - `size`: The size of the drawing area in the user space of the context
+    pub fn new(context: &'a CairoContext, size: (u32, u32)) -> Self {
+        Self {
+            context,
+            size,
+            init_flag: false,
+        }
+    }
+
+    fn set_color(&self, color: &RGBAColor) {
+        let (r, g, b) = color.rgb();
+        self.context.set_source_rgba(
+            f64::from(r) / 255.0,
+            f64::from(g) / 255.0,
+            f64::from(b) / 255.0,
+            color.alpha(),
+        );
+    }
+
+    fn set_stroke<S: BackendStyle>(&self, style: &S) {
+        self.set_color(&style.as_color());
+        self.context.set_line_width(f64::from(style.stroke_width()));
+        let (pattern, offset) = style.stroke_dash();
+        let pattern: Vec<_> = pattern.iter().map(|v| f64::from(*v)).collect();
+        self.context.set_dash(&pattern, f64::from(offset));
+    }
+
+    fn set_font(&self, font: &FontDesc) {
+        self.context
+            .select_font_face(font.get_name(), FontSlant::Normal, FontWeight::Normal);
+        self.context.set_font_size(font.get_size());
+    }
+
+    fn trace_path<I: IntoIterator<Item = BackendCoord>>(&self, path: I, stroke: bool) {
+        for (idx, point) in path.into_iter().enumerate() {
+            let (x, y) = if stroke {
+                stroke_point(point)
+            } else {
+                (f64::from(point.0), f64::from(point.1))
+            };
+            if idx == 0 {
+                self.context.move_to(x, y);
+            } else {
+                self.context.line_to(x, y);
+            }
+        }
+    }
+}
+
+impl<'a> DrawingBackend for CairoBackend<'a> {
+    type ErrorType = cairo::Error;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.size
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<cairo::Error>> {
+        if !self.init_flag {
+            self.context.set_line_cap(LineCap::Round);
+            self.context.set_line_join(LineJoin::Round);
+            self.init_flag = true;
+        }
+        self.context
+            .status()
+            .map_err(DrawingErrorKind::DrawingError)
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<cairo::Error>> {
+        self.init_flag = false;
+        self.context
+            .status()
+            .map_err(DrawingErrorKind::DrawingError)
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<cairo::Error>> {
+        if color.alpha() == 0.0 {
+            return Ok(());
+        }
+        self.set_color(color);
+        self.context
+            .rectangle(f64::from(point.0), f64::from(point.1), 1.0, 1.0);
+        self.context.fill().map_err(DrawingErrorKind::DrawingError)
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.as_color().alpha() == 0.0 {
+            return Ok(());
+        }
+        self.set_stroke(style);
+        self.trace_path(vec![from, to], true);
+        self.context
+            .stroke()
+            .map_err(DrawingErrorKind::DrawingError)
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.as_color().alpha() == 0.0 {
+            return Ok(());
+        }
+        let (w, h) = (
+            f64::from(bottom_right.0 - upper_left.0),
+            f64::from(bottom_right.1 - upper_left.1),
+        );
+        if fill {
+            self.set_color(&style.as_color());
+            self.context
+                .rectangle(f64::from(upper_left.0), f64::from(upper_left.1), w, h);
+            self.context.fill().map_err(DrawingErrorKind::DrawingError)
+        } else {
+            self.set_stroke(style);
+            let (x, y) = stroke_point(upper_left);
+            self.context.rectangle(x, y, w, h);
+            self.context
+                .stroke()
+                .map_err(DrawingErrorKind::DrawingError)
+        }
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.as_color().alpha() == 0.0 {
+            return Ok(());
+        }
+        self.set_stroke(style);
+        self.trace_path(path, true);
+        self.context
+            .stroke()
+            .map_err(DrawingErrorKind::DrawingError)
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.as_color().alpha() == 0.0 {
+            return Ok(());
+        }
+        self.set_color(&style.as_color());
+        self.trace_path(path, false);
+        self.context.close_path();
+        self.context.fill().map_err(DrawingErrorKind::DrawingError)
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.as_color().alpha() == 0.0 {
+            return Ok(());
+        }
+        let (x, y) = if fill {
+            (f64::from(center.0), f64::from(center.1))
+        } else {
+            stroke_point(center)
+        };
+        self.context.new_sub_path();
+        self.context
+            .arc(x, y, f64::from(radius), 0.0, 2.0 * std::f64::consts::PI);
+        if fill {
+            self.set_color(&style.as_color());
+            self.context.fill().map_err(DrawingErrorKind::DrawingError)
+        } else {
+            self.set_stroke(style);
+            self.context
+                .stroke()
+                .map_err(DrawingErrorKind::DrawingError)
+        }
+    }
+
+    fn draw_text<'b>(
+        &mut self,
+        text: &str,
+        font: &FontDesc<'b>,
+        pos: BackendCoord,
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if color.alpha() == 0.0 {
+            return Ok(());
+        }
+        let (w, h) = self.estimate_text_size(text, font)?;
+        let layout = ((0, 0), (w as i32, h as i32));
+
+        let trans = font.get_transform();
+        let offset = trans.offset(layout);
+        let angle = trans.angle();
+
+        self.set_font(font);
+        self.set_color(color);
+        let extents = self
+            .context
+            .text_extents(text)
+            .map_err(DrawingErrorKind::DrawingError)?;
+
+        // The text is rotated clockwise around the top-left corner
+        self.context
+            .save()
+            .map_err(DrawingErrorKind::DrawingError)?;
+        self.context
+            .translate(f64::from(pos.0 + offset.0), f64::from(pos.1 + offset.1));
+        self.context.rotate(angle.to_radians());
+        self.context
+            .move_to(-extents.x_bearing(), -extents.y_bearing());
+        let result = self.context.show_text(text);
+        self.context
+            .restore()
+            .map_err(DrawingErrorKind::DrawingError)?;
+        result.map_err(DrawingErrorKind::DrawingError)
+    }
+
+    fn estimate_text_size<'b>(
+        &self,
+        text: &str,
+        font: &FontDesc<'b>,
+    ) -> Result<(u32, u32), DrawingErrorKind<Self::ErrorType>> {
+        self.set_font(font);
+        let extents = self
+            .context
+            .text_extents(text)
+            .map_err(DrawingErrorKind::DrawingError)?;
+        Ok((
+            extents.width().ceil().max(0.0) as u32,
+            extents.height().ceil().max(0.0) as u32,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use cairo::{Format, ImageSurface};
+
+    #[test]
+    fn test_draw_on_image_surface() {
+        let mut surface = ImageSurface::create(Format::ARgb32, 20, 10).expect("Create surface");
+        {
+            let context = CairoContext::new(&surface).expect("Create context");
+            let root = CairoBackend::new(&context, (20, 10)).into_drawing_area();
+            root.fill(&WHITE).expect("Fill");
+            root.draw(&Rectangle::new([(10, 0), (20, 10)], RED.filled()))
+                .expect("Draw rectangle");
+        }
+
+        let stride = surface.stride() as usize;
+        let data = surface.data().expect("Read surface");
+        // The pixels are stored as native-endian ARGB
+        let pixel = |x: usize, y: usize| {
+            let offset = y * stride + x * 4;
+            u32::from_ne_bytes([
+                data[offset],
+                data[offset + 1],
+                data[offset + 2],
+                data[offset + 3],
+            ])
+        };
+        assert_eq!(pixel(5, 5), 0xffff_ffff);
+        assert_eq!(pixel(15, 5), 0xffff_0000);
+    }
+}
@@ -3,7 +3,8 @@ use wasm_bindgen::{JsCast, JsValue};
 use web_sys::{window, CanvasRenderingContext2d, HtmlCanvasElement};
 
 use crate::drawing::backend::{BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind};
-use crate::style::{Color, FontDesc, FontTransform, RGBAColor};
+use crate::drawing::rasterizer;
+use crate::style::{Color, FontDesc, RGBAColor};
 
 /// The backend that is drawing on the HTML canvas
 /// TODO: Support double bufferring
@@ -97,6 +98,9 @@ impl DrawingBackend for CanvasBackend {
         if style.as_color().alpha() == 0.0 {
             return Ok(());
         }
+        if !style.stroke_dash().0.is_empty() {
+            return rasterizer::draw_dashed_path(self, &[from, to], style);
+        }
 
         self.context
             .set_stroke_style(&make_canvas_color(style.as_color()));
@@ -126,6 +130,14 @@ impl DrawingBackend for CanvasBackend {
                 f64::from(bottom_right.0 - upper_left.0),
                 f64::from(bottom_right.1 - upper_left.1),
             );
+        } else if !style.stroke_dash().0.is_empty() {
+            let (x0, y0) = upper_left;
+            let (x1, y1) = bottom_right;
+            return rasterizer::draw_dashed_path(
+                self,
+                &[(x0, y0), (x1, y0), (x1, y1), (x0, y1), (x0, y0)],
+                style,
+            );
         } else {
             self.context
                 .set_stroke_style(&make_canvas_color(style.as_color()));
@@ -147,6 +159,10 @@ impl DrawingBackend for CanvasBackend {
         if style.as_color().alpha() == 0.0 {
             return Ok(());
         }
+        if !style.stroke_dash().0.is_empty() {
+            let path: Vec<_> = path.into_iter().collect();
+            return rasterizer::draw_dashed_path(self, &path, style);
+        }
         let mut path = path.into_iter();
         self.context.begin_path();
         if let Some(start) = path.next() {
@@ -230,13 +246,7 @@ impl DrawingBackend for CanvasBackend {
 
         let (mut x, mut y) = (pos.0, pos.1);
 
-        let degree = match font.get_transform() {
-            FontTransform::None => 0.0,
-            FontTransform::Rotate90 => 90.0,
-            FontTransform::Rotate180 => 180.0,
-            FontTransform::Rotate270 => 270.0,
-        } / 180.0
-            * std::f64::consts::PI;
+        let degree = font.get_transform().angle() / 180.0 * std::f64::consts::PI;
 
         if degree != 0.0 {
             self.context.save();
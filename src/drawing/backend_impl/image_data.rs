@@ -0,0 +1,127 @@
+/*!
+The in-memory RGBA backend for the browser target
+*/
+use wasm_bindgen::{Clamped, JsValue};
+use web_sys::ImageData;
+
+use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use crate::style::{Color, FontDesc, RGBAColor};
+
+pub struct ImageDataError(String);
+
+impl std::fmt::Display for ImageDataError {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        write!(fmt, "ImageData Error: {}", self.0)
+    }
+}
+
+impl std::fmt::Debug for ImageDataError {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        write!(fmt, "ImageDataError({})", self.0)
+    }
+}
+
+impl std::error::Error for ImageDataError {}
+
+/// The backend that rasterizes into an in-memory RGBA buffer, which can be turned into an
+/// `ImageData` for `putImageData`.
+///
+/// Unlike `CanvasBackend`, it doesn't rely on the DOM, thus heavy charts can be rasterized in
+/// a web worker and then put on an `OffscreenCanvas` or sent to the main thread.
+/// Note that text isn't supported, because the fonts on the browser target are measured and
+/// rendered by the DOM. The labels can be drawn with `CanvasBackend` on top of the image.
+pub struct ImageDataBackend {
+    size: (u32, u32),
+    data: Vec<u8>,
+}
+
+impl ImageDataBackend {
+    /// Create a new backend, the image is initially fully transparent
+    /// - `size`: The size of the image
+    pub fn new(size: (u32, u32)) -> Self {
+        Self {
+            size,
+            data: vec![0; size.0 as usize * size.1 as usize * 4],
+        }
+    }
+
+    /// Get the RGBA pixels, which are laid out row by row
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Create an `ImageData` from the pixels, which can be drawn with `putImageData`
+    pub fn to_image_data(&mut self) -> Result<ImageData, JsValue> {
+        ImageData::new_with_u8_clamped_array_and_sh(
+            Clamped(&mut self.data[..]),
+            self.size.0,
+            self.size.1,
+        )
+    }
+}
+
+impl DrawingBackend for ImageDataBackend {
+    type ErrorType = ImageDataError;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.size
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<ImageDataError>> {
+        Ok(())
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<ImageDataError>> {
+        Ok(())
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<ImageDataError>> {
+        let (w, h) = (self.size.0 as i32, self.size.1 as i32);
+        let alpha = color.alpha();
+        if point.0 < 0 || point.0 >= w || point.1 < 0 || point.1 >= h || alpha <= 0.0 {
+            return Ok(());
+        }
+
+        let idx = (point.1 * w + point.0) as usize * 4;
+        let pixel = &mut self.data[idx..idx + 4];
+        let (r, g, b) = color.rgb();
+        let alpha = alpha.min(1.0);
+
+        // Composite the color over the pixel, which may be translucent itself
+        let old_alpha = f64::from(pixel[3]) / 255.0;
+        let new_alpha = alpha + old_alpha * (1.0 - alpha);
+        for (old, new) in pixel.iter_mut().zip(&[r, g, b]) {
+            let value = f64::from(*new) * alpha + f64::from(*old) * old_alpha * (1.0 - alpha);
+            *old = (value / new_alpha).min(255.0) as u8;
+        }
+        pixel[3] = (new_alpha * 255.0).round() as u8;
+
+        Ok(())
+    }
+
+    fn draw_text<'a>(
+        &mut self,
+        _text: &str,
+        _font: &FontDesc<'a>,
+        _pos: BackendCoord,
+        _color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<ImageDataError>> {
+        Err(DrawingErrorKind::DrawingError(ImageDataError(
+            "Text is not supported by the image data backend".to_string(),
+        )))
+    }
+
+    fn estimate_text_size<'a>(
+        &self,
+        _text: &str,
+        _font: &FontDesc<'a>,
+    ) -> Result<(u32, u32), DrawingErrorKind<ImageDataError>> {
+        Err(DrawingErrorKind::DrawingError(ImageDataError(
+            "Text is not supported by the image data backend".to_string(),
+        )))
+    }
+}
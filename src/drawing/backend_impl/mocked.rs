@@ -12,6 +12,7 @@ pub struct MockedBackend {
     init_count: u32,
     pub draw_count: u32,
     pub num_draw_pixel_call: u32,
+    pub num_draw_pixels_call: u32,
     pub num_draw_line_call: u32,
     pub num_draw_rect_call: u32,
     pub num_draw_circle_call: u32,
@@ -51,6 +52,7 @@ impl MockedBackend {
             init_count: 0,
             draw_count: 0,
             num_draw_pixel_call: 0,
+            num_draw_pixels_call: 0,
             num_draw_line_call: 0,
             num_draw_rect_call: 0,
             num_draw_circle_call: 0,
@@ -137,6 +139,24 @@ impl DrawingBackend for MockedBackend {
         Ok(())
     }
 
+    fn draw_pixels(
+        &mut self,
+        pixels: &[(BackendCoord, RGBAColor)],
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.check_before_draw();
+        self.num_draw_pixels_call += 1;
+        for (point, color) in pixels {
+            if let Some(mut checker) = self.check_draw_pixel.pop_front() {
+                checker(color.clone(), *point);
+
+                if self.check_draw_pixel.is_empty() {
+                    self.check_draw_pixel.push_back(checker);
+                }
+            }
+        }
+        Ok(())
+    }
+
     fn draw_line<S: BackendStyle>(
         &mut self,
         from: BackendCoord,
@@ -1,18 +1,37 @@
+mod bounds;
+pub use bounds::BoundsBackend;
+
 #[cfg(feature = "svg")]
 mod svg;
 #[cfg(feature = "svg")]
 pub use self::svg::SVGBackend;
 
+#[cfg(feature = "pdf")]
+mod pdf;
+#[cfg(feature = "pdf")]
+pub use self::pdf::PdfBackend;
+
 #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
 mod bitmap;
 #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
 pub use bitmap::BitMapBackend;
+#[cfg(all(not(target_arch = "wasm32"), feature = "image", feature = "rayon"))]
+pub use bitmap::BitMapBand;
+#[cfg(all(not(target_arch = "wasm32"), feature = "image", feature = "gif"))]
+pub use bitmap::GifOptions;
+#[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+pub use image::ImageOutputFormat;
 
 #[cfg(target_arch = "wasm32")]
 mod canvas;
 #[cfg(target_arch = "wasm32")]
 pub use canvas::CanvasBackend;
 
+#[cfg(target_arch = "wasm32")]
+mod image_data;
+#[cfg(target_arch = "wasm32")]
+pub use image_data::ImageDataBackend;
+
 #[cfg(test)]
 mod mocked;
 #[cfg(test)]
@@ -22,3 +41,20 @@ pub use mocked::{create_mocked_drawing_area, MockedBackend};
 mod piston;
 #[cfg(all(not(target_arch = "wasm32"), feature = "piston"))]
 pub use piston::{draw_piston_window, PistonBackend};
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "wgpu"))]
+mod wgpu;
+#[cfg(all(not(target_arch = "wasm32"), feature = "wgpu"))]
+pub use self::wgpu::WgpuBackend;
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "cairo"))]
+mod cairo;
+#[cfg(all(not(target_arch = "wasm32"), feature = "cairo"))]
+pub use self::cairo::CairoBackend;
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "window"))]
+mod window;
+#[cfg(all(not(target_arch = "wasm32"), feature = "window"))]
+pub use self::window::{WindowBackend, WindowEvent};
+#[cfg(all(not(target_arch = "wasm32"), feature = "window"))]
+pub use minifb::{Key as WindowKey, MouseButton};
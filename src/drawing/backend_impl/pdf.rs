@@ -0,0 +1,590 @@
+/*!
+The PDF document drawing backend
+*/
+
+use crate::drawing::backend::{BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind};
+use crate::drawing::Metadata;
+use crate::style::{Color, FontDesc, RGBAColor};
+
+use std::collections::BTreeSet;
+use std::fs::File;
+use std::io::{Error, Write};
+use std::path::Path;
+
+/// The factor to approximate a quarter of circle with a cubic Bezier curve
+const BEZIER_CIRCLE_FACTOR: f64 = 0.552_284_749_8;
+
+/// The standard fonts which are available in every PDF reader, thus we don't need to embed them
+const STANDARD_FONTS: [(&str, &str); 3] = [
+    ("F1", "Helvetica"),
+    ("F2", "Times-Roman"),
+    ("F3", "Courier"),
+];
+
+fn make_pdf_float(value: f64) -> String {
+    // Adding zero turns the negative zero into zero, which is formatted without the sign
+    format!("{}", (value * 1000.0).round() / 1000.0 + 0.0)
+}
+
+fn make_pdf_color<C: Color>(color: &C, op: &str) -> String {
+    let (r, g, b) = color.rgb();
+    format!(
+        "{} {} {} {}\n",
+        make_pdf_float(f64::from(r) / 255.0),
+        make_pdf_float(f64::from(g) / 255.0),
+        make_pdf_float(f64::from(b) / 255.0),
+        op
+    )
+}
+
+/// Quote the text as a PDF literal string. The text is encoded with `WinAnsiEncoding`, which
+/// is same as Latin-1 for the printable chars, and the other chars are replaced with `?`.
+fn make_pdf_string(text: &str) -> Vec<u8> {
+    let mut ret = vec![b'('];
+    for c in text.chars() {
+        match c {
+            '(' | ')' | '\\' => {
+                ret.push(b'\\');
+                ret.push(c as u8);
+            }
+            c if (' '..='~').contains(&c) || ('\u{a0}'..='\u{ff}').contains(&c) => {
+                ret.push(c as u32 as u8)
+            }
+            _ => ret.push(b'?'),
+        }
+    }
+    ret.push(b')');
+    ret
+}
+
+/// Pick the standard font for the font family
+fn pdf_font_name(family: &str) -> &'static str {
+    let family = family.to_lowercase();
+    if family.contains("mono") || family.contains("courier") {
+        STANDARD_FONTS[2].0
+    } else if (family.contains("serif") && !family.contains("sans")) || family.contains("times") {
+        STANDARD_FONTS[1].0
+    } else {
+        STANDARD_FONTS[0].0
+    }
+}
+
+/// Quote the text as a PDF text string for the document information, which is encoded in
+/// UTF-16BE with the byte order mark
+fn make_pdf_text_string(text: &str) -> String {
+    let mut ret = String::from("<FEFF");
+    for unit in text.encode_utf16() {
+        ret.push_str(&format!("{:04X}", unit));
+    }
+    ret.push('>');
+    ret
+}
+
+/// Convert the RFC 3339 time, e.g. `2019-12-02T09:30:00+09:00`, to a PDF date, e.g.
+/// `D:20191202093000+09'00'`, `None` if the time isn't in the RFC 3339 format
+fn make_pdf_date(time: &str) -> Option<String> {
+    let (date, clock, zone) = (time.get(..10)?, time.get(11..19)?, time.get(19..)?);
+    let date: String = date.split('-').collect();
+    let clock: String = clock.split(':').collect();
+    let all_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    if date.len() != 8 || clock.len() != 6 || !all_digits(&date) || !all_digits(&clock) {
+        return None;
+    }
+
+    // The fraction of the second isn't supported by the PDF date
+    let zone = zone.trim_start_matches(|c: char| c == '.' || c.is_ascii_digit());
+    let zone = if zone.eq_ignore_ascii_case("z") {
+        "Z".to_string()
+    } else {
+        let (sign, hours, minutes) = (zone.get(..1)?, zone.get(1..3)?, zone.get(4..)?);
+        if (sign != "+" && sign != "-") || !all_digits(hours) || !all_digits(minutes) {
+            return None;
+        }
+        format!("{}{}'{}'", sign, hours, minutes)
+    };
+    Some(format!("D:{}{}{}", date, clock, zone))
+}
+
+enum Target<'a> {
+    File(&'a Path),
+    Buffer(&'a mut Vec<u8>),
+}
+
+/// The PDF document drawing backend, which draws the figure as vector graphics on a single
+/// page. A pixel of the backend is a point (1/72 inch) in the document.
+///
+/// The text is drawn with the standard fonts of PDF, thus the font family is mapped to
+/// Helvetica, Times or Courier, and the chars out of Latin-1 are not supported.
+pub struct PdfBackend<'a> {
+    target: Target<'a>,
+    size: (u32, u32),
+    content: Vec<u8>,
+    /// The alpha values used by the document, in thousandths
+    alphas: BTreeSet<u32>,
+    current_alpha: u32,
+    current_dash: (Vec<u32>, u32),
+    metadata: Option<Metadata>,
+    saved: bool,
+}
+
+impl<'a> PdfBackend<'a> {
+    fn with_target(target: Target<'a>, size: (u32, u32)) -> Self {
+        Self {
+            target,
+            size,
+            content: b"1 J 1 j\n".to_vec(),
+            alphas: BTreeSet::new(),
+            current_alpha: 1000,
+            current_dash: (vec![], 0),
+            metadata: None,
+            saved: false,
+        }
+    }
+
+    /// Create a new PDF drawing backend
+    pub fn new<T: AsRef<Path> + ?Sized>(path: &'a T, size: (u32, u32)) -> Self {
+        Self::with_target(Target::File(path.as_ref()), size)
+    }
+
+    /// Create a new PDF drawing backend and store the document into a u8 buffer
+    pub fn with_buffer(buf: &'a mut Vec<u8>, size: (u32, u32)) -> Self {
+        Self::with_target(Target::Buffer(buf), size)
+    }
+
+    /// Set the metadata of the document, which is written as the document information
+    /// dictionary. The entries `Title`, `Author` and `Subject` are shown by the PDF readers.
+    pub fn metadata(mut self, metadata: Metadata) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+
+    fn emit(&mut self, op: &str) {
+        self.content.extend_from_slice(op.as_bytes());
+    }
+
+    /// Convert the backend coordinate to the PDF coordinate, which has the origin at the
+    /// bottom-left corner
+    fn point(&self, (x, y): (f64, f64)) -> String {
+        format!(
+            "{} {}",
+            make_pdf_float(x),
+            make_pdf_float(f64::from(self.size.1) - y)
+        )
+    }
+
+    /// Set the alpha for both stroking and filling, returns false if nothing is visible
+    fn set_alpha(&mut self, alpha: f64) -> bool {
+        let alpha = (alpha.clamp(0.0, 1.0) * 1000.0).round() as u32;
+        if alpha == 0 {
+            return false;
+        }
+        if alpha != self.current_alpha {
+            self.alphas.insert(alpha);
+            self.emit(&format!("/GS{} gs\n", alpha));
+            self.current_alpha = alpha;
+        }
+        true
+    }
+
+    fn begin_stroke<S: BackendStyle>(&mut self, style: &S) -> bool {
+        let color = style.as_color();
+        if !self.set_alpha(color.alpha()) {
+            return false;
+        }
+        self.emit(&make_pdf_color(&color, "RG"));
+        self.emit(&format!("{} w\n", style.stroke_width()));
+        let (pattern, offset) = style.stroke_dash();
+        let dash = (pattern.to_vec(), offset);
+        if dash != self.current_dash {
+            let pattern: Vec<_> = pattern.iter().map(|v| v.to_string()).collect();
+            self.emit(&format!("[{}] {} d\n", pattern.join(" "), offset));
+            self.current_dash = dash;
+        }
+        true
+    }
+
+    fn begin_fill<S: BackendStyle>(&mut self, style: &S) -> bool {
+        let color = style.as_color();
+        if !self.set_alpha(color.alpha()) {
+            return false;
+        }
+        self.emit(&make_pdf_color(&color, "rg"));
+        true
+    }
+
+    fn emit_path<I: IntoIterator<Item = BackendCoord>>(&mut self, path: I) -> usize {
+        let mut count = 0;
+        for (x, y) in path {
+            let op = if count == 0 { "m" } else { "l" };
+            let point = self.point((f64::from(x), f64::from(y)));
+            self.emit(&format!("{} {}\n", point, op));
+            count += 1;
+        }
+        count
+    }
+
+    fn emit_circle(&mut self, (cx, cy): BackendCoord, radius: u32) {
+        let (cx, cy, r) = (f64::from(cx), f64::from(cy), f64::from(radius));
+        let k = r * BEZIER_CIRCLE_FACTOR;
+        let start = self.point((cx + r, cy));
+        self.emit(&format!("{} m\n", start));
+        for (c1, c2, end) in &[
+            ((cx + r, cy + k), (cx + k, cy + r), (cx, cy + r)),
+            ((cx - k, cy + r), (cx - r, cy + k), (cx - r, cy)),
+            ((cx - r, cy - k), (cx - k, cy - r), (cx, cy - r)),
+            ((cx + k, cy - r), (cx + r, cy - k), (cx + r, cy)),
+        ] {
+            let op = format!(
+                "{} {} {} c\n",
+                self.point(*c1),
+                self.point(*c2),
+                self.point(*end)
+            );
+            self.emit(&op);
+        }
+    }
+
+    /// Build the whole document
+    fn build_document(&self) -> Vec<u8> {
+        let mut objects = vec![];
+
+        objects.push(b"<< /Type /Catalog /Pages 2 0 R >>".to_vec());
+        objects.push(b"<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_vec());
+
+        let fonts: String = STANDARD_FONTS
+            .iter()
+            .map(|(name, base)| {
+                format!(
+                    "/{} << /Type /Font /Subtype /Type1 /BaseFont /{} /Encoding /WinAnsiEncoding >> ",
+                    name, base
+                )
+            })
+            .collect();
+        let states: String = self
+            .alphas
+            .iter()
+            .map(|a| {
+                let alpha = make_pdf_float(f64::from(*a) / 1000.0);
+                format!("/GS{} << /CA {} /ca {} >> ", a, alpha, alpha)
+            })
+            .collect();
+        objects.push(
+            format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] /Contents 4 0 R \
+                 /Resources << /Font << {}>> /ExtGState << {}>> >> >>",
+                self.size.0, self.size.1, fonts, states
+            )
+            .into_bytes(),
+        );
+
+        let mut stream = format!("<< /Length {} >>\nstream\n", self.content.len()).into_bytes();
+        stream.extend_from_slice(&self.content);
+        stream.extend_from_slice(b"\nendstream");
+        objects.push(stream);
+
+        let info = self.metadata.as_ref().filter(|m| !m.is_empty()).map(|m| {
+            let entries: String = m
+                .entries()
+                .iter()
+                .filter_map(|(key, value)| {
+                    // The creation time is the standard entry of a date, the invalid one is dropped
+                    if key == "Creation Time" {
+                        return make_pdf_date(value)
+                            .map(|date| format!("/CreationDate ({}) ", date));
+                    }
+                    let key: String = key.chars().filter(char::is_ascii_alphanumeric).collect();
+                    Some(format!("/{} {} ", key, make_pdf_text_string(value)))
+                })
+                .collect();
+            format!("<< {}>>", entries).into_bytes()
+        });
+        if let Some(info) = info.as_ref() {
+            objects.push(info.clone());
+        }
+
+        let mut doc = b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n".to_vec();
+        let mut offsets = vec![];
+        for (idx, object) in objects.iter().enumerate() {
+            offsets.push(doc.len());
+            doc.extend_from_slice(format!("{} 0 obj\n", idx + 1).as_bytes());
+            doc.extend_from_slice(object);
+            doc.extend_from_slice(b"\nendobj\n");
+        }
+
+        let xref = doc.len();
+        doc.extend_from_slice(
+            format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).as_bytes(),
+        );
+        for offset in offsets {
+            doc.extend_from_slice(format!("{:010} 00000 n \n", offset).as_bytes());
+        }
+
+        let info_ref = if info.is_some() {
+            format!(" /Info {} 0 R", objects.len())
+        } else {
+            String::new()
+        };
+        doc.extend_from_slice(
+            format!(
+                "trailer\n<< /Size {} /Root 1 0 R{} >>\nstartxref\n{}\n%%EOF\n",
+                objects.len() + 1,
+                info_ref,
+                xref
+            )
+            .as_bytes(),
+        );
+        doc
+    }
+}
+
+impl<'a> DrawingBackend for PdfBackend<'a> {
+    type ErrorType = Error;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.size
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<Error>> {
+        Ok(())
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<Error>> {
+        if !self.saved {
+            let doc = self.build_document();
+            match self.target {
+                Target::File(path) => File::create(path)
+                    .and_then(|mut file| file.write_all(&doc))
+                    .map_err(DrawingErrorKind::DrawingError)?,
+                Target::Buffer(ref mut buf) => {
+                    buf.clear();
+                    buf.extend_from_slice(&doc);
+                }
+            }
+            self.saved = true;
+        }
+        Ok(())
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<Error>> {
+        if !self.begin_fill(color) {
+            return Ok(());
+        }
+        let corner = self.point((f64::from(point.0), f64::from(point.1 + 1)));
+        self.emit(&format!("{} 1 1 re f\n", corner));
+        Ok(())
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if !self.begin_stroke(style) {
+            return Ok(());
+        }
+        self.emit_path(vec![from, to]);
+        self.emit("S\n");
+        Ok(())
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let visible = if fill {
+            self.begin_fill(style)
+        } else {
+            self.begin_stroke(style)
+        };
+        if !visible {
+            return Ok(());
+        }
+        let corner = self.point((f64::from(upper_left.0), f64::from(bottom_right.1)));
+        self.emit(&format!(
+            "{} {} {} re {}\n",
+            corner,
+            bottom_right.0 - upper_left.0,
+            bottom_right.1 - upper_left.1,
+            if fill { "f" } else { "S" }
+        ));
+        Ok(())
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if !self.begin_stroke(style) {
+            return Ok(());
+        }
+        if self.emit_path(path) > 0 {
+            self.emit("S\n");
+        }
+        Ok(())
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if !self.begin_fill(style) {
+            return Ok(());
+        }
+        if self.emit_path(path) > 0 {
+            self.emit("h f\n");
+        }
+        Ok(())
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let visible = if fill {
+            self.begin_fill(style)
+        } else {
+            self.begin_stroke(style)
+        };
+        if !visible {
+            return Ok(());
+        }
+        self.emit_circle(center, radius);
+        self.emit(if fill { "f\n" } else { "S\n" });
+        Ok(())
+    }
+
+    fn draw_text<'b>(
+        &mut self,
+        text: &str,
+        font: &FontDesc<'b>,
+        pos: BackendCoord,
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if !self.begin_fill(color) {
+            return Ok(());
+        }
+        let layout = font.layout_box(text).map_err(DrawingErrorKind::FontError)?;
+
+        let trans = font.get_transform();
+        let offset = trans.offset(layout);
+        let (x0, y0) = (f64::from(pos.0 + offset.0), f64::from(pos.1 + offset.1));
+
+        // The text is rotated clockwise around the top-left corner, and the baseline is
+        // below the corner by the ascent of the text
+        let (sin, cos) = trans.angle().to_radians().sin_cos();
+        let ascent = -f64::from((layout.0).1);
+        let origin = self.point((x0 - ascent * sin, y0 + ascent * cos));
+
+        self.emit(&format!(
+            "BT\n/{} {} Tf\n{} {} {} {} {} Tm\n",
+            pdf_font_name(font.get_name()),
+            make_pdf_float(font.get_size()),
+            make_pdf_float(cos),
+            make_pdf_float(-sin),
+            make_pdf_float(sin),
+            make_pdf_float(cos),
+            origin
+        ));
+        self.content.extend(make_pdf_string(text));
+        self.emit(" Tj\nET\n");
+        Ok(())
+    }
+}
+
+impl Drop for PdfBackend<'_> {
+    fn drop(&mut self) {
+        if !self.saved {
+            self.present().expect("Unable to save the PDF document");
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_make_pdf_string() {
+        assert_eq!(make_pdf_string("a(b)\\"), b"(a\\(b\\)\\\\)".to_vec());
+        assert_eq!(make_pdf_string("\u{b5}\u{4e2d}"), b"(\xb5?)".to_vec());
+        assert_eq!(make_pdf_text_string("Hi"), "<FEFF00480069>");
+    }
+
+    #[test]
+    fn test_make_pdf_date() {
+        assert_eq!(
+            make_pdf_date("2019-12-02T09:30:00+09:00").unwrap(),
+            "D:20191202093000+09'00'"
+        );
+        assert_eq!(
+            make_pdf_date("2019-12-02T09:30:00.123Z").unwrap(),
+            "D:20191202093000Z"
+        );
+        assert_eq!(make_pdf_date("2019-12-02"), None);
+        assert_eq!(make_pdf_date("yesterday at noon, +09:00"), None);
+    }
+
+    #[test]
+    fn test_pdf_document() {
+        let mut buffer = vec![];
+        {
+            let root = PdfBackend::with_buffer(&mut buffer, (200, 100))
+                .metadata(
+                    Metadata::new()
+                        .title("Sales")
+                        .creation_time("2019-12-02T09:30:00-05:00"),
+                )
+                .into_drawing_area();
+            root.fill(&WHITE).unwrap();
+            root.draw(&Rectangle::new([(10, 10), (50, 40)], RED.mix(0.5).filled()))
+                .unwrap();
+            root.draw(&crate::element::Path::new(vec![(0, 0), (200, 100)], &BLUE))
+                .unwrap();
+            root.draw(&Circle::new((100, 50), 10, &BLACK)).unwrap();
+        }
+
+        // Skip the binary comment in the header, so the offsets are same in the text
+        let header = b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n";
+        assert!(buffer.starts_with(header));
+        let content = String::from_utf8(buffer[header.len()..].to_vec()).unwrap();
+        let offset_of = |pos: usize| pos + header.len();
+
+        assert!(content.ends_with("%%EOF\n"));
+        assert!(content.contains("/MediaBox [0 0 200 100]"));
+        assert!(content.contains("/GS500 << /CA 0.5 /ca 0.5 >>"));
+        assert!(content.contains("1 0 0 rg\n10 60 40 30 re f\n"));
+        assert!(content.contains("0 100 m\n200 0 l\nS\n"));
+        assert!(content.contains("/Title <FEFF00530061006C00650073>"));
+        assert!(content.contains("/CreationDate (D:20191202093000-05'00')"));
+        assert!(content.contains("/Info 5 0 R"));
+
+        // Check the cross-reference table points to the objects
+        let xref = content.find("xref\n").unwrap();
+        let start: usize = content[content.find("startxref\n").unwrap() + 10..]
+            .lines()
+            .next()
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert_eq!(start, offset_of(xref));
+        for (idx, line) in content[xref..].lines().skip(3).take(5).enumerate() {
+            let offset: usize = line[..10].parse().unwrap();
+            let obj = &buffer[offset..];
+            assert!(obj.starts_with(format!("{} 0 obj", idx + 1).as_bytes()));
+        }
+    }
+}
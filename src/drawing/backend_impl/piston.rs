@@ -4,6 +4,7 @@ use piston_window::{circle_arc, ellipse, line, rectangle, Event, Loop};
 use piston_window::{G2d, PistonWindow};
 
 use crate::drawing::backend::{BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind};
+use crate::drawing::rasterizer;
 use crate::style::{Color, RGBAColor};
 
 pub struct PistonBackend<'a, 'b> {
@@ -90,6 +91,10 @@ impl<'a, 'b> DrawingBackend for PistonBackend<'a, 'b> {
         to: BackendCoord,
         style: &S,
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if !style.stroke_dash().0.is_empty() {
+            return rasterizer::draw_dashed_path(self, &[from, to], style);
+        }
+
         line(
             make_piston_rgba(&style.as_color()),
             self.scale,
@@ -118,6 +123,14 @@ impl<'a, 'b> DrawingBackend for PistonBackend<'a, 'b> {
                 self.context.transform,
                 self.graphics,
             );
+        } else if !style.stroke_dash().0.is_empty() {
+            let (x0, y0) = upper_left;
+            let (x1, y1) = bottom_right;
+            return rasterizer::draw_dashed_path(
+                self,
+                &[(x0, y0), (x1, y0), (x1, y1), (x0, y1), (x0, y0)],
+                style,
+            );
         } else {
             let color = make_piston_rgba(&style.as_color());
             let [x0, y0, x1, y1] = make_point_pair(upper_left, bottom_right, self.scale);
@@ -2,11 +2,18 @@
 The SVG image drawing backend
 */
 
-use svg::node::element::{Circle, Line, Polygon, Polyline, Rectangle, Text};
-use svg::Document;
+#[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+use svg::node::element::Image;
+use svg::node::element::{
+    Circle, Description, Element, Group, Line, Polygon, Polyline, Rectangle, Text, Title,
+};
+use svg::{Document, Node};
 
+#[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+use crate::drawing::backend::bitmap_len;
 use crate::drawing::backend::{BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind};
-use crate::style::{Color, FontDesc, FontTransform, RGBAColor};
+use crate::drawing::Metadata;
+use crate::style::{deterministic_output, Color, FontDesc, RGBAColor};
 
 use std::io::{Cursor, Error};
 use std::path::Path;
@@ -17,7 +24,45 @@ fn make_svg_color<C: Color>(color: &C) -> String {
 }
 
 fn make_svg_opacity<C: Color>(color: &C) -> String {
-    return format!("{}", color.alpha());
+    make_svg_float(color.alpha())
+}
+
+/// Get the `stroke-dasharray` and `stroke-dashoffset` of the style, if the stroke is dashed
+fn make_svg_dash<S: BackendStyle>(style: &S) -> Option<(String, u32)> {
+    let (pattern, offset) = style.stroke_dash();
+    if pattern.is_empty() {
+        return None;
+    }
+    let pattern: Vec<_> = pattern.iter().map(|v| v.to_string()).collect();
+    Some((pattern.join(" "), offset))
+}
+
+fn make_svg_float(value: f64) -> String {
+    if deterministic_output() {
+        // Adding zero turns the negative zero into zero, which is formatted without the sign
+        return format!("{}", (value * 1000.0).round() / 1000.0 + 0.0);
+    }
+    format!("{}", value)
+}
+
+/// Encode the data in base64, which is used to embed the images as data URIs
+#[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+fn encode_base64(data: &[u8]) -> String {
+    const TABLE: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut result = String::with_capacity(data.len() * 4 / 3 + 4);
+    for chunk in data.chunks(3) {
+        let bits = chunk.iter().enumerate().fold(0u32, |bits, (idx, byte)| {
+            bits | u32::from(*byte) << (16 - idx * 8)
+        });
+        for idx in 0..4 {
+            if idx <= chunk.len() {
+                result.push(TABLE[(bits >> (18 - idx * 6) & 0x3f) as usize] as char);
+            } else {
+                result.push('=');
+            }
+        }
+    }
+    result
 }
 
 enum Target<'a> {
@@ -30,6 +75,8 @@ pub struct SVGBackend<'a> {
     target: Target<'a>,
     size: (u32, u32),
     document: Option<Document>,
+    /// The groups that haven't been ended yet, the innermost one is the last
+    groups: Vec<Group>,
     saved: bool,
 }
 
@@ -40,12 +87,22 @@ impl<'a> SVGBackend<'a> {
         self.document = Some(op(temp.unwrap()));
     }
 
+    /// Add the node to the innermost group, or to the document if there's no group
+    fn add_node<N: Node>(&mut self, node: N) {
+        if let Some(group) = self.groups.pop() {
+            self.groups.push(group.add(node));
+        } else {
+            self.update_document(|d| d.add(node));
+        }
+    }
+
     /// Create a new SVG drawing backend
     pub fn new<T: AsRef<Path> + ?Sized>(path: &'a T, size: (u32, u32)) -> Self {
         Self {
             target: Target::File(path.as_ref()),
             size,
             document: Some(Document::new().set("viewBox", (0, 0, size.0, size.1))),
+            groups: vec![],
             saved: false,
         }
     }
@@ -56,9 +113,39 @@ impl<'a> SVGBackend<'a> {
             target: Target::Buffer(Cursor::new(buf)),
             size,
             document: Some(Document::new().set("viewBox", (0, 0, size.0, size.1))),
+            groups: vec![],
             saved: false,
         }
     }
+
+    /// Set the accessible title of the image, which is emitted as the `<title>` element and
+    /// the `aria-label` of the image, thus it's read by the screen readers.
+    pub fn title(mut self, title: &str) -> Self {
+        let node = Title::new().add(svg::node::Text::new(title));
+        self.update_document(|d| d.set("role", "img").set("aria-label", title).add(node));
+        self
+    }
+
+    /// Set the accessible description of the image, which is emitted as the `<desc>` element
+    pub fn description(mut self, description: &str) -> Self {
+        let node = Description::new().add(svg::node::Text::new(description));
+        self.update_document(|d| d.set("role", "img").add(node));
+        self
+    }
+
+    /// Set the metadata of the image, which is written as the `<metadata>` element with an
+    /// `<entry key="...">` child for each entry
+    pub fn metadata(mut self, metadata: Metadata) -> Self {
+        let mut node = Element::new("metadata");
+        for (key, value) in metadata.entries() {
+            let mut entry = Element::new("entry");
+            entry.assign("key", key.clone());
+            entry.append(svg::node::Text::new(value.clone()));
+            node.append(entry);
+        }
+        self.update_document(|d| d.add(node));
+        self
+    }
 }
 
 impl<'a> DrawingBackend for SVGBackend<'a> {
@@ -74,6 +161,9 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
 
     fn present(&mut self) -> Result<(), DrawingErrorKind<Error>> {
         if !self.saved {
+            while let Some(group) = self.groups.pop() {
+                self.add_node(group);
+            }
             match self.target {
                 Target::File(path) => svg::save(path, self.document.as_ref().unwrap())
                     .map_err(DrawingErrorKind::DrawingError)?,
@@ -85,6 +175,24 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
         Ok(())
     }
 
+    fn begin_group(&mut self, description: &str) -> Result<(), DrawingErrorKind<Error>> {
+        let title = Title::new().add(svg::node::Text::new(description));
+        self.groups.push(
+            Group::new()
+                .set("role", "group")
+                .set("aria-label", description)
+                .add(title),
+        );
+        Ok(())
+    }
+
+    fn end_group(&mut self) -> Result<(), DrawingErrorKind<Error>> {
+        if let Some(group) = self.groups.pop() {
+            self.add_node(group);
+        }
+        Ok(())
+    }
+
     fn draw_pixel(
         &mut self,
         point: BackendCoord,
@@ -101,7 +209,7 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
             .set("stroke", "none")
             .set("opacity", make_svg_opacity(color))
             .set("fill", make_svg_color(color));
-        self.update_document(|d| d.add(node));
+        self.add_node(node);
         Ok(())
     }
 
@@ -114,7 +222,7 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
         if style.as_color().alpha() == 0.0 {
             return Ok(());
         }
-        let node = Line::new()
+        let mut node = Line::new()
             .set("x1", from.0)
             .set("y1", from.1)
             .set("x2", to.0)
@@ -122,7 +230,12 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
             .set("opacity", make_svg_opacity(&style.as_color()))
             .set("stroke", make_svg_color(&style.as_color()))
             .set("stroke-width", style.stroke_width());
-        self.update_document(|d| d.add(node));
+        if let Some((pattern, offset)) = make_svg_dash(style) {
+            node = node
+                .set("stroke-dasharray", pattern)
+                .set("stroke-dashoffset", offset);
+        }
+        self.add_node(node);
         Ok(())
     }
 
@@ -147,6 +260,11 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
                 .set("opacity", make_svg_opacity(&style.as_color()))
                 .set("stroke", make_svg_color(&style.as_color()))
                 .set("fill", "none");
+            if let Some((pattern, offset)) = make_svg_dash(style) {
+                node = node
+                    .set("stroke-dasharray", pattern)
+                    .set("stroke-dashoffset", offset);
+            }
         } else {
             node = node
                 .set("opacity", make_svg_opacity(&style.as_color()))
@@ -154,7 +272,7 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
                 .set("stroke", "none");
         }
 
-        self.update_document(|d| d.add(node));
+        self.add_node(node);
         Ok(())
     }
 
@@ -166,7 +284,7 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
         if style.as_color().alpha() == 0.0 {
             return Ok(());
         }
-        let node = Polyline::new()
+        let mut node = Polyline::new()
             .set("fill", "none")
             .set("opacity", make_svg_opacity(&style.as_color()))
             .set("stroke", make_svg_color(&style.as_color()))
@@ -178,7 +296,12 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
                     s
                 }),
             );
-        self.update_document(|d| d.add(node));
+        if let Some((pattern, offset)) = make_svg_dash(style) {
+            node = node
+                .set("stroke-dasharray", pattern)
+                .set("stroke-dashoffset", offset);
+        }
+        self.add_node(node);
         Ok(())
     }
 
@@ -200,7 +323,7 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
                     s
                 }),
             );
-        self.update_document(|d| d.add(node));
+        self.add_node(node);
         Ok(())
     }
 
@@ -231,9 +354,38 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
                 .set("stroke", "none");
         }
 
-        self.update_document(|d| d.add(node));
+        self.add_node(node);
         Ok(())
     }
+    #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+    fn blit_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        (iw, ih): (u32, u32),
+        src: &[u8],
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        match bitmap_len((iw, ih)) {
+            Some(len) if src.len() >= len => {}
+            _ => return Ok(()),
+        }
+        let mut png = vec![];
+        image::png::PNGEncoder::new(&mut png)
+            .encode(src, iw, ih, image::RGB(8))
+            .map_err(DrawingErrorKind::DrawingError)?;
+
+        let node = Image::new()
+            .set("x", pos.0)
+            .set("y", pos.1)
+            .set("width", iw)
+            .set("height", ih)
+            .set(
+                "href",
+                format!("data:image/png;base64,{}", encode_base64(&png)),
+            );
+        self.add_node(node);
+        Ok(())
+    }
+
     fn draw_text<'b>(
         &mut self,
         text: &str,
@@ -256,23 +408,22 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
             .set("x", x0)
             .set("y", y0 - (layout.0).1)
             .set("font-family", font.get_name())
-            .set("font-size", font.get_size())
+            .set("font-size", make_svg_float(font.get_size()))
             .set("opacity", make_svg_opacity(color))
             .set("fill", make_svg_color(color));
 
-        let node = match trans {
-            FontTransform::Rotate90 => node.set("transform", format!("rotate(90, {}, {})", x0, y0)),
-            FontTransform::Rotate180 => {
-                node.set("transform", format!("rotate(180, {}, {})", x0, y0))
-            }
-            FontTransform::Rotate270 => {
-                node.set("transform", format!("rotate(270, {}, {})", x0, y0))
-            }
-            _ => node,
+        let angle = trans.angle();
+        let node = if angle != 0.0 {
+            node.set(
+                "transform",
+                format!("rotate({}, {}, {})", make_svg_float(angle), x0, y0),
+            )
+        } else {
+            node
         }
         .add(context);
 
-        self.update_document(|d| d.add(node));
+        self.add_node(node);
 
         Ok(())
     }
@@ -285,3 +436,78 @@ impl Drop for SVGBackend<'_> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_accessible_description() {
+        let mut buffer = vec![];
+        {
+            let root = SVGBackend::with_buffer(&mut buffer, (100, 100))
+                .title("Sales")
+                .description("Monthly sales of 2019")
+                .into_drawing_area();
+            let mut chart = ChartBuilder::on(&root)
+                .build_ranged(0..10, 0..10)
+                .expect("Create chart");
+            chart
+                .draw_series_with_description(
+                    LineSeries::new(vec![(0, 0), (5, 5)], &RED),
+                    "Sales of store A",
+                )
+                .expect("Drawing failure");
+        }
+        let content = String::from_utf8(buffer).unwrap();
+        assert!(content.contains("role=\"img\""));
+        assert!(content.contains("aria-label=\"Sales\""));
+        assert!(content.contains("<desc>"));
+        assert!(content.contains("Monthly sales of 2019"));
+        assert!(content.contains("aria-label=\"Sales of store A\""));
+        assert!(content.contains("role=\"group\""));
+    }
+
+    #[test]
+    fn test_metadata() {
+        let mut buffer = vec![];
+        {
+            let root = SVGBackend::with_buffer(&mut buffer, (100, 100))
+                .metadata(Metadata::new().author("Alice").entry("Source", "db"))
+                .into_drawing_area();
+            root.fill(&WHITE).unwrap();
+        }
+        let content = String::from_utf8(buffer).unwrap();
+        assert!(content.contains("<metadata>"));
+        assert!(content.contains("<entry key=\"Author\">"));
+        assert!(content.contains("Alice"));
+        assert!(content.contains("<entry key=\"Source\">"));
+    }
+
+    #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+    #[test]
+    fn test_encode_base64() {
+        assert_eq!(encode_base64(b""), "");
+        assert_eq!(encode_base64(b"f"), "Zg==");
+        assert_eq!(encode_base64(b"fo"), "Zm8=");
+        assert_eq!(encode_base64(b"foo"), "Zm9v");
+        assert_eq!(encode_base64(b"foobar"), "Zm9vYmFy");
+    }
+
+    #[test]
+    fn test_dashed_stroke() {
+        let mut buffer = vec![];
+        {
+            let root = SVGBackend::with_buffer(&mut buffer, (100, 100)).into_drawing_area();
+            root.draw(&crate::element::Path::new(
+                vec![(0, 50), (100, 50)],
+                ShapeStyle::from(&BLACK).dashed(&[5, 3], 2),
+            ))
+            .unwrap();
+        }
+        let content = String::from_utf8(buffer).unwrap();
+        assert!(content.contains("stroke-dasharray=\"5 3\""));
+        assert!(content.contains("stroke-dashoffset=\"2\""));
+    }
+}
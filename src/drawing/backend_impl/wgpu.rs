@@ -0,0 +1,372 @@
+/*!
+The GPU drawing backend based on `wgpu`
+*/
+use wgpu::util::DeviceExt;
+
+use crate::drawing::backend::{BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind};
+use crate::drawing::rasterizer;
+use crate::style::{Color, RGBAColor};
+
+const SHADER: &str = r"
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) color: vec4<f32>,
+};
+
+@vertex
+fn vs_main(@location(0) position: vec2<f32>, @location(1) color: vec4<f32>) -> VertexOutput {
+    var out: VertexOutput;
+    out.position = vec4<f32>(position, 0.0, 1.0);
+    out.color = color;
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    return in.color;
+}
+";
+
+#[derive(Debug)]
+pub struct WgpuError(String);
+
+impl std::fmt::Display for WgpuError {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        write!(fmt, "wgpu Error: {}", self.0)
+    }
+}
+
+impl std::error::Error for WgpuError {}
+
+/// The vertex uploaded to the GPU, the position is in the normalized device coordinate
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Vertex {
+    position: [f32; 2],
+    color: [f32; 4],
+}
+
+/// Tessellates the primitives into triangles, the input points are in pixels
+struct Tessellator {
+    size: (f32, f32),
+    vertices: Vec<Vertex>,
+}
+
+impl Tessellator {
+    fn new((w, h): (u32, u32)) -> Self {
+        Self {
+            size: (w as f32, h as f32),
+            vertices: vec![],
+        }
+    }
+
+    fn push_triangle(&mut self, points: [(f32, f32); 3], color: &RGBAColor) {
+        let (r, g, b) = color.rgb();
+        let color = [
+            f32::from(r) / 255.0,
+            f32::from(g) / 255.0,
+            f32::from(b) / 255.0,
+            color.alpha().clamp(0.0, 1.0) as f32,
+        ];
+        for (x, y) in points.iter() {
+            self.vertices.push(Vertex {
+                position: [x / self.size.0 * 2.0 - 1.0, 1.0 - y / self.size.1 * 2.0],
+                color,
+            });
+        }
+    }
+
+    fn push_quad(&mut self, points: [(f32, f32); 4], color: &RGBAColor) {
+        self.push_triangle([points[0], points[1], points[2]], color);
+        self.push_triangle([points[0], points[2], points[3]], color);
+    }
+
+    /// Push the axis-aligned box, which covers the pixels from `(x0, y0)` to `(x1, y1)`
+    /// (both inclusive)
+    fn push_box(&mut self, (x0, y0): BackendCoord, (x1, y1): BackendCoord, color: &RGBAColor) {
+        let (x0, x1) = (x0.min(x1) as f32, x0.max(x1) as f32 + 1.0);
+        let (y0, y1) = (y0.min(y1) as f32, y0.max(y1) as f32 + 1.0);
+        self.push_quad([(x0, y0), (x1, y0), (x1, y1), (x0, y1)], color);
+    }
+
+    /// Push the line between the centers of the two pixels, extruded to the width
+    fn push_line(&mut self, from: BackendCoord, to: BackendCoord, width: u32, color: &RGBAColor) {
+        let (x0, y0) = (from.0 as f32 + 0.5, from.1 as f32 + 0.5);
+        let (x1, y1) = (to.0 as f32 + 0.5, to.1 as f32 + 0.5);
+        let half = width.max(1) as f32 / 2.0;
+        let len = ((x1 - x0).powi(2) + (y1 - y0).powi(2)).sqrt();
+
+        if len == 0.0 {
+            self.push_quad(
+                [
+                    (x0 - half, y0 - half),
+                    (x0 + half, y0 - half),
+                    (x0 + half, y0 + half),
+                    (x0 - half, y0 + half),
+                ],
+                color,
+            );
+            return;
+        }
+
+        // Extend the line by half pixel on both ends, so the end pixels are fully covered
+        let (dx, dy) = ((x1 - x0) / len * 0.5, (y1 - y0) / len * 0.5);
+        let (nx, ny) = (-dy * 2.0 * half, dx * 2.0 * half);
+        let (x0, y0, x1, y1) = (x0 - dx, y0 - dy, x1 + dx, y1 + dy);
+        self.push_quad(
+            [
+                (x0 + nx, y0 + ny),
+                (x1 + nx, y1 + ny),
+                (x1 - nx, y1 - ny),
+                (x0 - nx, y0 - ny),
+            ],
+            color,
+        );
+    }
+}
+
+/// The backend that renders on the GPU with `wgpu`.
+///
+/// All the primitives are tessellated into triangles, which are buffered and rendered to the
+/// target texture in a single draw call when the frame is presented, thus a large amount of
+/// series can be rendered at interactive rates.
+/// The target is drawn on top of its current content, so clear it with `DrawingArea::fill`
+/// or with the render pass of the application before drawing a new frame.
+pub struct WgpuBackend<'a> {
+    device: &'a wgpu::Device,
+    queue: &'a wgpu::Queue,
+    target: &'a wgpu::TextureView,
+    size: (u32, u32),
+    pipeline: wgpu::RenderPipeline,
+    tessellator: Tessellator,
+}
+
+impl<'a> WgpuBackend<'a> {
+    /// Create a new backend that renders to a texture
+    /// - `device`: The device used to render
+    /// - `queue`: The queue which the commands are submitted to
+    /// - `target`: The view of the texture to render to, e.g. the texture of a surface
+    /// - `format`: The format of the texture
+    /// - `size`: The size of the texture
+    pub fn new(
+        device: &'a wgpu::Device,
+        queue: &'a wgpu::Queue,
+        target: &'a wgpu::TextureView,
+        format: wgpu::TextureFormat,
+        size: (u32, u32),
+    ) -> Self {
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("plotters"),
+            source: wgpu::ShaderSource::Wgsl(SHADER.into()),
+        });
+        let layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("plotters"),
+            bind_group_layouts: &[],
+            push_constant_ranges: &[],
+        });
+        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("plotters"),
+            layout: Some(&layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: "vs_main",
+                buffers: &[wgpu::VertexBufferLayout {
+                    array_stride: std::mem::size_of::<Vertex>() as wgpu::BufferAddress,
+                    step_mode: wgpu::VertexStepMode::Vertex,
+                    attributes: &wgpu::vertex_attr_array![0 => Float32x2, 1 => Float32x4],
+                }],
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: "fs_main",
+                targets: &[Some(wgpu::ColorTargetState {
+                    format,
+                    blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+            }),
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+        });
+
+        Self {
+            device,
+            queue,
+            target,
+            size,
+            pipeline,
+            tessellator: Tessellator::new(size),
+        }
+    }
+}
+
+impl<'a> DrawingBackend for WgpuBackend<'a> {
+    type ErrorType = WgpuError;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.size
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<WgpuError>> {
+        Ok(())
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<WgpuError>> {
+        let vertices = &self.tessellator.vertices;
+        if vertices.is_empty() {
+            return Ok(());
+        }
+
+        // The vertex is a plain old data type with no padding, so it can be uploaded as bytes
+        let contents = unsafe {
+            std::slice::from_raw_parts(
+                vertices.as_ptr() as *const u8,
+                vertices.len() * std::mem::size_of::<Vertex>(),
+            )
+        };
+        let buffer = self
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("plotters"),
+                contents,
+                usage: wgpu::BufferUsages::VERTEX,
+            });
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("plotters"),
+            });
+        {
+            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("plotters"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: self.target,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Load,
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            pass.set_pipeline(&self.pipeline);
+            pass.set_vertex_buffer(0, buffer.slice(..));
+            pass.draw(0..vertices.len() as u32, 0..1);
+        }
+        self.queue.submit(Some(encoder.finish()));
+
+        self.tessellator.vertices.clear();
+        Ok(())
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<WgpuError>> {
+        if color.alpha() > 0.0 {
+            self.tessellator.push_box(point, point, color);
+        }
+        Ok(())
+    }
+
+    fn draw_pixels(
+        &mut self,
+        pixels: &[(BackendCoord, RGBAColor)],
+    ) -> Result<(), DrawingErrorKind<WgpuError>> {
+        for (point, color) in pixels {
+            if color.alpha() > 0.0 {
+                self.tessellator.push_box(*point, *point, color);
+            }
+        }
+        Ok(())
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<WgpuError>> {
+        if !style.stroke_dash().0.is_empty() {
+            return rasterizer::draw_dashed_path(self, &[from, to], style);
+        }
+
+        let color = style.as_color();
+        if color.alpha() > 0.0 {
+            self.tessellator
+                .push_line(from, to, style.stroke_width(), &color);
+        }
+        Ok(())
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<WgpuError>> {
+        let color = style.as_color();
+        if color.alpha() <= 0.0 {
+            return Ok(());
+        }
+
+        let ((x0, y0), (x1, y1)) = (upper_left, bottom_right);
+        if !fill && !style.stroke_dash().0.is_empty() {
+            return rasterizer::draw_dashed_path(
+                self,
+                &[(x0, y0), (x1, y0), (x1, y1), (x0, y1), (x0, y0)],
+                style,
+            );
+        }
+        if fill {
+            self.tessellator.push_box((x0, y0), (x1, y1), &color);
+        } else {
+            self.tessellator.push_box((x0, y0), (x1, y0), &color);
+            self.tessellator.push_box((x0, y1), (x1, y1), &color);
+            self.tessellator.push_box((x0, y0), (x0, y1), &color);
+            self.tessellator.push_box((x1, y0), (x1, y1), &color);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::style::RED;
+
+    #[test]
+    fn test_tessellate_box() {
+        let mut tessellator = Tessellator::new((10, 10));
+        tessellator.push_box((0, 0), (4, 9), &RED.to_rgba());
+
+        assert_eq!(tessellator.vertices.len(), 6);
+        let positions: Vec<_> = tessellator.vertices.iter().map(|v| v.position).collect();
+        assert_eq!(positions[0], [-1.0, 1.0]);
+        assert_eq!(positions[2], [0.0, -1.0]);
+        assert_eq!(tessellator.vertices[0].color, [1.0, 0.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_tessellate_line() {
+        let mut tessellator = Tessellator::new((10, 10));
+        tessellator.push_line((1, 4), (8, 4), 2, &RED.to_rgba());
+
+        let positions: Vec<_> = tessellator.vertices.iter().map(|v| v.position).collect();
+        // The line covers the pixels from x = 1 to x = 8 and from y = 3.5 to y = 5.5
+        let to_pixel = |p: [f32; 2]| {
+            let round = |v: f32| (v * 1000.0).round() / 1000.0;
+            (round((p[0] + 1.0) * 5.0), round((1.0 - p[1]) * 5.0))
+        };
+        assert_eq!(to_pixel(positions[0]), (1.0, 5.5));
+        assert_eq!(to_pixel(positions[1]), (9.0, 5.5));
+        assert_eq!(to_pixel(positions[2]), (9.0, 3.5));
+    }
+}
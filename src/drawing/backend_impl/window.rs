@@ -0,0 +1,240 @@
+/*!
+The real-time drawing backend which draws on a native window with `minifb`
+*/
+use minifb::{Key, KeyRepeat, MouseButton, MouseMode, Window, WindowOptions};
+
+use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use crate::style::{Color, RGBAColor};
+
+/// The event happened on the window since the last time the events were polled
+#[derive(Clone, Debug, PartialEq)]
+pub enum WindowEvent {
+    /// A key is pressed
+    KeyPressed(Key),
+    /// A key is released
+    KeyReleased(Key),
+    /// The mouse is moved to the position in pixels
+    MouseMoved(BackendCoord),
+    /// A mouse button is pressed at the position in pixels
+    MousePressed(MouseButton, BackendCoord),
+    /// A mouse button is released at the position in pixels
+    MouseReleased(MouseButton, BackendCoord),
+    /// The mouse wheel is scrolled, by the horizontal and vertical amount
+    Scrolled(f32, f32),
+    /// The window is closed by the user
+    Closed,
+}
+
+const MOUSE_BUTTONS: [MouseButton; 3] =
+    [MouseButton::Left, MouseButton::Middle, MouseButton::Right];
+
+/// Blend the color into the pixel, which is stored as `0RGB` as the window requires
+fn blend_pixel(old: u32, color: &RGBAColor) -> u32 {
+    let alpha = color.alpha().clamp(0.0, 1.0);
+    let (r, g, b) = color.rgb();
+    let blend = |shift: u32, new: u8| {
+        let old = f64::from((old >> shift) & 0xff);
+        let value = old * (1.0 - alpha) + f64::from(new) * alpha;
+        (value.round() as u32).min(255) << shift
+    };
+    blend(16, r) | blend(8, g) | blend(0, b)
+}
+
+/// The real-time drawing backend which draws on a native window. The frame is drawn in memory,
+/// and `present` shows it on the window. The events of the window are collected whenever the
+/// window is updated, and `poll_events` takes them, thus a live-updating dashboard is built with
+/// a loop which polls the events, redraws the chart and presents the frame.
+///
+/// ```rust,no_run
+/// use plotters::prelude::*;
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+///
+/// let backend = Rc::new(RefCell::new(WindowBackend::new("Live", (640, 480)).unwrap()));
+/// let root = DrawingArea::from(&backend);
+/// let mut frame = 0;
+/// while backend.borrow().is_open() {
+///     let events = backend.borrow_mut().poll_events();
+///     if events.contains(&WindowEvent::KeyPressed(WindowKey::Escape)) {
+///         break;
+///     }
+///     root.fill(&WHITE).unwrap();
+///     root.draw(&Circle::new((frame % 640, 240), 10, RED.filled()))
+///         .unwrap();
+///     root.present().unwrap();
+///     frame += 1;
+/// }
+/// ```
+pub struct WindowBackend {
+    window: Window,
+    size: (u32, u32),
+    buffer: Vec<u32>,
+    events: Vec<WindowEvent>,
+    mouse_pos: Option<BackendCoord>,
+    mouse_down: [bool; 3],
+    closed: bool,
+    dirty: Option<(BackendCoord, BackendCoord)>,
+    shown: bool,
+}
+
+impl WindowBackend {
+    /// Open a new window
+    /// - `title`: The title of the window
+    /// - `size`: The size of the window in pixels
+    pub fn new(title: &str, size: (u32, u32)) -> Result<Self, minifb::Error> {
+        let window = Window::new(
+            title,
+            size.0 as usize,
+            size.1 as usize,
+            WindowOptions::default(),
+        )?;
+        Ok(Self {
+            window,
+            size,
+            buffer: vec![0xff_ffff; size.0 as usize * size.1 as usize],
+            events: vec![],
+            mouse_pos: None,
+            mouse_down: [false; 3],
+            closed: false,
+            dirty: None,
+            shown: false,
+        })
+    }
+
+    /// Limit the frame rate of the window, `present` waits if the frames come faster
+    /// - `fps`: The maximum number of frames per second
+    pub fn target_fps(mut self, fps: usize) -> Self {
+        self.window.set_target_fps(fps);
+        self
+    }
+
+    /// Check if the window is still open
+    pub fn is_open(&self) -> bool {
+        !self.closed && self.window.is_open()
+    }
+
+    /// Process the pending events of the window without presenting a new frame, and take all
+    /// the events collected since the last call
+    pub fn poll_events(&mut self) -> Vec<WindowEvent> {
+        self.window.update();
+        self.collect_events();
+        std::mem::take(&mut self.events)
+    }
+
+    /// Get the region that has been modified since the last time the frame was presented, as
+    /// the upper-left and bottom-right corners (both inclusive) of the pixels drawn.
+    /// Returns `None` if nothing has been drawn, in which case `present` only processes the
+    /// events and leaves the frame on the window as it is.
+    pub fn dirty_region(&self) -> Option<(BackendCoord, BackendCoord)> {
+        self.dirty
+    }
+
+    /// Get the underlying window, e.g. to change the title or query the state of a key
+    pub fn window(&mut self) -> &mut Window {
+        &mut self.window
+    }
+
+    /// Translate the state of the window after an update into the events
+    fn collect_events(&mut self) {
+        for key in self.window.get_keys_pressed(KeyRepeat::No) {
+            self.events.push(WindowEvent::KeyPressed(key));
+        }
+        for key in self.window.get_keys_released() {
+            self.events.push(WindowEvent::KeyReleased(key));
+        }
+
+        let pos = self
+            .window
+            .get_mouse_pos(MouseMode::Discard)
+            .map(|(x, y)| (x as i32, y as i32));
+        if let Some(pos) = pos {
+            if self.mouse_pos != Some(pos) {
+                self.events.push(WindowEvent::MouseMoved(pos));
+            }
+        }
+        self.mouse_pos = pos.or(self.mouse_pos);
+
+        let pos = self.mouse_pos.unwrap_or((0, 0));
+        for (idx, button) in MOUSE_BUTTONS.iter().enumerate() {
+            let down = self.window.get_mouse_down(*button);
+            if down != self.mouse_down[idx] {
+                self.events.push(if down {
+                    WindowEvent::MousePressed(*button, pos)
+                } else {
+                    WindowEvent::MouseReleased(*button, pos)
+                });
+                self.mouse_down[idx] = down;
+            }
+        }
+
+        if let Some((dx, dy)) = self.window.get_scroll_wheel() {
+            self.events.push(WindowEvent::Scrolled(dx, dy));
+        }
+
+        if !self.closed && !self.window.is_open() {
+            self.closed = true;
+            self.events.push(WindowEvent::Closed);
+        }
+    }
+}
+
+impl DrawingBackend for WindowBackend {
+    type ErrorType = minifb::Error;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.size
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<minifb::Error>> {
+        Ok(())
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<minifb::Error>> {
+        // Uploading the frame is the expensive part, so skip it if nothing has changed
+        if self.shown && self.dirty.is_none() {
+            self.window.update();
+        } else {
+            self.window
+                .update_with_buffer(&self.buffer, self.size.0 as usize, self.size.1 as usize)
+                .map_err(DrawingErrorKind::DrawingError)?;
+            self.shown = true;
+        }
+        self.dirty = None;
+        self.collect_events();
+        Ok(())
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<minifb::Error>> {
+        let (w, h) = (self.size.0 as i32, self.size.1 as i32);
+        if point.0 < 0 || point.1 < 0 || point.0 >= w || point.1 >= h || color.alpha() <= 0.0 {
+            return Ok(());
+        }
+        let pixel = &mut self.buffer[(point.1 * w + point.0) as usize];
+        *pixel = blend_pixel(*pixel, color);
+        self.dirty = Some(match self.dirty {
+            Some(((x0, y0), (x1, y1))) => (
+                (x0.min(point.0), y0.min(point.1)),
+                (x1.max(point.0), y1.max(point.1)),
+            ),
+            None => (point, point),
+        });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::style::{RED, WHITE};
+
+    #[test]
+    fn test_blend_pixel() {
+        assert_eq!(blend_pixel(0, &RED.to_rgba()), 0xff_0000);
+        assert_eq!(blend_pixel(0xff_ffff, &WHITE.to_rgba()), 0xff_ffff);
+        assert_eq!(blend_pixel(0xff_ffff, &RED.mix(0.5)), 0xff_8080);
+    }
+}
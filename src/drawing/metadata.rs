@@ -0,0 +1,55 @@
+/// The metadata of a figure, which is embedded in the output file by the backends supporting it,
+/// so that the generated figures are traceable in the archival pipelines.
+///
+/// The PNG images of `BitMapBackend` carry the metadata as `tEXt` chunks, and `SVGBackend`
+/// writes it as the `<metadata>` element.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Metadata {
+    entries: Vec<(String, String)>,
+}
+
+impl Metadata {
+    /// Create an empty metadata
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the title of the figure
+    pub fn title<S: Into<String>>(self, title: S) -> Self {
+        self.entry("Title", title)
+    }
+
+    /// Set the author of the figure
+    pub fn author<S: Into<String>>(self, author: S) -> Self {
+        self.entry("Author", author)
+    }
+
+    /// Set the creation time of the figure in the RFC 3339 format, e.g. `2019-12-02T09:30:00Z`.
+    /// The PDF backend drops the time in the other formats.
+    pub fn creation_time<S: Into<String>>(self, time: S) -> Self {
+        self.entry("Creation Time", time)
+    }
+
+    /// Set a custom entry, which replaces the existing entry with the same key
+    /// - `key`: The key of the entry, which should be 1 to 79 Latin-1 chars for PNG
+    /// - `value`: The value of the entry
+    pub fn entry<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        let (key, value) = (key.into(), value.into());
+        if let Some(entry) = self.entries.iter_mut().find(|(k, _)| *k == key) {
+            entry.1 = value;
+        } else {
+            self.entries.push((key, value));
+        }
+        self
+    }
+
+    /// Get all the entries, in the order they were set
+    pub fn entries(&self) -> &[(String, String)] {
+        &self.entries
+    }
+
+    /// Check if there's no entry
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
@@ -14,11 +14,14 @@ Currently we have following backend implemented:
 - `BitMapBackend`: The backend that creates bitmap, this is based on `image` crate
 - `SVGBackend`: The backend that creates SVG image, based on `svg` crate.
 - `PistonBackend`: The backend that uses Piston Window for realtime rendering. Disabled by default, use feature `piston` to turn on.
+- `WgpuBackend`: The backend that renders on the GPU with `wgpu`. Disabled by default, use feature `wgpu` to turn on.
 - `CanvasBackend`: The backend that operates HTML5 Canvas, this is availible when `Plotters` is targeting WASM.
+- `ImageDataBackend`: The backend that rasterizes into RGBA `ImageData` without the DOM, this is availible when `Plotters` is targeting WASM.
 
 */
 mod area;
 mod backend_impl;
+mod metadata;
 mod rasterizer;
 
 pub mod backend;
@@ -27,4 +30,8 @@ pub use area::{DrawingArea, DrawingAreaErrorKind, IntoDrawingArea};
 
 pub use backend_impl::*;
 
+pub use metadata::Metadata;
+
 pub use backend::DrawingBackend;
+
+pub(crate) use rasterizer::clip_path;
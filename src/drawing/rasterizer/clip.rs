@@ -0,0 +1,120 @@
+use crate::drawing::backend::BackendCoord;
+
+/// Clip the line segment against the rectangle with the Liang-Barsky algorithm.
+/// Returns the visible part of the segment, or `None` if it's entirely out of the rectangle.
+/// - `upper_left`, `bottom_right`: The corners of the rectangle, both inclusive
+pub(crate) fn clip_line(
+    from: BackendCoord,
+    to: BackendCoord,
+    upper_left: BackendCoord,
+    bottom_right: BackendCoord,
+) -> Option<(BackendCoord, BackendCoord)> {
+    let (x0, y0) = (f64::from(from.0), f64::from(from.1));
+    let (dx, dy) = (f64::from(to.0) - x0, f64::from(to.1) - y0);
+
+    let (mut t0, mut t1) = (0.0f64, 1.0f64);
+    for &(p, q) in &[
+        (-dx, x0 - f64::from(upper_left.0)),
+        (dx, f64::from(bottom_right.0) - x0),
+        (-dy, y0 - f64::from(upper_left.1)),
+        (dy, f64::from(bottom_right.1) - y0),
+    ] {
+        if p == 0.0 {
+            // The segment is parallel to this edge, and it's visible only if it's inside
+            if q < 0.0 {
+                return None;
+            }
+            continue;
+        }
+        let t = q / p;
+        if p < 0.0 {
+            if t > t1 {
+                return None;
+            }
+            t0 = t0.max(t);
+        } else {
+            if t < t0 {
+                return None;
+            }
+            t1 = t1.min(t);
+        }
+    }
+
+    let point = |t: f64| ((x0 + t * dx).round() as i32, (y0 + t * dy).round() as i32);
+    Some((
+        if t0 == 0.0 { from } else { point(t0) },
+        if t1 == 1.0 { to } else { point(t1) },
+    ))
+}
+
+/// Clip the polyline against the rectangle, the polyline is split into the visible pieces
+/// - `upper_left`, `bottom_right`: The corners of the rectangle, both inclusive
+pub(crate) fn clip_path<I: IntoIterator<Item = BackendCoord>>(
+    path: I,
+    upper_left: BackendCoord,
+    bottom_right: BackendCoord,
+) -> Vec<Vec<BackendCoord>> {
+    let mut pieces: Vec<Vec<BackendCoord>> = vec![];
+    let mut current: Vec<BackendCoord> = vec![];
+    let mut last = None;
+    for point in path {
+        if let Some(from) = last {
+            if let Some((a, b)) = clip_line(from, point, upper_left, bottom_right) {
+                if current.last() != Some(&a) {
+                    if !current.is_empty() {
+                        pieces.push(std::mem::take(&mut current));
+                    }
+                    current.push(a);
+                }
+                current.push(b);
+            }
+        } else if clip_line(point, point, upper_left, bottom_right).is_some() {
+            current.push(point);
+        }
+        last = Some(point);
+    }
+    if !current.is_empty() {
+        pieces.push(current);
+    }
+    pieces
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_clip_line() {
+        let (ul, br) = ((0, 0), (100, 100));
+        assert_eq!(
+            clip_line((10, 10), (20, 30), ul, br),
+            Some(((10, 10), (20, 30)))
+        );
+        assert_eq!(
+            clip_line((-50, 50), (150, 50), ul, br),
+            Some(((0, 50), (100, 50)))
+        );
+        assert_eq!(
+            clip_line((50, 50), (250, 250), ul, br),
+            Some(((50, 50), (100, 100)))
+        );
+        assert_eq!(clip_line((-10, -10), (-20, 50), ul, br), None);
+        assert_eq!(clip_line((150, -10), (250, 50), ul, br), None);
+    }
+
+    #[test]
+    fn test_clip_path() {
+        let pieces = clip_path(
+            vec![(10, 10), (50, 200), (90, 10), (95, 20)],
+            (0, 0),
+            (100, 100),
+        );
+        assert_eq!(
+            pieces,
+            vec![
+                vec![(10, 10), (29, 100)],
+                vec![(71, 100), (90, 10), (95, 20)]
+            ]
+        );
+    }
+}
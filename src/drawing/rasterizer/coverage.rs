@@ -0,0 +1,215 @@
+/*!
+The coverage based anti-aliased rasterization. The shapes are described in the continuous space,
+in which the pixel `(x, y)` is the unit square centered at `(x, y)`. The coverage of the pixels
+is reported as the horizontal runs of the pixels with the same coverage, thus the fully covered
+part of a row is drawn as a whole span.
+*/
+
+/// The number of the sub-scanlines sampled in a row of pixels
+const SUB_SCANLINES: usize = 4;
+
+/// Quantize the coverage, so that the neighbour pixels with almost the same coverage are
+/// merged into a single run
+fn quantize(coverage: f64) -> f64 {
+    (coverage.min(1.0) * 256.0).round() / 256.0
+}
+
+/// Report the runs of the pixels with the same coverage in a row
+fn emit_runs<E, F: FnMut(i32, i32, i32, f64) -> Result<(), E>>(
+    y: i32,
+    x0: i32,
+    row: &[f64],
+    span: &mut F,
+) -> Result<(), E> {
+    let mut start = 0;
+    while start < row.len() {
+        let coverage = quantize(row[start]);
+        let mut end = start;
+        while end + 1 < row.len() && quantize(row[end + 1]) == coverage {
+            end += 1;
+        }
+        if coverage > 0.0 {
+            span(y, x0 + start as i32, x0 + end as i32, coverage)?;
+        }
+        start = end + 1;
+    }
+    Ok(())
+}
+
+/// Compute the coverage of a polygon with the even-odd rule, the pixels out of the `size` are
+/// clipped. The `span` callback is called with `(y, x0, x1, coverage)` for each run of pixels.
+pub(crate) fn polygon_coverage<E, F: FnMut(i32, i32, i32, f64) -> Result<(), E>>(
+    vertices: &[(f64, f64)],
+    (w, h): (i32, i32),
+    mut span: F,
+) -> Result<(), E> {
+    if vertices.len() < 3
+        || vertices
+            .iter()
+            .any(|(x, y)| !x.is_finite() || !y.is_finite())
+    {
+        return Ok(());
+    }
+
+    let (mut x_min, mut x_max) = (f64::INFINITY, f64::NEG_INFINITY);
+    let (mut y_min, mut y_max) = (f64::INFINITY, f64::NEG_INFINITY);
+    for (x, y) in vertices {
+        x_min = x_min.min(*x);
+        x_max = x_max.max(*x);
+        y_min = y_min.min(*y);
+        y_max = y_max.max(*y);
+    }
+
+    let pixel_of = |v: f64| (v + 0.5).floor().max(-1.0).min(f64::from(w.max(h))) as i32;
+    let (x0, x1) = (pixel_of(x_min).max(0), pixel_of(x_max).min(w - 1));
+    let (y0, y1) = (pixel_of(y_min).max(0), pixel_of(y_max).min(h - 1));
+    if x0 > x1 || y0 > y1 {
+        return Ok(());
+    }
+
+    let mut row = vec![0.0; (x1 - x0 + 1) as usize];
+    let mut crossings = vec![];
+
+    for y in y0..=y1 {
+        row.iter_mut().for_each(|c| *c = 0.0);
+
+        for k in 0..SUB_SCANLINES {
+            let sy = f64::from(y) - 0.5 + (k as f64 + 0.5) / SUB_SCANLINES as f64;
+
+            crossings.clear();
+            for (idx, a) in vertices.iter().enumerate() {
+                let b = &vertices[(idx + 1) % vertices.len()];
+                if (a.1 <= sy) != (b.1 <= sy) {
+                    crossings.push(a.0 + (sy - a.1) * (b.0 - a.0) / (b.1 - a.1));
+                }
+            }
+            crossings.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+            for pair in crossings.chunks_exact(2) {
+                let left = pair[0].max(f64::from(x0) - 0.5);
+                let right = pair[1].min(f64::from(x1) + 0.5);
+                if left >= right {
+                    continue;
+                }
+                let (first, last) = (pixel_of(left).max(x0), pixel_of(right).min(x1));
+                for x in first..=last {
+                    let overlap = right.min(f64::from(x) + 0.5) - left.max(f64::from(x) - 0.5);
+                    if overlap > 0.0 {
+                        row[(x - x0) as usize] += overlap / SUB_SCANLINES as f64;
+                    }
+                }
+            }
+        }
+
+        emit_runs(y, x0, &row, &mut span)?;
+    }
+
+    Ok(())
+}
+
+/// Compute the coverage of a filled circle, or the outline of the circle with the given stroke
+/// width. The pixels out of the `size` are clipped. The `span` callback is called with
+/// `(y, x0, x1, coverage)` for each run of pixels.
+pub(crate) fn circle_coverage<E, F: FnMut(i32, i32, i32, f64) -> Result<(), E>>(
+    center: (i32, i32),
+    radius: f64,
+    stroke_width: Option<f64>,
+    (w, h): (i32, i32),
+    mut span: F,
+) -> Result<(), E> {
+    let (inner, outer) = match stroke_width {
+        Some(width) => (Some(radius - width / 2.0), radius + width / 2.0),
+        None => (None, radius),
+    };
+
+    // The coverage is estimated by the distance from the pixel center to the edges
+    let coverage = |d: f64| {
+        let c = (outer + 0.5 - d).min(1.0);
+        let c = match inner {
+            Some(inner) => c.min(d - inner + 0.5),
+            None => c,
+        };
+        c.max(0.0)
+    };
+
+    let extent = outer.ceil() as i32 + 1;
+    let (x0, x1) = ((center.0 - extent).max(0), (center.0 + extent).min(w - 1));
+    let (y0, y1) = ((center.1 - extent).max(0), (center.1 + extent).min(h - 1));
+    if x0 > x1 || y0 > y1 {
+        return Ok(());
+    }
+
+    let mut row = vec![0.0; (x1 - x0 + 1) as usize];
+    for y in y0..=y1 {
+        let dy = f64::from(y - center.1);
+        for (idx, c) in row.iter_mut().enumerate() {
+            let dx = f64::from(x0 + idx as i32 - center.0);
+            *c = coverage((dx * dx + dy * dy).sqrt());
+        }
+        emit_runs(y, x0, &row, &mut span)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn rasterize<F: FnOnce(&mut dyn FnMut(i32, i32, i32, f64) -> Result<(), ()>)>(
+        size: (i32, i32),
+        draw: F,
+    ) -> Vec<f64> {
+        let mut pixels = vec![0.0; (size.0 * size.1) as usize];
+        draw(&mut |y, x0, x1, c| {
+            for x in x0..=x1 {
+                pixels[(y * size.0 + x) as usize] += c;
+            }
+            Ok(())
+        });
+        pixels
+    }
+
+    #[test]
+    fn test_polygon_coverage() {
+        // The square covers the pixels 1 to 3 fully, and half of the pixels on the edges
+        let pixels = rasterize((6, 6), |span| {
+            polygon_coverage(
+                &[(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)],
+                (6, 6),
+                span,
+            )
+            .unwrap()
+        });
+        assert_eq!(pixels[2 * 6 + 2], 1.0);
+        assert_eq!(pixels[2 * 6], 0.5);
+        assert_eq!(pixels[0], 0.25);
+        assert_eq!(pixels[2 * 6 + 5], 0.0);
+        let total: f64 = pixels.iter().sum();
+        assert!((total - 16.0).abs() < 1e-9);
+
+        // The diagonal edge of the triangle is anti-aliased
+        let pixels = rasterize((6, 6), |span| {
+            polygon_coverage(&[(0.0, 0.0), (5.0, 0.0), (0.0, 5.0)], (6, 6), span).unwrap()
+        });
+        assert!(pixels[2 * 6 + 3] > 0.0 && pixels[2 * 6 + 3] < 1.0);
+        assert_eq!(pixels[6 + 1], 1.0);
+    }
+
+    #[test]
+    fn test_circle_coverage() {
+        let pixels = rasterize((11, 11), |span| {
+            circle_coverage((5, 5), 4.0, None, (11, 11), span).unwrap()
+        });
+        assert_eq!(pixels[5 * 11 + 5], 1.0);
+        assert_eq!(pixels[5 * 11 + 9], 0.5);
+        assert_eq!(pixels[0], 0.0);
+
+        let pixels = rasterize((11, 11), |span| {
+            circle_coverage((5, 5), 4.0, Some(1.0), (11, 11), span).unwrap()
+        });
+        assert_eq!(pixels[5 * 11 + 5], 0.0);
+        assert_eq!(pixels[5 * 11 + 9], 1.0);
+        assert_eq!(pixels[5 * 11 + 10], 0.0);
+    }
+}
@@ -0,0 +1,134 @@
+use crate::drawing::backend::{BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind};
+use crate::style::ShapeStyle;
+
+/// Split the path into the dashes by the dash pattern. The pattern is the alternating lengths
+/// of the dashes and the gaps, and it's repeated twice if it has an odd number of entries,
+/// so the dashes and the gaps alternate in the next round.
+pub(crate) fn dash_path(
+    path: &[BackendCoord],
+    pattern: &[u32],
+    offset: u32,
+) -> Vec<Vec<BackendCoord>> {
+    let total: u32 = pattern.iter().sum();
+    if total == 0 {
+        return vec![path.to_vec()];
+    }
+
+    let pattern: Vec<_> = if pattern.len() % 2 == 1 {
+        pattern.iter().chain(pattern.iter()).copied().collect()
+    } else {
+        pattern.to_vec()
+    };
+
+    // Find the position in the pattern where the stroke starts
+    let mut idx = 0;
+    let mut phase = offset % pattern.iter().sum::<u32>();
+    while phase >= pattern[idx] {
+        phase -= pattern[idx];
+        idx = (idx + 1) % pattern.len();
+    }
+    let mut remaining = f64::from(pattern[idx] - phase);
+
+    let to_point = |(x, y): (f64, f64)| (x.round() as i32, y.round() as i32);
+
+    let mut dashes = vec![];
+    let mut current = vec![];
+    for segment in path.windows(2) {
+        let (a, b) = (segment[0], segment[1]);
+        let (dx, dy) = (f64::from(b.0 - a.0), f64::from(b.1 - a.1));
+        let len = (dx * dx + dy * dy).sqrt();
+        let at = |t: f64| {
+            if len == 0.0 {
+                (f64::from(a.0), f64::from(a.1))
+            } else {
+                (f64::from(a.0) + dx * t / len, f64::from(a.1) + dy * t / len)
+            }
+        };
+
+        let mut t = 0.0;
+        loop {
+            let on = idx % 2 == 0;
+            if on && current.is_empty() {
+                current.push(to_point(at(t)));
+            }
+
+            let step = remaining.min(len - t);
+            t += step;
+            remaining -= step;
+
+            if on {
+                current.push(to_point(at(t)));
+            }
+
+            if remaining > 1e-9 {
+                break;
+            }
+
+            // The current entry of the pattern is used up, switch between dash and gap
+            if on && current.len() > 1 {
+                dashes.push(std::mem::take(&mut current));
+            }
+            current.clear();
+            idx = (idx + 1) % pattern.len();
+            remaining = f64::from(pattern[idx]);
+
+            if t >= len {
+                break;
+            }
+        }
+    }
+
+    if current.len() > 1 {
+        dashes.push(current);
+    }
+
+    dashes
+}
+
+/// Draw the path with the dash pattern of the style, each dash is drawn as a solid path
+pub(crate) fn draw_dashed_path<DB: DrawingBackend, S: BackendStyle>(
+    b: &mut DB,
+    path: &[BackendCoord],
+    style: &S,
+) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+    let (pattern, offset) = style.stroke_dash();
+    let solid = ShapeStyle {
+        color: style.as_color(),
+        filled: false,
+        stroke_width: style.stroke_width(),
+        dash_pattern: vec![],
+        dash_offset: 0,
+    };
+    for dash in dash_path(path, pattern, offset) {
+        b.draw_path(dash, &solid)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_dash_path() {
+        let dashes = dash_path(&[(0, 0), (10, 0)], &[3, 2], 0);
+        assert_eq!(dashes, vec![vec![(0, 0), (3, 0)], vec![(5, 0), (8, 0)]]);
+
+        // The offset shifts the pattern
+        let dashes = dash_path(&[(0, 0), (10, 0)], &[3, 2], 4);
+        assert_eq!(dashes, vec![vec![(1, 0), (4, 0)], vec![(6, 0), (9, 0)]]);
+
+        // The dash continues around the corner
+        let dashes = dash_path(&[(0, 0), (2, 0), (2, 4)], &[3, 2], 0);
+        assert_eq!(
+            dashes,
+            vec![vec![(0, 0), (2, 0), (2, 1)], vec![(2, 3), (2, 4)]]
+        );
+
+        // The odd pattern is repeated, thus the second round starts with a gap
+        let dashes = dash_path(&[(0, 0), (6, 0)], &[2], 0);
+        assert_eq!(dashes, vec![vec![(0, 0), (2, 0)], vec![(4, 0), (6, 0)]]);
+
+        assert_eq!(dash_path(&[(0, 0), (6, 0)], &[0, 0], 0).len(), 1);
+    }
+}
@@ -75,8 +75,6 @@ pub(crate) fn draw_line<DB: DrawingBackend, S: BackendStyle>(
         (from, to)
     };
 
-    let grad = f64::from(to.1 - from.1) / f64::from(to.0 - from.0);
-
     let mut put_pixel = |(x, y): BackendCoord, b: f64| {
         if steep {
             back.draw_pixel((y, x), &style.as_color().mix(b))
@@ -85,14 +83,74 @@ pub(crate) fn draw_line<DB: DrawingBackend, S: BackendStyle>(
         }
     };
 
-    let mut y = f64::from(from.1);
+    // The y coordinate is stepped in 16.16 fixed point, so that the fractional part is the
+    // coverage of the lower pixel
+    let grad = (i64::from(to.1 - from.1) << 16) / i64::from(to.0 - from.0);
+    let mut y = i64::from(from.1) << 16;
 
     for x in from.0..=to.0 {
-        put_pixel((x, y as i32), 1.0 + y.floor() - y)?;
-        put_pixel((x, y as i32 + 1), y - y.floor())?;
+        let frac = (y & 0xffff) as f64 / 65536.0;
+        put_pixel((x, (y >> 16) as i32), 1.0 - frac)?;
+        put_pixel((x, (y >> 16) as i32 + 1), frac)?;
 
         y += grad;
     }
 
     Ok(())
 }
+
+/// Rasterize a 1px line with the integer Bresenham algorithm, which doesn't do any
+/// anti-aliasing. The callback is called with each pixel on the line in order.
+#[cfg(feature = "bitmap")]
+pub(crate) fn bresenham<E, F: FnMut(BackendCoord) -> Result<(), E>>(
+    from: BackendCoord,
+    to: BackendCoord,
+    mut put_pixel: F,
+) -> Result<(), E> {
+    let (dx, dy) = ((to.0 - from.0).abs(), -(to.1 - from.1).abs());
+    let (sx, sy) = ((to.0 - from.0).signum(), (to.1 - from.1).signum());
+
+    let (mut x, mut y) = from;
+    let mut err = dx + dy;
+
+    loop {
+        put_pixel((x, y))?;
+
+        if (x, y) == to {
+            return Ok(());
+        }
+
+        let e2 = 2 * err;
+        if e2 >= dy {
+            err += dy;
+            x += sx;
+        }
+        if e2 <= dx {
+            err += dx;
+            y += sy;
+        }
+    }
+}
+
+#[cfg(all(test, feature = "bitmap"))]
+#[test]
+fn test_bresenham() {
+    let mut pixels = vec![];
+    bresenham((0, 0), (5, 2), |p| {
+        pixels.push(p);
+        Ok::<(), ()>(())
+    })
+    .unwrap();
+    assert_eq!(pixels, [(0, 0), (1, 0), (2, 1), (3, 1), (4, 2), (5, 2)]);
+
+    pixels.clear();
+    bresenham((2, 5), (0, -1), |p| {
+        pixels.push(p);
+        Ok::<(), ()>(())
+    })
+    .unwrap();
+    assert_eq!(
+        pixels,
+        [(2, 5), (2, 4), (1, 3), (1, 2), (1, 1), (0, 0), (0, -1)]
+    );
+}
@@ -1,5 +1,7 @@
 mod line;
 pub(super) use line::draw_line;
+#[cfg(feature = "bitmap")]
+pub(super) use line::bresenham;
 
 mod rect;
 pub(super) use rect::draw_rect;
@@ -11,3 +13,14 @@ mod polygon;
 pub(super) use polygon::fill_polygon;
 
 pub(super) mod path;
+
+#[cfg(feature = "bitmap")]
+mod coverage;
+#[cfg(feature = "bitmap")]
+pub(super) use coverage::{circle_coverage, polygon_coverage};
+
+mod dash;
+pub(crate) use dash::draw_dashed_path;
+
+mod clip;
+pub(crate) use clip::clip_path;
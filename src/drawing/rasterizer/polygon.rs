@@ -98,33 +98,23 @@ pub(crate) fn fill_polygon<DB: DrawingBackend, S: BackendStyle>(
             return back.draw_line((x_span.0, y_span.0), (x_span.1, y_span.1), style);
         }
 
-        let horizental_sweep = x_span.1 - x_span.0 > y_span.1 - y_span.0;
-
+        // Always sweep the rows, so each scanline is a horizontal line, which is contiguous in
+        // most of the bitmap layouts and the backend is able to write the whole row at once
         let mut edges: Vec<_> = vertices
             .iter()
             .zip(vertices.iter().skip(1))
             .map(|(a, b)| (*a, *b))
             .collect();
         edges.push((vertices[vertices.len() - 1], vertices[0]));
-        edges.sort_by_key(|((x1, y1), (x2, y2))| {
-            if horizental_sweep {
-                *x1.min(x2)
-            } else {
-                *y1.min(y2)
-            }
-        });
+        edges.sort_by_key(|((_, y1), (_, y2))| *y1.min(y2));
 
         for edge in &mut edges.iter_mut() {
-            if horizental_sweep {
-                if (edge.0).0 > (edge.1).0 {
-                    std::mem::swap(&mut edge.0, &mut edge.1);
-                }
-            } else if (edge.0).1 > (edge.1).1 {
+            if (edge.0).1 > (edge.1).1 {
                 std::mem::swap(&mut edge.0, &mut edge.1);
             }
         }
 
-        let (low, high) = if horizental_sweep { x_span } else { y_span };
+        let (low, high) = y_span;
 
         let mut idx = 0;
 
@@ -146,22 +136,11 @@ pub(crate) fn fill_polygon<DB: DrawingBackend, S: BackendStyle>(
                 if idx >= edges.len() {
                     break;
                 }
-                let line = if horizental_sweep {
-                    (edges[idx].0).0
-                } else {
-                    (edges[idx].0).1
-                };
-                if line > sweep_line {
+                if (edges[idx].0).1 > sweep_line {
                     break;
                 }
 
-                let edge_obj = if horizental_sweep {
-                    Edge::horizental_sweep(edges[idx].0, edges[idx].1)
-                } else {
-                    Edge::vertical_sweep(edges[idx].0, edges[idx].1)
-                };
-
-                if let Some(edge_obj) = edge_obj {
+                if let Some(edge_obj) = Edge::vertical_sweep(edges[idx].0, edges[idx].1) {
                     active_edge.push(edge_obj);
                 }
 
@@ -203,35 +182,19 @@ pub(crate) fn fill_polygon<DB: DrawingBackend, S: BackendStyle>(
                             continue;
                         }
 
-                        if horizental_sweep {
-                            back.draw_line(
-                                (sweep_line, from.ceil() as i32),
-                                (sweep_line, to.floor() as i32),
-                                &style.as_color(),
-                            )?;
-                            back.draw_pixel(
-                                (sweep_line, from.floor() as i32),
-                                &style.as_color().mix(from.ceil() - from),
-                            )?;
-                            back.draw_pixel(
-                                (sweep_line, to.ceil() as i32),
-                                &style.as_color().mix(to - to.floor()),
-                            )?;
-                        } else {
-                            back.draw_line(
-                                (from.ceil() as i32, sweep_line),
-                                (to.floor() as i32, sweep_line),
-                                &style.as_color(),
-                            )?;
-                            back.draw_pixel(
-                                (from.floor() as i32, sweep_line),
-                                &style.as_color().mix(from.ceil() - from),
-                            )?;
-                            back.draw_pixel(
-                                (to.ceil() as i32, sweep_line),
-                                &style.as_color().mix(to.floor() - to),
-                            )?;
-                        }
+                        back.draw_line(
+                            (from.ceil() as i32, sweep_line),
+                            (to.floor() as i32, sweep_line),
+                            &style.as_color(),
+                        )?;
+                        back.draw_pixel(
+                            (from.floor() as i32, sweep_line),
+                            &style.as_color().mix(from.ceil() - from),
+                        )?;
+                        back.draw_pixel(
+                            (to.ceil() as i32, sweep_line),
+                            &style.as_color().mix(to - to.floor()),
+                        )?;
 
                         first = None;
                         second = None;
@@ -25,14 +25,10 @@ pub(crate) fn draw_rect<B: DrawingBackend, S: BackendStyle>(
     );
 
     if fill {
-        if bottom_right.0 - upper_left.0 < bottom_right.1 - upper_left.1 {
-            for x in upper_left.0..=bottom_right.0 {
-                b.draw_line((x, upper_left.1), (x, bottom_right.1), style)?;
-            }
-        } else {
-            for y in upper_left.1..=bottom_right.1 {
-                b.draw_line((upper_left.0, y), (bottom_right.0, y), style)?;
-            }
+        // Always fill the rectangle with scanlines, since a row is contiguous in most of the
+        // bitmap layouts and the backend is able to write the whole row at once
+        for y in upper_left.1..=bottom_right.1 {
+            b.draw_line((upper_left.0, y), (bottom_right.0, y), style)?;
         }
     } else {
         b.draw_line(
@@ -0,0 +1,205 @@
+/*!
+  The arrow element, which points from one coordinate to another and is useful for annotations
+*/
+
+use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use crate::element::{Drawable, PointCollection};
+use crate::style::{ShapeStyle, TextStyle};
+
+/// The shape of an end of an arrow
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ArrowHead {
+    /// No head, the line simply ends
+    None,
+    /// A filled triangle
+    Filled,
+    /// An open head made of two lines
+    Open,
+}
+
+/// The arrow element, which is a line from one coordinate to another with a head at the end.
+/// An optional label is placed beyond the tail, so the arrow points from the label to the
+/// annotated point.
+///
+/// ```rust,no_run
+/// use plotters::prelude::*;
+///
+/// let root = BitMapBackend::new("arrow.png", (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_ranged(0.0..10.0, 0.0..10.0)
+///     .unwrap();
+/// chart
+///     .draw_series(std::iter::once(
+///         Arrow::new((7.0, 8.0), (5.0, 6.0), &RED)
+///             .head_size(12)
+///             .label("peak here", ("Arial", 15).into_font()),
+///     ))
+///     .unwrap();
+/// ```
+pub struct Arrow<'a, Coord> {
+    points: [Coord; 2],
+    style: ShapeStyle,
+    head: ArrowHead,
+    tail: ArrowHead,
+    head_size: u32,
+    label: Option<(String, TextStyle<'a>)>,
+}
+
+impl<'a, Coord> Arrow<'a, Coord> {
+    /// Create a new arrow with a filled head and no tail
+    /// - `from`: The coordinate of the tail
+    /// - `to`: The coordinate the arrow points to
+    /// - `style`: The style of the arrow
+    pub fn new<S: Into<ShapeStyle>>(from: Coord, to: Coord, style: S) -> Self {
+        Self {
+            points: [from, to],
+            style: style.into(),
+            head: ArrowHead::Filled,
+            tail: ArrowHead::None,
+            head_size: 10,
+            label: None,
+        }
+    }
+
+    /// Set the shape of the head
+    pub fn head(mut self, head: ArrowHead) -> Self {
+        self.head = head;
+        self
+    }
+
+    /// Set the shape of the tail, which points backward
+    pub fn tail(mut self, tail: ArrowHead) -> Self {
+        self.tail = tail;
+        self
+    }
+
+    /// Set the length of the head and the tail in pixels
+    pub fn head_size(mut self, size: u32) -> Self {
+        self.head_size = size;
+        self
+    }
+
+    /// Attach a text label beyond the tail of the arrow
+    /// - `text`: The text of the label
+    /// - `style`: The style of the label
+    pub fn label<T: Into<String>, S: Into<TextStyle<'a>>>(mut self, text: T, style: S) -> Self {
+        self.label = Some((text.into(), style.into()));
+        self
+    }
+}
+
+impl<'b, 'a, Coord: 'b> PointCollection<'b, Coord> for &'b Arrow<'a, Coord> {
+    type Borrow = &'b Coord;
+    type IntoIter = &'b [Coord];
+    fn point_iter(self) -> &'b [Coord] {
+        &self.points
+    }
+}
+
+impl<'a, Coord> Arrow<'a, Coord> {
+    /// Draw an end of the arrow at `tip`, pointing in the direction of the unit vector, and
+    /// return the point where the line should stop
+    fn draw_head<DB: DrawingBackend>(
+        &self,
+        head: ArrowHead,
+        tip: (f64, f64),
+        (ux, uy): (f64, f64),
+        backend: &mut DB,
+    ) -> Result<(f64, f64), DrawingErrorKind<DB::ErrorType>> {
+        let size = f64::from(self.head_size);
+        let base = (tip.0 - ux * size, tip.1 - uy * size);
+        // The head is as wide as it's long
+        let (nx, ny) = (-uy * size / 2.0, ux * size / 2.0);
+        let round = |(x, y): (f64, f64)| (x.round() as i32, y.round() as i32);
+        let left = round((base.0 + nx, base.1 + ny));
+        let right = round((base.0 - nx, base.1 - ny));
+
+        match head {
+            ArrowHead::None => Ok(tip),
+            ArrowHead::Filled => {
+                backend.fill_polygon(vec![left, round(tip), right], &self.style.color)?;
+                Ok(base)
+            }
+            ArrowHead::Open => {
+                backend.draw_path(vec![left, round(tip), right], &self.style)?;
+                Ok(tip)
+            }
+        }
+    }
+}
+
+impl<'a, Coord, DB: DrawingBackend> Drawable<DB> for Arrow<'a, Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (from, to) = match (points.next(), points.next()) {
+            (Some(from), Some(to)) => (from, to),
+            _ => return Ok(()),
+        };
+        let from = (f64::from(from.0), f64::from(from.1));
+        let to = (f64::from(to.0), f64::from(to.1));
+
+        let len = ((to.0 - from.0).powi(2) + (to.1 - from.1).powi(2)).sqrt();
+        // The direction from the tail to the head, which is arbitrary for a zero length arrow
+        let (ux, uy) = if len > 0.0 {
+            ((to.0 - from.0) / len, (to.1 - from.1) / len)
+        } else {
+            (1.0, 0.0)
+        };
+
+        let end = self.draw_head(self.head, to, (ux, uy), backend)?;
+        let start = self.draw_head(self.tail, from, (-ux, -uy), backend)?;
+        backend.draw_line(
+            (start.0.round() as i32, start.1.round() as i32),
+            (end.0.round() as i32, end.1.round() as i32),
+            &self.style,
+        )?;
+
+        if let Some((text, style)) = &self.label {
+            // Place the label beyond the tail, the edge of the text facing the arrow touches
+            // the gap around the tail
+            let (w, h) = style.font.box_size(text).unwrap_or((0, 0));
+            let (dx, dy) = (-ux, -uy);
+            let gap = 3.0;
+            let x = from.0 + dx * gap + f64::from(w) / 2.0 * (dx - 1.0);
+            let y = from.1 + dy * gap + f64::from(h) / 2.0 * (dy - 1.0);
+            backend.draw_text(
+                text,
+                &style.font,
+                (x.round() as i32, y.round() as i32),
+                &style.color,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "svg"))]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_arrow() {
+        let mut buffer = vec![];
+        {
+            let root = SVGBackend::with_buffer(&mut buffer, (200, 200)).into_drawing_area();
+            let arrow = Arrow::new((10, 100), (110, 100), &RED)
+                .tail(ArrowHead::Open)
+                .head_size(10)
+                .label("peak here", ("Arial", 10).into_font());
+            root.draw(&arrow).expect("Drawing arrow");
+        }
+
+        let content = String::from_utf8(buffer).unwrap();
+        // The filled head is a triangle with the tip at the end point
+        assert_eq!(content.matches("<polygon").count(), 1);
+        assert!(content.contains("100,105 110,100 100,95"));
+        // The open tail and the line
+        assert_eq!(content.matches("<polyline").count(), 1);
+        assert_eq!(content.matches("<line").count(), 1);
+        assert!(content.contains("peak here"));
+    }
+}
@@ -1,5 +1,6 @@
 use super::{Drawable, PointCollection};
 use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use crate::drawing::clip_path;
 use crate::style::ShapeStyle;
 
 /// An element of a single pixel
@@ -58,10 +59,24 @@ fn test_pixel_element() {
         .expect("Drawing Failure");
 }
 
+/// The curve used to smooth a path, the tension is in `[0, 1]`, where 0 is the smoothest curve
+/// and 1 makes the path straight lines
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Smoothing {
+    /// The cardinal spline passing through all the points, which is the Catmull-Rom spline
+    /// with zero tension. The curve may overshoot around the sharp turns.
+    CatmullRom(f64),
+    /// The cubic Bezier segments which are horizontal at each point, so the curve never
+    /// overshoots the points. This is suitable for the data sorted by the X coordinate.
+    Bezier(f64),
+}
+
 /// An element of a series of connected lines
 pub struct Path<Coord> {
     points: Vec<Coord>,
     style: ShapeStyle,
+    simplify_tolerance: Option<f64>,
+    smoothing: Option<Smoothing>,
 }
 impl<Coord> Path<Coord> {
     /// Create a new path
@@ -72,8 +87,136 @@ impl<Coord> Path<Coord> {
         Self {
             points: points.into(),
             style: style.into(),
+            simplify_tolerance: None,
+            smoothing: None,
+        }
+    }
+
+    /// Simplify the path with Douglas-Peucker algorithm before it's drawn.
+    /// The points which are closer than `tolerance` pixels to the simplified path are removed,
+    /// which speeds up the rasterization and shrinks the vector output for dense data.
+    /// - `tolerance`: The max distance in pixels between the removed points and the result path
+    pub fn set_simplify_tolerance(&mut self, tolerance: f64) -> &mut Self {
+        self.simplify_tolerance = Some(tolerance);
+        self
+    }
+
+    /// Draw the path as a smooth curve through the points, the curve is computed in pixels
+    /// so it looks the same on any coordinate system
+    /// - `smoothing`: The curve used to smooth the path
+    pub fn set_smoothing(&mut self, smoothing: Smoothing) -> &mut Self {
+        self.smoothing = Some(smoothing);
+        self
+    }
+}
+
+/// Interpolate a path in the backend coordinate with cubic Bezier segments
+fn smooth_path(points: &[BackendCoord], smoothing: Smoothing) -> Vec<BackendCoord> {
+    let mut points = points.to_vec();
+    points.dedup();
+    if points.len() <= 2 {
+        return points;
+    }
+
+    let p: Vec<_> = points
+        .iter()
+        .map(|(x, y)| (f64::from(*x), f64::from(*y)))
+        .collect();
+    let last = p.len() - 1;
+
+    // The control points of the segment from the point to the next one
+    let controls = |i: usize| match smoothing {
+        Smoothing::CatmullRom(tension) => {
+            let scale = (1.0 - tension.clamp(0.0, 1.0)) / 6.0;
+            let tangent = |i: usize| {
+                let (a, b) = (p[i.saturating_sub(1)], p[(i + 1).min(last)]);
+                // The tangents at the end points are made from the single segment
+                let k = if i == 0 || i == last { 2.0 } else { 1.0 };
+                ((b.0 - a.0) * k * scale, (b.1 - a.1) * k * scale)
+            };
+            let (t0, t1) = (tangent(i), tangent(i + 1));
+            (
+                (p[i].0 + t0.0, p[i].1 + t0.1),
+                (p[i + 1].0 - t1.0, p[i + 1].1 - t1.1),
+            )
+        }
+        Smoothing::Bezier(tension) => {
+            let dx = (p[i + 1].0 - p[i].0) * (1.0 - tension.clamp(0.0, 1.0)) / 2.0;
+            ((p[i].0 + dx, p[i].1), (p[i + 1].0 - dx, p[i + 1].1))
+        }
+    };
+
+    let mut result = vec![points[0]];
+    for i in 0..last {
+        let (c0, c1) = controls(i);
+        let (a, b) = (p[i], p[i + 1]);
+        // Sample the segment about every 2 pixels along its control polygon
+        let dist =
+            |u: (f64, f64), v: (f64, f64)| ((u.0 - v.0).powi(2) + (u.1 - v.1).powi(2)).sqrt();
+        let steps = ((dist(a, c0) + dist(c0, c1) + dist(c1, b)) / 2.0)
+            .ceil()
+            .clamp(1.0, 1000.0) as usize;
+        for step in 1..=steps {
+            let t = step as f64 / steps as f64;
+            let s = 1.0 - t;
+            let bezier = |a: f64, c0: f64, c1: f64, b: f64| {
+                s * s * s * a + 3.0 * s * s * t * c0 + 3.0 * s * t * t * c1 + t * t * t * b
+            };
+            result.push((
+                bezier(a.0, c0.0, c1.0, b.0).round() as i32,
+                bezier(a.1, c0.1, c1.1, b.1).round() as i32,
+            ));
         }
     }
+    result.dedup();
+    result
+}
+
+/// Simplify a path in the backend coordinate with Douglas-Peucker algorithm
+fn simplify_path(points: &[BackendCoord], tolerance: f64) -> Vec<BackendCoord> {
+    // Remove the repeated points first, since they are quite common once the data is mapped
+    // to pixels and they make the distance to the segment undefined
+    let mut points = points.to_vec();
+    points.dedup();
+
+    if points.len() <= 2 {
+        return points;
+    }
+
+    let dist = |p: BackendCoord, a: BackendCoord, b: BackendCoord| {
+        let (dx, dy) = (f64::from(b.0 - a.0), f64::from(b.1 - a.1));
+        let (px, py) = (f64::from(p.0 - a.0), f64::from(p.1 - a.1));
+        let len = (dx * dx + dy * dy).sqrt();
+        if len == 0.0 {
+            (px * px + py * py).sqrt()
+        } else {
+            (px * dy - py * dx).abs() / len
+        }
+    };
+
+    let mut keep = vec![false; points.len()];
+    keep[0] = true;
+    keep[points.len() - 1] = true;
+
+    let mut stack = vec![(0, points.len() - 1)];
+
+    while let Some((begin, end)) = stack.pop() {
+        let farthest = (begin + 1..end)
+            .map(|idx| (idx, dist(points[idx], points[begin], points[end])))
+            .fold((begin, 0.0), |a, b| if b.1 > a.1 { b } else { a });
+
+        if farthest.1 > tolerance {
+            keep[farthest.0] = true;
+            stack.push((begin, farthest.0));
+            stack.push((farthest.0, end));
+        }
+    }
+
+    points
+        .into_iter()
+        .zip(keep)
+        .filter_map(|(p, k)| if k { Some(p) } else { None })
+        .collect()
 }
 
 impl<'a, Coord: 'a> PointCollection<'a, Coord> for &'a Path<Coord> {
@@ -84,13 +227,44 @@ impl<'a, Coord: 'a> PointCollection<'a, Coord> for &'a Path<Coord> {
     }
 }
 
+impl<Coord> Path<Coord> {
+    /// Smooth and simplify the path in the backend coordinate, if it's configured to
+    fn prepare_points<I: Iterator<Item = BackendCoord>>(&self, points: I) -> Vec<BackendCoord> {
+        let mut points: Vec<_> = points.collect();
+        if let Some(smoothing) = self.smoothing {
+            points = smooth_path(&points, smoothing);
+        }
+        if let Some(tolerance) = self.simplify_tolerance {
+            points = simplify_path(&points, tolerance);
+        }
+        points
+    }
+}
+
 impl<Coord, DB: DrawingBackend> Drawable<DB> for Path<Coord> {
     fn draw<I: Iterator<Item = BackendCoord>>(
         &self,
         points: I,
         backend: &mut DB,
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
-        backend.draw_path(points, &self.style)
+        if self.simplify_tolerance.is_none() && self.smoothing.is_none() {
+            return backend.draw_path(points, &self.style);
+        }
+        backend.draw_path(self.prepare_points(points), &self.style)
+    }
+
+    fn draw_clipped<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        (upper_left, bottom_right): (BackendCoord, BackendCoord),
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        // The whole path is smoothed before clipping, so the curve keeps its shape at the edge
+        let points = self.prepare_points(points);
+        for piece in clip_path(points, upper_left, bottom_right) {
+            backend.draw_path(piece, &self.style)?;
+        }
+        Ok(())
     }
 }
 
@@ -116,6 +290,69 @@ fn test_path_element() {
     .expect("Drawing Failure");
 }
 
+#[cfg(test)]
+#[test]
+fn test_path_simplify() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_draw_path(|_, _, path| {
+            assert_eq!(path, vec![(0, 0), (100, 0), (100, 100)]);
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_path_call, 1);
+        });
+    });
+    let mut path = Path::new(
+        vec![(0, 0), (0, 0), (50, 1), (100, 0), (101, 50), (100, 100)],
+        &BLUE,
+    );
+    path.set_simplify_tolerance(1.5);
+    da.draw(&path).expect("Drawing Failure");
+}
+
+#[cfg(test)]
+#[test]
+fn test_path_smoothing() {
+    let points = vec![(0, 100), (50, 0), (100, 100)];
+    for smoothing in [Smoothing::CatmullRom(0.0), Smoothing::Bezier(0.0)].iter() {
+        let path = smooth_path(&points, *smoothing);
+        // The curve passes through all the points
+        assert!(points.iter().all(|p| path.contains(p)));
+        assert!(path.len() > 20);
+        assert!(path.windows(2).all(|w| w[0].0 <= w[1].0));
+    }
+
+    // The Bezier curve doesn't overshoot the peak, while the Catmull-Rom does between the
+    // points with a different slope
+    let path = smooth_path(&[(0, 100), (50, 0), (100, 0)], Smoothing::Bezier(0.0));
+    assert!(path.iter().all(|p| p.1 >= 0));
+    let path = smooth_path(&[(0, 100), (50, 0), (100, 0)], Smoothing::CatmullRom(0.0));
+    assert!(path.iter().any(|p| p.1 < 0));
+
+    // The full tension makes straight lines
+    let path = smooth_path(&points, Smoothing::CatmullRom(1.0));
+    assert!(path.iter().all(|p| ((p.0 - 50).abs() * 2 - p.1).abs() <= 2));
+}
+
+#[cfg(all(test, feature = "bitmap"))]
+#[test]
+fn test_path_smoothing_clipped() {
+    use crate::prelude::*;
+    let mut buffer = vec![];
+    {
+        let root = BitMapBackend::with_buffer(&mut buffer, (100, 100)).into_drawing_area();
+        let area = root.margin(50, 0, 0, 0);
+        let mut path = Path::new(vec![(0, 40), (50, 0), (99, 0)], &WHITE);
+        path.set_smoothing(Smoothing::CatmullRom(0.0));
+        area.draw(&path).expect("Drawing Failure");
+    }
+
+    // The overshoot of the curve above the area is clipped rather than drawn over the margin
+    let row = |y: usize| &buffer[y * 300..(y + 1) * 300];
+    assert!((0..50).all(|y| row(y).iter().all(|v| *v == 0)));
+    assert!(row(50).iter().any(|v| *v > 0));
+}
+
 /// A rectangle element
 pub struct Rectangle<Coord> {
     points: [Coord; 2],
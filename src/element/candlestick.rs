@@ -1,5 +1,5 @@
 /*!
-  The candelstick element, which showing the high/low/open/close price
+  The candelstick and OHLC bar elements, which showing the high/low/open/close price
 */
 
 use std::cmp::Ordering;
@@ -80,3 +80,125 @@ impl<X, Y: PartialOrd, DB: DrawingBackend> Drawable<DB> for CandleStick<X, Y> {
         Ok(())
     }
 }
+
+/// The OHLC bar data point element, which is a vertical bar from the low to the high price with
+/// a tick on the left at the open price and a tick on the right at the close price. It takes the
+/// same data as `CandleStick`.
+///
+/// ```rust,no_run
+/// use plotters::prelude::*;
+///
+/// let root = BitMapBackend::new("ohlc.png", (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_ranged(0..3, 0.0..10.0)
+///     .unwrap();
+/// chart
+///     .draw_series(vec![
+///         OHLCBar::new(1, 3.0, 8.0, 2.0, 7.0, &GREEN, &RED, 10),
+///         OHLCBar::new(2, 7.0, 9.0, 4.0, 5.0, &GREEN, &RED, 10),
+///     ])
+///     .unwrap();
+/// ```
+pub struct OHLCBar<X, Y: PartialOrd> {
+    style: ShapeStyle,
+    width: u32,
+    points: [(X, Y); 4],
+}
+
+impl<X: Clone, Y: PartialOrd> OHLCBar<X, Y> {
+    /// Create a new OHLC bar element, which requires the Y coordinate can be compared
+    /// - `x`: The X coordinate of the bar
+    /// - `open`, `high`, `low`, `close`: The prices
+    /// - `gain_style`: The style if the close price is higher than the open price
+    /// - `loss_style`: The style otherwise
+    /// - `width`: The width of the bar including the ticks in pixels
+    #[allow(clippy::too_many_arguments)]
+    pub fn new<GS: Into<ShapeStyle>, LS: Into<ShapeStyle>>(
+        x: X,
+        open: Y,
+        high: Y,
+        low: Y,
+        close: Y,
+        gain_style: GS,
+        loss_style: LS,
+        width: u32,
+    ) -> Self {
+        Self {
+            style: match open.partial_cmp(&close) {
+                Some(Ordering::Less) => gain_style.into(),
+                _ => loss_style.into(),
+            },
+            width,
+            points: [
+                (x.clone(), open),
+                (x.clone(), high),
+                (x.clone(), low),
+                (x, close),
+            ],
+        }
+    }
+}
+
+impl<'a, X: 'a, Y: PartialOrd + 'a> PointCollection<'a, (X, Y)> for &'a OHLCBar<X, Y> {
+    type Borrow = &'a (X, Y);
+    type IntoIter = &'a [(X, Y)];
+    fn point_iter(self) -> &'a [(X, Y)] {
+        &self.points
+    }
+}
+
+impl<X, Y: PartialOrd, DB: DrawingBackend> Drawable<DB> for OHLCBar<X, Y> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = points.take(4).collect();
+        if points.len() == 4 {
+            let (open, high, low, close) = (points[0], points[1], points[2], points[3]);
+            let (l, r) = (
+                self.width as i32 / 2,
+                self.width as i32 - self.width as i32 / 2,
+            );
+
+            backend.draw_line(high, low, &self.style)?;
+            backend.draw_line((open.0 - l, open.1), open, &self.style)?;
+            backend.draw_line(close, (close.0 + r, close.1), &self.style)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "svg"))]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_ohlc_bar() {
+        let mut buffer = vec![];
+        {
+            let root = SVGBackend::with_buffer(&mut buffer, (100, 100)).into_drawing_area();
+            root.draw(&OHLCBar::new(50, 30, 10, 90, 20, &GREEN, &RED, 10))
+                .expect("Drawing Failure");
+            root.draw(&OHLCBar::new(20, 30, 10, 90, 40, &GREEN, &RED, 10))
+                .expect("Drawing Failure");
+        }
+
+        let content = String::from_utf8(buffer).unwrap();
+        let lines: Vec<_> = content.split("<line").skip(1).collect();
+        assert_eq!(lines.len(), 6);
+        // The close price of the first bar is lower than the open price
+        assert!(lines[..3].iter().all(|l| l.contains("#FF0000")));
+        assert!(lines[3..].iter().all(|l| l.contains("#00FF00")));
+        // The high-low bar and the ticks of the open and the close price
+        for (line, attrs) in lines.iter().zip(&[
+            [r#"x1="50""#, r#"y1="10""#, r#"x2="50""#, r#"y2="90""#],
+            [r#"x1="45""#, r#"y1="30""#, r#"x2="50""#, r#"y2="30""#],
+            [r#"x1="50""#, r#"y1="20""#, r#"x2="55""#, r#"y2="20""#],
+        ]) {
+            for attr in attrs {
+                assert!(line.contains(attr), "{} not in {}", attr, line);
+            }
+        }
+    }
+}
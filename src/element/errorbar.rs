@@ -4,16 +4,23 @@ use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 use crate::element::{Drawable, PointCollection};
 use crate::style::ShapeStyle;
 
+/// The orientation of an error bar, which maps the key and the value to the guest coordinate
 pub trait ErrorBarOrient<K, V> {
+    /// The type of the X coordinate
     type XType;
+    /// The type of the Y coordinate
     type YType;
 
+    /// Make the guest coordinate from the key and the value
     fn make_coord(key: K, val: V) -> (Self::XType, Self::YType);
+    /// Get the end points of the cap at the given backend coordinate
     fn ending_coord(coord: BackendCoord, w: u32) -> (BackendCoord, BackendCoord);
 }
 
+/// The horizontal error bar, the key is on the Y axis and the values are on the X axis
 pub struct ErrorBarOrientH<K, V>(PhantomData<(K, V)>);
 
+/// The vertical error bar, the key is on the X axis and the values are on the Y axis
 pub struct ErrorBarOrientV<K, V>(PhantomData<(K, V)>);
 
 impl<K, V> ErrorBarOrient<K, V> for ErrorBarOrientH<K, V> {
@@ -48,6 +55,22 @@ impl<K, V> ErrorBarOrient<K, V> for ErrorBarOrientV<K, V> {
     }
 }
 
+/// The error bar element, which shows the range of a measurement as a line between the lower
+/// and the upper values with a cap at both ends, and a circle marker at the center value.
+///
+/// ```rust,no_run
+/// use plotters::prelude::*;
+///
+/// let root = BitMapBackend::new("errorbar.png", (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_ranged(0..10, 0f64..10f64)
+///     .unwrap();
+/// chart
+///     .draw_series((1..9).map(|x| {
+///         ErrorBar::new_vertical(x, x as f64 - 0.5, x as f64, x as f64 + 1.0, BLUE.filled(), 10)
+///     }))
+///     .unwrap();
+/// ```
 pub struct ErrorBar<K, V, O: ErrorBarOrient<K, V>> {
     style: ShapeStyle,
     width: u32,
@@ -57,6 +80,14 @@ pub struct ErrorBar<K, V, O: ErrorBarOrient<K, V>> {
 }
 
 impl<K, V> ErrorBar<K, V, ErrorBarOrientV<K, V>> {
+    /// Create a vertical error bar
+    /// - `key`: The X coordinate of the error bar
+    /// - `min`: The lower value
+    /// - `avg`: The center value, where the marker is drawn
+    /// - `max`: The upper value
+    /// - `style`: The style of the error bar, the stroke width applies to the lines and the
+    ///   marker is filled if the style is filled
+    /// - `width`: The width of the caps in pixels, which is also the diameter of the marker
     pub fn new_vertical<S: Into<ShapeStyle>>(
         key: K,
         min: V,
@@ -76,6 +107,14 @@ impl<K, V> ErrorBar<K, V, ErrorBarOrientV<K, V>> {
 }
 
 impl<K, V> ErrorBar<K, V, ErrorBarOrientH<K, V>> {
+    /// Create a horizontal error bar
+    /// - `key`: The Y coordinate of the error bar
+    /// - `min`: The lower value
+    /// - `avg`: The center value, where the marker is drawn
+    /// - `max`: The upper value
+    /// - `style`: The style of the error bar, the stroke width applies to the lines and the
+    ///   marker is filled if the style is filled
+    /// - `width`: The height of the caps in pixels, which is also the diameter of the marker
     pub fn new_horizental<S: Into<ShapeStyle>>(
         key: K,
         min: V,
@@ -116,20 +155,81 @@ impl<K, V, O: ErrorBarOrient<K, V>, DB: DrawingBackend> Drawable<DB> for ErrorBa
         let points: Vec<_> = points.take(3).collect();
 
         let (from, to) = O::ending_coord(points[0], self.width);
-        backend.draw_line(from, to, &self.style.color)?;
+        backend.draw_line(from, to, &self.style)?;
 
         let (from, to) = O::ending_coord(points[2], self.width);
-        backend.draw_line(from, to, &self.style.color)?;
+        backend.draw_line(from, to, &self.style)?;
 
-        backend.draw_line(points[0], points[2], &self.style.color)?;
+        backend.draw_line(points[0], points[2], &self.style)?;
 
-        backend.draw_circle(
-            points[1],
-            self.width / 2,
-            &self.style.color,
-            self.style.filled,
-        )?;
+        backend.draw_circle(points[1], self.width / 2, &self.style, self.style.filled)?;
 
         Ok(())
     }
 }
+
+#[cfg(all(test, feature = "svg"))]
+mod test {
+    use crate::drawing::backend::BackendCoord;
+    use crate::prelude::*;
+
+    // Get the end points and the stroke width of each line in the SVG document
+    fn svg_lines(content: &str) -> Vec<(BackendCoord, BackendCoord, i32)> {
+        content
+            .split("<line")
+            .skip(1)
+            .map(|node| {
+                let attr = |name: &str| -> i32 {
+                    let start = node.find(&format!(" {}=\"", name)).unwrap() + name.len() + 3;
+                    let len = node[start..].find('"').unwrap();
+                    node[start..start + len].parse().unwrap()
+                };
+                (
+                    (attr("x1"), attr("y1")),
+                    (attr("x2"), attr("y2")),
+                    attr("stroke-width"),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_errorbar_stroke_width() {
+        let mut buffer = vec![];
+        {
+            let root = SVGBackend::with_buffer(&mut buffer, (100, 100)).into_drawing_area();
+            root.draw(&ErrorBar::new_vertical(
+                50,
+                20,
+                50,
+                80,
+                RED.stroke_width(3),
+                10,
+            ))
+            .expect("Drawing Failure");
+            root.draw(&ErrorBar::new_horizental(
+                50,
+                20,
+                50,
+                80,
+                BLUE.stroke_width(2),
+                10,
+            ))
+            .expect("Drawing Failure");
+        }
+
+        let content = String::from_utf8(buffer).unwrap();
+        assert_eq!(
+            svg_lines(&content),
+            vec![
+                ((45, 20), (55, 20), 3),
+                ((45, 80), (55, 80), 3),
+                ((50, 20), (50, 80), 3),
+                ((20, 45), (20, 55), 2),
+                ((80, 45), (80, 55), 2),
+                ((20, 50), (80, 50), 2),
+            ]
+        );
+        assert_eq!(content.matches("<circle").count(), 2);
+    }
+}
@@ -0,0 +1,256 @@
+/*!
+  The bitmap element, which embeds an image at a coordinate
+*/
+
+use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use crate::element::{Drawable, PointCollection};
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+use image::{DynamicImage, GenericImageView};
+
+/// The bitmap element, which draws an RGB image with its upper-left corner at the coordinate.
+/// The image is drawn in its own size by default, or it can be scaled to a size in pixels or
+/// stretched to cover a rectangle in the guest coordinate, e.g. for a background map.
+///
+/// The image is copied with `DrawingBackend::blit_bitmap`, so the vector backends such as
+/// `SVGBackend` embed the image rather than drawing the pixels one by one.
+///
+/// ```rust,no_run
+/// use plotters::prelude::*;
+///
+/// let root = BitMapBackend::new("image.png", (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_ranged(0.0..1.0, 0.0..1.0)
+///     .unwrap();
+/// let image = image::open("background.png").unwrap();
+/// let element = BitMapElement::from(((0.0, 1.0), image)).stretch_to((1.0, 0.0));
+/// chart.draw_series(std::iter::once(element)).unwrap();
+/// ```
+pub struct BitMapElement<Coord> {
+    points: Vec<Coord>,
+    size: (u32, u32),
+    image: Vec<u8>,
+    draw_size: Option<(u32, u32)>,
+}
+
+impl<Coord> BitMapElement<Coord> {
+    /// Create a new bitmap element from the pixels
+    /// - `pos`: The coordinate of the upper-left corner
+    /// - `size`: The width and the height of the image
+    /// - `buf`: The pixels of the image in RGB, row by row
+    /// - returns the element, or `None` if the buffer doesn't match the size
+    pub fn with_buffer(pos: Coord, size: (u32, u32), buf: Vec<u8>) -> Option<Self> {
+        let len = (size.0 as usize)
+            .checked_mul(size.1 as usize)
+            .and_then(|len| len.checked_mul(3));
+        if len != Some(buf.len()) {
+            return None;
+        }
+        Some(Self {
+            points: vec![pos],
+            size,
+            image: buf,
+            draw_size: None,
+        })
+    }
+
+    /// Scale the image to the size in pixels
+    pub fn pixel_size(mut self, size: (u32, u32)) -> Self {
+        self.draw_size = Some(size);
+        self
+    }
+
+    /// Stretch the image to cover the rectangle from the upper-left corner to the coordinate
+    /// - `corner`: The coordinate of the bottom-right corner
+    pub fn stretch_to(mut self, corner: Coord) -> Self {
+        self.points.truncate(1);
+        self.points.push(corner);
+        self
+    }
+
+    /// Get the upper-left corner and the size of the pixel rectangle the image covers
+    fn placement(
+        &self,
+        mut points: impl Iterator<Item = BackendCoord>,
+    ) -> Option<(BackendCoord, (u32, u32))> {
+        let (x0, y0) = points.next()?;
+        Some(match points.next() {
+            Some((x1, y1)) => (
+                (x0.min(x1), y0.min(y1)),
+                ((x1 - x0).unsigned_abs(), (y1 - y0).unsigned_abs()),
+            ),
+            None => ((x0, y0), self.draw_size.unwrap_or(self.size)),
+        })
+    }
+
+    /// Draw the part of the placed image inside the clipping rectangle. Only the visible pixels
+    /// are sampled from the image, thus a zoomed-in image doesn't scale the whole image.
+    fn draw_part<DB: DrawingBackend>(
+        &self,
+        (pos, size): (BackendCoord, (u32, u32)),
+        (upper_left, bottom_right): (BackendCoord, BackendCoord),
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let x0 = pos.0.max(upper_left.0);
+        let y0 = pos.1.max(upper_left.1);
+        let x1 = (i64::from(pos.0) + i64::from(size.0)).min(i64::from(bottom_right.0) + 1);
+        let y1 = (i64::from(pos.1) + i64::from(size.1)).min(i64::from(bottom_right.1) + 1);
+        if i64::from(x0) >= x1 || i64::from(y0) >= y1 {
+            return Ok(());
+        }
+        let (w, h) = ((x1 - i64::from(x0)) as u32, (y1 - i64::from(y0)) as u32);
+
+        if size == self.size && (w, h) == size {
+            return backend.blit_bitmap(pos, size, &self.image);
+        }
+
+        // Nearest neighbour sampling, which keeps the pixels of small images sharp
+        let (iw, ih) = (u64::from(self.size.0), u64::from(self.size.1));
+        let dx = (i64::from(x0) - i64::from(pos.0)) as u64;
+        let dy = (i64::from(y0) - i64::from(pos.1)) as u64;
+        let mut part = Vec::with_capacity(w as usize * h as usize * 3);
+        for y in 0..u64::from(h) {
+            let sy = (dy + y) * ih / u64::from(size.1);
+            for x in 0..u64::from(w) {
+                let sx = (dx + x) * iw / u64::from(size.0);
+                let idx = ((sy * iw + sx) * 3) as usize;
+                part.extend_from_slice(&self.image[idx..idx + 3]);
+            }
+        }
+        backend.blit_bitmap((x0, y0), (w, h), &part)
+    }
+}
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+impl<Coord> From<(Coord, DynamicImage)> for BitMapElement<Coord> {
+    fn from((pos, image): (Coord, DynamicImage)) -> Self {
+        let size = image.dimensions();
+        Self {
+            points: vec![pos],
+            size,
+            image: image.to_rgb().into_raw(),
+            draw_size: None,
+        }
+    }
+}
+
+impl<'a, Coord: 'a> PointCollection<'a, Coord> for &'a BitMapElement<Coord> {
+    type Borrow = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for BitMapElement<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (w, h) = backend.get_size();
+        let clip = ((0, 0), (w as i32 - 1, h as i32 - 1));
+        match self.placement(points) {
+            Some(placement) => self.draw_part(placement, clip, backend),
+            None => Ok(()),
+        }
+    }
+
+    fn draw_clipped<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        clip: (BackendCoord, BackendCoord),
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        match self.placement(points) {
+            Some(placement) => self.draw_part(placement, clip, backend),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_bitmap_element() {
+        let da = crate::create_mocked_drawing_area(100, 100, |m| {
+            let mut pixels = vec![];
+            m.check_draw_pixel(move |c, p| {
+                pixels.push(p);
+                if pixels.len() == 4 {
+                    // The 2x1 image is scaled to 2x2
+                    assert_eq!(pixels, vec![(10, 20), (11, 20), (10, 21), (11, 21)]);
+                }
+                let expected = if p.0 == 10 { RED } else { BLUE };
+                assert_eq!(c, expected.to_rgba());
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_pixels_call, 1);
+            });
+        });
+
+        assert!(BitMapElement::with_buffer((10, 20), (2, 1), vec![0; 3]).is_none());
+        let element = BitMapElement::with_buffer((10, 20), (2, 1), vec![255, 0, 0, 0, 0, 255])
+            .unwrap()
+            .pixel_size((2, 2));
+        da.draw(&element).expect("Drawing Failure");
+    }
+
+    #[test]
+    fn test_zoomed_bitmap_element() {
+        let da = crate::create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_pixel(|c, p| {
+                let expected = if p.0 < 50 { RED } else { BLUE };
+                assert_eq!(c, expected.to_rgba());
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_pixels_call, 1);
+            });
+        });
+        let mut chart = ChartBuilder::on(&da)
+            .build_ranged(-1.0..1.0, -1.0..1.0)
+            .unwrap();
+
+        // The 2x1 image is stretched to millions of pixels, but only the visible ones are
+        // sampled
+        let element = BitMapElement::with_buffer((-1e5, 1e5), (2, 1), vec![255, 0, 0, 0, 0, 255])
+            .unwrap()
+            .stretch_to((1e5, -1e5));
+        chart.draw_series(std::iter::once(element)).unwrap();
+
+        assert!(BitMapElement::with_buffer((0, 0), (u32::MAX, u32::MAX), vec![]).is_none());
+    }
+
+    #[test]
+    fn test_blit_short_bitmap() {
+        use crate::drawing::backend::DrawingBackend;
+        use crate::drawing::MockedBackend;
+
+        // The default implementation draws nothing if the source is too short
+        let mut backend = MockedBackend::new(100, 100);
+        backend.blit_bitmap((0, 0), (3, 2), &[255; 15]).unwrap();
+        backend
+            .blit_bitmap((0, 0), (u32::MAX, u32::MAX), &[255; 15])
+            .unwrap();
+        assert_eq!(backend.num_draw_pixels_call, 0);
+    }
+
+    #[cfg(all(feature = "svg", feature = "image"))]
+    #[test]
+    fn test_bitmap_element_svg() {
+        let mut buffer = vec![];
+        {
+            let root = SVGBackend::with_buffer(&mut buffer, (100, 100)).into_drawing_area();
+            let element = BitMapElement::with_buffer((10, 20), (2, 2), vec![128; 12]).unwrap();
+            root.draw(&element).expect("Drawing Failure");
+        }
+
+        // The image is embedded as a whole rather than the pixels
+        let content = String::from_utf8(buffer).unwrap();
+        assert_eq!(content.matches("<image").count(), 1);
+        assert!(content.contains("data:image/png;base64,"));
+        assert!(!content.contains("<rect"));
+    }
+}
@@ -167,11 +167,20 @@ mod composable;
 pub use composable::{ComposedElement, EmptyElement};
 
 mod candlestick;
-pub use candlestick::CandleStick;
+pub use candlestick::{CandleStick, OHLCBar};
 
 mod errorbar;
 pub use errorbar::{ErrorBar, ErrorBarOrientH, ErrorBarOrientV};
 
+mod pie;
+pub use pie::Pie;
+
+mod arrow;
+pub use arrow::{Arrow, ArrowHead};
+
+mod image;
+pub use self::image::BitMapElement;
+
 /// A type which is logically a collection of points, under any given coordinate system
 pub trait PointCollection<'a, Coord> {
     /// The item in point iterator
@@ -193,6 +202,27 @@ pub trait Drawable<DB: DrawingBackend> {
         pos: I,
         backend: &mut DB,
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>>;
+
+    /// Draws the element which may be partially out of the drawing area. The key points are
+    /// translated into the image coordinate but not truncated, and the element should be clipped
+    /// by the rectangle from `upper_left` to `bottom_right` (both inclusive).
+    /// By default, the key points are moved into the rectangle and then `draw` is called.
+    fn draw_clipped<I: Iterator<Item = BackendCoord>>(
+        &self,
+        pos: I,
+        (upper_left, bottom_right): (BackendCoord, BackendCoord),
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.draw(
+            pos.map(|(x, y)| {
+                (
+                    x.max(upper_left.0).min(bottom_right.0),
+                    y.max(upper_left.1).min(bottom_right.1),
+                )
+            }),
+            backend,
+        )
+    }
 }
 
 trait DynDrawable<'a, DB: DrawingBackend>
@@ -204,6 +234,13 @@ where
         points: &mut dyn Iterator<Item = BackendCoord>,
         backend: &mut DB,
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>>;
+
+    fn draw_clipped_dyn(
+        &self,
+        points: &mut dyn Iterator<Item = BackendCoord>,
+        clip: (BackendCoord, BackendCoord),
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>>;
 }
 
 impl<'a, DB: DrawingBackend, T: Drawable<DB> + 'a> DynDrawable<'a, DB> for T {
@@ -214,6 +251,15 @@ impl<'a, DB: DrawingBackend, T: Drawable<DB> + 'a> DynDrawable<'a, DB> for T {
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
         T::draw(self, points, backend)
     }
+
+    fn draw_clipped_dyn(
+        &self,
+        points: &mut dyn Iterator<Item = BackendCoord>,
+        clip: (BackendCoord, BackendCoord),
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        T::draw_clipped(self, points, clip, backend)
+    }
 }
 
 /// The container for a dynamically dispatched element
@@ -244,6 +290,15 @@ impl<'a, DB: DrawingBackend + 'a, Coord: Clone> Drawable<DB> for DynElement<'a,
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
         self.drawable.draw_dyn(&mut pos, backend)
     }
+
+    fn draw_clipped<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut pos: I,
+        clip: (BackendCoord, BackendCoord),
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.drawable.draw_clipped_dyn(&mut pos, clip, backend)
+    }
 }
 
 /// The trait that makes the conversion from the statically dispatched element
@@ -274,3 +329,20 @@ where
         }
     }
 }
+
+#[cfg(test)]
+#[test]
+fn test_dyn_element_clipped() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(101, 101, |m| {
+        // The path is clipped at the edge, rather than having its points moved into the area
+        m.check_draw_path(|_, _, path| {
+            assert_eq!(path, vec![(0, 50), (50, 100)]);
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_path_call, 1);
+        });
+    });
+    let path: DynElement<_, (i32, i32)> = Path::new(vec![(-50, 0), (50, 100)], &BLUE).into_dyn();
+    da.draw(&path).expect("Drawing Failure");
+}
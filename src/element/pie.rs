@@ -0,0 +1,228 @@
+/*!
+  The pie element, which shows the proportion of the weights as the slices of a circle
+*/
+
+use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use crate::element::{Drawable, PointCollection};
+use crate::style::{Color, FontDesc, Palette, Palette99, RGBAColor, TextStyle};
+
+/// The pie element. The slices start at the top of the circle and go clockwise, and each
+/// slice is labelled outside of the circle. With an inner radius, the pie becomes a donut.
+///
+/// ```rust,no_run
+/// use plotters::prelude::*;
+///
+/// let root = BitMapBackend::new("pie.png", (640, 480)).into_drawing_area();
+/// let pie = Pie::new((320, 240), 150, vec![("Rust", 50.0), ("C++", 30.0), ("Go", 20.0)])
+///     .donut_hole(60)
+///     .percentages(("Arial", 15).into_font().color(&WHITE));
+/// root.draw(&pie).unwrap();
+/// ```
+pub struct Pie<'a, Coord> {
+    center: Coord,
+    radius: u32,
+    inner_radius: u32,
+    slices: Vec<(String, f64)>,
+    colors: Vec<RGBAColor>,
+    start_angle: f64,
+    label_style: TextStyle<'a>,
+    label_offset: u32,
+    percentage_style: Option<TextStyle<'a>>,
+}
+
+impl<'a, Coord> Pie<'a, Coord> {
+    /// Create a new pie element
+    /// - `center`: The center of the pie
+    /// - `radius`: The radius of the pie in pixels
+    /// - `slices`: The labels and the weights of the slices, the slices without a positive
+    ///   weight are not drawn
+    pub fn new<L: Into<String>, I: IntoIterator<Item = (L, f64)>>(
+        center: Coord,
+        radius: u32,
+        slices: I,
+    ) -> Self {
+        Self {
+            center,
+            radius,
+            inner_radius: 0,
+            slices: slices
+                .into_iter()
+                .map(|(label, weight)| (label.into(), weight))
+                .collect(),
+            colors: vec![],
+            start_angle: 0.0,
+            label_style: FontDesc::new("Arial", 12.0).into(),
+            label_offset: 5,
+            percentage_style: None,
+        }
+    }
+
+    /// Set the colors of the slices, the slices without a color are colored with `Palette99`
+    pub fn colors<C: Color>(mut self, colors: &[C]) -> Self {
+        self.colors = colors.iter().map(Color::to_rgba).collect();
+        self
+    }
+
+    /// Set the angle where the first slice starts, in degrees clockwise from the top
+    pub fn start_angle(mut self, degrees: f64) -> Self {
+        self.start_angle = degrees;
+        self
+    }
+
+    /// Make the pie a donut by cutting a hole in the middle
+    /// - `inner_radius`: The radius of the hole in pixels
+    pub fn donut_hole(mut self, inner_radius: u32) -> Self {
+        self.inner_radius = inner_radius;
+        self
+    }
+
+    /// Set the style of the labels of the slices
+    pub fn label_style<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        self.label_style = style.into();
+        self
+    }
+
+    /// Set the distance between the labels and the edge of the pie in pixels
+    pub fn label_offset(mut self, offset: u32) -> Self {
+        self.label_offset = offset;
+        self
+    }
+
+    /// Show the percentage of each slice in the middle of the slice
+    /// - `style`: The style of the percentages
+    pub fn percentages<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        self.percentage_style = Some(style.into());
+        self
+    }
+}
+
+impl<'b, 'a, Coord: 'b> PointCollection<'b, Coord> for &'b Pie<'a, Coord> {
+    type Borrow = &'b Coord;
+    type IntoIter = std::iter::Once<&'b Coord>;
+    fn point_iter(self) -> std::iter::Once<&'b Coord> {
+        std::iter::once(&self.center)
+    }
+}
+
+impl<'a, Coord, DB: DrawingBackend> Drawable<DB> for Pie<'a, Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (cx, cy) = match points.next() {
+            Some(center) => (f64::from(center.0), f64::from(center.1)),
+            None => return Ok(()),
+        };
+
+        let total: f64 = self
+            .slices
+            .iter()
+            .map(|(_, w)| *w)
+            .filter(|w| w.is_finite() && *w > 0.0)
+            .sum();
+        if total <= 0.0 {
+            return Ok(());
+        }
+
+        let (outer, inner) = (f64::from(self.radius), f64::from(self.inner_radius));
+        // The unit vector pointing to the angle in degrees clockwise from the top
+        let direction = |degrees: f64| {
+            let theta = degrees.to_radians();
+            (theta.sin(), -theta.cos())
+        };
+        let at = |degrees: f64, r: f64| {
+            let (ux, uy) = direction(degrees);
+            ((cx + ux * r).round() as i32, (cy + uy * r).round() as i32)
+        };
+
+        let mut angle = self.start_angle;
+        for (idx, (label, weight)) in self.slices.iter().enumerate() {
+            if !weight.is_finite() || *weight <= 0.0 {
+                continue;
+            }
+
+            let sweep = weight / total * 360.0;
+            let segments = (sweep.ceil() as usize).max(2);
+            let arc = |r: f64| {
+                (0..=segments).map(move |i| at(angle + sweep * i as f64 / segments as f64, r))
+            };
+
+            let mut polygon: Vec<_> = arc(outer).collect();
+            if inner > 0.0 {
+                polygon.extend(arc(inner).collect::<Vec<_>>().into_iter().rev());
+            } else {
+                polygon.push((cx.round() as i32, cy.round() as i32));
+            }
+
+            let color = self
+                .colors
+                .get(idx)
+                .cloned()
+                .unwrap_or_else(|| Palette99::pick(idx).to_rgba());
+            backend.fill_polygon(polygon, &color)?;
+
+            let middle = angle + sweep / 2.0;
+            let (ux, uy) = direction(middle);
+
+            // Place the label outside of the pie, the edge of the text facing the center
+            // touches the gap around the pie
+            let (w, h) = self.label_style.font.box_size(label).unwrap_or((0, 0));
+            let r = outer + f64::from(self.label_offset);
+            let x = cx + ux * r + f64::from(w) / 2.0 * (ux - 1.0);
+            let y = cy + uy * r + f64::from(h) / 2.0 * (uy - 1.0);
+            backend.draw_text(
+                label,
+                &self.label_style.font,
+                (x.round() as i32, y.round() as i32),
+                &self.label_style.color,
+            )?;
+
+            if let Some(style) = &self.percentage_style {
+                let text = format!("{:.1}%", weight / total * 100.0);
+                let (w, h) = style.font.box_size(&text).unwrap_or((0, 0));
+                let r = (outer + inner) / 2.0;
+                let x = cx + ux * r - f64::from(w) / 2.0;
+                let y = cy + uy * r - f64::from(h) / 2.0;
+                backend.draw_text(
+                    &text,
+                    &style.font,
+                    (x.round() as i32, y.round() as i32),
+                    &style.color,
+                )?;
+            }
+
+            angle += sweep;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "svg"))]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_pie() {
+        let mut buffer = vec![];
+        {
+            let root = SVGBackend::with_buffer(&mut buffer, (200, 200)).into_drawing_area();
+            let pie = Pie::new((100, 100), 50, vec![("a", 1.0), ("b", 0.0), ("c", 3.0)])
+                .colors(&[RED, GREEN])
+                .donut_hole(20)
+                .percentages(("Arial", 10).into_font());
+            root.draw(&pie).expect("Drawing pie");
+        }
+
+        let content = String::from_utf8(buffer).unwrap();
+        // The slice without weight is skipped
+        assert_eq!(content.matches("<polygon").count(), 2);
+        let texts: Vec<_> = content
+            .split("<text")
+            .skip(1)
+            .map(|text| text[text.find('>').unwrap() + 1..text.find('<').unwrap()].trim())
+            .collect();
+        assert_eq!(texts, vec!["a", "25.0%", "c", "75.0%"]);
+    }
+}
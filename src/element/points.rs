@@ -120,3 +120,187 @@ impl<Coord> PointElement<Coord> for Pixel<Coord> {
         Self::new(pos, style)
     }
 }
+
+/// The shape of a marker
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MarkerShape {
+    Circle,
+    Square,
+    Diamond,
+    TriangleUp,
+    TriangleDown,
+    Star,
+    /// The plus sign, which is always stroked
+    Plus,
+    /// The cross sign, which is always stroked
+    X,
+}
+
+impl MarkerShape {
+    /// All the shapes, which is handy to pick a distinct shape for each series
+    pub const ALL: [MarkerShape; 8] = [
+        MarkerShape::Circle,
+        MarkerShape::Square,
+        MarkerShape::Diamond,
+        MarkerShape::TriangleUp,
+        MarkerShape::TriangleDown,
+        MarkerShape::Star,
+        MarkerShape::Plus,
+        MarkerShape::X,
+    ];
+
+    /// The vertices of the polygon shape in polar coordinate, as the angle in degrees and the
+    /// radius relative to the size
+    fn vertices(self) -> Vec<(f64, f64)> {
+        match self {
+            MarkerShape::Diamond => vec![(-90.0, 1.0), (0.0, 1.0), (90.0, 1.0), (180.0, 1.0)],
+            MarkerShape::TriangleUp => vec![(-90.0, 1.0), (30.0, 1.0), (150.0, 1.0)],
+            MarkerShape::TriangleDown => vec![(90.0, 1.0), (210.0, 1.0), (330.0, 1.0)],
+            MarkerShape::Star => (0..10)
+                .map(|i| {
+                    (
+                        -90.0 + 36.0 * f64::from(i),
+                        if i % 2 == 0 { 1.0 } else { 0.4 },
+                    )
+                })
+                .collect(),
+            _ => vec![],
+        }
+    }
+}
+
+/// The marker element, which draws a point with one of the shapes in `MarkerShape`.
+/// All the shapes have the same size and take the same style: the shape is filled with a
+/// filled style, e.g. `RED.filled()`, and it's hollow otherwise.
+///
+/// ```rust,no_run
+/// use plotters::prelude::*;
+///
+/// let root = BitMapBackend::new("markers.png", (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_ranged(0..10, 0..10)
+///     .unwrap();
+/// chart
+///     .draw_series(PointSeries::of_marker(
+///         vec![(1, 2), (3, 4), (5, 6)],
+///         5,
+///         MarkerShape::Diamond,
+///         BLACK.filled(),
+///     ))
+///     .unwrap();
+/// ```
+pub struct Marker<Coord> {
+    center: Coord,
+    size: u32,
+    shape: MarkerShape,
+    style: ShapeStyle,
+}
+
+impl<Coord> Marker<Coord> {
+    /// Create a new marker
+    /// - `coord`: The center of the marker
+    /// - `size`: The distance from the center to the edge of the circle in pixels, the other
+    ///   shapes have about the same extent
+    /// - `shape`: The shape of the marker
+    /// - `style`: The style of the marker
+    pub fn new<T: Into<ShapeStyle>>(coord: Coord, size: u32, shape: MarkerShape, style: T) -> Self {
+        Self {
+            center: coord,
+            size,
+            shape,
+            style: style.into(),
+        }
+    }
+}
+
+impl<'a, Coord: 'a> PointCollection<'a, Coord> for &'a Marker<Coord> {
+    type Borrow = &'a Coord;
+    type IntoIter = std::iter::Once<&'a Coord>;
+    fn point_iter(self) -> std::iter::Once<&'a Coord> {
+        std::iter::once(&self.center)
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for Marker<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (x, y) = match points.next() {
+            Some(center) => center,
+            None => return Ok(()),
+        };
+        let size = self.size as i32;
+
+        match self.shape {
+            MarkerShape::Circle => {
+                backend.draw_circle((x, y), self.size, &self.style, self.style.filled)
+            }
+            MarkerShape::Square => backend.draw_rect(
+                (x - size, y - size),
+                (x + size, y + size),
+                &self.style,
+                self.style.filled,
+            ),
+            MarkerShape::Plus => {
+                backend.draw_line((x - size, y), (x + size, y), &self.style)?;
+                backend.draw_line((x, y - size), (x, y + size), &self.style)
+            }
+            MarkerShape::X => {
+                backend.draw_line((x - size, y - size), (x + size, y + size), &self.style)?;
+                backend.draw_line((x - size, y + size), (x + size, y - size), &self.style)
+            }
+            shape => {
+                let mut vertices: Vec<_> = shape
+                    .vertices()
+                    .into_iter()
+                    .map(|(deg, r)| {
+                        let (rad, r) = (deg * std::f64::consts::PI / 180.0, r * f64::from(size));
+                        (
+                            (f64::from(x) + rad.cos() * r).round() as i32,
+                            (f64::from(y) + rad.sin() * r).round() as i32,
+                        )
+                    })
+                    .collect();
+                if self.style.filled {
+                    backend.fill_polygon(vertices, &self.style.color)
+                } else {
+                    vertices.push(vertices[0]);
+                    backend.draw_path(vertices, &self.style)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_marker_element() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(100, 100, |m| {
+        m.check_draw_rect(|_, _, filled, ul, br| {
+            assert!(filled);
+            assert_eq!((ul, br), ((45, 45), (55, 55)));
+        });
+        m.check_draw_path(|_, _, path| {
+            // The hollow diamond is closed
+            assert_eq!(path, vec![(50, 45), (55, 50), (50, 55), (45, 50), (50, 45)]);
+        });
+        m.check_fill_polygon(|_, points| {
+            assert_eq!(points.len(), 10);
+            assert_eq!(points[0], (50, 45));
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_rect_call, 1);
+            assert_eq!(b.num_draw_path_call, 1);
+            assert_eq!(b.num_fill_polygon_call, 1);
+        });
+    });
+    da.draw(&Marker::new((50, 50), 5, MarkerShape::Square, RED.filled()))
+        .expect("Drawing Failure");
+    da.draw(&Marker::new((50, 50), 5, MarkerShape::Diamond, &RED))
+        .expect("Drawing Failure");
+    da.draw(&Marker::new((50, 50), 5, MarkerShape::Star, RED.filled()))
+        .expect("Drawing Failure");
+}
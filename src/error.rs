@@ -0,0 +1,132 @@
+/*!
+  The crate-level error type, which carries the context of the failure.
+
+  All the drawing APIs return `DrawingAreaErrorKind`, which is generic over the error type of
+  the backend. `PlottersError` erases the backend error type, thus the errors from different
+  backends can be propagated with `?` into the same type, and it reports where the error
+  happened, e.g. which series or which axis was being drawn.
+
+  ```rust,no_run
+  use plotters::error::PlottersError;
+  use plotters::prelude::*;
+
+  fn draw() -> Result<(), PlottersError> {
+      let root = SVGBackend::new("plot.svg", (640, 480)).into_drawing_area();
+      let mut chart = ChartBuilder::on(&root).build_ranged(0..10, 0..10)?;
+      chart.configure_mesh().draw()?;
+      chart.draw_series(LineSeries::new((0..10).map(|x| (x, x)), &RED))?;
+      Ok(())
+  }
+  ```
+*/
+use crate::drawing::DrawingAreaErrorKind;
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Describes what Plotters was doing when an error happened
+#[derive(Clone, Debug, PartialEq)]
+pub enum ErrorContext {
+    /// Laying out the chart, e.g. the caption and the label areas
+    Layout,
+    /// Drawing the mesh lines
+    Mesh,
+    /// Drawing the axis and the labels of the given dimension, `'x'` or `'y'`
+    Axis(char),
+    /// Drawing the series with the given index, in the order the series were drawn
+    Series(usize),
+    /// Performing the backend operation with the given name, e.g. `draw` or `present`
+    BackendOp(&'static str),
+}
+
+impl Display for ErrorContext {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            ErrorContext::Layout => write!(fmt, "laying out the chart"),
+            ErrorContext::Mesh => write!(fmt, "drawing the mesh"),
+            ErrorContext::Axis(axis) => write!(fmt, "drawing the {} axis", axis),
+            ErrorContext::Series(idx) => write!(fmt, "drawing series #{}", idx),
+            ErrorContext::BackendOp(op) => write!(fmt, "backend operation `{}`", op),
+        }
+    }
+}
+
+/// The error type of Plotters, which is independent from the backend
+#[derive(Debug)]
+pub struct PlottersError {
+    context: Vec<ErrorContext>,
+    source: Box<dyn Error + Send + Sync>,
+}
+
+impl PlottersError {
+    /// Get the context of the error, the outermost one comes first
+    pub fn context(&self) -> &[ErrorContext] {
+        &self.context
+    }
+}
+
+impl Display for PlottersError {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), std::fmt::Error> {
+        for context in self.context.iter() {
+            write!(fmt, "{}: ", context)?;
+        }
+        write!(fmt, "{}", self.source)
+    }
+}
+
+impl Error for PlottersError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(self.source.as_ref())
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> From<DrawingAreaErrorKind<E>> for PlottersError {
+    fn from(mut error: DrawingAreaErrorKind<E>) -> Self {
+        let mut context = vec![];
+        while let DrawingAreaErrorKind::Context(ctx, inner) = error {
+            context.push(ctx);
+            error = *inner;
+        }
+        Self {
+            context,
+            source: Box::new(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_error_context() {
+        let error: DrawingAreaErrorKind<std::io::Error> = DrawingAreaErrorKind::LayoutError
+            .context(ErrorContext::BackendOp("draw"))
+            .context(ErrorContext::Series(1));
+        let error = PlottersError::from(error);
+        assert_eq!(
+            error.context(),
+            &[ErrorContext::Series(1), ErrorContext::BackendOp("draw")]
+        );
+        assert_eq!(
+            error.to_string(),
+            "drawing series #1: backend operation `draw`: Bad layout"
+        );
+        assert!(error.source().is_some());
+    }
+
+    #[test]
+    fn test_root_kind() {
+        let error: DrawingAreaErrorKind<std::io::Error> = DrawingAreaErrorKind::SharingError
+            .context(ErrorContext::BackendOp("draw"))
+            .context(ErrorContext::Mesh);
+        match error.root_kind() {
+            DrawingAreaErrorKind::SharingError => {}
+            e => panic!("Unexpected root kind {:?}", e),
+        }
+        match error.into_root_kind() {
+            DrawingAreaErrorKind::SharingError => {}
+            e => panic!("Unexpected root kind {:?}", e),
+        }
+    }
+}
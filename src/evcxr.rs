@@ -5,10 +5,24 @@ use crate::drawing::{DrawingArea, IntoDrawingArea, SVGBackend};
 pub struct SVGWrapper(Vec<u8>, String);
 
 impl SVGWrapper {
+    /// Emit the figure as the evcxr display output, this is called by evcxr automatically
+    /// when the figure is the value of the last expression in a cell
     pub fn evcxr_display(&self) {
         println!("{:?}", self);
     }
 
+    /// Show the figure inline in the Jupyter notebook, this is useful when the figure
+    /// isn't the last expression of the cell
+    pub fn show(&self) {
+        self.evcxr_display();
+    }
+
+    /// Get the SVG source of the figure
+    pub fn svg(&self) -> String {
+        String::from_utf8_lossy(self.0.as_slice()).to_string()
+    }
+
+    /// Set the CSS style of the `div` element that contains the figure
     pub fn style<S: Into<String>>(mut self, style: S) -> Self {
         self.1 = style.into();
         self
@@ -38,3 +52,22 @@ pub fn evcxr_figure<
     draw(root).expect("Drawing failure");
     SVGWrapper(buffer, "".to_string())
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::style::RED;
+
+    #[test]
+    fn test_evcxr_output() {
+        let figure = evcxr_figure((100, 100), |root| {
+            root.fill(&RED)?;
+            Ok(())
+        })
+        .style("width:50%");
+        let output = format!("{:?}", figure);
+        assert!(output.starts_with("EVCXR_BEGIN_CONTENT text/html\n<div style=\"width:50%\">"));
+        assert!(output.ends_with("</div>\nEVCXR_END_CONTENT"));
+        assert!(figure.svg().contains("<svg"));
+    }
+}
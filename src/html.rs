@@ -0,0 +1,189 @@
+/*!
+  The self-contained interactive HTML output.
+
+  The chart is embedded into a single HTML file as SVG, with a small script which zooms the
+  figure with the mouse wheel (double click resets the zoom) and shows the tooltip of the
+  nearest data point. The tooltips are driven by the series metadata, which is registered
+  with `HTMLMetadata::add_series` while drawing the figure.
+*/
+use std::io::Write;
+use std::path::Path;
+
+use crate::chart::json_string;
+use crate::coord::Shift;
+use crate::drawing::backend::BackendCoord;
+use crate::drawing::{DrawingArea, IntoDrawingArea, SVGBackend};
+
+const SCRIPT: &str = r##"(function() {
+    var svg = document.querySelector("#plotters-figure svg");
+    var meta = JSON.parse(document.getElementById("plotters-metadata").textContent);
+    var tip = document.getElementById("plotters-tooltip");
+    var base = svg.viewBox.baseVal;
+    var full = [base.x, base.y, base.width, base.height];
+    var view = full.slice();
+    function setView(v) {
+        view = v;
+        svg.setAttribute("viewBox", v.join(" "));
+    }
+    function toSvg(e) {
+        var p = svg.createSVGPoint();
+        p.x = e.clientX;
+        p.y = e.clientY;
+        return p.matrixTransform(svg.getScreenCTM().inverse());
+    }
+    svg.addEventListener("wheel", function(e) {
+        e.preventDefault();
+        var p = toSvg(e), k = e.deltaY < 0 ? 0.8 : 1.25;
+        setView([p.x - (p.x - view[0]) * k, p.y - (p.y - view[1]) * k, view[2] * k, view[3] * k]);
+    });
+    svg.addEventListener("dblclick", function() {
+        setView(full.slice());
+    });
+    svg.addEventListener("mousemove", function(e) {
+        var p = toSvg(e), best = null, dist = Infinity, radius = 8 * view[2] / full[2];
+        meta.forEach(function(s) {
+            s.points.forEach(function(q) {
+                var d = Math.hypot(q[0] - p.x, q[1] - p.y);
+                if (d < dist) {
+                    dist = d;
+                    best = s.name + ": " + q[2];
+                }
+            });
+        });
+        if (best !== null && dist <= radius) {
+            tip.textContent = best;
+            tip.style.left = (e.pageX + 12) + "px";
+            tip.style.top = (e.pageY + 12) + "px";
+            tip.style.display = "block";
+        } else {
+            tip.style.display = "none";
+        }
+    });
+    svg.addEventListener("mouseleave", function() {
+        tip.style.display = "none";
+    });
+})();"##;
+
+const TOOLTIP_STYLE: &str = "position:absolute;display:none;padding:2px 6px;\
+                             background:#FFFFE0;border:1px solid #808080;font:12px sans-serif";
+
+/// The per-series metadata of an HTML figure, which drives the tooltips
+#[derive(Default)]
+pub struct HTMLMetadata {
+    series: Vec<(String, Vec<(BackendCoord, String)>)>,
+}
+
+impl HTMLMetadata {
+    /// Register the tooltips of a series
+    /// - `name`: The name of the series, which is the prefix of the tooltips
+    /// - `points`: The pixel positions of the data points and their tooltip text, the pixel
+    ///   position can be computed by `ChartContext::backend_coord`
+    pub fn add_series<S: Into<String>, I: IntoIterator<Item = (BackendCoord, String)>>(
+        &mut self,
+        name: S,
+        points: I,
+    ) -> &mut Self {
+        self.series
+            .push((name.into(), points.into_iter().collect()));
+        self
+    }
+
+    fn to_json(&self) -> String {
+        let series = self
+            .series
+            .iter()
+            .map(|(name, points)| {
+                let points = points
+                    .iter()
+                    .map(|((x, y), text)| format!("[{},{},{}]", x, y, json_string(text)))
+                    .collect::<Vec<_>>();
+                format!(
+                    "{{\"name\":{},\"points\":[{}]}}",
+                    json_string(name),
+                    points.join(",")
+                )
+            })
+            .collect::<Vec<_>>();
+        format!("[{}]", series.join(","))
+    }
+}
+
+/// The interactive HTML figure
+pub struct HTMLFigure {
+    svg: String,
+    size: (u32, u32),
+    metadata: HTMLMetadata,
+}
+
+impl HTMLFigure {
+    /// Get the HTML source of the figure
+    pub fn html(&self) -> String {
+        let svg = match self.svg.find("<svg") {
+            Some(start) => &self.svg[start..],
+            None => &self.svg[..],
+        };
+        format!(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>Plotters</title>\n</head>\n<body>\n\
+             <div id=\"plotters-figure\" style=\"width:{}px;height:{}px\">{}</div>\n\
+             <div id=\"plotters-tooltip\" style=\"{}\"></div>\n\
+             <script type=\"application/json\" id=\"plotters-metadata\">{}</script>\n\
+             <script>\n{}\n</script>\n</body>\n</html>\n",
+            self.size.0,
+            self.size.1,
+            svg,
+            TOOLTIP_STYLE,
+            self.metadata.to_json(),
+            SCRIPT
+        )
+    }
+
+    /// Save the figure to a HTML file
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
+        std::fs::File::create(path)?.write_all(self.html().as_bytes())
+    }
+}
+
+/// Start drawing an interactive HTML figure
+/// - `size`: The size of the figure in pixels
+/// - `draw`: The function that draws the figure and registers the metadata of the series
+/// - **returns**: The figure, which can be saved with `HTMLFigure::save`
+pub fn html_figure<
+    Draw: FnOnce(
+        DrawingArea<SVGBackend, Shift>,
+        &mut HTMLMetadata,
+    ) -> Result<(), Box<dyn std::error::Error>>,
+>(
+    size: (u32, u32),
+    draw: Draw,
+) -> HTMLFigure {
+    let mut buffer = vec![];
+    let mut metadata = HTMLMetadata::default();
+    {
+        let root = SVGBackend::with_buffer(&mut buffer, size).into_drawing_area();
+        draw(root, &mut metadata).expect("Drawing failure");
+    }
+    HTMLFigure {
+        svg: String::from_utf8_lossy(&buffer).to_string(),
+        size,
+        metadata,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::style::RED;
+
+    #[test]
+    fn test_html_figure() {
+        let figure = html_figure((100, 80), |root, meta| {
+            root.fill(&RED)?;
+            meta.add_series("data", vec![((10, 20), "(1, 2)".to_string())]);
+            Ok(())
+        });
+        let html = figure.html();
+        assert!(html.contains("<div id=\"plotters-figure\" style=\"width:100px;height:80px\"><svg"));
+        assert!(html.contains("[{\"name\":\"data\",\"points\":[[10,20,\"(1, 2)\"]]}]"));
+        assert!(html.contains(SCRIPT));
+    }
+}
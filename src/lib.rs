@@ -355,7 +355,7 @@ Plotters is not limited to any specific type of figure.
 You can create your own types of figures easily with the Plotters API.
 
 But Plotters provides some builtin figure types for convenience.
-Currently, we support line series, point series, candlestick series, and histogram.
+Currently, we support line series, point series, candlestick series, OHLC series, and histogram.
 And the library is designed to be able to render multiple figure into a single image.
 But Plotter is aimed to be a platform that is fully extendable to support any other types of figure.
 
@@ -567,6 +567,16 @@ This is the full list of features that is defined by `Plotters` crate. Use `defa
 | palette\_ext | Use crate `palette` for color expression| palette | Yes |
 | evcxr | Enable Evcxr support, which allows use `Plotters` in Jupyer Note Book | None | No |
 | make\_partial\_axis | Support for API `make_partial_axis`, which allows confguring partial axis from visible protion. | num-trait| Yes |
+| parallel | Enable `BitMapBackend::draw_layers_parallel`, which rasterizes independent layers concurrently | rayon | No |
+| ndarray | Enable `LineSeries::from_array` and `heatmap_from_array`, which create series from `ndarray` arrays | ndarray | No |
+| nalgebra | Accept `nalgebra::Point2` as coordinate, see `LineSeries::from_points` and `PointSeries::from_points` | nalgebra | No |
+| euclid | Accept `euclid::Point2D` as coordinate, see `LineSeries::from_points` and `PointSeries::from_points` | euclid | No |
+| html | Enable `html_figure`, which saves the chart as a self-contained HTML file with tooltips and zoom | svg | No |
+| wgpu | Enable `WgpuBackend`, which renders on the GPU with `wgpu` | wgpu | No |
+| pdf | Enable `PdfBackend`, which writes the figure as a vector PDF document | None | No |
+| cairo | Enable `CairoBackend`, which draws on a Cairo context, e.g. a GTK `DrawingArea` | cairo-rs | No |
+| window | Enable `WindowBackend`, which draws on a native window in real time | minifb | No |
+| video | Enable `BitMapBackend::video`, which encodes the frames into a video with `ffmpeg`, implies `bitmap` enabled | None | No |
 
 ## FAQ List
 
@@ -608,12 +618,16 @@ pub mod coord;
 pub mod data;
 pub mod drawing;
 pub mod element;
+pub mod error;
 pub mod series;
 pub mod style;
 
 #[cfg(feature = "evcxr")]
 pub mod evcxr;
 
+#[cfg(feature = "html")]
+pub mod html;
+
 #[cfg(test)]
 pub use crate::drawing::create_mocked_drawing_area;
 
@@ -622,30 +636,45 @@ pub use palette;
 
 /// The module imports the most commonly used types and modules in Plotters
 pub mod prelude {
-    pub use crate::chart::{ChartBuilder, ChartContext, LabelAreaPosition, SeriesLabelPosition};
+    pub use crate::chart::{
+        ChartBuilder, ChartContext, ColorBar, FinancialChart, LabelAreaPosition, LinkedCharts,
+        RealtimePlot, SeriesLabelPosition,
+    };
     pub use crate::coord::{
-        CoordTranslate, IntoCentric, IntoPartialAxis, LogCoord, LogRange, LogScalable, Ranged,
-        RangedCoord, RangedCoordf32, RangedCoordf64, RangedCoordi32, RangedCoordi64,
-        RangedCoordu32, RangedCoordu64,
+        Cartesian3d, Category, CoordTranslate, IntoCentric, IntoLogRange, IntoPartialAxis,
+        IntoPercentage, IntoSegmented, IntoWithKeyPoints, LogCoord, LogRange, LogScalable,
+        PolarCoord, Ranged, RangedCategory, RangedCoord, RangedCoordf32, RangedCoordf64,
+        RangedCoordi32, RangedCoordi64, RangedCoordu32, RangedCoordu64, SegmentValue,
     };
 
     #[cfg(feature = "chrono")]
-    pub use crate::coord::{RangedDate, RangedDateTime, RangedDuration};
+    pub use crate::coord::{
+        RangedDate, RangedDateTime, RangedDuration, RangedNaiveDate, RangedNaiveDateTime,
+        RangedStdDuration, RangedSystemTime,
+    };
 
     #[cfg(feature = "make_partial_axis")]
     pub use crate::coord::make_partial_axis;
 
     pub use crate::drawing::*;
-    pub use crate::series::{AreaSeries, Histogram, LineSeries, PointSeries};
+    #[cfg(feature = "ndarray")]
+    pub use crate::series::heatmap_from_array;
+    pub use crate::series::{
+        AreaSeries, BinnedHistogram, Binning, BubbleSeries, ContourSeries, GanttSeries,
+        GroupedBarSeries, HeatmapSeries, Histogram, LineSeries, NonFinitePolicy, OHLCSeries,
+        PointSeries, SmoothedLineSeries, StackedBarSeries, SurfaceSeries,
+    };
     pub use crate::style::{
-        Color, FontDesc, FontTransform, HSLColor, IntoFont, Palette, Palette100, Palette99,
-        Palette9999, PaletteColor, RGBColor, ShapeStyle, SimpleColor, TextStyle,
+        Color, ColorMap, CoolwarmColorMap, FontDesc, FontTransform, GrayscaleColorMap, HSLColor,
+        IntoFont, MagmaColorMap, Palette, Palette100, Palette99, Palette9999, PaletteColor,
+        RGBColor, ShapeStyle, SimpleColor, TextStyle, ViridisColorMap,
     };
     pub use crate::style::{BLACK, BLUE, CYAN, GREEN, MAGENTA, RED, TRANSPARENT, WHITE, YELLOW};
 
     pub use crate::element::{
-        CandleStick, Circle, Cross, DynElement, EmptyElement, ErrorBar, IntoDynElement,
-        MultiLineText, Path, Pixel, Polygon, Rectangle, Text, TriangleMarker,
+        Arrow, ArrowHead, BitMapElement, CandleStick, Circle, Cross, DynElement, EmptyElement,
+        ErrorBar, IntoDynElement, Marker, MarkerShape, MultiLineText, OHLCBar, Path, Pie, Pixel,
+        Polygon, Rectangle, Smoothing, Text, TriangleMarker,
     };
 
     #[allow(type_alias_bounds)]
@@ -657,4 +686,7 @@ pub mod prelude {
 
     #[cfg(feature = "evcxr")]
     pub use crate::evcxr::evcxr_figure;
+
+    #[cfg(feature = "html")]
+    pub use crate::html::html_figure;
 }
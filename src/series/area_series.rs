@@ -3,17 +3,29 @@ use crate::element::{DynElement, IntoDynElement, Path, Polygon};
 use crate::style::colors::TRANSPARENT;
 use crate::style::ShapeStyle;
 
+/// The lower edge of the area
+enum AreaBase<X, Y> {
+    /// The area is filled down to a constant value
+    Baseline(Y),
+    /// The area is filled down to another line, which makes a band
+    Line(Vec<(X, Y)>),
+}
+
 /// An area series is similar to a line series but use a filled polygon
 pub struct AreaSeries<DB: DrawingBackend, X: Clone, Y: Clone> {
     area_style: ShapeStyle,
     border_style: ShapeStyle,
-    baseline: Y,
+    base: AreaBase<X, Y>,
     data: Vec<(X, Y)>,
     state: u32,
     _p: std::marker::PhantomData<DB>,
 }
 
 impl<DB: DrawingBackend, X: Clone, Y: Clone> AreaSeries<DB, X, Y> {
+    /// Create an area series, which fills the area between the line and the baseline
+    /// - `iter`: The iterator of the points of the line
+    /// - `baseline`: The value of the baseline
+    /// - `area_style`: The style of the filled area
     pub fn new<S: Into<ShapeStyle>, I: IntoIterator<Item = (X, Y)>>(
         iter: I,
         baseline: Y,
@@ -21,7 +33,7 @@ impl<DB: DrawingBackend, X: Clone, Y: Clone> AreaSeries<DB, X, Y> {
     ) -> Self {
         Self {
             area_style: area_style.into(),
-            baseline,
+            base: AreaBase::Baseline(baseline),
             data: iter.into_iter().collect(),
             state: 0,
             border_style: (&TRANSPARENT).into(),
@@ -29,6 +41,30 @@ impl<DB: DrawingBackend, X: Clone, Y: Clone> AreaSeries<DB, X, Y> {
         }
     }
 
+    /// Create a band series, which fills the area between the lower and the upper lines, e.g.
+    /// the confidence interval of a measurement
+    /// - `iter`: The iterator of `(x, lower, upper)`
+    /// - `area_style`: The style of the filled band
+    pub fn band<S: Into<ShapeStyle>, I: IntoIterator<Item = (X, Y, Y)>>(
+        iter: I,
+        area_style: S,
+    ) -> Self {
+        let (lower, upper) = iter
+            .into_iter()
+            .map(|(x, lower, upper)| ((x.clone(), lower), (x, upper)))
+            .unzip();
+        Self {
+            area_style: area_style.into(),
+            base: AreaBase::Line(lower),
+            data: upper,
+            state: 0,
+            border_style: (&TRANSPARENT).into(),
+            _p: std::marker::PhantomData,
+        }
+    }
+
+    /// Set the style of the border. The border is the upper line of the area, and a band has
+    /// both the lower and the upper lines as the border.
     pub fn border_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
         self.border_style = style.into();
         self
@@ -42,8 +78,13 @@ impl<DB: DrawingBackend, X: Clone + 'static, Y: Clone + 'static> Iterator for Ar
             let mut data: Vec<_> = self.data.clone();
 
             if !data.is_empty() {
-                data.push((data[data.len() - 1].0.clone(), self.baseline.clone()));
-                data.push((data[0].0.clone(), self.baseline.clone()));
+                match &self.base {
+                    AreaBase::Baseline(baseline) => {
+                        data.push((data[data.len() - 1].0.clone(), baseline.clone()));
+                        data.push((data[0].0.clone(), baseline.clone()));
+                    }
+                    AreaBase::Line(lower) => data.extend(lower.iter().rev().cloned()),
+                }
             }
 
             self.state = 1;
@@ -55,8 +96,50 @@ impl<DB: DrawingBackend, X: Clone + 'static, Y: Clone + 'static> Iterator for Ar
             self.state = 2;
 
             Some(Path::new(data, self.border_style.clone()).into_dyn())
+        } else if self.state == 2 {
+            self.state = 3;
+
+            match &self.base {
+                AreaBase::Line(lower) => {
+                    Some(Path::new(lower.clone(), self.border_style.clone()).into_dyn())
+                }
+                AreaBase::Baseline(_) => None,
+            }
         } else {
             None
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_band_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_fill_polygon(|_, points| {
+                // The upper line goes forward and the lower line comes back
+                assert_eq!(points.len(), 6);
+                assert_eq!(points[0].0, points[5].0);
+                assert_eq!(points[2].0, points[3].0);
+                assert!(points[0].1 < points[5].1);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 1);
+                assert_eq!(b.num_draw_path_call, 2);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..10, 0..10)
+            .expect("Create chart");
+
+        chart
+            .draw_series(
+                AreaSeries::band(vec![(0, 1, 3), (5, 2, 6), (9, 4, 8)], &RED.mix(0.2))
+                    .border_style(&RED),
+            )
+            .expect("Drawing series");
+    }
+}
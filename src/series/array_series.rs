@@ -0,0 +1,82 @@
+use std::ops::Range;
+
+use ndarray::{ArrayBase, Data, Ix1, Ix2};
+
+use super::LineSeries;
+use crate::element::Rectangle;
+use crate::style::ShapeStyle;
+
+impl LineSeries<(f64, f64), Vec<(f64, f64)>> {
+    /// Create a line series from an one dimensional array. The values are evenly placed on the
+    /// X range, the first value is at `x_range.start` and the last one is at `x_range.end`.
+    /// - `x_range`: The X range the values are placed on
+    /// - `data`: The Y values
+    /// - `style`: The style of the line
+    pub fn from_array<D: Data<Elem = f64>, S: Into<ShapeStyle>>(
+        x_range: Range<f64>,
+        data: &ArrayBase<D, Ix1>,
+        style: S,
+    ) -> Self {
+        let step = if data.len() > 1 {
+            (x_range.end - x_range.start) / (data.len() - 1) as f64
+        } else {
+            0.0
+        };
+        let points = data
+            .iter()
+            .enumerate()
+            .map(|(idx, &y)| (x_range.start + step * idx as f64, y))
+            .collect::<Vec<_>>();
+        Self::new(points, style)
+    }
+}
+
+/// Create a heatmap from a two dimensional array. Each value of the array becomes a cell,
+/// the cell of `data[[row, col]]` covers `(col, row)` to `(col + 1, row + 1)` in the guest
+/// coordinate, so the chart is expected to have the range `0.0..cols` by `0.0..rows`.
+/// - `data`: The values of the heatmap
+/// - `style_func`: The function that maps a value to the style of its cell, for example
+///   `|v| RGBColor((v * 255.0) as u8, 0, 0).filled()`
+/// - **returns**: The series of cells, which can be taken by `ChartContext::draw_series`
+pub fn heatmap_from_array<'a, D, S, F>(
+    data: &'a ArrayBase<D, Ix2>,
+    style_func: F,
+) -> impl Iterator<Item = Rectangle<(f64, f64)>> + 'a
+where
+    D: Data<Elem = f64>,
+    S: Into<ShapeStyle>,
+    F: Fn(f64) -> S + 'a,
+{
+    data.indexed_iter().map(move |((row, col), &v)| {
+        let (x, y) = (col as f64, row as f64);
+        Rectangle::new([(x, y), (x + 1.0, y + 1.0)], style_func(v))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::element::PointCollection;
+    use crate::style::{Color, RGBColor, BLACK};
+    use ndarray::{arr1, arr2};
+
+    #[test]
+    fn test_line_series_from_array() {
+        let data = arr1(&[1.0, 2.0, 3.0]);
+        let path = LineSeries::from_array(0.0..1.0, &data, &BLACK)
+            .next()
+            .unwrap();
+        let points = (&path).point_iter().to_vec();
+        assert_eq!(points, vec![(0.0, 1.0), (0.5, 2.0), (1.0, 3.0)]);
+    }
+
+    #[test]
+    fn test_heatmap_from_array() {
+        let data = arr2(&[[0.0, 1.0, 0.5], [1.0, 0.0, 0.25]]);
+        let cells = heatmap_from_array(&data, |v| RGBColor((v * 255.0) as u8, 0, 0).filled())
+            .collect::<Vec<_>>();
+        assert_eq!(cells.len(), 6);
+        let points = (&cells[5]).point_iter().to_vec();
+        assert_eq!(points, vec![(2.0, 1.0), (3.0, 2.0)]);
+    }
+}
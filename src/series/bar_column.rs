@@ -0,0 +1,42 @@
+use crate::style::{Color, Palette, Palette99, ShapeStyle};
+
+/// A value column of a bar series with multiple columns, i.e. a layer of `StackedBarSeries` or
+/// a group of `GroupedBarSeries`, which is made of one bar of each category
+pub(crate) struct BarColumn<E> {
+    pub(crate) label: Option<String>,
+    pub(crate) style: ShapeStyle,
+    pub(crate) bars: Vec<E>,
+}
+
+impl<E> BarColumn<E> {
+    /// Create the empty columns of the rows. There're as many columns as the longest row has
+    /// values, or as the columns with a label, whichever is more. The columns without a label
+    /// are colored with `Palette99`.
+    /// - `labeled`: The labels and the styles of the leading columns
+    /// - `rows`: The categories and their value columns
+    pub(crate) fn for_rows<X, A>(
+        labeled: &[(Option<String>, ShapeStyle)],
+        rows: &[(X, Vec<A>)],
+    ) -> Vec<Self> {
+        let columns = rows
+            .iter()
+            .map(|(_, values)| values.len())
+            .max()
+            .unwrap_or(0)
+            .max(labeled.len());
+
+        (0..columns)
+            .map(|idx| {
+                let (label, style) = labeled
+                    .get(idx)
+                    .cloned()
+                    .unwrap_or_else(|| (None, Palette99::pick(idx).filled()));
+                BarColumn {
+                    label,
+                    style: style.filled(),
+                    bars: vec![],
+                }
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,288 @@
+use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use crate::element::{Circle, Drawable, PointCollection};
+use crate::style::{ColorMap, FontDesc, RGBAColor, ShapeStyle, TextStyle};
+
+use std::ops::Range;
+
+/// The bubble series, which draws a circle for each point with the radius decided by a value
+/// of the point, and optionally the color decided by another value.
+///
+/// By default the values are scaled so that the area of the circle is proportional to the
+/// value and the largest value gets the max radius.
+///
+/// ```rust,no_run
+/// use plotters::prelude::*;
+///
+/// let root = BitMapBackend::new("bubble.png", (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_ranged(0..10, 0..10)
+///     .unwrap();
+/// let series = BubbleSeries::new(vec![((2, 3), 10.0), ((5, 5), 40.0), ((8, 2), 90.0)], &BLUE)
+///     .max_radius(30)
+///     .color_by(vec![0.0, 0.5, 1.0], 0.0..1.0, ViridisColorMap);
+/// let legend = series.size_legend((500, 20), &[10.0, 50.0, 90.0]);
+/// chart.draw_series(series).unwrap();
+/// root.draw(&legend).unwrap();
+/// ```
+pub struct BubbleSeries<Coord> {
+    data: std::vec::IntoIter<(Coord, f64)>,
+    colors: std::vec::IntoIter<Option<RGBAColor>>,
+    style: ShapeStyle,
+    max_radius: Option<u32>,
+    max_value: f64,
+}
+
+impl<Coord> BubbleSeries<Coord> {
+    /// Create a new bubble series
+    /// - `data`: The points and the values deciding the sizes, the points without a positive
+    ///   finite value are not drawn
+    /// - `style`: The style of the bubbles
+    pub fn new<I: IntoIterator<Item = (Coord, f64)>, S: Into<ShapeStyle>>(
+        data: I,
+        style: S,
+    ) -> Self {
+        let data: Vec<_> = data.into_iter().collect();
+        let max_value = data
+            .iter()
+            .map(|(_, v)| *v)
+            .filter(|v| v.is_finite())
+            .fold(0.0, f64::max);
+        Self {
+            data: data.into_iter(),
+            colors: vec![].into_iter(),
+            style: style.into(),
+            max_radius: Some(20),
+            max_value,
+        }
+    }
+
+    /// Set the radius of the largest value in pixels, which is 20 by default
+    pub fn max_radius(mut self, radius: u32) -> Self {
+        self.max_radius = Some(radius);
+        self
+    }
+
+    /// Disable the size scaling, the values are used as the radius in pixels
+    pub fn no_scaling(mut self) -> Self {
+        self.max_radius = None;
+        self
+    }
+
+    /// Color the bubbles with the colormap
+    /// - `values`: The values deciding the colors, in the same order as the points. The bubbles
+    ///   of the non-finite values keep the color of the style.
+    /// - `value_range`: The range of the values mapped onto the colormap, the values out of the
+    ///   range are clamped
+    /// - `colormap`: The colormap
+    pub fn color_by<I: IntoIterator<Item = f64>, M: ColorMap>(
+        mut self,
+        values: I,
+        value_range: Range<f64>,
+        colormap: M,
+    ) -> Self {
+        let span = value_range.end - value_range.start;
+        self.colors = values
+            .into_iter()
+            .map(|v| {
+                if !v.is_finite() {
+                    return None;
+                }
+                let t = if span == 0.0 {
+                    0.0
+                } else {
+                    (v - value_range.start) / span
+                };
+                Some(colormap.get(t.clamp(0.0, 1.0)))
+            })
+            .collect::<Vec<_>>()
+            .into_iter();
+        self
+    }
+
+    /// Get the radius of the bubble in pixels for the value
+    pub fn radius(&self, value: f64) -> u32 {
+        if !value.is_finite() || value <= 0.0 {
+            return 0;
+        }
+        match self.max_radius {
+            Some(max) if self.max_value > 0.0 => {
+                ((value / self.max_value).sqrt() * f64::from(max)).round() as u32
+            }
+            Some(_) => 0,
+            None => value.round() as u32,
+        }
+    }
+
+    /// Create the legend showing the sizes of the values
+    /// - `pos`: The upper left corner of the legend
+    /// - `values`: The values shown in the legend
+    pub fn size_legend<'a, LC>(&self, pos: LC, values: &[f64]) -> BubbleLegend<'a, LC> {
+        BubbleLegend {
+            pos,
+            entries: values
+                .iter()
+                .map(|v| (format!("{}", v), self.radius(*v)))
+                .collect(),
+            style: ShapeStyle {
+                filled: false,
+                ..self.style.clone()
+            },
+            label_style: FontDesc::new("Arial", 12.0).into(),
+        }
+    }
+}
+
+impl<Coord> Iterator for BubbleSeries<Coord> {
+    type Item = Circle<Coord>;
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((coord, value)) = self.data.next() {
+            let color = self.colors.next().and_then(|color| color);
+            let radius = self.radius(value);
+            if radius == 0 {
+                continue;
+            }
+            let style = match color {
+                Some(color) => ShapeStyle {
+                    color,
+                    ..self.style.clone()
+                },
+                None => self.style.clone(),
+            };
+            return Some(Circle::new(coord, radius, style));
+        }
+        None
+    }
+}
+
+/// The legend of a bubble series, which shows a circle for each value in a column with the
+/// values as the labels. It's created by `BubbleSeries::size_legend`.
+pub struct BubbleLegend<'a, Coord> {
+    pos: Coord,
+    entries: Vec<(String, u32)>,
+    style: ShapeStyle,
+    label_style: TextStyle<'a>,
+}
+
+impl<'a, Coord> BubbleLegend<'a, Coord> {
+    /// Set the style of the labels
+    pub fn label_style<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        self.label_style = style.into();
+        self
+    }
+}
+
+impl<'b, 'a, Coord: 'b> PointCollection<'b, Coord> for &'b BubbleLegend<'a, Coord> {
+    type Borrow = &'b Coord;
+    type IntoIter = std::iter::Once<&'b Coord>;
+    fn point_iter(self) -> std::iter::Once<&'b Coord> {
+        std::iter::once(&self.pos)
+    }
+}
+
+impl<'a, Coord, DB: DrawingBackend> Drawable<DB> for BubbleLegend<'a, Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (x, mut y) = match points.next() {
+            Some(pos) => pos,
+            None => return Ok(()),
+        };
+        let max_radius = self.entries.iter().map(|e| e.1).max().unwrap_or(0) as i32;
+
+        for (label, radius) in self.entries.iter() {
+            let radius = *radius as i32;
+            let cy = y + radius;
+            backend.draw_circle((x + max_radius, cy), radius as u32, &self.style, false)?;
+
+            let (_, h) = self.label_style.font.box_size(label).unwrap_or((0, 0));
+            backend.draw_text(
+                label,
+                &self.label_style.font,
+                (x + max_radius * 2 + 5, cy - h as i32 / 2),
+                &self.label_style.color,
+            )?;
+
+            y += (radius * 2).max(h as i32) + 5;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_bubble_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_circle(|c, _, _, _, r| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(r, 5);
+            });
+            m.check_draw_circle(|c, _, _, _, r| {
+                assert_eq!(c, BLUE.to_rgba());
+                assert_eq!(r, 10);
+            });
+            m.drop_check(|b| {
+                // The points without a positive size are skipped
+                assert_eq!(b.num_draw_circle_call, 2);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..4, 0..4)
+            .expect("Create chart");
+
+        let series = BubbleSeries::new(
+            vec![
+                ((0, 1), 25.0),
+                ((1, 1), 100.0),
+                ((2, 1), 0.0),
+                ((3, 1), f64::NAN),
+            ],
+            &RED,
+        )
+        .max_radius(10)
+        .color_by(vec![0.0, 1.0], 0.0..1.0, |t: f64| {
+            if t > 0.5 {
+                BLUE.to_rgba()
+            } else {
+                RED.to_rgba()
+            }
+        });
+        assert_eq!(series.radius(100.0), 10);
+        assert_eq!(series.radius(25.0), 5);
+
+        let legend: BubbleLegend<(i32, i32)> = series.size_legend((0, 0), &[100.0]);
+        assert_eq!(legend.entries, vec![("100".to_string(), 10)]);
+
+        chart.draw_series(series).expect("Drawing Error");
+    }
+
+    #[test]
+    fn test_bubble_color_range() {
+        let red = |t: f64| RGBColor((t * 255.0) as u8, 0, 0).to_rgba();
+        let reds = |series: BubbleSeries<(i32, i32)>| -> Vec<_> {
+            series.colors.map(|c| c.map(|c| c.rgb().0)).collect()
+        };
+
+        // The values out of the range are clamped and the non-finite ones keep the style
+        let series = BubbleSeries::new(vec![((0, 0), 1.0); 4], &BLUE).color_by(
+            vec![-1.0, 0.5, 2.0, f64::NAN],
+            0.0..1.0,
+            red,
+        );
+        assert_eq!(reds(series), vec![Some(0), Some(127), Some(255), None]);
+
+        // An empty range doesn't make NaN
+        let series = BubbleSeries::new(vec![((0, 0), 1.0); 2], &BLUE).color_by(
+            vec![1.0, 2.0],
+            1.0..1.0,
+            red,
+        );
+        assert_eq!(reds(series), vec![Some(0), Some(0)]);
+    }
+}
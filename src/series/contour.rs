@@ -0,0 +1,368 @@
+use std::collections::HashMap;
+use std::ops::Range;
+
+use crate::coord::{Ranged, RangedCoordf64};
+use crate::drawing::DrawingBackend;
+use crate::element::{DynElement, IntoDynElement, Path, Polygon};
+use crate::style::{Color, ColorMap, ShapeStyle, BLACK};
+
+/// A vertex of a cell, which is `(x, y, value)`
+type Vertex = (f64, f64, f64);
+
+/// The key of a grid edge, which is the direction and the grid point the edge starts from
+type EdgeKey = (bool, usize, usize);
+
+/// The crossing of an iso-line on a grid edge
+type Crossing = (EdgeKey, (f64, f64));
+
+/// The contour series, which draws the iso-lines of a field sampled on a grid, and optionally
+/// fills the bands between the levels with the colors of a colormap. The iso-lines are traced
+/// with the marching squares algorithm.
+///
+/// ```rust,no_run
+/// use plotters::prelude::*;
+///
+/// let root = BitMapBackend::new("contour.png", (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_ranged(-3.0..3.0, -3.0..3.0)
+///     .unwrap();
+/// let values: Vec<Vec<f64>> = (0..=60)
+///     .map(|y| {
+///         (0..=60)
+///             .map(|x| {
+///                 let (x, y) = (x as f64 / 10.0 - 3.0, y as f64 / 10.0 - 3.0);
+///                 (-(x * x + y * y) / 2.0).exp()
+///             })
+///             .collect()
+///     })
+///     .collect();
+/// chart
+///     .draw_series(
+///         ContourSeries::new((-3.0, -3.0)..(3.0, 3.0), &values)
+///             .auto_levels(8)
+///             .filled(ViridisColorMap),
+///     )
+///     .unwrap();
+/// ```
+pub struct ContourSeries<DB: DrawingBackend> {
+    extent: [(f64, f64); 2],
+    columns: usize,
+    values: Vec<f64>,
+    levels: Option<Vec<f64>>,
+    auto_levels: usize,
+    line_style: ShapeStyle,
+    colormap: Option<Box<dyn ColorMap>>,
+    elements: Option<std::vec::IntoIter<DynElement<'static, DB, (f64, f64)>>>,
+}
+
+impl<DB: DrawingBackend> ContourSeries<DB> {
+    /// Create a new contour series
+    /// - `extent`: The corners of the grid, the first row of the values is at the start
+    /// - `values`: The rows of the values, each value is the sample at a grid point and all
+    ///   rows should have the same length. The cells with a non-finite value are skipped.
+    pub fn new<R: AsRef<[f64]>>(extent: Range<(f64, f64)>, values: &[R]) -> Self {
+        let columns = values
+            .iter()
+            .map(|row| row.as_ref().len())
+            .min()
+            .unwrap_or(0);
+        Self {
+            extent: [extent.start, extent.end],
+            columns,
+            values: values
+                .iter()
+                .flat_map(|row| row.as_ref()[..columns].iter().cloned())
+                .collect(),
+            levels: None,
+            auto_levels: 10,
+            line_style: (&BLACK).into(),
+            colormap: None,
+            elements: None,
+        }
+    }
+
+    /// Set the levels of the iso-lines
+    pub fn levels<I: IntoIterator<Item = f64>>(mut self, levels: I) -> Self {
+        let mut levels: Vec<_> = levels.into_iter().filter(|l| l.is_finite()).collect();
+        levels.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        self.levels = Some(levels);
+        self
+    }
+
+    /// Choose the levels automatically, which are the key points between the minimum and the
+    /// maximum values. This is the default with at most 10 levels.
+    /// - `max_levels`: The maximum number of levels
+    pub fn auto_levels(mut self, max_levels: usize) -> Self {
+        self.levels = None;
+        self.auto_levels = max_levels;
+        self
+    }
+
+    /// Set the style of the iso-lines
+    pub fn line_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.line_style = style.into();
+        self
+    }
+
+    /// Fill the bands between the levels with the colors of the colormap, the band below the
+    /// lowest level takes the start of the colormap and the one above the highest level takes
+    /// the end
+    pub fn filled<M: ColorMap + 'static>(mut self, colormap: M) -> Self {
+        self.colormap = Some(Box::new(colormap));
+        self
+    }
+
+    fn get_levels(&self) -> Vec<f64> {
+        if let Some(levels) = &self.levels {
+            return levels.clone();
+        }
+        let finite = self.values.iter().filter(|v| v.is_finite());
+        let min = finite.clone().fold(f64::INFINITY, |a, b| a.min(*b));
+        let max = finite.fold(f64::NEG_INFINITY, |a, b| a.max(*b));
+        if min >= max {
+            return vec![];
+        }
+        RangedCoordf64::from(min..max)
+            .key_points(self.auto_levels)
+            .into_iter()
+            .filter(|l| *l > min && *l < max)
+            .collect()
+    }
+
+    /// The grid point `(i, j)` with its value
+    fn vertex(&self, i: usize, j: usize) -> Vertex {
+        let rows = self.values.len() / self.columns;
+        let [(x0, y0), (x1, y1)] = self.extent;
+        let step = |from: f64, to: f64, idx: usize, count: usize| {
+            from + (to - from) * idx as f64 / (count.max(2) - 1) as f64
+        };
+        (
+            step(x0, x1, i, self.columns),
+            step(y0, y1, j, rows),
+            self.values[j * self.columns + i],
+        )
+    }
+
+    /// Iterate over the cells with all the corners finite, the corners are in the order of
+    /// `(i, j)`, `(i + 1, j)`, `(i + 1, j + 1)` and `(i, j + 1)`
+    fn cells(&self) -> impl Iterator<Item = ((usize, usize), [Vertex; 4])> + '_ {
+        let rows = self.values.len().checked_div(self.columns).unwrap_or(0);
+        (0..rows.saturating_sub(1))
+            .flat_map(move |j| (0..self.columns.saturating_sub(1)).map(move |i| (i, j)))
+            .map(move |(i, j)| {
+                (
+                    (i, j),
+                    [
+                        self.vertex(i, j),
+                        self.vertex(i + 1, j),
+                        self.vertex(i + 1, j + 1),
+                        self.vertex(i, j + 1),
+                    ],
+                )
+            })
+            .filter(|(_, cell)| cell.iter().all(|v| v.2.is_finite()))
+    }
+
+    /// Trace the iso-lines of the level with marching squares
+    fn trace(&self, level: f64) -> Vec<Vec<(f64, f64)>> {
+        let mut segments = vec![];
+        for ((i, j), cell) in self.cells() {
+            // The edges in the order around the cell: bottom, right, top and left
+            let keys = [
+                (true, i, j),
+                (false, i + 1, j),
+                (true, i, j + 1),
+                (false, i, j),
+            ];
+            let mut crossings = vec![];
+            for edge in 0..4 {
+                let (a, b) = (cell[edge], cell[(edge + 1) % 4]);
+                if (a.2 >= level) != (b.2 >= level) {
+                    let t = (level - a.2) / (b.2 - a.2);
+                    crossings.push((keys[edge], (a.0 + (b.0 - a.0) * t, a.1 + (b.1 - a.1) * t)));
+                }
+            }
+
+            match crossings.len() {
+                2 => segments.push((crossings[0], crossings[1])),
+                4 => {
+                    // The saddle is resolved by the average of the corners, the corners on
+                    // the other side of the center are cut off
+                    let center = cell.iter().map(|v| v.2).sum::<f64>() / 4.0;
+                    if (center >= level) == (cell[0].2 >= level) {
+                        segments.push((crossings[0], crossings[1]));
+                        segments.push((crossings[2], crossings[3]));
+                    } else {
+                        segments.push((crossings[3], crossings[0]));
+                        segments.push((crossings[1], crossings[2]));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        join_segments(segments)
+    }
+}
+
+/// Join the segments sharing the crossing on the same grid edge into polylines
+fn join_segments(segments: Vec<(Crossing, Crossing)>) -> Vec<Vec<(f64, f64)>> {
+    let mut by_key: HashMap<EdgeKey, Vec<usize>> = HashMap::new();
+    for (idx, (a, b)) in segments.iter().enumerate() {
+        by_key.entry(a.0).or_default().push(idx);
+        by_key.entry(b.0).or_default().push(idx);
+    }
+
+    let mut used = vec![false; segments.len()];
+    let mut lines = vec![];
+    for start in 0..segments.len() {
+        if used[start] {
+            continue;
+        }
+        used[start] = true;
+        let (a, b) = segments[start];
+        let mut line = std::collections::VecDeque::new();
+        line.push_back(a.1);
+        line.push_back(b.1);
+
+        // Extend the line from both ends until there's no more connected segment
+        for &(mut key, forward) in &[(b.0, true), (a.0, false)] {
+            while let Some(&next) = by_key[&key].iter().find(|idx| !used[**idx]) {
+                used[next] = true;
+                let (c, d) = segments[next];
+                let (point, other) = if c.0 == key { (d.1, d.0) } else { (c.1, c.0) };
+                if forward {
+                    line.push_back(point);
+                } else {
+                    line.push_front(point);
+                }
+                key = other;
+            }
+        }
+
+        lines.push(line.into_iter().collect());
+    }
+    lines
+}
+
+/// Clip the polygon to the part where the value is above (or below) the level, the vertices
+/// on the edges are interpolated linearly
+fn clip(polygon: &[Vertex], level: f64, above: bool) -> Vec<Vertex> {
+    let inside = |v: &Vertex| if above { v.2 >= level } else { v.2 < level };
+    let mut result = vec![];
+    for (idx, a) in polygon.iter().enumerate() {
+        let b = &polygon[(idx + 1) % polygon.len()];
+        if inside(a) {
+            result.push(*a);
+        }
+        if inside(a) != inside(b) {
+            let t = (level - a.2) / (b.2 - a.2);
+            result.push((a.0 + (b.0 - a.0) * t, a.1 + (b.1 - a.1) * t, level));
+        }
+    }
+    result
+}
+
+impl<DB: DrawingBackend> Iterator for ContourSeries<DB> {
+    type Item = DynElement<'static, DB, (f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.elements.is_none() {
+            let levels = self.get_levels();
+            let mut elements = vec![];
+
+            if let Some(colormap) = &self.colormap {
+                let bands = levels.len() + 1;
+                for ((_, _), cell) in self.cells() {
+                    for band in 0..bands {
+                        let mut polygon = cell.to_vec();
+                        if band > 0 {
+                            polygon = clip(&polygon, levels[band - 1], true);
+                        }
+                        if band < levels.len() && polygon.len() >= 3 {
+                            polygon = clip(&polygon, levels[band], false);
+                        }
+                        if polygon.len() < 3 {
+                            continue;
+                        }
+                        let t = if bands > 1 {
+                            band as f64 / (bands - 1) as f64
+                        } else {
+                            0.0
+                        };
+                        let points: Vec<_> = polygon.iter().map(|v| (v.0, v.1)).collect();
+                        elements.push(Polygon::new(points, colormap.get(t).filled()).into_dyn());
+                    }
+                }
+            }
+
+            for level in levels.iter() {
+                for line in self.trace(*level) {
+                    elements.push(Path::new(line, self.line_style.clone()).into_dyn());
+                }
+            }
+
+            self.elements = Some(elements.into_iter());
+        }
+
+        self.elements.as_mut().and_then(|e| e.next())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_contour_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_path(|_, _, path| {
+                // The four cells make a single closed diamond around the peak
+                assert_eq!(path.len(), 5);
+                assert_eq!(path[0], path[4]);
+            });
+            m.drop_check(|b| {
+                // Each cell is split into the band below and the band above the level
+                assert_eq!(b.num_fill_polygon_call, 8);
+                assert_eq!(b.num_draw_path_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0.0..2.0, 0.0..2.0)
+            .expect("Create chart");
+
+        // A peak in the middle of a 3x3 grid
+        let values = [[0.0, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, 0.0]];
+        chart
+            .draw_series(
+                ContourSeries::new((0.0, 0.0)..(2.0, 2.0), &values)
+                    .levels(vec![1.0])
+                    .filled(GrayscaleColorMap),
+            )
+            .expect("Drawing Error");
+    }
+
+    #[test]
+    fn test_contour_bands() {
+        let cell = [
+            (0.0, 0.0, 0.0),
+            (1.0, 0.0, 2.0),
+            (1.0, 1.0, 2.0),
+            (0.0, 1.0, 0.0),
+        ];
+        let above = clip(&cell, 1.0, true);
+        assert_eq!(
+            above,
+            vec![
+                (0.5, 0.0, 1.0),
+                (1.0, 0.0, 2.0),
+                (1.0, 1.0, 2.0),
+                (0.5, 1.0, 1.0)
+            ]
+        );
+        let below = clip(&cell, 1.0, false);
+        assert_eq!(below.len(), 4);
+        assert!(below.iter().all(|v| v.0 <= 0.5));
+    }
+}
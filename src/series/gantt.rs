@@ -0,0 +1,231 @@
+use std::fmt::Display;
+
+use crate::coord::{Category, IntoSegmented, RangedCategory, SegmentValue, SegmentedCoord};
+use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use crate::element::{Drawable, Marker, MarkerShape, PointCollection};
+use crate::style::{Color, ShapeStyle, BLACK};
+
+/// The Gantt chart series, which draws the tasks as horizontal bars from their start to their
+/// end, one row for each label, and the milestones as diamonds. The X axis is usually a date or
+/// datetime coordinate, but any coordinate works.
+///
+/// The rows are a categorical Y axis, which is created by `GanttSeries::task_axis` with the
+/// labels in the order they first appear, from the top to the bottom. The axis is segmented,
+/// so the label of each row is in the middle of its bar.
+///
+/// ```rust,no_run
+/// use chrono::{TimeZone, Utc};
+/// use plotters::prelude::*;
+///
+/// let day = |d| Utc.ymd(2019, 10, d);
+/// let series = GanttSeries::new(
+///     vec![
+///         ("Design", day(1), day(8)),
+///         ("Build", day(6), day(20)),
+///         ("Test", day(15), day(25)),
+///     ],
+///     BLUE.filled(),
+/// )
+/// .milestone("Release", day(28));
+///
+/// let root = BitMapBackend::new("gantt.png", (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .x_label_area_size(30)
+///     .y_label_area_size(60)
+///     .build_ranged(day(1)..day(31), series.task_axis())
+///     .unwrap();
+/// chart.configure_mesh().disable_y_mesh().draw().unwrap();
+/// chart.draw_series(series).unwrap();
+/// ```
+pub struct GanttSeries<T, L> {
+    labels: Vec<L>,
+    /// The rows, the start and the end of the tasks
+    tasks: Vec<(usize, T, T)>,
+    /// The rows and the times of the milestones
+    milestones: Vec<(usize, T)>,
+    style: ShapeStyle,
+    milestone_style: ShapeStyle,
+    margin: u32,
+    points: Vec<(T, SegmentValue<Category<L>>)>,
+}
+
+impl<T: Clone, L: PartialEq + Clone> GanttSeries<T, L> {
+    /// Create a new Gantt chart series
+    /// - `tasks`: The iterator of the tasks, each of them is the label, the start and the end.
+    ///   The tasks with the same label are drawn in the same row.
+    /// - `style`: The style of the task bars
+    pub fn new<I: IntoIterator<Item = (L, T, T)>, S: Into<ShapeStyle>>(tasks: I, style: S) -> Self {
+        let mut series = Self {
+            labels: vec![],
+            tasks: vec![],
+            milestones: vec![],
+            style: style.into(),
+            milestone_style: BLACK.filled(),
+            margin: 3,
+            points: vec![],
+        };
+        for (label, start, end) in tasks {
+            let row = series.row_of(label);
+            series.tasks.push((row, start, end));
+        }
+        series.update_points();
+        series
+    }
+
+    /// Add a milestone, which is drawn in the row of the tasks with the same label, or in a new
+    /// row if there's no such task
+    /// - `label`: The label of the milestone
+    /// - `at`: The time of the milestone
+    pub fn milestone(mut self, label: L, at: T) -> Self {
+        let row = self.row_of(label);
+        self.milestones.push((row, at));
+        self.update_points();
+        self
+    }
+
+    /// Set the style of the milestone markers
+    pub fn milestone_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.milestone_style = style.into();
+        self
+    }
+
+    /// Set the margin between the task bars and the boundaries of their rows in pixels
+    pub fn margin(mut self, margin: u32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Get the categorical Y axis of the rows, the first row is at the top
+    pub fn task_axis(&self) -> SegmentedCoord<RangedCategory<L>>
+    where
+        L: Display + Eq,
+    {
+        RangedCategory::new(self.labels.iter().rev().cloned()).into_segmented()
+    }
+
+    /// Get the row of the label, which is appended if it's a new label
+    fn row_of(&mut self, label: L) -> usize {
+        match self.labels.iter().position(|l| *l == label) {
+            Some(idx) => idx,
+            None => {
+                self.labels.push(label);
+                self.labels.len() - 1
+            }
+        }
+    }
+
+    /// Compute the coordinates of the bars and the markers. The rows are reversed on the axis,
+    /// thus the bar of a row spans from the category after the row to the category of the row.
+    fn update_points(&mut self) {
+        let axis = RangedCategory::new(self.labels.iter().rev().cloned());
+        let count = self.labels.len();
+        let top = |row: usize| axis.category(count - row);
+        let bottom = |row: usize| axis.category(count - row - 1);
+
+        let mut points = Vec::with_capacity(self.tasks.len() * 2 + self.milestones.len());
+        for (row, start, end) in &self.tasks {
+            points.push((start.clone(), SegmentValue::Exact(top(*row))));
+            points.push((end.clone(), SegmentValue::Exact(bottom(*row))));
+        }
+        for (row, at) in &self.milestones {
+            points.push((at.clone(), SegmentValue::CenterOf(bottom(*row))));
+        }
+        self.points = points;
+    }
+}
+
+impl<T, L> IntoIterator for GanttSeries<T, L> {
+    type Item = Self;
+    type IntoIter = std::iter::Once<Self>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        std::iter::once(self)
+    }
+}
+
+impl<'a, T: 'a, L: 'a> PointCollection<'a, (T, SegmentValue<Category<L>>)>
+    for &'a GanttSeries<T, L>
+{
+    type Borrow = &'a (T, SegmentValue<Category<L>>);
+    type IntoIter = &'a [(T, SegmentValue<Category<L>>)];
+    fn point_iter(self) -> Self::IntoIter {
+        &self.points
+    }
+}
+
+impl<T, L, DB: DrawingBackend> Drawable<DB> for GanttSeries<T, L> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let margin = self.margin as i32;
+        // The markers are half as high as the bars, if there's any
+        let mut size = 6;
+        for _ in &self.tasks {
+            let (a, b) = match (points.next(), points.next()) {
+                (Some(a), Some(b)) => (a, b),
+                _ => return Ok(()),
+            };
+            let (top, bottom) = (a.1.min(b.1) + margin, a.1.max(b.1) - margin);
+            if bottom < top {
+                continue;
+            }
+            size = ((bottom - top) / 4).max(1) as u32;
+            backend.draw_rect(
+                (a.0.min(b.0), top),
+                (a.0.max(b.0), bottom),
+                &self.style,
+                self.style.filled,
+            )?;
+        }
+
+        for center in points {
+            Marker::new((), size, MarkerShape::Diamond, self.milestone_style.clone())
+                .draw(std::iter::once(center), backend)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "svg"))]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_gantt_series() {
+        let mut buffer = vec![];
+        {
+            let root = SVGBackend::with_buffer(&mut buffer, (100, 90)).into_drawing_area();
+            let series = GanttSeries::new(vec![("a", 0, 5), ("b", 5, 10)], BLUE.filled())
+                .milestone("c", 8)
+                .milestone_style(RED.filled());
+            let axis = series.task_axis();
+            let labels: Vec<_> = axis
+                .key_points(10)
+                .iter()
+                .filter_map(|v| axis.format_label(v))
+                .collect();
+            assert_eq!(labels, vec!["c", "b", "a"]);
+
+            let mut chart = ChartBuilder::on(&root)
+                .build_ranged(0..10, axis)
+                .expect("Create chart");
+            chart.draw_series(series).expect("Drawing series");
+        }
+
+        let content = String::from_utf8(buffer).unwrap();
+        // Each of the 3 rows is 30px high, the first row is at the top
+        let rects: Vec<_> = content.split("<rect").skip(1).collect();
+        assert_eq!(rects.len(), 2);
+        for attr in &[r#"x="0""#, r#"y="3""#, r#"width="50""#, r#"height="24""#] {
+            assert!(rects[0].contains(attr));
+        }
+        for attr in &[r#"x="50""#, r#"y="33""#] {
+            assert!(rects[1].contains(attr));
+        }
+        // The milestone is in the middle of the last row
+        assert_eq!(content.matches("<polygon").count(), 1);
+        assert!(content.contains("80,69 "));
+    }
+}
@@ -0,0 +1,191 @@
+use std::marker::PhantomData;
+
+use super::BarColumn;
+use crate::coord::DescreteRanged;
+use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use crate::element::{Drawable, PointCollection};
+use crate::style::ShapeStyle;
+
+/// A bar of a grouped bar series, which takes one slot of the segment of its category
+pub(crate) struct GroupedBar<Coord> {
+    points: [Coord; 2],
+    style: ShapeStyle,
+    slot: (usize, usize),
+    margin: u32,
+}
+
+impl<'a, Coord: 'a> PointCollection<'a, Coord> for &'a GroupedBar<Coord> {
+    type Borrow = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for GroupedBar<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (a, b) = match (points.next(), points.next()) {
+            (Some(a), Some(b)) => (a, b),
+            _ => return Ok(()),
+        };
+        // The margin is applied to both sides of the whole group, and the rest of the segment
+        // is split evenly into the slots
+        let left = a.0.min(b.0) + self.margin as i32;
+        let width = (a.0.max(b.0) - self.margin as i32 - left).max(0);
+        let (idx, count) = (self.slot.0 as i32, self.slot.1.max(1) as i32);
+        let x0 = left + width * idx / count;
+        let x1 = left + width * (idx + 1) / count;
+        if x1 <= x0 {
+            return Ok(());
+        }
+        backend.draw_rect(
+            (x0, a.1.min(b.1)),
+            (x1 - 1, a.1.max(b.1)),
+            &self.style,
+            self.style.filled,
+        )
+    }
+}
+
+/// The group of a grouped bar series, which is made of one bar of each category
+pub(crate) type GroupedBarGroup<X, A> = BarColumn<GroupedBar<(X, A)>>;
+
+/// The series of vertical bars placed side by side, where each category has a bar for each of
+/// the value columns. The segment of each category is split evenly among the columns. Each
+/// column is a group of the series, which has its own style and label, and it's drawn by
+/// `ChartContext::draw_grouped_series` as a separate series, so each group has an entry in the
+/// series labels.
+///
+/// ```rust,no_run
+/// use plotters::prelude::*;
+///
+/// let root = BitMapBackend::new("grouped.png", (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_ranged(0u32..3u32, 0u32..10u32)
+///     .unwrap();
+/// chart
+///     .draw_grouped_series(
+///         GroupedBarSeries::new(vec![(0, vec![3, 4]), (1, vec![5, 2]), (2, vec![6, 8])], 10)
+///             .group("2019", &RED)
+///             .group("2020", &BLUE),
+///     )
+///     .unwrap();
+/// chart.configure_series_labels().draw().unwrap();
+/// ```
+pub struct GroupedBarSeries<BR, A>
+where
+    BR: DescreteRanged,
+    BR::ValueType: Eq + Clone,
+{
+    data: Vec<(BR::ValueType, Vec<A>)>,
+    groups: Vec<(Option<String>, ShapeStyle)>,
+    margin: u32,
+    baseline: A,
+    _p: PhantomData<BR>,
+}
+
+impl<BR, A> GroupedBarSeries<BR, A>
+where
+    BR: DescreteRanged,
+    BR::ValueType: Eq + Clone,
+    A: Clone + Default,
+{
+    /// Create a new grouped bar series
+    /// - `iter`: The iterator of the categories and their value columns
+    /// - `margin`: The margin between the groups of bars
+    pub fn new<I: IntoIterator<Item = (BR::ValueType, Vec<A>)>>(iter: I, margin: u32) -> Self {
+        Self {
+            data: iter.into_iter().collect(),
+            groups: vec![],
+            margin,
+            baseline: A::default(),
+            _p: PhantomData,
+        }
+    }
+
+    /// Set the label and the style of the next value column. The columns without a label are
+    /// not shown in the series labels, and they are colored with `Palette99` by default.
+    /// - `label`: The label of the column
+    /// - `style`: The style of the bars of the column
+    pub fn group<L: Into<String>, S: Into<ShapeStyle>>(mut self, label: L, style: S) -> Self {
+        self.groups.push((Some(label.into()), style.into()));
+        self
+    }
+
+    /// Set the baseline where the bars start
+    pub fn baseline(mut self, baseline: A) -> Self {
+        self.baseline = baseline;
+        self
+    }
+
+    /// Split the series into the groups, each column takes the same slot in every category
+    pub(crate) fn into_groups(self) -> Vec<GroupedBarGroup<BR::ValueType, A>> {
+        let mut groups = BarColumn::for_rows(&self.groups, &self.data);
+        let columns = groups.len();
+
+        for (x, values) in self.data {
+            let nx = BR::next_value(&x);
+            for (idx, (group, value)) in groups.iter_mut().zip(values).enumerate() {
+                group.bars.push(GroupedBar {
+                    points: [(x.clone(), value), (nx.clone(), self.baseline.clone())],
+                    style: group.style.clone(),
+                    slot: (idx, columns),
+                    margin: self.margin,
+                });
+            }
+        }
+
+        groups
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_grouped_bar_series() {
+        let drawing_area = create_mocked_drawing_area(200, 100, |m| {
+            // The segment of the category is 100px wide, and the bars take 40px each after the
+            // 10px margins
+            m.check_draw_rect(|c, _, _, ul, br| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!((ul.0, br.0), (10, 49));
+                assert_eq!(br.1 - ul.1, 30);
+            });
+            m.check_draw_rect(|c, _, _, ul, br| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!((ul.0, br.0), (110, 149));
+            });
+            m.check_draw_rect(|c, _, _, ul, br| {
+                assert_eq!(c, BLUE.to_rgba());
+                assert_eq!((ul.0, br.0), (50, 89));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 3);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0u32..2u32, 0u32..10u32)
+            .unwrap();
+
+        let series =
+            GroupedBarSeries::<RangedCoordu32, u32>::new(vec![(0, vec![3, 4]), (1, vec![5])], 10)
+                .group("a", &RED)
+                .group("b", &BLUE);
+        let groups = series.into_groups();
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[1].bars.len(), 1);
+
+        let series = GroupedBarSeries::new(vec![(0, vec![3, 4]), (1, vec![5])], 10)
+            .group("a", &RED)
+            .group("b", &BLUE);
+        chart.draw_grouped_series(series).unwrap();
+    }
+}
@@ -0,0 +1,177 @@
+use std::ops::Range;
+
+use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use crate::element::{Drawable, PointCollection};
+use crate::style::{ColorMap, RGBAColor};
+
+/// The heatmap series, which fills a grid of cells with the colors mapped from a matrix of
+/// values. The whole grid is a single element, which is drawn with one filled rectangle per
+/// cell directly on the backend, thus it's much faster than a series of `Rectangle` elements.
+///
+/// The cells are evenly spaced between the corners of the grid in the pixel space.
+///
+/// ```rust,no_run
+/// use plotters::prelude::*;
+///
+/// let root = BitMapBackend::new("heatmap.png", (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_ranged(0f64..10f64, 0f64..10f64)
+///     .unwrap();
+/// let values: Vec<Vec<f64>> = (0..10)
+///     .map(|y| (0..10).map(|x| (x * y) as f64).collect())
+///     .collect();
+/// chart
+///     .draw_series(HeatmapSeries::new(
+///         (0.0, 0.0)..(10.0, 10.0),
+///         &values,
+///         0.0..81.0,
+///         ViridisColorMap,
+///     ))
+///     .unwrap();
+/// ```
+pub struct HeatmapSeries<X, Y> {
+    corners: [(X, Y); 2],
+    columns: usize,
+    colors: Vec<Option<RGBAColor>>,
+}
+
+impl<X, Y> HeatmapSeries<X, Y> {
+    /// Create a new heatmap series
+    /// - `extent`: The corners of the grid, the first row of the values is at the start
+    /// - `values`: The rows of the values, all rows should have the same length. The non-finite
+    ///   values leave their cells empty.
+    /// - `value_range`: The range of the values which is mapped to the colormap, the values out
+    ///   of the range are clamped
+    /// - `colormap`: The colormap of the values
+    pub fn new<R: AsRef<[f64]>, M: ColorMap>(
+        extent: Range<(X, Y)>,
+        values: &[R],
+        value_range: Range<f64>,
+        colormap: M,
+    ) -> Self {
+        let columns = values
+            .iter()
+            .map(|row| row.as_ref().len())
+            .max()
+            .unwrap_or(0);
+        let span = value_range.end - value_range.start;
+
+        let mut colors = Vec::with_capacity(columns * values.len());
+        for row in values {
+            let row = row.as_ref();
+            colors.extend((0..columns).map(|idx| match row.get(idx) {
+                Some(v) if v.is_finite() => {
+                    let t = if span == 0.0 {
+                        0.0
+                    } else {
+                        (v - value_range.start) / span
+                    };
+                    Some(colormap.get(t.clamp(0.0, 1.0)))
+                }
+                _ => None,
+            }));
+        }
+
+        Self {
+            corners: [extent.start, extent.end],
+            columns,
+            colors,
+        }
+    }
+}
+
+impl<X, Y> IntoIterator for HeatmapSeries<X, Y> {
+    type Item = Self;
+    type IntoIter = std::iter::Once<Self>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        std::iter::once(self)
+    }
+}
+
+impl<'a, X: 'a, Y: 'a> PointCollection<'a, (X, Y)> for &'a HeatmapSeries<X, Y> {
+    type Borrow = &'a (X, Y);
+    type IntoIter = &'a [(X, Y)];
+    fn point_iter(self) -> &'a [(X, Y)] {
+        &self.corners
+    }
+}
+
+impl<X, Y, DB: DrawingBackend> Drawable<DB> for HeatmapSeries<X, Y> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (from, to) = match (points.next(), points.next()) {
+            (Some(a), Some(b)) => (a, b),
+            _ => return Ok(()),
+        };
+        if self.columns == 0 {
+            return Ok(());
+        }
+        let rows = self.colors.len() / self.columns;
+
+        // The boundaries are shared by the neighbour cells, so there's no gap between them
+        let edge = |from: i32, to: i32, idx: usize, count: usize| {
+            from + ((f64::from(to - from) * idx as f64 / count as f64).round() as i32)
+        };
+
+        for (row, cells) in self.colors.chunks(self.columns).enumerate() {
+            let (y0, y1) = (
+                edge(from.1, to.1, row, rows),
+                edge(from.1, to.1, row + 1, rows),
+            );
+            for (column, color) in cells.iter().enumerate() {
+                if let Some(color) = color {
+                    let (x0, x1) = (
+                        edge(from.0, to.0, column, self.columns),
+                        edge(from.0, to.0, column + 1, self.columns),
+                    );
+                    backend.draw_rect(
+                        (x0.min(x1), y0.min(y1)),
+                        (x0.max(x1), y0.max(y1)),
+                        color,
+                        true,
+                    )?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "svg"))]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_heatmap_series() {
+        let mut buffer = vec![];
+        {
+            let root = SVGBackend::with_buffer(&mut buffer, (90, 60)).into_drawing_area();
+            let mut chart = ChartBuilder::on(&root)
+                .build_ranged(0..3, 0..2)
+                .expect("Create chart");
+
+            chart
+                .draw_series(HeatmapSeries::new(
+                    (0, 0)..(3, 2),
+                    &[vec![0.0, 1.0, 2.0], vec![3.0, f64::NAN, 5.0]],
+                    0.0..5.0,
+                    GrayscaleColorMap,
+                ))
+                .expect("Drawing series");
+        }
+
+        let content = String::from_utf8(buffer).unwrap();
+        // The NaN cell is left empty
+        assert_eq!(content.matches("<rect").count(), 5);
+        // The first row starts at the bottom, and the cells share the boundaries
+        let first = content.split("<rect").nth(1).unwrap();
+        for attr in &[r#"x="0""#, r#"y="30""#, r#"width="30""#, r#"height="30""#] {
+            assert!(first.contains(attr));
+        }
+    }
+}
@@ -1,7 +1,8 @@
+use std::cmp::Ordering;
 use std::collections::{hash_map::IntoIter as HashMapIter, HashMap};
 use std::hash::Hash;
 use std::marker::PhantomData;
-use std::ops::AddAssign;
+use std::ops::{AddAssign, Range};
 
 use crate::chart::ChartContext;
 use crate::coord::{DescreteRanged, Ranged, RangedCoord};
@@ -73,6 +74,7 @@ where
     pub fn data<I: IntoIterator<Item = (BR::ValueType, A)>>(mut self, iter: I) -> Self {
         let mut buffer = HashMap::<BR::ValueType, A>::new();
         for (x, y) in iter.into_iter() {
+            let x = BR::floor_value(&x);
             *buffer.entry(x).or_insert_with(Default::default) += y;
         }
         self.iter = buffer.into_iter();
@@ -101,6 +103,7 @@ where
     ) -> Self {
         let mut buffer = HashMap::<BR::ValueType, A>::new();
         for (x, y) in iter.into_iter() {
+            let x = BR::floor_value(&x);
             *buffer.entry(x).or_insert_with(Default::default) += y;
         }
         Self {
@@ -112,6 +115,8 @@ where
         }
     }
 
+    /// Create an empty vertical histogram series, whose types are inferred from the chart,
+    /// set the data with `Histogram::data`
     pub fn vertical<ACoord, DB: DrawingBackend>(
         _: &ChartContext<DB, RangedCoord<BR, ACoord>>,
     ) -> Self
@@ -128,6 +133,41 @@ where
     BR::ValueType: Eq + Hash,
     A: AddAssign<A> + Default,
 {
+    /// Create a new horizental histogram series, where the categories are along the Y axis
+    /// and the bars grow along the X axis, e.g. a ranking chart.
+    ///
+    /// - `iter`: The data iterator
+    /// - `margin`: The margin between bars
+    /// - `style`: The style of bars
+    ///
+    /// Returns the newly created histogram series
+    pub fn new_horizental<S: Into<ShapeStyle>, I: IntoIterator<Item = (BR::ValueType, A)>>(
+        iter: I,
+        margin: u32,
+        style: S,
+    ) -> Self {
+        Self::empty().style(style).margin(margin).data(iter)
+    }
+
+    /// Create an empty horizental histogram series, whose types are inferred from the chart,
+    /// set the data with `Histogram::data`.
+    ///
+    /// ```rust,no_run
+    /// use plotters::prelude::*;
+    ///
+    /// let root = BitMapBackend::new("ranking.png", (640, 480)).into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&root)
+    ///     .build_ranged(0u32..100u32, 0u32..5u32)
+    ///     .unwrap();
+    /// chart
+    ///     .draw_series(
+    ///         Histogram::horizental(&chart)
+    ///             .style(BLUE.filled())
+    ///             .margin(10)
+    ///             .data(vec![(0, 35), (1, 52), (2, 68), (3, 80), (4, 93)]),
+    ///     )
+    ///     .unwrap();
+    /// ```
     pub fn horizental<ACoord, DB: DrawingBackend>(
         _: &ChartContext<DB, RangedCoord<ACoord, BR>>,
     ) -> Self
@@ -173,3 +213,326 @@ where
         None
     }
 }
+
+/// The strategy deciding the bins of a histogram built from raw samples
+#[derive(Clone, Debug, PartialEq)]
+pub enum Binning {
+    /// The bins of the fixed width, whose edges are the multiples of the width. At most 10000
+    /// bins are made from the smallest sample, and the larger samples beyond them are dropped.
+    FixedWidth(f64),
+    /// The Sturges' rule, which splits the range of the samples into `log2(n) + 1` bins and
+    /// works well for the normally distributed samples
+    Sturges,
+    /// The Freedman–Diaconis rule, which decides the width of the bins by the interquartile
+    /// range and is robust to the outliers
+    FreedmanDiaconis,
+    /// The explicit edges of the bins, the samples outside are dropped. The edges are sorted
+    /// and the duplicated or non-finite ones are ignored, thus at least 2 distinct edges are
+    /// needed, otherwise the histogram is empty.
+    Edges(Vec<f64>),
+}
+
+/// Get the quantile of the sorted samples with linear interpolation
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let pos = q * (sorted.len() - 1) as f64;
+    let (lo, hi) = (pos.floor() as usize, pos.ceil() as usize);
+    sorted[lo] + (sorted[hi] - sorted[lo]) * (pos - lo as f64)
+}
+
+impl Binning {
+    /// The max number of bins computed by the rules, which guards against the degenerated
+    /// samples producing a huge number of bins
+    const MAX_BINS: usize = 10_000;
+
+    /// Compute the edges of the bins for the sorted samples
+    fn edges(&self, sorted: &[f64]) -> Vec<f64> {
+        if let Binning::Edges(edges) = self {
+            let mut edges: Vec<_> = edges.iter().cloned().filter(|e| e.is_finite()).collect();
+            edges.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            edges.dedup();
+            if edges.len() < 2 {
+                edges.clear();
+            }
+            return edges;
+        }
+        let (min, max) = match (sorted.first(), sorted.last()) {
+            (Some(min), Some(max)) => (*min, *max),
+            _ => return vec![],
+        };
+
+        let width = match self {
+            Binning::FixedWidth(width) if *width > 0.0 => {
+                let first = (min / width).floor() as i64;
+                let last = ((max / width).floor() as i64)
+                    .saturating_add(1)
+                    .min(first.saturating_add(Self::MAX_BINS as i64));
+                if last <= first {
+                    return vec![];
+                }
+                return (first..=last).map(|k| k as f64 * width).collect();
+            }
+            Binning::FreedmanDiaconis => {
+                let iqr = quantile(sorted, 0.75) - quantile(sorted, 0.25);
+                2.0 * iqr / (sorted.len() as f64).cbrt()
+            }
+            _ => 0.0,
+        };
+
+        if max <= min {
+            return vec![min - 0.5, max + 0.5];
+        }
+        let bins = if width > 0.0 {
+            ((max - min) / width).ceil() as usize
+        } else {
+            // Sturges' rule, which is also the fallback of the other rules
+            (sorted.len() as f64).log2().ceil() as usize + 1
+        }
+        .min(Self::MAX_BINS);
+        let width = (max - min) / bins as f64;
+        (0..=bins).map(|k| min + k as f64 * width).collect()
+    }
+}
+
+/// The histogram built from the raw samples, which computes the bins and the counts itself.
+/// Each bin is drawn as a bar covering the range of the bin, thus it should be drawn on a
+/// continuous coordinate.
+///
+/// ```rust,no_run
+/// use plotters::prelude::*;
+///
+/// let samples = vec![1.2, 2.5, 2.7, 3.1, 3.3, 3.8, 4.6, 5.0];
+/// let histogram = BinnedHistogram::new(samples, Binning::Sturges, RED.filled()).density();
+/// let (x_range, y_range) = (histogram.x_range(), 0.0..histogram.max_value());
+///
+/// let root = BitMapBackend::new("histogram.png", (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_ranged(x_range, y_range)
+///     .unwrap();
+/// chart.draw_series(histogram).unwrap();
+/// ```
+pub struct BinnedHistogram {
+    /// The range and the number of samples of each bin
+    bins: Vec<(f64, f64, usize)>,
+    total: usize,
+    density: bool,
+    style: ShapeStyle,
+    margin: u32,
+    pos: usize,
+}
+
+impl BinnedHistogram {
+    /// Create a new histogram from the samples
+    /// - `samples`: The raw samples, the samples that aren't finite are dropped
+    /// - `binning`: The strategy deciding the bins
+    /// - `style`: The style of the bars
+    pub fn new<I: IntoIterator<Item = f64>, S: Into<ShapeStyle>>(
+        samples: I,
+        binning: Binning,
+        style: S,
+    ) -> Self {
+        let mut sorted: Vec<_> = samples.into_iter().filter(|x| x.is_finite()).collect();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let edges = binning.edges(&sorted);
+        let mut bins: Vec<_> = edges.windows(2).map(|e| (e[0], e[1], 0)).collect();
+        let mut total = 0;
+        if let (Some(first), Some(last)) = (edges.first(), edges.last()) {
+            for x in sorted.iter().filter(|x| (*first..=*last).contains(*x)) {
+                // The bins are closed on the left, and the last one is also closed on the right
+                let after = match edges.binary_search_by(|e| {
+                    if e <= x {
+                        Ordering::Less
+                    } else {
+                        Ordering::Greater
+                    }
+                }) {
+                    Ok(idx) | Err(idx) => idx,
+                };
+                let idx = after.saturating_sub(1).min(bins.len() - 1);
+                bins[idx].2 += 1;
+                total += 1;
+            }
+        }
+
+        Self {
+            bins,
+            total,
+            density: false,
+            style: style.into(),
+            margin: 0,
+            pos: 0,
+        }
+    }
+
+    /// Normalize the histogram as a probability density, thus the total area of the bars is 1
+    pub fn density(mut self) -> Self {
+        self.density = true;
+        self
+    }
+
+    /// Set the margin between the bars in pixels, which is 0 by default
+    pub fn margin(mut self, value: u32) -> Self {
+        self.margin = value;
+        self
+    }
+
+    /// Get the range covering all the bins
+    pub fn x_range(&self) -> Range<f64> {
+        match (self.bins.first(), self.bins.last()) {
+            (Some(first), Some(last)) => first.0..last.1,
+            _ => 0.0..1.0,
+        }
+    }
+
+    /// Get the height of the highest bar
+    pub fn max_value(&self) -> f64 {
+        self.bins().map(|(_, v)| v).fold(0.0, f64::max)
+    }
+
+    /// Get the range and the height of each bar, which is either the count or the density
+    pub fn bins(&self) -> impl Iterator<Item = (Range<f64>, f64)> + '_ {
+        self.bins.iter().map(move |bin| self.bar_of(bin))
+    }
+
+    /// Get the range and the height of the bar of the bin
+    fn bar_of(&self, &(lo, hi, count): &(f64, f64, usize)) -> (Range<f64>, f64) {
+        let count = count as f64;
+        let value = if !self.density {
+            count
+        } else if self.total > 0 && hi > lo {
+            count / (self.total as f64 * (hi - lo))
+        } else {
+            0.0
+        };
+        (lo..hi, value)
+    }
+}
+
+impl Iterator for BinnedHistogram {
+    type Item = Rectangle<(f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (range, value) = self.bar_of(self.bins.get(self.pos)?);
+        self.pos += 1;
+        let mut rect = Rectangle::new([(range.start, value), (range.end, 0.0)], self.style.clone());
+        rect.set_margin(0, 0, self.margin, self.margin);
+        Some(rect)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_horizental_histogram() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            // The bars grow from the left, and the margins shrink them vertically
+            for _ in 0..2 {
+                m.check_draw_rect(|_, _, filled, ul, br| {
+                    assert!(filled);
+                    assert!([((0, 5), (40, 45)), ((0, 55), (50, 95))].contains(&(ul, br)));
+                });
+            }
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 2);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..10, 0u32..2u32)
+            .unwrap();
+        chart
+            .draw_series(
+                Histogram::horizental(&chart)
+                    .margin(5)
+                    .data(vec![(0, 5), (1, 3), (1, 1)]),
+            )
+            .unwrap();
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_datetime_histogram() {
+        use crate::coord::{Hour, RangedDateTime};
+        use crate::element::PointCollection;
+        use chrono::{TimeZone, Utc};
+
+        let time = |h, m| Utc.ymd(2019, 1, 1).and_hms(h, m, 0);
+        let histogram = Histogram::<RangedDateTime<Utc, Hour>, i32>::new(
+            vec![(time(0, 10), 2), (time(0, 50), 3), (time(2, 30), 4)],
+            0,
+            &RED,
+        );
+        // The values are aggregated by the hours they fall in
+        let mut bars: Vec<_> = histogram.map(|bar| (&bar).point_iter().to_vec()).collect();
+        bars.sort();
+        assert_eq!(
+            bars,
+            vec![
+                vec![(time(0, 0), 5), (time(1, 0), 0)],
+                vec![(time(2, 0), 4), (time(3, 0), 0)],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_binning() {
+        let sorted = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 9.0];
+        // log2(8) + 1 = 4 bins
+        assert_eq!(
+            Binning::Sturges.edges(&sorted),
+            vec![1.0, 3.0, 5.0, 7.0, 9.0]
+        );
+        assert_eq!(
+            Binning::FixedWidth(5.0).edges(&sorted),
+            vec![0.0, 5.0, 10.0]
+        );
+        // IQR = 6.25 - 2.75 = 3.5, the width is 2 * 3.5 / 2 = 3.5
+        assert_eq!(Binning::FreedmanDiaconis.edges(&sorted).len(), 4);
+        assert_eq!(Binning::Sturges.edges(&[2.0, 2.0]), vec![1.5, 2.5]);
+        assert_eq!(
+            Binning::Edges(vec![2.0, f64::NAN, 0.0, 1.0, 1.0]).edges(&sorted),
+            vec![0.0, 1.0, 2.0]
+        );
+        assert_eq!(Binning::Edges(vec![3.0, 3.0]).edges(&sorted), vec![]);
+
+        // The fixed width bins are limited, and the bins out of i64 don't overflow
+        let edges = Binning::FixedWidth(1.0).edges(&[0.0, 20000.0]);
+        assert_eq!(edges.len(), Binning::MAX_BINS + 1);
+        assert_eq!(edges.last(), Some(&10000.0));
+        assert_eq!(Binning::FixedWidth(1.0).edges(&[1e30, 2e30]), vec![]);
+    }
+
+    #[test]
+    fn test_binned_histogram() {
+        let samples = vec![0.5, 1.5, 1.7, 2.0, 3.0, 10.0, f64::NAN];
+        let histogram =
+            BinnedHistogram::new(samples, Binning::Edges(vec![0.0, 1.0, 2.0, 3.0]), &RED);
+        // The samples outside the edges are dropped, the last bin is closed
+        let bins: Vec<_> = histogram.bins().collect();
+        assert_eq!(
+            bins,
+            vec![(0.0..1.0, 1.0), (1.0..2.0, 2.0), (2.0..3.0, 2.0)]
+        );
+        assert_eq!(histogram.x_range(), 0.0..3.0);
+
+        let histogram = histogram.density();
+        let area: f64 = histogram.bins().map(|(r, v)| (r.end - r.start) * v).sum();
+        assert!((area - 1.0).abs() < 1e-9);
+        assert_eq!(histogram.max_value(), 0.4);
+
+        let drawing_area = create_mocked_drawing_area(300, 300, |m| {
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 3);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0.0..3.0, 0.0..1.0)
+            .unwrap();
+        chart.draw_series(histogram).unwrap();
+
+        let histogram = BinnedHistogram::new(vec![3.0], Binning::Edges(vec![3.0]), &RED);
+        assert_eq!(histogram.bins().count(), 0);
+        assert_eq!(histogram.x_range(), 0.0..1.0);
+    }
+}
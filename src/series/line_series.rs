@@ -1,26 +1,40 @@
+#[cfg(any(feature = "nalgebra", feature = "euclid"))]
+use crate::coord::{IntoTupleCoord, TupleCoords};
 use crate::element::Path;
 use crate::style::ShapeStyle;
 
+use super::non_finite::{apply_non_finite_policy, FiniteCoord, NonFinitePolicy};
+
+type SplitFunc<Coord> = fn(Vec<Coord>, &NonFinitePolicy) -> Vec<Vec<Coord>>;
+
 /// The line series object, which takes an iterator of points in guest coordinate system
 /// and creates the element rendering the line plot
 pub struct LineSeries<Coord, I: IntoIterator<Item = Coord>> {
     style: ShapeStyle,
     data_iter: Option<I::IntoIter>,
+    simplify_tolerance: Option<f64>,
+    non_finite: Option<(NonFinitePolicy, SplitFunc<Coord>)>,
+    segments: Vec<Vec<Coord>>,
 }
 
 impl<Coord, I: IntoIterator<Item = Coord>> Iterator for LineSeries<Coord, I> {
     type Item = Path<Coord>;
     fn next(&mut self) -> Option<Self::Item> {
-        if self.data_iter.is_some() {
-            let mut data_iter = None;
-            std::mem::swap(&mut self.data_iter, &mut data_iter);
-            Some(Path::new(
-                data_iter.unwrap().collect::<Vec<_>>(),
-                self.style.clone(),
-            ))
-        } else {
-            None
+        if let Some(data_iter) = self.data_iter.take() {
+            let points: Vec<_> = data_iter.collect();
+            self.segments = match self.non_finite {
+                Some((ref policy, split)) => split(points, policy),
+                None => vec![points],
+            };
+            self.segments.reverse();
         }
+        self.segments.pop().map(|points| {
+            let mut path = Path::new(points, self.style.clone());
+            if let Some(tolerance) = self.simplify_tolerance {
+                path.set_simplify_tolerance(tolerance);
+            }
+            path
+        })
     }
 }
 
@@ -29,6 +43,95 @@ impl<Coord, I: IntoIterator<Item = Coord>> LineSeries<Coord, I> {
         Self {
             style: style.into(),
             data_iter: Some(iter.into_iter()),
+            simplify_tolerance: None,
+            non_finite: None,
+            segments: vec![],
         }
     }
+
+    /// Simplify the line in pixel space before drawing it, see `Path::set_simplify_tolerance`.
+    /// This is useful for high-frequency data, which has far more points than pixels.
+    /// - `tolerance`: The max distance in pixels between the removed points and the line
+    pub fn simplify(mut self, tolerance: f64) -> Self {
+        self.simplify_tolerance = Some(tolerance);
+        self
+    }
+
+    /// Set the policy of handling the NaN and infinite values, which are passed to the
+    /// coordinate system as they are by default.
+    /// With `NonFinitePolicy::Break`, the series draws a path for each segment of the line.
+    pub fn non_finite(mut self, policy: NonFinitePolicy) -> Self
+    where
+        Coord: FiniteCoord,
+    {
+        self.non_finite = Some((policy, apply_non_finite_policy::<Coord>));
+        self
+    }
+}
+
+impl<Coord> LineSeries<Coord, Vec<Coord>> {
+    /// Create a new line series from the points with missing samples, the line is broken at
+    /// each `None` rather than connecting the points across the gap
+    pub fn with_gaps<I: IntoIterator<Item = Option<Coord>>, S: Into<ShapeStyle>>(
+        iter: I,
+        style: S,
+    ) -> Self {
+        let mut segments = vec![vec![]];
+        for point in iter {
+            match point {
+                Some(point) => segments.last_mut().unwrap().push(point),
+                None if !segments.last().unwrap().is_empty() => segments.push(vec![]),
+                None => {}
+            }
+        }
+        segments.retain(|s| !s.is_empty());
+        segments.reverse();
+
+        Self {
+            style: style.into(),
+            data_iter: None,
+            simplify_tolerance: None,
+            non_finite: None,
+            segments,
+        }
+    }
+}
+
+#[cfg(any(feature = "nalgebra", feature = "euclid"))]
+impl<I: Iterator> LineSeries<<I::Item as IntoTupleCoord>::Tuple, TupleCoords<I>>
+where
+    I::Item: IntoTupleCoord,
+{
+    /// Create a new line series from the point types of the geometry libraries,
+    /// such as `nalgebra::Point2` or `euclid::Point2D`
+    pub fn from_points<T: IntoIterator<IntoIter = I>, S: Into<ShapeStyle>>(
+        points: T,
+        style: S,
+    ) -> Self {
+        Self::new(TupleCoords::new(points), style)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::element::PointCollection;
+
+    #[test]
+    fn test_line_series_with_gaps() {
+        let series = LineSeries::with_gaps(
+            vec![
+                None,
+                Some((0, 0)),
+                Some((1, 1)),
+                None,
+                None,
+                Some((3, 3)),
+                None,
+            ],
+            ShapeStyle::from(&crate::style::RED),
+        );
+        let paths: Vec<_> = series.map(|p| (&p).point_iter().to_vec()).collect();
+        assert_eq!(paths, vec![vec![(0, 0), (1, 1)], vec![(3, 3)]]);
+    }
 }
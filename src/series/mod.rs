@@ -11,11 +11,38 @@
 */
 
 mod area_series;
+#[cfg(feature = "ndarray")]
+mod array_series;
+mod bar_column;
+mod bubble;
+mod contour;
+mod gantt;
+mod grouped_bar;
+mod heatmap;
 mod histogram;
 mod line_series;
+mod non_finite;
+mod ohlc;
 mod point_series;
+mod smoothed_line;
+mod stacked_bar;
+mod surface;
 
 pub use area_series::AreaSeries;
-pub use histogram::Histogram;
+#[cfg(feature = "ndarray")]
+pub use array_series::heatmap_from_array;
+pub use bubble::{BubbleLegend, BubbleSeries};
+pub use contour::ContourSeries;
+pub use gantt::GanttSeries;
+pub use grouped_bar::GroupedBarSeries;
+pub use heatmap::HeatmapSeries;
+pub use histogram::{BinnedHistogram, Binning, Histogram};
 pub use line_series::LineSeries;
+pub use non_finite::{FiniteCoord, NonFinitePolicy};
+pub use ohlc::OHLCSeries;
 pub use point_series::PointSeries;
+pub use smoothed_line::SmoothedLineSeries;
+pub use stacked_bar::StackedBarSeries;
+pub use surface::SurfaceSeries;
+
+pub(crate) use bar_column::BarColumn;
@@ -0,0 +1,148 @@
+/// The policy of handling the NaN and infinite values in a series, which is applied before the
+/// values are mapped to the coordinate system
+#[derive(Clone, Debug, PartialEq)]
+pub enum NonFinitePolicy {
+    /// Drop the points with NaN or infinite values, the neighbours are connected directly
+    Skip,
+    /// Drop the points with NaN or infinite values and break the line there, thus the missing
+    /// values are shown as gaps
+    Break,
+    /// Clamp the infinite and out-of-range values into the range from the first value to the
+    /// second one. Only the values, i.e. the Y coordinates, are clamped. NaN can't be clamped,
+    /// thus the points with NaN or a non-finite X coordinate are dropped.
+    Clamp(f64, f64),
+}
+
+/// The coordinate which may have NaN or infinite values, see `NonFinitePolicy`
+pub trait FiniteCoord: Sized {
+    /// Check if all the values of the coordinate are finite
+    fn is_finite_coord(&self) -> bool;
+
+    /// Check if any of the values of the coordinate is NaN
+    fn has_nan(&self) -> bool;
+
+    /// Check if the coordinate can be clamped by `FiniteCoord::clamp_coord`
+    fn is_clampable(&self) -> bool {
+        !self.has_nan()
+    }
+
+    /// Clamp the values of the coordinate into the range from `min` to `max`. For a point, only
+    /// the Y coordinate is clamped.
+    fn clamp_coord(self, min: f64, max: f64) -> Self;
+}
+
+macro_rules! impl_finite_coord_for_float {
+    ($($t:ty),*) => {$(
+        impl FiniteCoord for $t {
+            fn is_finite_coord(&self) -> bool {
+                self.is_finite()
+            }
+            fn has_nan(&self) -> bool {
+                self.is_nan()
+            }
+            fn clamp_coord(self, min: f64, max: f64) -> Self {
+                (f64::from(self)).max(min).min(max) as $t
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_finite_coord_for_int {
+    ($($t:ty),*) => {$(
+        impl FiniteCoord for $t {
+            fn is_finite_coord(&self) -> bool {
+                true
+            }
+            fn has_nan(&self) -> bool {
+                false
+            }
+            fn clamp_coord(self, _min: f64, _max: f64) -> Self {
+                self
+            }
+        }
+    )*};
+}
+
+impl_finite_coord_for_float!(f32, f64);
+impl_finite_coord_for_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl<X: FiniteCoord, Y: FiniteCoord> FiniteCoord for (X, Y) {
+    fn is_finite_coord(&self) -> bool {
+        self.0.is_finite_coord() && self.1.is_finite_coord()
+    }
+    fn has_nan(&self) -> bool {
+        self.0.has_nan() || self.1.has_nan()
+    }
+    fn is_clampable(&self) -> bool {
+        self.0.is_finite_coord() && self.1.is_clampable()
+    }
+    fn clamp_coord(self, min: f64, max: f64) -> Self {
+        (self.0, self.1.clamp_coord(min, max))
+    }
+}
+
+/// Apply the policy to the points, and split them into the segments of the line
+pub(crate) fn apply_non_finite_policy<C: FiniteCoord>(
+    points: Vec<C>,
+    policy: &NonFinitePolicy,
+) -> Vec<Vec<C>> {
+    match *policy {
+        NonFinitePolicy::Skip => vec![points
+            .into_iter()
+            .filter(FiniteCoord::is_finite_coord)
+            .collect()],
+        NonFinitePolicy::Break => {
+            let mut segments = vec![vec![]];
+            for point in points {
+                if point.is_finite_coord() {
+                    segments.last_mut().unwrap().push(point);
+                } else if !segments.last().unwrap().is_empty() {
+                    segments.push(vec![]);
+                }
+            }
+            segments.retain(|s| !s.is_empty());
+            segments
+        }
+        NonFinitePolicy::Clamp(min, max) => vec![points
+            .into_iter()
+            .filter(FiniteCoord::is_clampable)
+            .map(|p| p.clamp_coord(min, max))
+            .collect()],
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_non_finite_policy() {
+        let points = vec![
+            (0.0, 1.0),
+            (1.0, f64::NAN),
+            (2.0, 2.0),
+            (3.0, 3.0),
+            (4.0, f64::INFINITY),
+        ];
+
+        assert_eq!(
+            apply_non_finite_policy(points.clone(), &NonFinitePolicy::Skip),
+            vec![vec![(0.0, 1.0), (2.0, 2.0), (3.0, 3.0)]]
+        );
+        assert_eq!(
+            apply_non_finite_policy(points.clone(), &NonFinitePolicy::Break),
+            vec![vec![(0.0, 1.0)], vec![(2.0, 2.0), (3.0, 3.0)]]
+        );
+        assert_eq!(
+            apply_non_finite_policy(points, &NonFinitePolicy::Clamp(-10.0, 10.0)),
+            vec![vec![(0.0, 1.0), (2.0, 2.0), (3.0, 3.0), (4.0, 10.0)]]
+        );
+
+        // The X coordinates are not clamped into the range of the values
+        let points = vec![(-50.0, 1.0), (20.0, -f64::INFINITY), (f64::INFINITY, 2.0)];
+        assert_eq!(
+            apply_non_finite_policy(points, &NonFinitePolicy::Clamp(-10.0, 10.0)),
+            vec![vec![(-50.0, 1.0), (20.0, -10.0)]]
+        );
+    }
+}
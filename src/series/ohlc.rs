@@ -0,0 +1,64 @@
+use crate::element::OHLCBar;
+use crate::style::ShapeStyle;
+
+/// The OHLC series, which draws an `OHLCBar` for each data point, for those who prefer bars to
+/// candlesticks. The data points are the X coordinate and the open, high, low and close prices.
+///
+/// ```rust,no_run
+/// use plotters::prelude::*;
+///
+/// let data = vec![(1, 3.0, 8.0, 2.0, 7.0), (2, 7.0, 9.0, 4.0, 5.0), (3, 5.0, 6.0, 1.0, 2.0)];
+/// let root = BitMapBackend::new("ohlc.png", (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_ranged(0..4, 0.0..10.0)
+///     .unwrap();
+/// chart
+///     .draw_series(OHLCSeries::new(data, &GREEN, &RED, 10))
+///     .unwrap();
+/// ```
+pub struct OHLCSeries<X, Y, I: Iterator<Item = (X, Y, Y, Y, Y)>> {
+    data: I,
+    gain_style: ShapeStyle,
+    loss_style: ShapeStyle,
+    width: u32,
+}
+
+impl<X, Y, I: Iterator<Item = (X, Y, Y, Y, Y)>> OHLCSeries<X, Y, I> {
+    /// Create a new OHLC series
+    /// - `iter`: The iterator of the X coordinates and the open, high, low and close prices
+    /// - `gain_style`: The style of the bars whose close price is higher than the open price
+    /// - `loss_style`: The style of the other bars
+    /// - `width`: The width of the bars including the ticks in pixels
+    pub fn new<T, GS, LS>(iter: T, gain_style: GS, loss_style: LS, width: u32) -> Self
+    where
+        T: IntoIterator<IntoIter = I>,
+        GS: Into<ShapeStyle>,
+        LS: Into<ShapeStyle>,
+    {
+        Self {
+            data: iter.into_iter(),
+            gain_style: gain_style.into(),
+            loss_style: loss_style.into(),
+            width,
+        }
+    }
+}
+
+impl<X: Clone, Y: PartialOrd, I: Iterator<Item = (X, Y, Y, Y, Y)>> Iterator
+    for OHLCSeries<X, Y, I>
+{
+    type Item = OHLCBar<X, Y>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (x, open, high, low, close) = self.data.next()?;
+        Some(OHLCBar::new(
+            x,
+            open,
+            high,
+            low,
+            close,
+            self.gain_style.clone(),
+            self.loss_style.clone(),
+            self.width,
+        ))
+    }
+}
@@ -1,4 +1,6 @@
-use crate::element::PointElement;
+#[cfg(any(feature = "nalgebra", feature = "euclid"))]
+use crate::coord::{IntoTupleCoord, TupleCoords};
+use crate::element::{Marker, MarkerShape, PointElement};
 use crate::style::ShapeStyle;
 
 /// The point plot object, which takes an iterator of points in guest coordinate system
@@ -36,6 +38,23 @@ where
     }
 }
 
+#[cfg(any(feature = "nalgebra", feature = "euclid"))]
+impl<'a, I: Iterator, E> PointSeries<'a, <I::Item as IntoTupleCoord>::Tuple, TupleCoords<I>, E>
+where
+    I::Item: IntoTupleCoord,
+    E: PointElement<<I::Item as IntoTupleCoord>::Tuple>,
+{
+    /// Create a new point series from the point types of the geometry libraries,
+    /// such as `nalgebra::Point2` or `euclid::Point2D`
+    pub fn from_points<T: IntoIterator<IntoIter = I>, S: Into<ShapeStyle>>(
+        points: T,
+        size: u32,
+        style: S,
+    ) -> Self {
+        Self::new(TupleCoords::new(points), size, style)
+    }
+}
+
 impl<'a, Coord, I: IntoIterator<Item = Coord>, E> PointSeries<'a, Coord, I, E> {
     /// Create a new point series. Similar to `PointSeries::new` but it doesn't
     /// requires the element implements point trait. So instead of using the point
@@ -54,3 +73,36 @@ impl<'a, Coord, I: IntoIterator<Item = Coord>, E> PointSeries<'a, Coord, I, E> {
         }
     }
 }
+
+impl<'a, Coord, I: IntoIterator<Item = Coord>> PointSeries<'a, Coord, I, Marker<Coord>> {
+    /// Create a new point series drawing each point with a marker of the shape
+    pub fn of_marker<S: Into<ShapeStyle>>(
+        iter: I,
+        size: u32,
+        shape: MarkerShape,
+        style: S,
+    ) -> Self {
+        macro_rules! make_marker {
+            ($($shape:ident),*) => {
+                match shape {
+                    $(MarkerShape::$shape => &|c, s, st| Marker::new(c, s, MarkerShape::$shape, st),)*
+                }
+            };
+        }
+        Self {
+            data_iter: iter.into_iter(),
+            size,
+            style: style.into(),
+            make_point: make_marker!(
+                Circle,
+                Square,
+                Diamond,
+                TriangleUp,
+                TriangleDown,
+                Star,
+                Plus,
+                X
+            ),
+        }
+    }
+}
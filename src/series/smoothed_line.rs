@@ -0,0 +1,95 @@
+use crate::element::{Path, Smoothing};
+use crate::style::ShapeStyle;
+
+/// The smoothed line series, which draws a smooth curve through the points rather than the
+/// straight segments. The curve is computed in pixels after the points are mapped, see
+/// `Smoothing` for the available curves.
+///
+/// ```rust,no_run
+/// use plotters::prelude::*;
+///
+/// let root = BitMapBackend::new("smoothed.png", (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_ranged(0..10, 0..10)
+///     .unwrap();
+/// chart
+///     .draw_series(
+///         SmoothedLineSeries::new(vec![(0, 1), (3, 8), (5, 4), (9, 6)], &RED)
+///             .smoothing(Smoothing::Bezier(0.2)),
+///     )
+///     .unwrap();
+/// ```
+pub struct SmoothedLineSeries<Coord> {
+    points: Option<Vec<Coord>>,
+    style: ShapeStyle,
+    smoothing: Smoothing,
+}
+
+impl<Coord> SmoothedLineSeries<Coord> {
+    /// Create a new smoothed line series, which uses the Catmull-Rom spline with zero tension
+    /// by default
+    pub fn new<I: IntoIterator<Item = Coord>, S: Into<ShapeStyle>>(iter: I, style: S) -> Self {
+        Self {
+            points: Some(iter.into_iter().collect()),
+            style: style.into(),
+            smoothing: Smoothing::CatmullRom(0.0),
+        }
+    }
+
+    /// Set the curve used to smooth the line
+    pub fn smoothing(mut self, smoothing: Smoothing) -> Self {
+        self.smoothing = smoothing;
+        self
+    }
+
+    /// Set the tension of the curve, which is in `[0, 1]`, where 0 is the smoothest curve and
+    /// 1 makes the line straight segments
+    pub fn tension(mut self, tension: f64) -> Self {
+        self.smoothing = match self.smoothing {
+            Smoothing::CatmullRom(_) => Smoothing::CatmullRom(tension),
+            Smoothing::Bezier(_) => Smoothing::Bezier(tension),
+        };
+        self
+    }
+}
+
+impl<Coord> Iterator for SmoothedLineSeries<Coord> {
+    type Item = Path<Coord>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.points.take().map(|points| {
+            let mut path = Path::new(points, self.style.clone());
+            path.set_smoothing(self.smoothing);
+            path
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_smoothed_line_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_path(|_, _, path| {
+                assert!(path.len() > 10);
+                assert!(path.windows(2).all(|w| w[0].0 <= w[1].0));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..10, 0..10)
+            .expect("Create chart");
+
+        chart
+            .draw_series(
+                SmoothedLineSeries::new(vec![(0, 1), (3, 8), (5, 4), (9, 6)], &RED)
+                    .smoothing(Smoothing::Bezier(0.0))
+                    .tension(0.3),
+            )
+            .expect("Drawing Error");
+    }
+}
@@ -0,0 +1,130 @@
+use std::marker::PhantomData;
+use std::ops::Add;
+
+use super::BarColumn;
+use crate::coord::DescreteRanged;
+use crate::element::Rectangle;
+use crate::style::ShapeStyle;
+
+/// The layer of a stacked bar series, which is made of one segment of each bar
+pub(crate) type StackedBarLayer<X, A> = BarColumn<Rectangle<(X, A)>>;
+
+/// The series of vertical bars, where each bar is made of the segments of multiple value
+/// columns stacked on top of each other. Each column is a layer of the series, which has its
+/// own style and label, and it's drawn by `ChartContext::draw_stacked_series` as a separate
+/// series, so each layer has an entry in the series labels.
+///
+/// ```rust,no_run
+/// use plotters::prelude::*;
+///
+/// let root = BitMapBackend::new("stacked.png", (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_ranged(0u32..3u32, 0u32..20u32)
+///     .unwrap();
+/// chart
+///     .draw_stacked_series(
+///         StackedBarSeries::new(vec![(0, vec![3, 4]), (1, vec![5, 2]), (2, vec![6, 8])], 5)
+///             .layer("Apples", &RED)
+///             .layer("Pears", &BLUE),
+///     )
+///     .unwrap();
+/// chart.configure_series_labels().draw().unwrap();
+/// ```
+pub struct StackedBarSeries<BR, A>
+where
+    BR: DescreteRanged,
+    BR::ValueType: Eq + Clone,
+{
+    data: Vec<(BR::ValueType, Vec<A>)>,
+    layers: Vec<(Option<String>, ShapeStyle)>,
+    margin: u32,
+    baseline: A,
+    _p: PhantomData<BR>,
+}
+
+impl<BR, A> StackedBarSeries<BR, A>
+where
+    BR: DescreteRanged,
+    BR::ValueType: Eq + Clone,
+    A: Clone + Default + Add<Output = A>,
+{
+    /// Create a new stacked bar series
+    /// - `iter`: The iterator of the categories and their value columns
+    /// - `margin`: The margin between the bars
+    pub fn new<I: IntoIterator<Item = (BR::ValueType, Vec<A>)>>(iter: I, margin: u32) -> Self {
+        Self {
+            data: iter.into_iter().collect(),
+            layers: vec![],
+            margin,
+            baseline: A::default(),
+            _p: PhantomData,
+        }
+    }
+
+    /// Set the label and the style of the next value column. The columns without a label are
+    /// not shown in the series labels, and they are colored with `Palette99` by default.
+    /// - `label`: The label of the column
+    /// - `style`: The style of the segments of the column
+    pub fn layer<L: Into<String>, S: Into<ShapeStyle>>(mut self, label: L, style: S) -> Self {
+        self.layers.push((Some(label.into()), style.into()));
+        self
+    }
+
+    /// Set the baseline where the bars start
+    pub fn baseline(mut self, baseline: A) -> Self {
+        self.baseline = baseline;
+        self
+    }
+
+    /// Split the series into the layers, the segments of each bar start from where the segment
+    /// of the previous layer ends
+    pub(crate) fn into_layers(self) -> Vec<StackedBarLayer<BR::ValueType, A>> {
+        let mut layers = BarColumn::for_rows(&self.layers, &self.data);
+
+        for (x, values) in self.data {
+            let nx = BR::next_value(&x);
+            let mut bottom = self.baseline.clone();
+            for (layer, value) in layers.iter_mut().zip(values) {
+                let top = bottom.clone() + value;
+                let mut rect = Rectangle::new(
+                    [(x.clone(), top.clone()), (nx.clone(), bottom)],
+                    layer.style.clone(),
+                );
+                rect.set_margin(0, 0, self.margin, self.margin);
+                layer.bars.push(rect);
+                bottom = top;
+            }
+        }
+
+        layers
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::coord::RangedCoordu32;
+    use crate::element::PointCollection;
+
+    #[test]
+    fn test_stacked_layers() {
+        let layers = StackedBarSeries::<RangedCoordu32, u32>::new(
+            vec![(0, vec![3, 4]), (1, vec![5, 2, 1])],
+            0,
+        )
+        .layer("a", &crate::style::RED)
+        .into_layers();
+
+        assert_eq!(layers.len(), 3);
+        assert_eq!(layers[0].label.as_deref(), Some("a"));
+        assert!(layers[1].label.is_none());
+
+        let corners = |layer: usize, bar: usize| -> Vec<(u32, u32)> {
+            layers[layer].bars[bar].point_iter().to_vec()
+        };
+        assert_eq!(corners(0, 0), vec![(0, 3), (1, 0)]);
+        assert_eq!(corners(1, 0), vec![(0, 7), (1, 3)]);
+        assert_eq!(corners(2, 0), vec![(1, 8), (2, 7)]);
+        assert_eq!(layers[2].bars.len(), 1);
+    }
+}
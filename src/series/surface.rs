@@ -0,0 +1,125 @@
+use crate::element::Polygon;
+use crate::style::ShapeStyle;
+
+/// The surface series, which draws the surface `y = f(x, z)` on a 3D chart as a grid of
+/// filled quadrilaterals. The cells are sorted by the depth when the series is drawn with
+/// `ChartContext::draw_series` on a 3D chart, so the near cells cover the far ones.
+///
+/// ```rust,no_run
+/// use plotters::prelude::*;
+///
+/// let root = BitMapBackend::new("surface.png", (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_3d(-3.0..3.0, -1.0..1.0, -3.0..3.0)
+///     .unwrap();
+/// chart.configure_axes().draw().unwrap();
+///
+/// let axis: Vec<f64> = (-30..=30).map(|v| v as f64 / 10.0).collect();
+/// chart
+///     .draw_series(
+///         SurfaceSeries::new(
+///             axis.clone(),
+///             axis,
+///             |x, z| (x * x + z * z).sqrt().cos(),
+///             &BLUE.mix(0.2),
+///         )
+///         .style_func(|y| ViridisColorMap.get((*y + 1.0) / 2.0).filled()),
+///     )
+///     .unwrap();
+/// ```
+pub struct SurfaceSeries<'a, X, Y, Z> {
+    xs: Vec<X>,
+    zs: Vec<Z>,
+    ys: Vec<Y>,
+    style_func: Box<dyn Fn(&Y) -> ShapeStyle + 'a>,
+    idx: usize,
+}
+
+impl<'a, X, Y, Z> SurfaceSeries<'a, X, Y, Z> {
+    /// Create a new surface series
+    /// - `xs`: The values on the X axis of the grid
+    /// - `zs`: The values on the Z axis of the grid
+    /// - `func`: The function computes the height of the surface at `(x, z)`
+    /// - `style`: The style of the cells
+    pub fn new<XI, ZI, F, S>(xs: XI, zs: ZI, func: F, style: S) -> Self
+    where
+        XI: IntoIterator<Item = X>,
+        ZI: IntoIterator<Item = Z>,
+        F: Fn(&X, &Z) -> Y,
+        S: Into<ShapeStyle>,
+    {
+        let xs: Vec<_> = xs.into_iter().collect();
+        let zs: Vec<_> = zs.into_iter().collect();
+        let ys = zs
+            .iter()
+            .flat_map(|z| xs.iter().map(move |x| (x, z)))
+            .map(|(x, z)| func(x, z))
+            .collect();
+        let style = style.into();
+        Self {
+            xs,
+            zs,
+            ys,
+            style_func: Box::new(move |_| style.clone()),
+            idx: 0,
+        }
+    }
+
+    /// Set the function which decides the style of a cell by its height, e.g. to color the
+    /// surface with a colormap. The height of a cell is the value at its corner with the
+    /// smallest index on both axes.
+    pub fn style_func<F: Fn(&Y) -> ShapeStyle + 'a>(mut self, func: F) -> Self {
+        self.style_func = Box::new(func);
+        self
+    }
+}
+
+impl<'a, X: Clone, Y: Clone, Z: Clone> Iterator for SurfaceSeries<'a, X, Y, Z> {
+    type Item = Polygon<(X, Y, Z)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let columns = self.xs.len();
+        if columns < 2 || self.zs.len() < 2 {
+            return None;
+        }
+
+        let (i, j) = (self.idx % (columns - 1), self.idx / (columns - 1));
+        if j + 1 >= self.zs.len() {
+            return None;
+        }
+        self.idx += 1;
+
+        let point = |i: usize, j: usize| {
+            (
+                self.xs[i].clone(),
+                self.ys[j * columns + i].clone(),
+                self.zs[j].clone(),
+            )
+        };
+        Some(Polygon::new(
+            vec![
+                point(i, j),
+                point(i + 1, j),
+                point(i + 1, j + 1),
+                point(i, j + 1),
+            ],
+            (self.style_func)(&self.ys[j * columns + i]),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::element::PointCollection;
+    use crate::style::BLUE;
+
+    #[test]
+    fn test_surface_series() {
+        let cells: Vec<_> =
+            SurfaceSeries::new(vec![0, 1, 2], vec![0, 10], |x, z| x + z, &BLUE).collect();
+        assert_eq!(cells.len(), 2);
+        let points: Vec<_> = (&cells[1]).point_iter().to_vec();
+        assert_eq!(points, vec![(1, 1, 0), (2, 2, 0), (2, 12, 10), (1, 11, 10)]);
+    }
+}
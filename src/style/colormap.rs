@@ -0,0 +1,133 @@
+use super::color::RGBAColor;
+
+/// The colormap, which maps a number in `[0, 1]` to a color. It's used to show a value as a
+/// color, e.g. by a heatmap, a surface or a scatter series with the colored points.
+///
+/// Any function from `f64` to `RGBAColor` is also a colormap.
+pub trait ColorMap {
+    /// Get the color of the value
+    /// - `t`: The value, which is clamped to `[0, 1]`
+    /// - **returns**: The color of the value
+    fn get(&self, t: f64) -> RGBAColor;
+}
+
+/// Clamp the value to `[0, 1]`, and NaN is treated as 0
+fn clamp_unit(t: f64) -> f64 {
+    if t.is_nan() {
+        0.0
+    } else {
+        t.clamp(0.0, 1.0)
+    }
+}
+
+impl<F: Fn(f64) -> RGBAColor> ColorMap for F {
+    fn get(&self, t: f64) -> RGBAColor {
+        self(clamp_unit(t))
+    }
+}
+
+/// Interpolate the evenly spaced color stops linearly
+fn interpolate(stops: &[(u8, u8, u8)], t: f64) -> RGBAColor {
+    let t = clamp_unit(t);
+    let pos = t * (stops.len() - 1) as f64;
+    let idx = (pos.floor() as usize).min(stops.len() - 2);
+    let frac = pos - idx as f64;
+
+    let (a, b) = (stops[idx], stops[idx + 1]);
+    let mix = |a: u8, b: u8| (f64::from(a) + (f64::from(b) - f64::from(a)) * frac).round() as u8;
+    RGBAColor(mix(a.0, b.0), mix(a.1, b.1), mix(a.2, b.2), 1.0)
+}
+
+macro_rules! def_colormap {
+    ($(#[$meta:meta])* $name:ident, [$($stop:expr),*]) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, Default)]
+        pub struct $name;
+
+        impl ColorMap for $name {
+            fn get(&self, t: f64) -> RGBAColor {
+                interpolate(&[$($stop),*], t)
+            }
+        }
+    };
+}
+
+def_colormap!(
+    /// The perceptually uniform colormap from dark blue through green to yellow
+    ViridisColorMap,
+    [
+        (68, 1, 84),
+        (71, 44, 122),
+        (59, 81, 139),
+        (44, 113, 142),
+        (33, 144, 141),
+        (39, 173, 129),
+        (92, 200, 99),
+        (170, 220, 50),
+        (253, 231, 37)
+    ]
+);
+
+def_colormap!(
+    /// The perceptually uniform colormap from black through purple and orange to light yellow
+    MagmaColorMap,
+    [
+        (0, 0, 4),
+        (28, 16, 68),
+        (79, 18, 123),
+        (129, 37, 129),
+        (181, 54, 122),
+        (229, 80, 100),
+        (251, 135, 97),
+        (254, 194, 135),
+        (252, 253, 191)
+    ]
+);
+
+def_colormap!(
+    /// The diverging colormap from blue through light gray to red, which fits the values
+    /// around a meaningful center
+    CoolwarmColorMap,
+    [
+        (59, 76, 192),
+        (98, 130, 234),
+        (141, 176, 254),
+        (184, 208, 249),
+        (221, 221, 221),
+        (245, 196, 173),
+        (244, 154, 123),
+        (222, 96, 77),
+        (180, 4, 38)
+    ]
+);
+
+def_colormap!(
+    /// The colormap from black to white
+    GrayscaleColorMap,
+    [(0, 0, 0), (255, 255, 255)]
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::style::Color;
+
+    #[test]
+    fn test_colormap() {
+        assert_eq!(GrayscaleColorMap.get(0.0).rgb(), (0, 0, 0));
+        assert_eq!(GrayscaleColorMap.get(0.5).rgb(), (128, 128, 128));
+        assert_eq!(GrayscaleColorMap.get(2.0).rgb(), (255, 255, 255));
+        assert_eq!(GrayscaleColorMap.get(f64::NAN).rgb(), (0, 0, 0));
+
+        assert_eq!(ViridisColorMap.get(0.0).rgb(), (68, 1, 84));
+        assert_eq!(ViridisColorMap.get(1.0).rgb(), (253, 231, 37));
+        assert_eq!(CoolwarmColorMap.get(0.5).rgb(), (221, 221, 221));
+        assert_eq!(MagmaColorMap.get(0.0625).rgb(), (14, 8, 36));
+
+        let map = |t: f64| RGBAColor(0, 0, 0, t);
+        assert_eq!(map.get(0.5).alpha(), 0.5);
+        assert_eq!(map.get(1.5).alpha(), 1.0);
+        assert_eq!(map.get(-1.0).alpha(), 0.0);
+        assert_eq!(map.get(f64::NAN).alpha(), 0.0);
+    }
+}
@@ -0,0 +1,21 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static DETERMINISTIC_OUTPUT: AtomicBool = AtomicBool::new(false);
+
+/// Turn on or off the deterministic output mode, which is off by default.
+///
+/// In this mode, the output is byte-stable across runs and platforms, so that the rendered
+/// charts can be compared with the golden files in the snapshot tests:
+/// - The text is measured with the pinned font metrics rather than the fonts installed on the
+///   system, thus the layout doesn't depend on the fonts, see `FontDesc::layout_box`
+/// - The SVG backend rounds the float attributes to 3 decimal places
+///
+/// Note that the glyphs of the bitmap backend are still rasterized with the system fonts.
+pub fn set_deterministic_output(enabled: bool) {
+    DETERMINISTIC_OUTPUT.store(enabled, Ordering::SeqCst);
+}
+
+/// Check if the deterministic output mode is on, see `set_deterministic_output`
+pub fn deterministic_output() -> bool {
+    DETERMINISTIC_OUTPUT.load(Ordering::SeqCst)
+}
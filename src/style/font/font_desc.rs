@@ -1,5 +1,5 @@
 use super::{FontData, FontDataInternal};
-use crate::style::{Color, LayoutBox, TextStyle};
+use crate::style::{deterministic_output, Color, LayoutBox, TextStyle};
 
 use std::convert::From;
 
@@ -12,13 +12,54 @@ pub type FontResult<T> = Result<T, FontError>;
 /// Specifying text transformations
 #[derive(Clone)]
 pub enum FontTransform {
+    /// The text is not transformed
     None,
+    /// Rotate the text 90 degrees clockwise
     Rotate90,
+    /// Rotate the text 180 degrees
     Rotate180,
+    /// Rotate the text 270 degrees clockwise, which makes the text read from bottom to top
     Rotate270,
+    /// Rotate the text clockwise by an arbitrary angle in degrees
+    RotateAngle(f32),
 }
 
 impl FontTransform {
+    /// Get the angle of the clockwise rotation in degrees
+    pub fn angle(&self) -> f64 {
+        match self {
+            FontTransform::None => 0.0,
+            FontTransform::Rotate90 => 90.0,
+            FontTransform::Rotate180 => 180.0,
+            FontTransform::Rotate270 => 270.0,
+            FontTransform::RotateAngle(angle) => f64::from(*angle),
+        }
+    }
+
+    fn rotate(&self, x: f64, y: f64) -> (f64, f64) {
+        let (sin, cos) = self.angle().to_radians().sin_cos();
+        (x * cos - y * sin, x * sin + y * cos)
+    }
+
+    /// Get the bounding box of a `w` by `h` box after the rotation, the top-left corner of the
+    /// box is the origin of the rotation
+    fn rotated_box(&self, w: i32, h: i32) -> ((f64, f64), (f64, f64)) {
+        let (w, h) = (f64::from(w), f64::from(h));
+        let corners = [
+            (0.0, 0.0),
+            self.rotate(w, 0.0),
+            self.rotate(0.0, h),
+            self.rotate(w, h),
+        ];
+        corners.iter().fold(
+            (
+                (f64::INFINITY, f64::INFINITY),
+                (f64::NEG_INFINITY, f64::NEG_INFINITY),
+            ),
+            |((x0, y0), (x1, y1)), &(x, y)| ((x0.min(x), y0.min(y)), (x1.max(x), y1.max(y))),
+        )
+    }
+
     /// Compute the offset of the "top-left" cornor of the text.
     /// "Top-left" defined as the first char's top-left in reading orientation.
     pub fn offset(&self, layout: LayoutBox) -> (i32, i32) {
@@ -27,6 +68,12 @@ impl FontTransform {
             FontTransform::Rotate90 => ((layout.1).1 - (layout.0).1, 0),
             FontTransform::Rotate180 => ((layout.1).0 - (layout.0).0, (layout.1).1 - (layout.0).1),
             FontTransform::Rotate270 => (0, (layout.1).0 - (layout.0).0),
+            FontTransform::RotateAngle(_) => {
+                let w = (layout.1).0 - (layout.0).0;
+                let h = (layout.1).1 - (layout.0).1;
+                let ((x0, y0), _) = self.rotated_box(w, h);
+                (-x0.round() as i32, -y0.round() as i32)
+            }
         }
     }
 
@@ -37,6 +84,10 @@ impl FontTransform {
             FontTransform::Rotate90 => (-y, x),
             FontTransform::Rotate180 => (-x, -y),
             FontTransform::Rotate270 => (y, -x),
+            FontTransform::RotateAngle(_) => {
+                let (x, y) = self.rotate(f64::from(x), f64::from(y));
+                (x.round() as i32, y.round() as i32)
+            }
         }
     }
 }
@@ -126,20 +177,40 @@ impl<'a> FontDesc<'a> {
         self.size
     }
 
-    /// Get the size of the text if rendered in this font
+    /// Get the size of the text if rendered in this font.
+    /// In the deterministic output mode, the pinned metrics are used instead, see
+    /// `pinned_layout_box`.
     pub fn layout_box(&self, text: &str) -> FontResult<((i32, i32), (i32, i32))> {
+        if deterministic_output() {
+            return Ok(self.pinned_layout_box(text));
+        }
         match &self.data {
             Ok(ref font) => font.estimate_layout(self.size, text),
             Err(e) => Err(e.clone()),
         }
     }
 
+    /// Get the layout box of the text with the pinned metrics, which doesn't depend on the
+    /// fonts installed on the system: each char is 0.6 em wide, and the ascent and descent
+    /// are 0.8 em and 0.2 em.
+    pub fn pinned_layout_box(&self, text: &str) -> LayoutBox {
+        let width = (self.size * 0.6 * text.chars().count() as f64).round() as i32;
+        let ascent = (self.size * 0.8).round() as i32;
+        let descent = (self.size * 0.2).round() as i32;
+        ((0, -ascent), (width, descent))
+    }
+
     /// Get the size of the text if rendered in this font.
     /// This is similar to `layout_box` function, but it apply the font transformation
     /// and estimate the overall size of the font
     pub fn box_size(&self, text: &str) -> FontResult<(u32, u32)> {
         let ((min_x, min_y), (max_x, max_y)) = self.layout_box(text)?;
-        let (w, h) = self.get_transform().transform(max_x - min_x, max_y - min_y);
+        let trans = self.get_transform();
+        if let FontTransform::RotateAngle(_) = trans {
+            let ((x0, y0), (x1, y1)) = trans.rotated_box(max_x - min_x, max_y - min_y);
+            return Ok(((x1 - x0).round() as u32, (y1 - y0).round() as u32));
+        }
+        let (w, h) = trans.transform(max_x - min_x, max_y - min_y);
         Ok((w.abs() as u32, h.abs() as u32))
     }
 
@@ -156,3 +227,33 @@ impl<'a> FontDesc<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_pinned_layout_box() {
+        let font: FontDesc = ("sans-serif", 20.0).into();
+        assert_eq!(font.pinned_layout_box("abc"), ((0, -16), (36, 4)));
+        assert_eq!(font.pinned_layout_box(""), ((0, -16), (0, 4)));
+    }
+
+    #[test]
+    fn test_rotate_angle() {
+        let layout = ((0, -16), (36, 4));
+        let trans = FontTransform::RotateAngle(90.0);
+        assert_eq!(trans.offset(layout), FontTransform::Rotate90.offset(layout));
+        assert_eq!(
+            trans.transform(3, 5),
+            FontTransform::Rotate90.transform(3, 5)
+        );
+
+        // The text rotated by 45 degrees spans (w + h) / sqrt(2) in both directions
+        let trans = FontTransform::RotateAngle(45.0);
+        assert_eq!(trans.offset(layout), (14, 0));
+        let ((x0, y0), (x1, y1)) = trans.rotated_box(36, 20);
+        assert!((x1 - x0 - 56.0 / 2f64.sqrt()).abs() < 1e-9);
+        assert!((y1 - y0 - 56.0 / 2f64.sqrt()).abs() < 1e-9);
+    }
+}
@@ -2,11 +2,10 @@ use std::collections::HashMap;
 use std::i32;
 use std::marker::PhantomPinned;
 use std::pin::Pin;
-use std::rc::Rc;
 use std::slice::from_raw_parts;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 
-use rusttype::{point, Error, Font, Scale};
+use rusttype::{point, Error, Font, GlyphId, PositionedGlyph, Scale};
 
 use lazy_static::lazy_static;
 
@@ -20,7 +19,7 @@ type FontResult<T> = Result<T, FontError>;
 pub enum FontError {
     LockError,
     NoSuchFont,
-    FontLoadError(Rc<Error>),
+    FontLoadError(Arc<Error>),
 }
 
 impl std::fmt::Display for FontError {
@@ -89,7 +88,7 @@ fn load_font_data(face: &str) -> FontResult<&'static Font<'static>> {
                 .build();
             if let Some((data, _)) = system_fonts::get(&query) {
                 let font =
-                    OwnedFont::new(data).map_err(|e| FontError::FontLoadError(Rc::new(e)))?;
+                    OwnedFont::new(data).map_err(|e| FontError::FontLoadError(Arc::new(e)))?;
                 cache.insert(face.to_string(), font);
             } else {
                 return Err(FontError::NoSuchFont);
@@ -109,10 +108,79 @@ fn load_font_data(face: &str) -> FontResult<&'static Font<'static>> {
 /// time, thus clear the font cache may cause problem.
 #[allow(dead_code)]
 pub unsafe fn clear_font_cache() -> FontResult<()> {
-    if let Ok(mut cache) = FONT_DATA_CACHE.lock() {
-        *cache = HashMap::new();
+    // The glyphs are keyed by the address of the font, which may be reused by a reloaded font
+    GLYPH_CACHE
+        .lock()
+        .map_err(|_| FontError::LockError)?
+        .clear();
+    let mut cache = FONT_DATA_CACHE.lock().map_err(|_| FontError::LockError)?;
+    *cache = HashMap::new();
+    Ok(())
+}
+
+/// The coverage of a rasterized glyph. The offset is relative to the integer part of the
+/// glyph position, thus it can be reused by any glyph with the same fractional position.
+struct CachedGlyph {
+    offset: (i32, i32),
+    width: usize,
+    coverage: Vec<f32>,
+}
+
+/// The key of a rasterized glyph: font address, size, glyph id and the fractional position
+type GlyphKey = (usize, u32, GlyphId, u32, u32);
+
+/// The maximum number of glyphs kept in the glyph cache, once it's full the cache is flushed
+const GLYPH_CACHE_LIMIT: usize = 4096;
+
+lazy_static! {
+    static ref GLYPH_CACHE: Mutex<HashMap<GlyphKey, Arc<CachedGlyph>>> = Mutex::new(HashMap::new());
+}
+
+/// Rasterize the glyph or get the previous rasterization result from the glyph cache.
+/// Since all the tick labels are rendered with the same font, this saves most of the work
+/// for the label-heavy charts.
+fn rasterize_glyph(
+    font: &'static Font<'static>,
+    size: f32,
+    g: &PositionedGlyph,
+) -> FontResult<Option<Arc<CachedGlyph>>> {
+    let rect = match g.pixel_bounding_box() {
+        Some(rect) => rect,
+        None => return Ok(None),
+    };
+
+    let pos = g.position();
+    let (base_x, base_y) = (pos.x.floor(), pos.y.floor());
+    let key = (
+        font as *const Font<'static> as usize,
+        size.to_bits(),
+        g.id(),
+        (pos.x - base_x).to_bits(),
+        (pos.y - base_y).to_bits(),
+    );
+
+    let mut cache = GLYPH_CACHE.lock().map_err(|_| FontError::LockError)?;
+
+    if let Some(glyph) = cache.get(&key) {
+        return Ok(Some(glyph.clone()));
     }
-    Err(FontError::LockError)
+
+    let width = rect.width() as usize;
+    let mut coverage = vec![0.0; width * rect.height() as usize];
+    g.draw(|x, y, v| coverage[y as usize * width + x as usize] = v);
+
+    let glyph = Arc::new(CachedGlyph {
+        offset: (rect.min.x - base_x as i32, rect.min.y - base_y as i32),
+        width,
+        coverage,
+    });
+
+    if cache.len() >= GLYPH_CACHE_LIMIT {
+        cache.clear();
+    }
+    cache.insert(key, glyph.clone());
+
+    Ok(Some(glyph))
 }
 
 #[derive(Clone)]
@@ -164,22 +232,109 @@ impl FontData for FontDataInternal {
         let base_x = x + trans.offset(layout).0;
         let base_y = y + trans.offset(layout).1;
 
+        if let FontTransform::RotateAngle(_) = trans {
+            return draw_rotated(font, size, text, &trans, layout, (base_x, base_y), draw);
+        }
+
         for g in font.layout(text, scale, point(0.0, 0.0)) {
-            if let Some(rect) = g.pixel_bounding_box() {
-                let x0 = rect.min.x;
-                let y0 = rect.min.y - (layout.0).1;
-                g.draw(|x, y, v| {
-                    let (x, y) = trans.transform(x as i32 + x0, y as i32 + y0);
+            if let Some(glyph) = rasterize_glyph(font, size as f32, &g)? {
+                let pos = g.position();
+                let x0 = pos.x.floor() as i32 + glyph.offset.0;
+                let y0 = pos.y.floor() as i32 + glyph.offset.1 - (layout.0).1;
+                for (idx, &v) in glyph.coverage.iter().enumerate() {
+                    let (x, y) = ((idx % glyph.width) as i32, (idx / glyph.width) as i32);
+                    let (x, y) = trans.transform(x + x0, y + y0);
                     if x + base_x >= 0 && y + base_y >= 0 && result.is_ok() {
                         result = draw(x + base_x, y + base_y, v);
                     }
-                });
+                }
             }
         }
         Ok(result)
     }
 }
 
+/// Draw the text rotated by an arbitrary angle. Mapping the pixels of the glyphs one by one
+/// leaves holes in the rotated text, thus the glyphs are rendered into a buffer first, and
+/// each pixel of the rotated text samples the buffer at the position before the rotation.
+fn draw_rotated<E, DrawFunc: FnMut(i32, i32, f32) -> Result<(), E>>(
+    font: &'static Font<'static>,
+    size: f64,
+    text: &str,
+    trans: &FontTransform,
+    layout: LayoutBox,
+    (base_x, base_y): (i32, i32),
+    mut draw: DrawFunc,
+) -> FontResult<Result<(), E>> {
+    let x_min = (layout.0).0;
+    let width = ((layout.1).0 - (layout.0).0).max(0) as usize + 1;
+    let height = ((layout.1).1 - (layout.0).1).max(0) as usize + 1;
+    let mut buffer = vec![0.0f32; width * height];
+
+    for g in font.layout(text, Scale::uniform(size as f32), point(0.0, 0.0)) {
+        if let Some(glyph) = rasterize_glyph(font, size as f32, &g)? {
+            let pos = g.position();
+            let x0 = pos.x.floor() as i32 + glyph.offset.0 - x_min;
+            let y0 = pos.y.floor() as i32 + glyph.offset.1 - (layout.0).1;
+            for (idx, &v) in glyph.coverage.iter().enumerate() {
+                let x = (idx % glyph.width) as i32 + x0;
+                let y = (idx / glyph.width) as i32 + y0;
+                if x >= 0 && y >= 0 && (x as usize) < width && (y as usize) < height {
+                    let p = &mut buffer[y as usize * width + x as usize];
+                    *p = p.max(v);
+                }
+            }
+        }
+    }
+
+    let sample = |x: i32, y: i32| {
+        if x < 0 || y < 0 || x as usize >= width || y as usize >= height {
+            return 0.0;
+        }
+        f64::from(buffer[y as usize * width + x as usize])
+    };
+
+    let (sin, cos) = trans.angle().to_radians().sin_cos();
+    let (w, h) = (width as i32, height as i32);
+    let corners = [
+        trans.transform(x_min, 0),
+        trans.transform(x_min + w, 0),
+        trans.transform(x_min, h),
+        trans.transform(x_min + w, h),
+    ];
+    let (dx0, dx1) = (
+        corners.iter().map(|c| c.0).min().unwrap_or(0) - 1,
+        corners.iter().map(|c| c.0).max().unwrap_or(0) + 1,
+    );
+    let (dy0, dy1) = (
+        corners.iter().map(|c| c.1).min().unwrap_or(0) - 1,
+        corners.iter().map(|c| c.1).max().unwrap_or(0) + 1,
+    );
+
+    for dy in dy0..=dy1 {
+        for dx in dx0..=dx1 {
+            // Rotate back to the position in the buffer and interpolate the coverage
+            let (dxf, dyf) = (f64::from(dx), f64::from(dy));
+            let sx = dxf * cos + dyf * sin;
+            let sy = -dxf * sin + dyf * cos;
+            let (ix, iy) = (sx.floor() as i32 - x_min, sy.floor() as i32);
+            let (fx, fy) = (sx - sx.floor(), sy - sy.floor());
+            let v = sample(ix, iy) * (1.0 - fx) * (1.0 - fy)
+                + sample(ix + 1, iy) * fx * (1.0 - fy)
+                + sample(ix, iy + 1) * (1.0 - fx) * fy
+                + sample(ix + 1, iy + 1) * fx * fy;
+            let (x, y) = (dx + base_x, dy + base_y);
+            if v > 0.0 && x >= 0 && y >= 0 {
+                if let Err(e) = draw(x, y, v as f32) {
+                    return Ok(Err(e));
+                }
+            }
+        }
+    }
+
+    Ok(Ok(()))
+}
+
 #[cfg(test)]
 mod test {
 
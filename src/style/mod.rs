@@ -2,7 +2,9 @@
   The style for shapes and text, font, color, etc.
 */
 mod color;
+mod colormap;
 pub mod colors;
+mod deterministic;
 mod font;
 mod palette;
 
@@ -12,7 +14,9 @@ mod palette_ext;
 /// Definitions of palettes of accessibility
 pub use self::palette::*;
 pub use color::{Color, HSLColor, PaletteColor, RGBAColor, RGBColor, SimpleColor};
+pub use colormap::{ColorMap, CoolwarmColorMap, GrayscaleColorMap, MagmaColorMap, ViridisColorMap};
 pub use colors::{BLACK, BLUE, CYAN, GREEN, MAGENTA, RED, TRANSPARENT, WHITE, YELLOW};
+pub use deterministic::{deterministic_output, set_deterministic_output};
 pub use font::{FontDesc, FontError, FontResult, FontTransform, IntoFont, LayoutBox};
 
 /// Style of a text
@@ -61,25 +65,45 @@ pub struct ShapeStyle {
     pub color: RGBAColor,
     pub filled: bool,
     pub stroke_width: u32,
+    /// The on/off lengths of the dashes in pixels, the stroke is solid if it's empty
+    pub dash_pattern: Vec<u32>,
+    /// The distance into the dash pattern at which the stroke starts
+    pub dash_offset: u32,
 }
 
 impl ShapeStyle {
     /// Make a filled shape style
     pub fn filled(&self) -> Self {
         Self {
-            color: self.color.to_rgba(),
             filled: true,
-            stroke_width: self.stroke_width,
+            ..self.clone()
         }
     }
 
     pub fn stroke_width(&self, width: u32) -> Self {
         Self {
-            color: self.color.to_rgba(),
-            filled: self.filled,
             stroke_width: width,
+            ..self.clone()
         }
     }
+
+    /// Make a dashed stroke style
+    /// - `pattern`: The alternating lengths of the dashes and the gaps in pixels
+    /// - `offset`: The distance into the pattern at which the stroke starts
+    pub fn dashed(&self, pattern: &[u32], offset: u32) -> Self {
+        Self {
+            dash_pattern: pattern.to_vec(),
+            dash_offset: offset,
+            ..self.clone()
+        }
+    }
+
+    /// Make a dotted stroke style, the dots are as long as the stroke width and separated by
+    /// twice of the stroke width
+    pub fn dotted(&self) -> Self {
+        let width = self.stroke_width.max(1);
+        self.dashed(&[width, width * 2], 0)
+    }
 }
 
 impl<'a, T: Color> From<&'a T> for ShapeStyle {
@@ -88,6 +112,8 @@ impl<'a, T: Color> From<&'a T> for ShapeStyle {
             color: f.to_rgba(),
             filled: false,
             stroke_width: 1,
+            dash_pattern: vec![],
+            dash_offset: 0,
         }
     }
 }